@@ -27,4 +27,4 @@ use regex::Regex;
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
-use iso20022_common::ValidationError;
\ No newline at end of file
+use iso20022_common::{currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
\ No newline at end of file
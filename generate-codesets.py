@@ -0,0 +1,105 @@
+import argparse
+import glob
+import os
+import re
+
+header = '''// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+'''
+
+def rust_string(value: str) -> str:
+    return '"' + value.replace('\\', '\\\\').replace('"', '\\"') + '"'
+
+def read_code_sets(path: str) -> tuple[str, dict]:
+    """Release and codes of the bundled External Code Sets, as (code, name, definition) by code set."""
+    release = None
+    code_sets = {}
+    with open(path, encoding='utf-8') as f:
+        for number, line in enumerate(f, 1):
+            line = line.rstrip('\n')
+            if line.startswith('#release\t'):
+                release = line.split('\t', 1)[1]
+                continue
+            if not line or line.startswith('#'):
+                continue
+            fields = line.split('\t')
+            if len(fields) != 4:
+                raise ValueError(f'{path}:{number}: expected code set, code, name and definition')
+            code_set, code, name, definition = fields
+            code_sets.setdefault(code_set, {})[code] = (name, definition)
+    if release is None:
+        raise ValueError(f'{path}: missing #release line')
+    return release, code_sets
+
+def code_set(name: str, codes: dict) -> str:
+    """ExternalCodeSet entry of a code set, its codes sorted for binary search."""
+    lines = [f'\tExternalCodeSet {{ name: {rust_string(name)}, codes: &[\n']
+    for code in sorted(codes):
+        code_name, definition = codes[code]
+        lines.append(
+            f'\t\tExternalCode {{ code: {rust_string(code)}, name: {rust_string(code_name)}, '
+            f'definition: {rust_string(definition)} }},\n'
+        )
+    lines.append('\t] },\n')
+    return ''.join(lines)
+
+def schema_code_sets(directory: str) -> set:
+    """Names of the External Code Sets typed in the schemas below the directory."""
+    names = set()
+    for path in glob.glob(os.path.join(directory, '**', '*.xsd'), recursive=True):
+        with open(path, encoding='utf-8') as f:
+            names.update(re.findall(r'simpleType name="(External\w+Code)"', f.read()))
+    return names
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate the External Code Sets table of iso20022_common::external'
+    )
+    parser.add_argument('code_sets', help='Tab separated code set file, e.g. codesets/ExternalCodeSets.tsv')
+    parser.add_argument('output', help='File to write, e.g. iso20022-common/src/external_code_sets.rs')
+    parser.add_argument('--schemas', help='Schema directory, e.g. xsd/iso20022, to list the code sets it types that are not bundled')
+    args = parser.parse_args()
+
+    release, code_sets = read_code_sets(args.code_sets)
+    with open(args.output, 'w', encoding='utf-8') as f:
+        f.write(header)
+        f.write('// Generated by generate-codesets.py from codesets/ExternalCodeSets.tsv, see external.rs.\n\n')
+        f.write('/// Release of the External Code Sets the bundled codes were taken from.\n')
+        f.write(f'pub const RELEASE: &str = {rust_string(release)};\n\n')
+        f.write('static CODE_SETS: &[ExternalCodeSet] = &[\n')
+        f.write(''.join(code_set(name, code_sets[name]) for name in sorted(code_sets)))
+        f.write('];\n')
+
+    count = sum(len(codes) for codes in code_sets.values())
+    print(f"Wrote {count} codes of {len(code_sets)} code sets to {args.output}")
+    if args.schemas:
+        missing = sorted(schema_code_sets(args.schemas) - code_sets.keys())
+        print(f"{len(missing)} code sets of {args.schemas} are not bundled and pass unchecked:")
+        for name in missing:
+            print(f"  {name}")
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
@@ -29,6 +29,7 @@ find "$output_directory" -type f -name "*.rs" | while read -r file; do
     mv "$file" "$dir/$base_name.rs"  # Append the .rs extension correctly
 done
 
+python3 generate-validation.py $output_directory $input_directory
 python3 generate-common.py $output_directory
 
 lib_file=$output_directory"/lib.rs"
@@ -59,8 +60,13 @@ echo '// Open Payment Message Parsing Library
 
 ' > "$lib_file"
 
+# Hand-written modules kept next to the generated ones (rules.rs, stream.rs, header.rs) are
+# declared too, stream behind the feature that pulls in quick-xml
 for f in `find $output_directory -iname "*.rs" -type f -print | sort -n`; do
     module_name=$(basename "$f" .rs)
+    if [ "$module_name" = "stream" ]; then
+        echo '#[cfg(feature = "streaming")]' >> "$lib_file"
+    fi
     if [ "$module_name" != "lib" ] && [ "$module_name" != "common" ]; then
         echo "pub mod $module_name;" >> "$lib_file"
     fi
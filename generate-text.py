@@ -0,0 +1,96 @@
+import re
+import xml.etree.ElementTree as ET
+from pathlib import Path
+import argparse
+
+XS = '{http://www.w3.org/2001/XMLSchema}'
+
+header = '''// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+'''
+
+# Facets a text type is built from, any enumeration makes it a code list instead
+text_facets = {'length', 'minLength', 'maxLength', 'pattern'}
+
+def scan_text_types(directory: str) -> dict:
+    """Facets of every xs:string based simple type constrained by length or pattern, by name."""
+    types = {}
+    for xsd in sorted(Path(directory).rglob('*.xsd')):
+        root = ET.parse(xsd).getroot()
+        for simple in root.iter(XS + 'simpleType'):
+            restriction = simple.find(XS + 'restriction')
+            if not simple.get('name') or restriction is None or restriction.get('base') != 'xs:string':
+                continue
+            facets = {facet.tag[len(XS):]: facet.get('value') for facet in restriction}
+            if facets and set(facets) <= text_facets:
+                types.setdefault(simple.get('name'), facets)
+    return types
+
+def rust_string(value: str) -> str:
+    return '"' + value.replace('\\', '\\\\').replace('"', '\\"') + '"'
+
+def text_type(name: str, facets: dict) -> str:
+    """Doc comment and text_types! entry of a type."""
+    min_length = facets.get('length', facets.get('minLength'))
+    max_length = facets.get('length', facets.get('maxLength'))
+    docs = []
+    if min_length and min_length == max_length:
+        docs.append(f'exactly {min_length} characters')
+    elif min_length and max_length:
+        docs.append(f'{min_length} to {max_length} characters')
+    elif max_length:
+        docs.append(f'at most {max_length} characters')
+    elif min_length:
+        docs.append(f'at least {min_length} characters')
+    if 'pattern' in facets:
+        docs.append(f'matching `{facets["pattern"]}`')
+    doc = ', '.join(docs)
+    pattern = f'Some({rust_string(facets["pattern"])})' if 'pattern' in facets else 'None'
+    return (
+        f'\t/// `{name}`: {doc}.\n'
+        f'\t{name}({min_length or 0}, {max_length or "usize::MAX"}, {pattern});\n'
+    )
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate the constrained text types of iso20022_common::text from the XSDs'
+    )
+    parser.add_argument('schemas', help='Directory searched for .xsd files, e.g. xsd/iso20022')
+    parser.add_argument('output', help='File to write, e.g. iso20022-common/src/text_types.rs')
+    args = parser.parse_args()
+
+    types = scan_text_types(args.schemas)
+    with open(args.output, 'w', encoding='utf-8') as f:
+        f.write(header)
+        f.write('// Generated by generate-text.py from the XSDs, see text.rs.\n\n')
+        f.write('text_types! {\n')
+        f.write(''.join(text_type(name, types[name]) for name in sorted(types)))
+        f.write('}\n')
+
+    print(f"Wrote {len(types)} text types to {args.output}")
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
@@ -0,0 +1,421 @@
+import os
+import re
+import xml.etree.ElementTree as ET
+from pathlib import Path
+import argparse
+
+XS = '{http://www.w3.org/2001/XMLSchema}'
+
+# Fail-fast validate() as emitted by xgen
+validate_pattern = re.compile(
+    r'\tpub fn validate\(&self\) -> Result<\(\), ValidationError> \{\n'
+    r'(?!\t\tself\.validate_with\()'
+    r'(.*?)'
+    r'\t\tOk\(\(\)\)\n\t\}\n',
+    re.DOTALL
+)
+
+imports = {
+    'use iso20022_common::{common::*, ValidationError};':
+        'use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};',
+    'use iso20022_common::ValidationError;':
+        'use iso20022_common::{currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};',
+}
+
+struct_pattern = re.compile(r'\npub struct (\w+) \{\n(.*?)\n\}\n', re.DOTALL)
+field_pattern = re.compile(r'serde\(rename = "([^"]*)"[^\n]*\n\tpub (\w+):')
+# xgen maps every xs:decimal based type to f64
+decimal_field_pattern = re.compile(
+    r'serde\(rename = "([^"]*)"[^\n]*\n\tpub (\w+): (Option<Vec<|Option<|Vec<|)f64(>*),'
+)
+# Accepted codes of the `Ccy` attribute of an amount, by its type
+currency_codes = {
+    'ActiveCurrencyCode': 'CurrencyCodes::Active',
+    'ActiveOrHistoricCurrencyCode': 'CurrencyCodes::ActiveOrHistoric',
+    'CurrencyCode': 'CurrencyCodes::ActiveOrHistoric',
+}
+# Identifier types with a semantic check in iso20022_common::identifier, run on request of the validator
+identifier_schemes = {
+    'IBAN2007Identifier': 'IdentifierScheme::Iban',
+    'BICFIDec2014Identifier': 'IdentifierScheme::Bic',
+    'BICFIIdentifier': 'IdentifierScheme::Bic',
+    'AnyBICDec2014Identifier': 'IdentifierScheme::Bic',
+    'AnyBICIdentifier': 'IdentifierScheme::Bic',
+    'LEIIdentifier': 'IdentifierScheme::Lei',
+    'UUIDv4Identifier': 'IdentifierScheme::Uetr',
+}
+# Codes of the External Code Sets, checked on request of the validator against those bundled in iso20022_common::external
+external_code_set = re.compile(r'External\w+Code$')
+# Aliases in iso20022_common of the date and time types, typed with its `typed_dates` feature
+date_aliases = {'xs:date': 'ISODate', 'xs:dateTime': 'ISODateTime', 'xs:time': 'ISOTime'}
+# Facets of the text types of iso20022_common::text, typed with its `typed_text` feature,
+# as listed by generate-text.py
+text_facets = {'length', 'minLength', 'maxLength', 'pattern'}
+# xgen maps both to String
+string_field_pattern = re.compile(
+    r'(serde\(rename = "([^"]*)"[^\n]*\n\tpub (\w+): (Option<Vec<|Option<|Vec<|))String(>*,)'
+)
+impl_pattern = re.compile(r'\nimpl (\w+) \{\n')
+
+def scan_fields(content: str) -> dict:
+    """Map every struct to the XML names of its fields, taken from the serde renames."""
+    structs = {}
+    for match in struct_pattern.finditer(content):
+        fields = {}
+        for name, field in field_pattern.findall(match.group(2)):
+            # `$value` / `$text` content has no element of its own
+            fields[field] = '' if name.startswith('$') else name
+        structs[match.group(1)] = fields
+    return structs
+
+def scan_schemas(directory: str) -> tuple[dict, dict, dict, set]:
+    """
+    Read the facets of the xs:decimal based simple types, the date and time alias of the
+    xs:date, xs:dateTime and xs:time based ones, the names of the text types, and the type
+    of every element, attribute and simple content of the complex types, keyed by their
+    serde rename.
+    """
+    restrictions = {}
+    complex_types = {}
+    for xsd in sorted(Path(directory).glob('*.xsd')):
+        root = ET.parse(xsd).getroot()
+        for simple in root.iter(XS + 'simpleType'):
+            restriction = simple.find(XS + 'restriction')
+            if simple.get('name') and restriction is not None:
+                facets = {facet.tag[len(XS):]: facet.get('value') for facet in restriction}
+                restrictions.setdefault(simple.get('name'), (restriction.get('base'), facets))
+        for complex_type in root.iter(XS + 'complexType'):
+            members = {}
+            for element in complex_type.iter(XS + 'element'):
+                members[element.get('name')] = element.get('type')
+            for attribute in complex_type.iter(XS + 'attribute'):
+                members['@' + attribute.get('name')] = attribute.get('type')
+            for content in complex_type.iter(XS + 'simpleContent'):
+                for derivation in content:
+                    members['$value'] = derivation.get('base')
+            if complex_type.get('name'):
+                complex_types.setdefault(complex_type.get('name'), members)
+
+    def resolve(name: str, seen: tuple = ()) -> dict | None:
+        # Facets of a decimal type, inherited along its restriction chain
+        if name == 'xs:decimal':
+            return {}
+        if name not in restrictions or name in seen:
+            return None
+        base, facets = restrictions[name]
+        inherited = resolve(base, seen + (name,))
+        return None if inherited is None else {**inherited, **facets}
+
+    def resolve_date(name: str, seen: tuple = ()) -> str | None:
+        if name in date_aliases:
+            return date_aliases[name]
+        if name not in restrictions or name in seen:
+            return None
+        return resolve_date(restrictions[name][0], seen + (name,))
+
+    simple_types = {}
+    date_types = {}
+    text_types = set()
+    for name, (base, facets) in restrictions.items():
+        if base == 'xs:string' and facets and set(facets) <= text_facets:
+            text_types.add(name)
+        facets = resolve(name)
+        if facets is not None:
+            simple_types[name] = facets
+        alias = resolve_date(name)
+        if alias is not None:
+            date_types[name] = alias
+    return complex_types, simple_types, date_types, text_types
+
+def decimal_literal(value: str) -> str:
+    """Decimal::new() expression of an xs:decimal facet value."""
+    sign = '-' if value.startswith('-') else ''
+    integer, _, fraction = value.lstrip('+-').partition('.')
+    mantissa = (integer + fraction).lstrip('0') or '0'
+    return f'Decimal::new({sign}{mantissa}, {len(fraction)})'
+
+def decimal_checks(field: str, element: str, wrapper: str, facets: dict) -> str:
+    """Checks of the digit and bound facets of a decimal field."""
+    # Plain fields are checked in place, wrapped ones through `val`
+    target, value, reference = ('val', '*val', 'val') if wrapper else (f'self.{field}', f'self.{field}', f'&self.{field}')
+    checks = []
+    def check(condition: str, error: str):
+        checks.append(
+            f'if {condition} {{\n'
+            f'\tvalidator.report_at("{element}", "{field}", ValidationError::{error})?;\n'
+            f'}}\n'
+        )
+    if 'totalDigits' in facets:
+        check(f'{target}.total_digits() > {facets["totalDigits"]}', f'total_digits("{field}", {facets["totalDigits"]}, {reference})')
+    if 'fractionDigits' in facets:
+        check(f'{target}.fraction_digits() > {facets["fractionDigits"]}', f'fraction_digits("{field}", {facets["fractionDigits"]}, {reference})')
+    for facet, op, kind in (('minInclusive', '<', 'min_inclusive'), ('maxInclusive', '>', 'max_inclusive'),
+                            ('minExclusive', '<=', 'min_exclusive'), ('maxExclusive', '>=', 'max_exclusive')):
+        if facet in facets:
+            bound = decimal_literal(facets[facet])
+            check(f'{value} {op} {bound}', f'{kind}("{field}", {bound}, {reference})')
+    if not checks:
+        return ''
+
+    if wrapper == 'Option<Vec<':
+        head, tail = [f'if let Some(ref vec) = self.{field} {{', '\tfor val in vec {'], ['\t}', '}']
+    elif wrapper == 'Option<':
+        head, tail = [f'if let Some(ref val) = self.{field} {{'], ['}']
+    elif wrapper == 'Vec<':
+        head, tail = [f'for val in &self.{field} {{'], ['}']
+    else:
+        head, tail = [], []
+    lines = head + ['\t' * len(head) + line for line in ''.join(checks).splitlines()] + tail
+    return ''.join('\t\t' + line + '\n' for line in lines)
+
+def scan_decimals(content: str, schema: tuple | None) -> dict:
+    """
+    Map every struct to the facet checks of its decimal fields, followed for currency
+    amounts by the check of the `Ccy` attribute and the minor units of the currency.
+    """
+    complex_types, simple_types, _, _ = schema if schema else ({}, {}, {}, set())
+    structs = {}
+    for match in struct_pattern.finditer(content):
+        members = complex_types.get(match.group(1), {})
+        checks = ''
+        decimals = {}
+        for name, field, wrapper, _ in decimal_field_pattern.findall(match.group(2)):
+            facets = simple_types.get(members.get(name), {})
+            element = '' if name.startswith('$') else name
+            checks += decimal_checks(field, element, wrapper, facets)
+            decimals[name] = (field, wrapper)
+        fields = dict(field_pattern.findall(match.group(2)))
+        if members.get('@Ccy') in currency_codes and fields.get('@Ccy') == 'ccy' and decimals.get('$value') == ('value', ''):
+            checks += f'\t\tcurrency::validate_amount(validator, &self.ccy, &self.value, {currency_codes[members["@Ccy"]]})?;\n'
+        structs[match.group(1)] = checks
+    return structs
+
+def date_check(field: str, element: str, wrapper: str) -> str:
+    """Validation of a date or time field, compiled in with the `typed_dates` feature only."""
+    if wrapper == 'Option<Vec<':
+        check = (f'if let Some(ref vec) = self.{field} {{ for (i, item) in vec.iter().enumerate() {{ '
+                 f'validator.item("{element}", "{field}", i, |validator| item.validate_with(validator))? }} }}')
+    elif wrapper == 'Option<':
+        check = (f'if let Some(ref val) = self.{field} {{ '
+                 f'validator.element("{element}", "{field}", |validator| val.validate_with(validator))? }}')
+    elif wrapper == 'Vec<':
+        check = (f'for (i, item) in self.{field}.iter().enumerate() {{ '
+                 f'validator.item("{element}", "{field}", i, |validator| item.validate_with(validator))? }}')
+    else:
+        check = f'validator.element("{element}", "{field}", |validator| self.{field}.validate_with(validator))?;'
+    return '\t\t#[cfg(feature = "typed_dates")]\n\t\t' + check + '\n'
+
+def value_check(field: str, element: str, wrapper: str, function: str, argument: str) -> str:
+    """
+    Check of a field by `function(validator, element, field, argument, value)`, such as the
+    semantic check of an IBAN or the check of a code against its External Code Set.
+    """
+    if wrapper == 'Option<Vec<':
+        check = (f'if let Some(ref vec) = self.{field} {{ for (i, item) in vec.iter().enumerate() {{ '
+                 f'validator.item("{element}", "{field}", i, |validator| {function}(validator, "", "", {argument}, item))? }} }}')
+    elif wrapper == 'Option<':
+        check = f'if let Some(ref val) = self.{field} {{ {function}(validator, "{element}", "{field}", {argument}, val)? }}'
+    elif wrapper == 'Vec<':
+        check = (f'for (i, item) in self.{field}.iter().enumerate() {{ '
+                 f'validator.item("{element}", "{field}", i, |validator| {function}(validator, "", "", {argument}, item))? }}')
+    else:
+        check = f'{function}(validator, "{element}", "{field}", {argument}, &self.{field})?;'
+    return '\t\t' + check + '\n'
+
+def scan_typed_fields(content: str, schema: tuple | None) -> dict:
+    """
+    Map every struct to its date, time and text fields, as (rename, field, type, wrapper),
+    the type being the alias or text type of iso20022_common the field is declared with.
+    """
+    complex_types, _, date_types, text_types = schema if schema else ({}, {}, {}, set())
+    structs = {}
+    for match in struct_pattern.finditer(content):
+        members = complex_types.get(match.group(1), {})
+        fields = []
+        for _, name, field, wrapper, _ in string_field_pattern.findall(match.group(2)):
+            member = members.get(name)
+            typed = date_types.get(member) or date_aliases.get(member) or (member if member in text_types else None)
+            if typed:
+                fields.append((name, field, typed, wrapper))
+        structs[match.group(1)] = fields
+    return structs
+
+def rewrite_body(body: str, fields: dict) -> str:
+    """
+    Route every violation and nested validation through the validator, so the same
+    body serves both the fail-fast and the accumulating walk, and let it know which
+    element is being validated so errors carry their paths.
+    """
+    def element(field: str) -> str:
+        return fields.get(field, field)
+
+    def report(field: str, error: str) -> str:
+        if field in fields:
+            return f'validator.report_at("{element(field)}", "{field}", {error})?;'
+        return f'validator.report({error})?;'
+
+    # Length facets report the bound and the actual length
+    for op, code, kind in (('<', 1001, 'min_length'), ('>', 1002, 'max_length')):
+        body = re.sub(
+            r'if (\S+)\.chars\(\)\.count\(\) ' + op + r' (\d+) \{\n(\t+)'
+            r'return Err\(ValidationError::new\(' + str(code) + r', "(\w+) [^"]*"\.to_string\(\)\)\);',
+            lambda m: f'if {m.group(1)}.chars().count() {op} {m.group(2)} {{\n{m.group(3)}'
+                      + report(m.group(4), f'ValidationError::{kind}("{m.group(4)}", {m.group(2)}, {m.group(1)}.chars().count())'),
+            body
+        )
+    # Pattern facets are compiled once into a static per checked field, matched against
+    # the whole value as XSD requires, and report the pattern and the offending value
+    body = re.sub(
+        r'let pattern = Regex::new\("(.*?)"\)\.unwrap\(\);\n(\t+)if !pattern\.is_match\((.*?)\) \{\n(\t+)'
+        r'return Err\(ValidationError::new\(1005, "(\w+) [^"]*"\.to_string\(\)\)\);',
+        lambda m: f'static PATTERN_{m.group(5).upper()}: Pattern = Pattern::new("{m.group(1)}");\n'
+                  f'{m.group(2)}if !PATTERN_{m.group(5).upper()}.is_match({m.group(3)}) {{\n{m.group(4)}'
+                  + report(m.group(5), f'ValidationError::pattern("{m.group(5)}", PATTERN_{m.group(5).upper()}.as_str(), {m.group(3)})'),
+        body
+    )
+    body = re.sub(
+        r'return Err\((ValidationError::new\(\d+, "(\w+) .*?\))\);',
+        lambda m: report(m.group(2), m.group(1)),
+        body
+    )
+    body = re.sub(
+        r'return Err\((ValidationError::new\(.*?\))\);',
+        r'validator.report(\1)?;',
+        body
+    )
+    body = re.sub(
+        r'if let Some\(ref vec\) = self\.(\w+) \{ for item in vec \{ item\.validate\(\)\? \} \}',
+        lambda m: f'if let Some(ref vec) = self.{m.group(1)} {{ for (i, item) in vec.iter().enumerate() {{ '
+                  f'validator.item("{element(m.group(1))}", "{m.group(1)}", i, |validator| item.validate_with(validator))? }} }}',
+        body
+    )
+    body = re.sub(
+        r'for item in &self\.(\w+) \{ item\.validate\(\)\? \}',
+        lambda m: f'for (i, item) in self.{m.group(1)}.iter().enumerate() {{ '
+                  f'validator.item("{element(m.group(1))}", "{m.group(1)}", i, |validator| item.validate_with(validator))? }}',
+        body
+    )
+    body = re.sub(
+        r'if let Some\(ref val\) = self\.(\w+) \{ val\.validate\(\)\? \}',
+        lambda m: f'if let Some(ref val) = self.{m.group(1)} {{ '
+                  f'validator.element("{element(m.group(1))}", "{m.group(1)}", |validator| val.validate_with(validator))? }}',
+        body
+    )
+    body = re.sub(
+        r'self\.(\w+)\.validate\(\)\?;',
+        lambda m: f'validator.element("{element(m.group(1))}", "{m.group(1)}", |validator| self.{m.group(1)}.validate_with(validator))?;',
+        body
+    )
+    body = body.replace('.validate()?', '.validate_with(validator)?')
+    return body
+
+def rewrite_validate(match: re.Match, fields: dict, decimals: str) -> str:
+    body = rewrite_body(match.group(1), fields) + decimals
+    # Types without any check leave the validator unused, as do those only checking
+    # dates and times without the `typed_dates` feature
+    parameter = 'validator' if 'validator' in body else '_validator'
+    ungated = re.sub(r'\t\t#\[cfg\(feature = "typed_dates"\)\]\n[^\n]*\n', '', body)
+    allow = '' if parameter == '_validator' or 'validator' in ungated else \
+        '\t#[cfg_attr(not(feature = "typed_dates"), allow(unused_variables))]\n'
+    return (
+        '\tpub fn validate(&self) -> Result<(), ValidationError> {\n'
+        '\t\tself.validate_with(&mut Validator::fail_fast())\n'
+        '\t}\n'
+        '\n'
+        '\tpub fn validate_all(&self) -> ValidationReport {\n'
+        '\t\tValidationReport::collect(|validator| self.validate_with(validator))\n'
+        '\t}\n'
+        '\n'
+        f'{allow}'
+        f'\tpub fn validate_with(&self, {parameter}: &mut Validator) -> Result<(), ValidationError> {{\n'
+        f'{body}'
+        '\t\tOk(())\n'
+        '\t}\n'
+    )
+
+def process_file(file_path: Path, schema: tuple | None) -> bool:
+    """Rewrite the validators of a single file, returns whether it changed."""
+    with open(file_path, 'r', encoding='utf-8') as f:
+        content = f.read()
+
+    structs = scan_fields(content)
+    decimals = scan_decimals(content, schema)
+    typed_fields = scan_typed_fields(content, schema)
+
+    def rewrite(match: re.Match) -> str:
+        # Fields of the type whose impl block holds this validate()
+        impls = impl_pattern.findall(content, 0, match.start())
+        name = impls[-1] if impls else None
+        checks = decimals.get(name, '')
+        for rename, field, typed, wrapper in typed_fields.get(name, []):
+            element = '' if rename.startswith('$') else rename
+            if typed in date_aliases.values():
+                checks += date_check(field, element, wrapper)
+            elif typed in identifier_schemes:
+                checks += value_check(field, element, wrapper, 'identifier::validate', identifier_schemes[typed])
+            elif external_code_set.match(typed):
+                checks += value_check(field, element, wrapper, 'external::validate', f'"{typed}"')
+        return rewrite_validate(match, structs.get(name, {}), checks)
+
+    def retype_fields(match: re.Match) -> str:
+        types = {field: typed for _, field, typed, _ in typed_fields.get(match.group(1), [])}
+        body = string_field_pattern.sub(
+            lambda m: m.group(1) + types[m.group(3)] + m.group(5) if m.group(3) in types else m.group(0),
+            match.group(2)
+        )
+        return match.group(0).replace(match.group(2), body)
+
+    new_content = validate_pattern.sub(rewrite, content)
+    # Date, time and text fields are declared with the types of iso20022_common, which are
+    # Strings unless its `typed_dates` or `typed_text` feature is enabled
+    new_content = struct_pattern.sub(retype_fields, new_content)
+    # Decimals are held exactly, so that `100.10` is written back as `100.10`
+    new_content = re.sub(
+        r'(?m)^(\tpub \w+: (?:Option<Vec<|Option<|Vec<|))f64(>*,)$',
+        r'\1Decimal\2',
+        new_content
+    )
+    if new_content != content:
+        for old, new in imports.items():
+            new_content = new_content.replace(old, new)
+
+    if new_content != content:
+        with open(file_path, 'w', encoding='utf-8') as f:
+            f.write(new_content)
+        return True
+    return False
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Add accumulating validation (validate_all / validate_with) to generated types'
+    )
+    parser.add_argument('directory',
+                       help='Directory containing .rs files (default: current directory)',
+                       default='.',
+                       nargs='?')
+
+    parser.add_argument('schemas',
+                       help='Directory containing the .xsd files the types were generated from, for the decimal facets',
+                       default=None,
+                       nargs='?')
+
+    args = parser.parse_args()
+
+    schema = scan_schemas(args.schemas) if args.schemas else None
+    dir_path = Path(args.directory).resolve()
+    rust_files = sorted(f for f in os.listdir(dir_path) if f.endswith('.rs'))
+
+    changed = 0
+    for filename in rust_files:
+        try:
+            if process_file(dir_path / filename, schema):
+                changed += 1
+        except Exception as e:
+            print(f"Error processing {dir_path / filename}: {e}")
+            return 1
+
+    print(f"Rewrote validators in {changed} files")
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
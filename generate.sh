@@ -23,6 +23,8 @@ mv iso20022-reda/src/common.rs iso20022-common/src/common-reda.rs
 mv iso20022-remt/src/common.rs iso20022-common/src/common-remt.rs
 cp common.rs iso20022-common/src
 python3 generate-common.py iso20022-common/src 0
+python3 generate-text.py xsd/iso20022 iso20022-common/src/text_types.rs
+python3 generate-codesets.py codesets/ExternalCodeSets.tsv iso20022-common/src/external_code_sets.rs --schemas xsd/iso20022
 rm iso20022-common/src/common-acmt.rs
 rm iso20022-common/src/common-admi.rs
 rm iso20022-common/src/common-auth.rs
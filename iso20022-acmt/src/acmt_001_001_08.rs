@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -72,22 +72,30 @@ pub struct AccountOpeningInstructionV08 {
 
 impl AccountOpeningInstructionV08 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.ordr_ref { val.validate()? }
-		if let Some(ref val) = self.prvs_ref { val.validate()? }
-		self.instr_dtls.validate()?;
-		self.invstmt_acct.validate()?;
-		self.acct_pties.validate()?;
-		if let Some(ref vec) = self.intrmies { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.plcmnt { val.validate()? }
-		if let Some(ref val) = self.new_isse_allcn { val.validate()? }
-		if let Some(ref vec) = self.svgs_invstmt_plan { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.wdrwl_invstmt_plan { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.csh_sttlm { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.svc_lvl_agrmt { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.addtl_inf { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.mkt_prctc_vrsn { val.validate()? }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.ordr_ref { validator.element("OrdrRef", "ordr_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.prvs_ref { validator.element("PrvsRef", "prvs_ref", |validator| val.validate_with(validator))? }
+		validator.element("InstrDtls", "instr_dtls", |validator| self.instr_dtls.validate_with(validator))?;
+		validator.element("InvstmtAcct", "invstmt_acct", |validator| self.invstmt_acct.validate_with(validator))?;
+		validator.element("AcctPties", "acct_pties", |validator| self.acct_pties.validate_with(validator))?;
+		if let Some(ref vec) = self.intrmies { for (i, item) in vec.iter().enumerate() { validator.item("Intrmies", "intrmies", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.plcmnt { validator.element("Plcmnt", "plcmnt", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.new_isse_allcn { validator.element("NewIsseAllcn", "new_isse_allcn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.svgs_invstmt_plan { for (i, item) in vec.iter().enumerate() { validator.item("SvgsInvstmtPlan", "svgs_invstmt_plan", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.wdrwl_invstmt_plan { for (i, item) in vec.iter().enumerate() { validator.item("WdrwlInvstmtPlan", "wdrwl_invstmt_plan", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.csh_sttlm { for (i, item) in vec.iter().enumerate() { validator.item("CshSttlm", "csh_sttlm", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.svc_lvl_agrmt { for (i, item) in vec.iter().enumerate() { validator.item("SvcLvlAgrmt", "svc_lvl_agrmt", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.addtl_inf { for (i, item) in vec.iter().enumerate() { validator.item("AddtlInf", "addtl_inf", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.mkt_prctc_vrsn { validator.element("MktPrctcVrsn", "mkt_prctc_vrsn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -72,22 +72,30 @@ pub struct AccountModificationInstructionV08 {
 
 impl AccountModificationInstructionV08 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.prvs_ref { val.validate()? }
-		if let Some(ref val) = self.instr_dtls { val.validate()? }
-		self.invstmt_acct_selctn.validate()?;
-		if let Some(ref val) = self.modfd_invstmt_acct { val.validate()? }
-		if let Some(ref vec) = self.modfd_acct_pties { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.modfd_intrmies { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.modfd_plcmnt { val.validate()? }
-		if let Some(ref val) = self.modfd_isse_allcn { val.validate()? }
-		if let Some(ref vec) = self.modfd_svgs_invstmt_plan { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.modfd_wdrwl_invstmt_plan { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.modfd_csh_sttlm { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.modfd_svc_lvl_agrmt { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.modfd_addtl_inf { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.mkt_prctc_vrsn { val.validate()? }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.prvs_ref { validator.element("PrvsRef", "prvs_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.instr_dtls { validator.element("InstrDtls", "instr_dtls", |validator| val.validate_with(validator))? }
+		validator.element("InvstmtAcctSelctn", "invstmt_acct_selctn", |validator| self.invstmt_acct_selctn.validate_with(validator))?;
+		if let Some(ref val) = self.modfd_invstmt_acct { validator.element("ModfdInvstmtAcct", "modfd_invstmt_acct", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.modfd_acct_pties { for (i, item) in vec.iter().enumerate() { validator.item("ModfdAcctPties", "modfd_acct_pties", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.modfd_intrmies { for (i, item) in vec.iter().enumerate() { validator.item("ModfdIntrmies", "modfd_intrmies", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.modfd_plcmnt { validator.element("ModfdPlcmnt", "modfd_plcmnt", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.modfd_isse_allcn { validator.element("ModfdIsseAllcn", "modfd_isse_allcn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.modfd_svgs_invstmt_plan { for (i, item) in vec.iter().enumerate() { validator.item("ModfdSvgsInvstmtPlan", "modfd_svgs_invstmt_plan", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.modfd_wdrwl_invstmt_plan { for (i, item) in vec.iter().enumerate() { validator.item("ModfdWdrwlInvstmtPlan", "modfd_wdrwl_invstmt_plan", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.modfd_csh_sttlm { for (i, item) in vec.iter().enumerate() { validator.item("ModfdCshSttlm", "modfd_csh_sttlm", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.modfd_svc_lvl_agrmt { for (i, item) in vec.iter().enumerate() { validator.item("ModfdSvcLvlAgrmt", "modfd_svc_lvl_agrmt", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.modfd_addtl_inf { for (i, item) in vec.iter().enumerate() { validator.item("ModfdAddtlInf", "modfd_addtl_inf", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.mkt_prctc_vrsn { validator.element("MktPrctcVrsn", "mkt_prctc_vrsn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -44,8 +44,16 @@ pub struct RequestForAccountManagementStatusReportV06 {
 
 impl RequestForAccountManagementStatusReportV06 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		self.req_dtls.validate()?;
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		validator.element("ReqDtls", "req_dtls", |validator| self.req_dtls.validate_with(validator))?;
 		Ok(())
 	}
 }
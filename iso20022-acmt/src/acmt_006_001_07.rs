@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct AccountManagementStatusReportV07 {
 
 impl AccountManagementStatusReportV07 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		for item in &self.rltd_ref { item.validate()? }
-		self.sts_rpt.validate()?;
-		if let Some(ref val) = self.mkt_prctc_vrsn { val.validate()? }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		for (i, item) in self.rltd_ref.iter().enumerate() { validator.item("RltdRef", "rltd_ref", i, |validator| item.validate_with(validator))? }
+		validator.element("StsRpt", "sts_rpt", |validator| self.sts_rpt.validate_with(validator))?;
+		if let Some(ref val) = self.mkt_prctc_vrsn { validator.element("MktPrctcVrsn", "mkt_prctc_vrsn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
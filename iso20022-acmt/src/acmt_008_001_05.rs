@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -64,18 +64,26 @@ pub struct AccountOpeningAmendmentRequestV05 {
 
 impl AccountOpeningAmendmentRequestV05 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.refs.validate()?;
-		if let Some(ref val) = self.fr { val.validate()? }
-		if let Some(ref val) = self.ctrct_dts { val.validate()? }
-		if let Some(ref val) = self.undrlyg_mstr_agrmt { val.validate()? }
-		self.acct.validate()?;
-		self.acct_svcr_id.validate()?;
-		self.org.validate()?;
-		if let Some(ref vec) = self.mndt { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.grp { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.ref_acct { val.validate()? }
-		if let Some(ref vec) = self.dgtl_sgntr { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Refs", "refs", |validator| self.refs.validate_with(validator))?;
+		if let Some(ref val) = self.fr { validator.element("Fr", "fr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.ctrct_dts { validator.element("CtrctDts", "ctrct_dts", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.undrlyg_mstr_agrmt { validator.element("UndrlygMstrAgrmt", "undrlyg_mstr_agrmt", |validator| val.validate_with(validator))? }
+		validator.element("Acct", "acct", |validator| self.acct.validate_with(validator))?;
+		validator.element("AcctSvcrId", "acct_svcr_id", |validator| self.acct_svcr_id.validate_with(validator))?;
+		validator.element("Org", "org", |validator| self.org.validate_with(validator))?;
+		if let Some(ref vec) = self.mndt { for (i, item) in vec.iter().enumerate() { validator.item("Mndt", "mndt", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.grp { for (i, item) in vec.iter().enumerate() { validator.item("Grp", "grp", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.ref_acct { validator.element("RefAcct", "ref_acct", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.dgtl_sgntr { for (i, item) in vec.iter().enumerate() { validator.item("DgtlSgntr", "dgtl_sgntr", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
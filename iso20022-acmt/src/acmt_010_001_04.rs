@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -54,13 +54,21 @@ pub struct AccountRequestAcknowledgementV04 {
 
 impl AccountRequestAcknowledgementV04 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.refs.validate()?;
-		if let Some(ref val) = self.fr { val.validate()? }
-		if let Some(ref vec) = self.acct_id { for item in vec { item.validate()? } }
-		self.org_id.validate()?;
-		self.acct_svcr_id.validate()?;
-		if let Some(ref vec) = self.dgtl_sgntr { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Refs", "refs", |validator| self.refs.validate_with(validator))?;
+		if let Some(ref val) = self.fr { validator.element("Fr", "fr", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.acct_id { for (i, item) in vec.iter().enumerate() { validator.item("AcctId", "acct_id", i, |validator| item.validate_with(validator))? } }
+		validator.element("OrgId", "org_id", |validator| self.org_id.validate_with(validator))?;
+		validator.element("AcctSvcrId", "acct_svcr_id", |validator| self.acct_svcr_id.validate_with(validator))?;
+		if let Some(ref vec) = self.dgtl_sgntr { for (i, item) in vec.iter().enumerate() { validator.item("DgtlSgntr", "dgtl_sgntr", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,10 +48,18 @@ pub struct IdentificationModificationAdviceV04 {
 
 impl IdentificationModificationAdviceV04 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.assgnmt.validate()?;
-		if let Some(ref val) = self.orgnl_tx_ref { val.validate()? }
-		for item in &self.mod_attr { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Assgnmt", "assgnmt", |validator| self.assgnmt.validate_with(validator))?;
+		if let Some(ref val) = self.orgnl_tx_ref { validator.element("OrgnlTxRef", "orgnl_tx_ref", |validator| val.validate_with(validator))? }
+		for (i, item) in self.mod_attr.iter().enumerate() { validator.item("Mod", "mod_attr", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
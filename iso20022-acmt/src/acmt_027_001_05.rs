@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,12 +52,20 @@ pub struct AccountSwitchInformationRequestV05 {
 
 impl AccountSwitchInformationRequestV05 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		self.acct_swtch_dtls.validate()?;
-		self.new_acct.validate()?;
-		self.od_acct.validate()?;
-		if let Some(ref vec) = self.bal_trf { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		validator.element("AcctSwtchDtls", "acct_swtch_dtls", |validator| self.acct_swtch_dtls.validate_with(validator))?;
+		validator.element("NewAcct", "new_acct", |validator| self.new_acct.validate_with(validator))?;
+		validator.element("OdAcct", "od_acct", |validator| self.od_acct.validate_with(validator))?;
+		if let Some(ref vec) = self.bal_trf { for (i, item) in vec.iter().enumerate() { validator.item("BalTrf", "bal_trf", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
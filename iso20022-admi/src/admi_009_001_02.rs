@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,9 +37,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct StaticDataRequestV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgId") )]
-	pub msg_id: String,
+	pub msg_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmSsnIdr", skip_serializing_if = "Option::is_none") )]
-	pub sttlm_ssn_idr: Option<String>,
+	pub sttlm_ssn_idr: Option<Exact4AlphaNumericText>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "DataReqDtls") )]
 	pub data_req_dtls: RequestDetails3,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SplmtryData", skip_serializing_if = "Option::is_none") )]
@@ -48,20 +48,28 @@ pub struct StaticDataRequestV02 {
 
 impl StaticDataRequestV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
 		if self.msg_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "msg_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("MsgId", "msg_id", ValidationError::min_length("msg_id", 1, self.msg_id.chars().count()))?;
 		}
 		if self.msg_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "msg_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("MsgId", "msg_id", ValidationError::max_length("msg_id", 35, self.msg_id.chars().count()))?;
 		}
 		if let Some(ref val) = self.sttlm_ssn_idr {
-			let pattern = Regex::new("[a-zA-Z0-9]{4}").unwrap();
-			if !pattern.is_match(val) {
-				return Err(ValidationError::new(1005, "sttlm_ssn_idr does not match the required pattern".to_string()));
+			static PATTERN_STTLM_SSN_IDR: Pattern = Pattern::new("[a-zA-Z0-9]{4}");
+			if !PATTERN_STTLM_SSN_IDR.is_match(val) {
+				validator.report_at("SttlmSsnIdr", "sttlm_ssn_idr", ValidationError::pattern("sttlm_ssn_idr", PATTERN_STTLM_SSN_IDR.as_str(), val))?;
 			}
 		}
-		self.data_req_dtls.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		validator.element("DataReqDtls", "data_req_dtls", |validator| self.data_req_dtls.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
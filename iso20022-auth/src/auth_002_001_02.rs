@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,9 +37,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct InformationRequestResponseV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RspnId") )]
-	pub rspn_id: String,
+	pub rspn_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "InvstgtnId") )]
-	pub invstgtn_id: String,
+	pub invstgtn_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RspnSts") )]
 	pub rspn_sts: StatusResponse1Code,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SchCrit") )]
@@ -52,22 +52,30 @@ pub struct InformationRequestResponseV02 {
 
 impl InformationRequestResponseV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
 		if self.rspn_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "rspn_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("RspnId", "rspn_id", ValidationError::min_length("rspn_id", 1, self.rspn_id.chars().count()))?;
 		}
 		if self.rspn_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "rspn_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("RspnId", "rspn_id", ValidationError::max_length("rspn_id", 35, self.rspn_id.chars().count()))?;
 		}
 		if self.invstgtn_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "invstgtn_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("InvstgtnId", "invstgtn_id", ValidationError::min_length("invstgtn_id", 1, self.invstgtn_id.chars().count()))?;
 		}
 		if self.invstgtn_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "invstgtn_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("InvstgtnId", "invstgtn_id", ValidationError::max_length("invstgtn_id", 35, self.invstgtn_id.chars().count()))?;
 		}
-		self.rspn_sts.validate()?;
-		self.sch_crit.validate()?;
-		for item in &self.rtr_ind { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		validator.element("RspnSts", "rspn_sts", |validator| self.rspn_sts.validate_with(validator))?;
+		validator.element("SchCrit", "sch_crit", |validator| self.sch_crit.validate_with(validator))?;
+		for (i, item) in self.rtr_ind.iter().enumerate() { validator.item("RtrInd", "rtr_ind", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,7 +37,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct InformationRequestStatusChangeNotificationV01 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "OrgnlBizQry") )]
-	pub orgnl_biz_qry: String,
+	pub orgnl_biz_qry: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CnfdtltySts") )]
 	pub cnfdtlty_sts: bool,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SplmtryData", skip_serializing_if = "Option::is_none") )]
@@ -46,13 +46,21 @@ pub struct InformationRequestStatusChangeNotificationV01 {
 
 impl InformationRequestStatusChangeNotificationV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
 		if self.orgnl_biz_qry.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "orgnl_biz_qry is shorter than the minimum length of 1".to_string()));
+			validator.report_at("OrgnlBizQry", "orgnl_biz_qry", ValidationError::min_length("orgnl_biz_qry", 1, self.orgnl_biz_qry.chars().count()))?;
 		}
 		if self.orgnl_biz_qry.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "orgnl_biz_qry exceeds the maximum length of 35".to_string()));
+			validator.report_at("OrgnlBizQry", "orgnl_biz_qry", ValidationError::max_length("orgnl_biz_qry", 35, self.orgnl_biz_qry.chars().count()))?;
 		}
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
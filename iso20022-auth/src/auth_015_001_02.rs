@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -46,9 +46,17 @@ pub struct MoneyMarketOvernightIndexSwapsStatisticalReportV02 {
 
 impl MoneyMarketOvernightIndexSwapsStatisticalReportV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.rpt_hdr.validate()?;
-		self.ovrnght_indx_swps_rpt.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("RptHdr", "rpt_hdr", |validator| self.rpt_hdr.validate_with(validator))?;
+		validator.element("OvrnghtIndxSwpsRpt", "ovrnght_indx_swps_rpt", |validator| self.ovrnght_indx_swps_rpt.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -39,7 +39,7 @@ pub struct FinancialInstrumentReportingInvalidReferenceDataReportV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "DtPrd") )]
 	pub dt_prd: Period4Choice,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "NbOfRcrds", skip_serializing_if = "Option::is_none") )]
-	pub nb_of_rcrds: Option<f64>,
+	pub nb_of_rcrds: Option<Decimal>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrms") )]
 	pub fin_instrms: Vec<SecuritiesInvalidReferenceDataReport4>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SplmtryData", skip_serializing_if = "Option::is_none") )]
@@ -48,9 +48,25 @@ pub struct FinancialInstrumentReportingInvalidReferenceDataReportV02 {
 
 impl FinancialInstrumentReportingInvalidReferenceDataReportV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.dt_prd.validate()?;
-		for item in &self.fin_instrms { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("DtPrd", "dt_prd", |validator| self.dt_prd.validate_with(validator))?;
+		for (i, item) in self.fin_instrms.iter().enumerate() { validator.item("FinInstrms", "fin_instrms", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.nb_of_rcrds {
+			if val.total_digits() > 18 {
+				validator.report_at("NbOfRcrds", "nb_of_rcrds", ValidationError::total_digits("nb_of_rcrds", 18, val))?;
+			}
+			if val.fraction_digits() > 0 {
+				validator.report_at("NbOfRcrds", "nb_of_rcrds", ValidationError::fraction_digits("nb_of_rcrds", 0, val))?;
+			}
+		}
 		Ok(())
 	}
 }
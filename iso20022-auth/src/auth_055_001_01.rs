@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct CCPMemberRequirementsReportV01 {
 
 impl CCPMemberRequirementsReportV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		for item in &self.intra_day_rqrmnt_amt { item.validate()? }
-		if let Some(ref vec) = self.intra_day_mrgn_call { for item in vec { item.validate()? } }
-		for item in &self.end_of_day_rqrmnt { item.validate()? }
-		for item in &self.dflt_fnd_rqrmnt { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		for (i, item) in self.intra_day_rqrmnt_amt.iter().enumerate() { validator.item("IntraDayRqrmntAmt", "intra_day_rqrmnt_amt", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.intra_day_mrgn_call { for (i, item) in vec.iter().enumerate() { validator.item("IntraDayMrgnCall", "intra_day_mrgn_call", i, |validator| item.validate_with(validator))? } }
+		for (i, item) in self.end_of_day_rqrmnt.iter().enumerate() { validator.item("EndOfDayRqrmnt", "end_of_day_rqrmnt", i, |validator| item.validate_with(validator))? }
+		for (i, item) in self.dflt_fnd_rqrmnt.iter().enumerate() { validator.item("DfltFndRqrmnt", "dflt_fnd_rqrmnt", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,12 +52,20 @@ pub struct CCPIncomeStatementAndCapitalAdequacyReportV01 {
 
 impl CCPIncomeStatementAndCapitalAdequacyReportV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.incm_stmt.validate()?;
-		self.cptl_rqrmnts.validate()?;
-		self.ttl_cptl.validate()?;
-		self.lqd_fin_rsrcs.validate()?;
-		for item in &self.hpthtcl_cptl_measr { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("IncmStmt", "incm_stmt", |validator| self.incm_stmt.validate_with(validator))?;
+		validator.element("CptlRqrmnts", "cptl_rqrmnts", |validator| self.cptl_rqrmnts.validate_with(validator))?;
+		validator.element("TtlCptl", "ttl_cptl", |validator| self.ttl_cptl.validate_with(validator))?;
+		validator.element("LqdFinRsrcs", "lqd_fin_rsrcs", |validator| self.lqd_fin_rsrcs.validate_with(validator))?;
+		for (i, item) in self.hpthtcl_cptl_measr.iter().enumerate() { validator.item("HpthtclCptlMeasr", "hpthtcl_cptl_measr", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
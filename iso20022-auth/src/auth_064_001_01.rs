@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -46,9 +46,17 @@ pub struct CCPAvailableFinancialResourcesReportV01 {
 
 impl CCPAvailableFinancialResourcesReportV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.avlbl_fin_rsrcs_amt.validate()?;
-		if let Some(ref val) = self.othr_prfndd_rsrcs { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("AvlblFinRsrcsAmt", "avlbl_fin_rsrcs_amt", |validator| self.avlbl_fin_rsrcs_amt.validate_with(validator))?;
+		if let Some(ref val) = self.othr_prfndd_rsrcs { validator.element("OthrPrfnddRsrcs", "othr_prfndd_rsrcs", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct CancelTransactionV11 {
 
 impl CancelTransactionV11 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_hdr.validate()?;
-		self.pmt_id.validate()?;
-		if let Some(ref val) = self.csh_acct { val.validate()? }
-		if let Some(ref val) = self.cxl_rsn { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgHdr", "msg_hdr", |validator| self.msg_hdr.validate_with(validator))?;
+		validator.element("PmtId", "pmt_id", |validator| self.pmt_id.validate_with(validator))?;
+		if let Some(ref val) = self.csh_acct { validator.element("CshAcct", "csh_acct", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.cxl_rsn { validator.element("CxlRsn", "cxl_rsn", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
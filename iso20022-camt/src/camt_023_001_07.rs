@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -56,14 +56,22 @@ pub struct BackupPaymentV07 {
 
 impl BackupPaymentV07 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_hdr.validate()?;
-		if let Some(ref val) = self.orgnl_msg_id { val.validate()? }
-		if let Some(ref val) = self.instr_inf { val.validate()? }
-		self.trfd_amt.validate()?;
-		self.cdtr.validate()?;
-		if let Some(ref val) = self.cdtr_agt { val.validate()? }
-		if let Some(ref val) = self.dbtr_agt { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgHdr", "msg_hdr", |validator| self.msg_hdr.validate_with(validator))?;
+		if let Some(ref val) = self.orgnl_msg_id { validator.element("OrgnlMsgId", "orgnl_msg_id", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.instr_inf { validator.element("InstrInf", "instr_inf", |validator| val.validate_with(validator))? }
+		validator.element("TrfdAmt", "trfd_amt", |validator| self.trfd_amt.validate_with(validator))?;
+		validator.element("Cdtr", "cdtr", |validator| self.cdtr.validate_with(validator))?;
+		if let Some(ref val) = self.cdtr_agt { validator.element("CdtrAgt", "cdtr_agt", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.dbtr_agt { validator.element("DbtrAgt", "dbtr_agt", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
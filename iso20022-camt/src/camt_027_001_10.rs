@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,12 +52,20 @@ pub struct ClaimNonReceiptV10 {
 
 impl ClaimNonReceiptV10 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.assgnmt.validate()?;
-		if let Some(ref val) = self.case { val.validate()? }
-		self.undrlyg.validate()?;
-		if let Some(ref val) = self.cover_dtls { val.validate()? }
-		if let Some(ref val) = self.instr_for_assgne { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Assgnmt", "assgnmt", |validator| self.assgnmt.validate_with(validator))?;
+		if let Some(ref val) = self.case { validator.element("Case", "case", |validator| val.validate_with(validator))? }
+		validator.element("Undrlyg", "undrlyg", |validator| self.undrlyg.validate_with(validator))?;
+		if let Some(ref val) = self.cover_dtls { validator.element("CoverDtls", "cover_dtls", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.instr_for_assgne { validator.element("InstrForAssgne", "instr_for_assgne", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
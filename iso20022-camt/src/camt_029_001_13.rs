@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -60,16 +60,24 @@ pub struct ResolutionOfInvestigationV13 {
 
 impl ResolutionOfInvestigationV13 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.assgnmt.validate()?;
-		if let Some(ref val) = self.rslvd_case { val.validate()? }
-		self.sts.validate()?;
-		if let Some(ref vec) = self.cxl_dtls { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.mod_dtls { val.validate()? }
-		if let Some(ref val) = self.clm_non_rct_dtls { val.validate()? }
-		if let Some(ref val) = self.stmt_dtls { val.validate()? }
-		if let Some(ref val) = self.crrctn_tx { val.validate()? }
-		if let Some(ref val) = self.rsltn_rltd_inf { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Assgnmt", "assgnmt", |validator| self.assgnmt.validate_with(validator))?;
+		if let Some(ref val) = self.rslvd_case { validator.element("RslvdCase", "rslvd_case", |validator| val.validate_with(validator))? }
+		validator.element("Sts", "sts", |validator| self.sts.validate_with(validator))?;
+		if let Some(ref vec) = self.cxl_dtls { for (i, item) in vec.iter().enumerate() { validator.item("CxlDtls", "cxl_dtls", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.mod_dtls { validator.element("ModDtls", "mod_dtls", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.clm_non_rct_dtls { validator.element("ClmNonRctDtls", "clm_non_rct_dtls", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.stmt_dtls { validator.element("StmtDtls", "stmt_dtls", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.crrctn_tx { validator.element("CrrctnTx", "crrctn_tx", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.rsltn_rltd_inf { validator.element("RsltnRltdInf", "rsltn_rltd_inf", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct CaseStatusReportV06 {
 
 impl CaseStatusReportV06 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.hdr.validate()?;
-		self.case.validate()?;
-		self.sts.validate()?;
-		if let Some(ref val) = self.new_assgnmt { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Hdr", "hdr", |validator| self.hdr.validate_with(validator))?;
+		validator.element("Case", "case", |validator| self.case.validate_with(validator))?;
+		validator.element("Sts", "sts", |validator| self.sts.validate_with(validator))?;
+		if let Some(ref val) = self.new_assgnmt { validator.element("NewAssgnmt", "new_assgnmt", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -58,15 +58,23 @@ pub struct FundDetailedEstimatedCashForecastReportV04 {
 
 impl FundDetailedEstimatedCashForecastReportV04 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.pool_ref { val.validate()? }
-		if let Some(ref vec) = self.prvs_ref { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.rltd_ref { for item in vec { item.validate()? } }
-		self.msg_pgntn.validate()?;
-		if let Some(ref val) = self.fnd_or_sub_fnd_dtls { val.validate()? }
-		for item in &self.estmtd_fnd_csh_fcst_dtls { item.validate()? }
-		if let Some(ref val) = self.cnsltd_net_csh_fcst { val.validate()? }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.pool_ref { validator.element("PoolRef", "pool_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.prvs_ref { for (i, item) in vec.iter().enumerate() { validator.item("PrvsRef", "prvs_ref", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.rltd_ref { for (i, item) in vec.iter().enumerate() { validator.item("RltdRef", "rltd_ref", i, |validator| item.validate_with(validator))? } }
+		validator.element("MsgPgntn", "msg_pgntn", |validator| self.msg_pgntn.validate_with(validator))?;
+		if let Some(ref val) = self.fnd_or_sub_fnd_dtls { validator.element("FndOrSubFndDtls", "fnd_or_sub_fnd_dtls", |validator| val.validate_with(validator))? }
+		for (i, item) in self.estmtd_fnd_csh_fcst_dtls.iter().enumerate() { validator.item("EstmtdFndCshFcstDtls", "estmtd_fnd_csh_fcst_dtls", i, |validator| item.validate_with(validator))? }
+		if let Some(ref val) = self.cnsltd_net_csh_fcst { validator.element("CnsltdNetCshFcst", "cnsltd_net_csh_fcst", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,12 +52,20 @@ pub struct FundConfirmedCashForecastReportCancellationV03 {
 
 impl FundConfirmedCashForecastReportCancellationV03 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.pool_ref { val.validate()? }
-		if let Some(ref val) = self.prvs_ref { val.validate()? }
-		if let Some(ref vec) = self.rltd_ref { for item in vec { item.validate()? } }
-		self.msg_pgntn.validate()?;
-		if let Some(ref val) = self.csh_fcst_rpt_to_be_canc { val.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.pool_ref { validator.element("PoolRef", "pool_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.prvs_ref { validator.element("PrvsRef", "prvs_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.rltd_ref { for (i, item) in vec.iter().enumerate() { validator.item("RltdRef", "rltd_ref", i, |validator| item.validate_with(validator))? } }
+		validator.element("MsgPgntn", "msg_pgntn", |validator| self.msg_pgntn.validate_with(validator))?;
+		if let Some(ref val) = self.csh_fcst_rpt_to_be_canc { validator.element("CshFcstRptToBeCanc", "csh_fcst_rpt_to_be_canc", |validator| val.validate_with(validator))? }
 		Ok(())
 	}
 }
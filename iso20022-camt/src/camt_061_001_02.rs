@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -41,22 +41,30 @@ pub struct PayInCallV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RptData") )]
 	pub rpt_data: ReportData5,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmSsnIdr", skip_serializing_if = "Option::is_none") )]
-	pub sttlm_ssn_idr: Option<String>,
+	pub sttlm_ssn_idr: Option<Exact4AlphaNumericText>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SplmtryData", skip_serializing_if = "Option::is_none") )]
 	pub splmtry_data: Option<Vec<SupplementaryData1>>,
 }
 
 impl PayInCallV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.pty_id.validate()?;
-		self.rpt_data.validate()?;
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("PtyId", "pty_id", |validator| self.pty_id.validate_with(validator))?;
+		validator.element("RptData", "rpt_data", |validator| self.rpt_data.validate_with(validator))?;
 		if let Some(ref val) = self.sttlm_ssn_idr {
-			let pattern = Regex::new("[a-zA-Z0-9]{4}").unwrap();
-			if !pattern.is_match(val) {
-				return Err(ValidationError::new(1005, "sttlm_ssn_idr does not match the required pattern".to_string()));
+			static PATTERN_STTLM_SSN_IDR: Pattern = Pattern::new("[a-zA-Z0-9]{4}");
+			if !PATTERN_STTLM_SSN_IDR.is_match(val) {
+				validator.report_at("SttlmSsnIdr", "sttlm_ssn_idr", ValidationError::pattern("sttlm_ssn_idr", PATTERN_STTLM_SSN_IDR.as_str(), val))?;
 			}
 		}
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,12 +52,20 @@ pub struct PayInScheduleV03 {
 
 impl PayInScheduleV03 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.pty_id.validate()?;
-		self.rpt_data.validate()?;
-		if let Some(ref vec) = self.pay_in_schdl_lng_bal { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.pay_in_schdl_itm { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.pay_in_fctrs { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("PtyId", "pty_id", |validator| self.pty_id.validate_with(validator))?;
+		validator.element("RptData", "rpt_data", |validator| self.rpt_data.validate_with(validator))?;
+		if let Some(ref vec) = self.pay_in_schdl_lng_bal { for (i, item) in vec.iter().enumerate() { validator.item("PayInSchdlLngBal", "pay_in_schdl_lng_bal", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.pay_in_schdl_itm { for (i, item) in vec.iter().enumerate() { validator.item("PayInSchdlItm", "pay_in_schdl_itm", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.pay_in_fctrs { validator.element("PayInFctrs", "pay_in_fctrs", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
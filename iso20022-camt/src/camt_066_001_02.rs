@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -39,7 +39,7 @@ pub struct IntraBalanceMovementInstructionV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Id", skip_serializing_if = "Option::is_none") )]
 	pub id: Option<TransactionAndDocumentIdentification6>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CorpActnEvtId", skip_serializing_if = "Option::is_none") )]
-	pub corp_actn_evt_id: Option<String>,
+	pub corp_actn_evt_id: Option<Max35Text>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "NbCounts", skip_serializing_if = "Option::is_none") )]
 	pub nb_counts: Option<NumberCount1Choice>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Lnkgs", skip_serializing_if = "Option::is_none") )]
@@ -58,22 +58,30 @@ pub struct IntraBalanceMovementInstructionV02 {
 
 impl IntraBalanceMovementInstructionV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.id { val.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.id { validator.element("Id", "id", |validator| val.validate_with(validator))? }
 		if let Some(ref val) = self.corp_actn_evt_id {
 			if val.chars().count() < 1 {
-				return Err(ValidationError::new(1001, "corp_actn_evt_id is shorter than the minimum length of 1".to_string()));
+				validator.report_at("CorpActnEvtId", "corp_actn_evt_id", ValidationError::min_length("corp_actn_evt_id", 1, val.chars().count()))?;
 			}
 			if val.chars().count() > 35 {
-				return Err(ValidationError::new(1002, "corp_actn_evt_id exceeds the maximum length of 35".to_string()));
+				validator.report_at("CorpActnEvtId", "corp_actn_evt_id", ValidationError::max_length("corp_actn_evt_id", 35, val.chars().count()))?;
 			}
 		}
-		if let Some(ref val) = self.nb_counts { val.validate()? }
-		if let Some(ref vec) = self.lnkgs { for item in vec { item.validate()? } }
-		self.csh_acct.validate()?;
-		if let Some(ref val) = self.csh_acct_ownr { val.validate()? }
-		if let Some(ref val) = self.csh_acct_svcr { val.validate()? }
-		self.intra_bal.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		if let Some(ref val) = self.nb_counts { validator.element("NbCounts", "nb_counts", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.lnkgs { for (i, item) in vec.iter().enumerate() { validator.item("Lnkgs", "lnkgs", i, |validator| item.validate_with(validator))? } }
+		validator.element("CshAcct", "csh_acct", |validator| self.csh_acct.validate_with(validator))?;
+		if let Some(ref val) = self.csh_acct_ownr { validator.element("CshAcctOwnr", "csh_acct_ownr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.csh_acct_svcr { validator.element("CshAcctSvcr", "csh_acct_svcr", |validator| val.validate_with(validator))? }
+		validator.element("IntraBal", "intra_bal", |validator| self.intra_bal.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
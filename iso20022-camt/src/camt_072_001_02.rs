@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -54,13 +54,21 @@ pub struct IntraBalanceMovementModificationRequestV02 {
 
 impl IntraBalanceMovementModificationRequestV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.id { val.validate()? }
-		self.csh_acct.validate()?;
-		if let Some(ref val) = self.csh_acct_ownr { val.validate()? }
-		if let Some(ref val) = self.csh_acct_svcr { val.validate()? }
-		for item in &self.req_dtls { item.validate()? }
-		if let Some(ref val) = self.undrlyg_intra_bal { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.id { validator.element("Id", "id", |validator| val.validate_with(validator))? }
+		validator.element("CshAcct", "csh_acct", |validator| self.csh_acct.validate_with(validator))?;
+		if let Some(ref val) = self.csh_acct_ownr { validator.element("CshAcctOwnr", "csh_acct_ownr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.csh_acct_svcr { validator.element("CshAcctSvcr", "csh_acct_svcr", |validator| val.validate_with(validator))? }
+		for (i, item) in self.req_dtls.iter().enumerate() { validator.item("ReqDtls", "req_dtls", i, |validator| item.validate_with(validator))? }
+		if let Some(ref val) = self.undrlyg_intra_bal { validator.element("UndrlygIntraBal", "undrlyg_intra_bal", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
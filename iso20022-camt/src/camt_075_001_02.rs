@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -39,7 +39,7 @@ pub struct IntraBalanceMovementCancellationRequestStatusAdviceV02 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Id", skip_serializing_if = "Option::is_none") )]
 	pub id: Option<DocumentIdentification51>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqRef") )]
-	pub req_ref: String,
+	pub req_ref: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "TxId") )]
 	pub tx_id: References14,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "PrcgSts") )]
@@ -58,20 +58,28 @@ pub struct IntraBalanceMovementCancellationRequestStatusAdviceV02 {
 
 impl IntraBalanceMovementCancellationRequestStatusAdviceV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.id { val.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.id { validator.element("Id", "id", |validator| val.validate_with(validator))? }
 		if self.req_ref.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "req_ref is shorter than the minimum length of 1".to_string()));
+			validator.report_at("ReqRef", "req_ref", ValidationError::min_length("req_ref", 1, self.req_ref.chars().count()))?;
 		}
 		if self.req_ref.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "req_ref exceeds the maximum length of 35".to_string()));
+			validator.report_at("ReqRef", "req_ref", ValidationError::max_length("req_ref", 35, self.req_ref.chars().count()))?;
 		}
-		self.tx_id.validate()?;
-		self.prcg_sts.validate()?;
-		self.csh_acct.validate()?;
-		if let Some(ref val) = self.csh_acct_ownr { val.validate()? }
-		if let Some(ref val) = self.csh_acct_svcr { val.validate()? }
-		if let Some(ref val) = self.undrlyg_intra_bal { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		validator.element("TxId", "tx_id", |validator| self.tx_id.validate_with(validator))?;
+		validator.element("PrcgSts", "prcg_sts", |validator| self.prcg_sts.validate_with(validator))?;
+		validator.element("CshAcct", "csh_acct", |validator| self.csh_acct.validate_with(validator))?;
+		if let Some(ref val) = self.csh_acct_ownr { validator.element("CshAcctOwnr", "csh_acct_ownr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.csh_acct_svcr { validator.element("CshAcctSvcr", "csh_acct_svcr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.undrlyg_intra_bal { validator.element("UndrlygIntraBal", "undrlyg_intra_bal", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
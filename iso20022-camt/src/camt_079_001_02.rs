@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,10 +48,18 @@ pub struct IntraBalanceMovementQueryResponseV02 {
 
 impl IntraBalanceMovementQueryResponseV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.id { val.validate()? }
-		self.pgntn.validate()?;
-		self.rpt_gnl_dtls.validate()?;
-		if let Some(ref val) = self.rpt_or_err { val.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.id { validator.element("Id", "id", |validator| val.validate_with(validator))? }
+		validator.element("Pgntn", "pgntn", |validator| self.pgntn.validate_with(validator))?;
+		validator.element("RptGnlDtls", "rpt_gnl_dtls", |validator| self.rpt_gnl_dtls.validate_with(validator))?;
+		if let Some(ref val) = self.rpt_or_err { validator.element("RptOrErr", "rpt_or_err", |validator| val.validate_with(validator))? }
 		Ok(())
 	}
 }
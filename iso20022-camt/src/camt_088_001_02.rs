@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct NetReportV02 {
 
 impl NetReportV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.net_rpt_data.validate()?;
-		self.net_svc_ptcpt_id.validate()?;
-		if let Some(ref val) = self.net_svc_ctr_pty_id { val.validate()? }
-		for item in &self.net_oblgtn { item.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("NetRptData", "net_rpt_data", |validator| self.net_rpt_data.validate_with(validator))?;
+		validator.element("NetSvcPtcptId", "net_svc_ptcpt_id", |validator| self.net_svc_ptcpt_id.validate_with(validator))?;
+		if let Some(ref val) = self.net_svc_ctr_pty_id { validator.element("NetSvcCtrPtyId", "net_svc_ctr_pty_id", |validator| val.validate_with(validator))? }
+		for (i, item) in self.net_oblgtn.iter().enumerate() { validator.item("NetOblgtn", "net_oblgtn", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -115,3 +115,6 @@ pub mod camt_108_001_02;
 pub mod camt_109_001_02;
 pub mod camt_110_001_01;
 pub mod camt_111_001_01;
+pub mod rules;
+#[cfg(feature = "streaming")]
+pub mod stream;
@@ -0,0 +1,119 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Cross-field rules of the camt message definitions, see [`iso20022_common::rules`].
+//!
+//! The transactions summary of a statement, report or notification counts and sums its
+//! entries. A paginated one may summarise the entries of all pages, so it is only checked
+//! when the entries are not paginated.
+
+use iso20022_common::common::{ReportEntry14, TotalTransactions6};
+use iso20022_common::rules;
+use iso20022_common::{ValidationError, Validator};
+use crate::camt_052_001_12::BankToCustomerAccountReportV12;
+use crate::camt_053_001_12::BankToCustomerStatementV12;
+use crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12;
+use crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11;
+
+impl BankToCustomerAccountReportV12 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        for (i, rpt) in self.rpt.iter().enumerate() {
+            if rpt.rpt_pgntn.is_none() {
+                validator.item("Rpt", "rpt", i, |validator| transactions_summary(validator, &rpt.txs_summry, &rpt.ntry))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BankToCustomerStatementV12 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        for (i, stmt) in self.stmt.iter().enumerate() {
+            if stmt.stmt_pgntn.is_none() {
+                validator.item("Stmt", "stmt", i, |validator| transactions_summary(validator, &stmt.txs_summry, &stmt.ntry))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BankToCustomerDebitCreditNotificationV12 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        for (i, ntfctn) in self.ntfctn.iter().enumerate() {
+            if ntfctn.ntfctn_pgntn.is_none() {
+                validator.item("Ntfctn", "ntfctn", i, |validator| transactions_summary(validator, &ntfctn.txs_summry, &ntfctn.ntry))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FIToFIPaymentCancellationRequestV11 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        let ctrl_data = match self.ctrl_data {
+            Some(ref ctrl_data) => ctrl_data,
+            None => return Ok(()),
+        };
+        let count = self.undrlyg.iter().filter_map(|undrlyg| undrlyg.tx_inf.as_ref()).map(Vec::len).sum();
+        validator.element("CtrlData", "ctrl_data", |validator| rules::number_of_transactions(validator, &ctrl_data.nb_of_txs, count))
+    }
+}
+
+/// Checks the `TtlNtries` of `txs_summry` against the `ntry` of the current element, and the
+/// `TtlCdtNtries` and `TtlDbtNtries` against the `TtlNtries`.
+fn transactions_summary(validator: &mut Validator, txs_summry: &Option<TotalTransactions6>, ntry: &Option<Vec<ReportEntry14>>) -> Result<(), ValidationError> {
+    let txs_summry = match txs_summry {
+        Some(txs_summry) => txs_summry,
+        None => return Ok(()),
+    };
+    let entries = ntry.as_deref().unwrap_or_default();
+    validator.element("TxsSummry", "txs_summry", |validator| {
+        if let Some(ref ttl_ntries) = txs_summry.ttl_ntries {
+            validator.element("TtlNtries", "ttl_ntries", |validator| {
+                if let Some(ref nb_of_ntries) = ttl_ntries.nb_of_ntries {
+                    rules::check(validator, "NbOfNtries", "nb_of_ntries", "TotalEntriesRule", rules::counts(nb_of_ntries, entries.len()), || {
+                        format!("NbOfNtries does not match the number of entries, {}", entries.len())
+                    })?;
+                }
+                if let Some(ref sum) = ttl_ntries.sum {
+                    let holds = rules::sum(entries.iter().map(|ntry| ntry.amt.value)) == Some(*sum);
+                    rules::check(validator, "Sum", "sum", "TotalEntriesSumRule", holds, || "Sum does not equal the sum of the amounts of the entries".to_string())?;
+                }
+                Ok(())
+            })?;
+        }
+        let credits = txs_summry.ttl_cdt_ntries.as_ref().and_then(|ttl| ttl.nb_of_ntries.as_ref()).and_then(|nb| nb.parse::<usize>().ok());
+        let debits = txs_summry.ttl_dbt_ntries.as_ref().and_then(|ttl| ttl.nb_of_ntries.as_ref()).and_then(|nb| nb.parse::<usize>().ok());
+        match (credits, debits) {
+            (Some(credits), Some(debits)) => rules::check(validator, "", "", "TotalCreditAndDebitEntriesRule", credits.checked_add(debits) == Some(entries.len()), || {
+                format!("TtlCdtNtries and TtlDbtNtries do not add up to the number of entries, {}", entries.len())
+            }),
+            _ => Ok(()),
+        }
+    })
+}
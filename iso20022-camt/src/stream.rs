@@ -0,0 +1,186 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Pull-based readers for large camt.053 and camt.054 messages.
+//!
+//! The readers walk the XML with `quick-xml` and only deserialize one element at a time,
+//! so memory stays bounded by the largest single entry rather than the whole message.
+
+use std::io::BufRead;
+use std::marker::PhantomData;
+use iso20022_common::common::{AccountNotification22, AccountStatement13, GroupHeader116, ReportEntry14, SupplementaryData1};
+use iso20022_common::stream::{copy_element, missing_element, read_element, read_wrapped};
+use quick_xml::events::Event;
+use quick_xml::{DeError, Reader};
+use serde::de::DeserializeOwned;
+
+/// An item read from a camt.053 or camt.054 message, in document order.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub enum EntryEvent<A> {
+	/// The `GrpHdr` of the message.
+	GroupHeader(Box<GroupHeader116>),
+	/// A `Stmt` / `Ntfctn` holding the elements preceding its entries, `ntry` is always `None`.
+	Report(Box<A>),
+	/// A single `Ntry` of the current report.
+	Entry(Box<ReportEntry14>),
+	/// The end of the current report, holding all of its elements except the entries.
+	ReportEnd(Box<A>),
+	/// A message level `SplmtryData` element.
+	SupplementaryData(Box<SupplementaryData1>),
+}
+
+/// Streaming reader yielding the group header, then each report and its entries one at a time.
+pub struct EntryReader<R, A> {
+	reader: Reader<R>,
+	buf: Vec<u8>,
+	message: &'static [u8],
+	report: &'static [u8],
+	in_message: bool,
+	in_report: bool,
+	report_sent: bool,
+	done: bool,
+	// Content of the current report without its entries.
+	header: Vec<u8>,
+	pending: Option<EntryEvent<A>>,
+	_report: PhantomData<A>,
+}
+
+/// Streaming reader over a camt.053 `BkToCstmrStmt` message.
+pub type StatementReader<R> = EntryReader<R, AccountStatement13>;
+
+/// Streaming reader over a camt.054 `BkToCstmrDbtCdtNtfctn` message.
+pub type NotificationReader<R> = EntryReader<R, AccountNotification22>;
+
+impl<R: BufRead> EntryReader<R, AccountStatement13> {
+	pub fn new(reader: R) -> Self {
+		Self::with_elements(reader, b"BkToCstmrStmt", b"Stmt")
+	}
+}
+
+impl<R: BufRead> EntryReader<R, AccountNotification22> {
+	pub fn new(reader: R) -> Self {
+		Self::with_elements(reader, b"BkToCstmrDbtCdtNtfctn", b"Ntfctn")
+	}
+}
+
+impl<R: BufRead, A: DeserializeOwned> EntryReader<R, A> {
+	fn with_elements(reader: R, message: &'static [u8], report: &'static [u8]) -> Self {
+		EntryReader {
+			reader: Reader::from_reader(reader),
+			buf: Vec::new(),
+			message,
+			report,
+			in_message: false,
+			in_report: false,
+			report_sent: false,
+			done: false,
+			header: Vec::new(),
+			pending: None,
+			_report: PhantomData,
+		}
+	}
+
+	fn next_event(&mut self) -> Result<Option<EntryEvent<A>>, DeError> {
+		if let Some(event) = self.pending.take() {
+			return Ok(Some(event));
+		}
+		loop {
+			self.buf.clear();
+			let event = self.reader.read_event_into(&mut self.buf)?.into_owned();
+			match event {
+				Event::Start(e) if self.in_report => {
+					if e.local_name().as_ref() == b"Ntry" {
+						let entry = EntryEvent::Entry(Box::new(read_element(&mut self.reader, e)?));
+						if !self.report_sent {
+							self.report_sent = true;
+							self.pending = Some(entry);
+							return Ok(Some(EntryEvent::Report(Box::new(read_wrapped(self.report, &self.header)?))));
+						}
+						return Ok(Some(entry));
+					}
+					let content = copy_element(&mut self.reader, Event::Start(e))?;
+					self.header.extend_from_slice(&content);
+				}
+				Event::End(_) if self.in_report => {
+					self.in_report = false;
+					let end = EntryEvent::ReportEnd(Box::new(read_wrapped(self.report, &self.header)?));
+					if !self.report_sent {
+						self.pending = Some(end);
+						return Ok(Some(EntryEvent::Report(Box::new(read_wrapped(self.report, &self.header)?))));
+					}
+					return Ok(Some(end));
+				}
+				Event::Empty(e) if self.in_report => {
+					let content = copy_element(&mut self.reader, Event::Empty(e))?;
+					self.header.extend_from_slice(&content);
+				}
+				Event::Text(e) if self.in_report => self.header.extend_from_slice(&e),
+				Event::Start(e) if self.in_message => match e.local_name().as_ref() {
+					b"GrpHdr" => return Ok(Some(EntryEvent::GroupHeader(Box::new(read_element(&mut self.reader, e)?)))),
+					b"SplmtryData" => return Ok(Some(EntryEvent::SupplementaryData(Box::new(read_element(&mut self.reader, e)?)))),
+					name if name == self.report => {
+						self.in_report = true;
+						self.report_sent = false;
+						self.header.clear();
+					}
+					_ => {
+						let end = e.to_end().into_owned();
+						self.reader.read_to_end_into(end.name(), &mut self.buf)?;
+					}
+				},
+				Event::End(_) if self.in_message => {
+					self.in_message = false;
+					self.done = true;
+					return Ok(None);
+				}
+				Event::Start(e) if e.local_name().as_ref() == self.message => self.in_message = true,
+				Event::Eof if !self.in_message => {
+					return Err(missing_element(self.message));
+				}
+				Event::Eof => return Err(DeError::UnexpectedEof),
+				_ => {}
+			}
+		}
+	}
+}
+
+impl<R: BufRead, A: DeserializeOwned> Iterator for EntryReader<R, A> {
+	type Item = Result<EntryEvent<A>, DeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done && self.pending.is_none() {
+			return None;
+		}
+		match self.next_event() {
+			Ok(Some(event)) => Some(Ok(event)),
+			Ok(None) => None,
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
@@ -0,0 +1,399 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+//! ISO 4217 currency codes and the minor units their amounts are written with.
+
+use crate::{Decimal, ValidationError, Validator};
+
+/// Whether a currency code is in circulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyStatus {
+    /// Listed in the current ISO 4217 list of currencies and funds.
+    Active,
+    /// Withdrawn from circulation, e.g. `DEM` or `HRK`.
+    Historic,
+}
+
+/// The currency codes an amount accepts, as set by the type of its `Ccy` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyCodes {
+    /// `ActiveCurrencyCode`.
+    Active,
+    /// `ActiveOrHistoricCurrencyCode` and `CurrencyCode`.
+    ActiveOrHistoric,
+}
+
+/// An ISO 4217 currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+    pub code: &'static str,
+    /// Number of digits after the decimal separator, `None` where ISO 4217 gives none, e.g. for gold `XAU`
+    /// or for historic currencies.
+    pub minor_units: Option<u32>,
+    pub status: CurrencyStatus,
+}
+
+impl Currency {
+    /// Looks up an alphabetic code in the bundled ISO 4217 table.
+    pub fn lookup(code: &str) -> Option<&'static Currency> {
+        CURRENCIES.binary_search_by(|currency| currency.code.cmp(code)).ok().map(|i| &CURRENCIES[i])
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == CurrencyStatus::Active
+    }
+}
+
+/// Checks the `Ccy` attribute of an amount and the amount itself against the bundled table.
+///
+/// A code missing from the table, or a historic code where `codes` only accepts active ones, is
+/// reported at the attribute. An amount with more fraction digits than the minor units of its
+/// currency, e.g. `<IntrBkSttlmAmt Ccy="JPY">100.5</IntrBkSttlmAmt>`, is reported at the value.
+/// Trailing zeros are not counted, as for the `fractionDigits` facet.
+pub fn validate_amount(validator: &mut Validator, ccy: &str, amount: &Decimal, codes: CurrencyCodes) -> Result<(), ValidationError> {
+    let Some(currency) = Currency::lookup(ccy) else {
+        // Codes that are not three capital letters are left to the pattern facet of the attribute
+        if ccy.len() == 3 && ccy.bytes().all(|b| b.is_ascii_uppercase()) {
+            validator.report_at("@Ccy", "ccy", ValidationError::unknown_currency("ccy", ccy))?;
+        }
+        return Ok(());
+    };
+    if codes == CurrencyCodes::Active && !currency.is_active() {
+        validator.report_at("@Ccy", "ccy", ValidationError::historic_currency("ccy", ccy))?;
+    }
+    if let Some(minor_units) = currency.minor_units {
+        if amount.fraction_digits() > minor_units {
+            validator.report_at("", "value", ValidationError::currency_minor_units("value", ccy, minor_units, amount))?;
+        }
+    }
+    Ok(())
+}
+
+const fn active(code: &'static str, minor_units: u32) -> Currency {
+    Currency { code, minor_units: Some(minor_units), status: CurrencyStatus::Active }
+}
+
+// Funds, precious metals and other codes without minor units.
+const fn active_without_minor_units(code: &'static str) -> Currency {
+    Currency { code, minor_units: None, status: CurrencyStatus::Active }
+}
+
+const fn historic(code: &'static str) -> Currency {
+    Currency { code, minor_units: None, status: CurrencyStatus::Historic }
+}
+
+// ISO 4217 list one (active codes) and the historic codes of list three still seen in
+// payments, sorted by code for the binary search.
+static CURRENCIES: &[Currency] = &[
+    historic("ADP"),
+    active("AED", 2),
+    historic("AFA"),
+    active("AFN", 2),
+    active("ALL", 2),
+    active("AMD", 2),
+    historic("ANG"),
+    active("AOA", 2),
+    active("ARS", 2),
+    historic("ATS"),
+    active("AUD", 2),
+    active("AWG", 2),
+    historic("AZM"),
+    active("AZN", 2),
+    active("BAM", 2),
+    active("BBD", 2),
+    active("BDT", 2),
+    historic("BEF"),
+    historic("BGN"),
+    active("BHD", 3),
+    active("BIF", 0),
+    active("BMD", 2),
+    active("BND", 2),
+    active("BOB", 2),
+    active("BOV", 2),
+    active("BRL", 2),
+    active("BSD", 2),
+    active("BTN", 2),
+    active("BWP", 2),
+    active("BYN", 2),
+    historic("BYR"),
+    active("BZD", 2),
+    active("CAD", 2),
+    active("CDF", 2),
+    active("CHE", 2),
+    active("CHF", 2),
+    active("CHW", 2),
+    active("CLF", 4),
+    active("CLP", 0),
+    active("CNY", 2),
+    active("COP", 2),
+    active("COU", 2),
+    active("CRC", 2),
+    historic("CSD"),
+    active("CUC", 2),
+    active("CUP", 2),
+    active("CVE", 2),
+    historic("CYP"),
+    active("CZK", 2),
+    historic("DEM"),
+    active("DJF", 0),
+    active("DKK", 2),
+    active("DOP", 2),
+    active("DZD", 2),
+    historic("EEK"),
+    active("EGP", 2),
+    active("ERN", 2),
+    historic("ESP"),
+    active("ETB", 2),
+    active("EUR", 2),
+    historic("FIM"),
+    active("FJD", 2),
+    active("FKP", 2),
+    historic("FRF"),
+    active("GBP", 2),
+    active("GEL", 2),
+    historic("GHC"),
+    active("GHS", 2),
+    active("GIP", 2),
+    active("GMD", 2),
+    active("GNF", 0),
+    historic("GRD"),
+    active("GTQ", 2),
+    active("GYD", 2),
+    active("HKD", 2),
+    active("HNL", 2),
+    historic("HRK"),
+    active("HTG", 2),
+    active("HUF", 2),
+    active("IDR", 2),
+    historic("IEP"),
+    active("ILS", 2),
+    active("INR", 2),
+    active("IQD", 3),
+    active("IRR", 2),
+    active("ISK", 0),
+    historic("ITL"),
+    active("JMD", 2),
+    active("JOD", 3),
+    active("JPY", 0),
+    active("KES", 2),
+    active("KGS", 2),
+    active("KHR", 2),
+    active("KMF", 0),
+    active("KPW", 2),
+    active("KRW", 0),
+    active("KWD", 3),
+    active("KYD", 2),
+    active("KZT", 2),
+    active("LAK", 2),
+    active("LBP", 2),
+    active("LKR", 2),
+    active("LRD", 2),
+    active("LSL", 2),
+    historic("LTL"),
+    historic("LUF"),
+    historic("LVL"),
+    active("LYD", 3),
+    active("MAD", 2),
+    active("MDL", 2),
+    active("MGA", 2),
+    active("MKD", 2),
+    active("MMK", 2),
+    active("MNT", 2),
+    active("MOP", 2),
+    historic("MRO"),
+    active("MRU", 2),
+    historic("MTL"),
+    active("MUR", 2),
+    active("MVR", 2),
+    active("MWK", 2),
+    active("MXN", 2),
+    active("MXV", 2),
+    active("MYR", 2),
+    historic("MZM"),
+    active("MZN", 2),
+    active("NAD", 2),
+    active("NGN", 2),
+    active("NIO", 2),
+    historic("NLG"),
+    active("NOK", 2),
+    active("NPR", 2),
+    active("NZD", 2),
+    active("OMR", 3),
+    active("PAB", 2),
+    active("PEN", 2),
+    active("PGK", 2),
+    active("PHP", 2),
+    active("PKR", 2),
+    active("PLN", 2),
+    historic("PTE"),
+    active("PYG", 0),
+    active("QAR", 2),
+    historic("ROL"),
+    active("RON", 2),
+    active("RSD", 2),
+    active("RUB", 2),
+    active("RWF", 0),
+    active("SAR", 2),
+    active("SBD", 2),
+    active("SCR", 2),
+    historic("SDD"),
+    active("SDG", 2),
+    active("SEK", 2),
+    active("SGD", 2),
+    active("SHP", 2),
+    historic("SIT"),
+    historic("SKK"),
+    active("SLE", 2),
+    historic("SLL"),
+    active("SOS", 2),
+    active("SRD", 2),
+    historic("SRG"),
+    active("SSP", 2),
+    historic("STD"),
+    active("STN", 2),
+    active("SVC", 2),
+    active("SYP", 2),
+    active("SZL", 2),
+    active("THB", 2),
+    active("TJS", 2),
+    historic("TMM"),
+    active("TMT", 2),
+    active("TND", 3),
+    active("TOP", 2),
+    historic("TRL"),
+    active("TRY", 2),
+    active("TTD", 2),
+    active("TWD", 2),
+    active("TZS", 2),
+    active("UAH", 2),
+    active("UGX", 0),
+    active("USD", 2),
+    active("USN", 2),
+    active("UYI", 0),
+    active("UYU", 2),
+    active("UYW", 4),
+    active("UZS", 2),
+    historic("VEB"),
+    active("VED", 2),
+    historic("VEF"),
+    active("VES", 2),
+    active("VND", 0),
+    active("VUV", 0),
+    active("WST", 2),
+    active("XAF", 0),
+    active_without_minor_units("XAG"),
+    active_without_minor_units("XAU"),
+    active_without_minor_units("XBA"),
+    active_without_minor_units("XBB"),
+    active_without_minor_units("XBC"),
+    active_without_minor_units("XBD"),
+    active("XCD", 2),
+    active("XCG", 2),
+    active_without_minor_units("XDR"),
+    historic("XEU"),
+    active("XOF", 0),
+    active_without_minor_units("XPD"),
+    active("XPF", 0),
+    active_without_minor_units("XPT"),
+    active_without_minor_units("XSU"),
+    active_without_minor_units("XTS"),
+    active_without_minor_units("XUA"),
+    active_without_minor_units("XXX"),
+    active("YER", 2),
+    historic("YUM"),
+    active("ZAR", 2),
+    historic("ZMK"),
+    active("ZMW", 2),
+    historic("ZWD"),
+    active("ZWG", 2),
+    historic("ZWL"),
+    historic("ZWN"),
+    historic("ZWR"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ValidationErrorKind, ValidationReport};
+
+    fn check(ccy: &str, amount: &str, codes: CurrencyCodes) -> ValidationReport {
+        let amount: Decimal = amount.parse().unwrap();
+        ValidationReport::collect(|validator| validate_amount(validator, ccy, &amount, codes))
+    }
+
+    #[test]
+    fn table_is_sorted_and_unique() {
+        assert!(CURRENCIES.windows(2).all(|pair| pair[0].code < pair[1].code));
+        for currency in CURRENCIES {
+            assert_eq!(Currency::lookup(currency.code), Some(currency));
+            assert!(currency.code.len() == 3 && currency.code.bytes().all(|b| b.is_ascii_uppercase()), "{}", currency.code);
+        }
+    }
+
+    #[test]
+    fn minor_units() {
+        let minor_units = |code| Currency::lookup(code).unwrap().minor_units;
+        assert_eq!(minor_units("JPY"), Some(0));
+        assert_eq!(minor_units("EUR"), Some(2));
+        assert_eq!(minor_units("BHD"), Some(3));
+        assert_eq!(minor_units("CLF"), Some(4));
+        assert_eq!(minor_units("XAU"), None);
+    }
+
+    #[test]
+    fn amounts_within_minor_units_pass() {
+        for (ccy, amount) in [("JPY", "100"), ("JPY", "100.00"), ("EUR", "100.10"), ("BHD", "1.125"), ("XAU", "1.123456")] {
+            assert!(check(ccy, amount, CurrencyCodes::Active).errors.is_empty(), "{} {}", ccy, amount);
+        }
+    }
+
+    #[test]
+    fn amounts_beyond_minor_units_are_reported() {
+        for (ccy, amount, max) in [("JPY", "100.5", 0), ("EUR", "100.105", 2), ("BHD", "1.1255", 3)] {
+            let report = check(ccy, amount, CurrencyCodes::Active);
+            assert_eq!(report.errors.len(), 1, "{} {}", ccy, amount);
+            assert!(matches!(&*report.errors[0].kind, ValidationErrorKind::CurrencyMinorUnits { currency, max: m, .. } if currency == ccy && *m == max));
+            assert_eq!(report.errors[0].field_path, "value");
+        }
+    }
+
+    #[test]
+    fn unknown_currency_is_reported_at_the_attribute() {
+        let report = check("ABC", "1", CurrencyCodes::ActiveOrHistoric);
+        assert_eq!(report.errors.len(), 1);
+        assert!(matches!(&*report.errors[0].kind, ValidationErrorKind::UnknownCurrency { currency } if currency == "ABC"));
+        assert_eq!(report.errors[0].path, "/@Ccy");
+        // Malformed codes are left to the pattern facet
+        assert!(check("eur", "1", CurrencyCodes::Active).errors.is_empty());
+    }
+
+    #[test]
+    fn historic_currency_depends_on_the_accepted_codes() {
+        assert!(check("DEM", "1.50", CurrencyCodes::ActiveOrHistoric).errors.is_empty());
+        let report = check("DEM", "1.50", CurrencyCodes::Active);
+        assert_eq!(report.errors.len(), 1);
+        assert!(matches!(&*report.errors[0].kind, ValidationErrorKind::HistoricCurrency { currency } if currency == "DEM"));
+    }
+}
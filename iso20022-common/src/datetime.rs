@@ -0,0 +1,486 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+//! `xs:date`, `xs:time` and `xs:dateTime` values that keep the form they were written in.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ValidationError, Validator};
+
+/// Fields of the `ISODate` type: a [`Date`] with the `typed_dates` feature, a `String` otherwise.
+#[cfg(feature = "typed_dates")]
+pub type ISODate = Date;
+/// Fields of the `ISODateTime` type: a [`DateTime`] with the `typed_dates` feature, a `String` otherwise.
+#[cfg(feature = "typed_dates")]
+pub type ISODateTime = DateTime;
+/// Fields of the `ISOTime` type: a [`Time`] with the `typed_dates` feature, a `String` otherwise.
+#[cfg(feature = "typed_dates")]
+pub type ISOTime = Time;
+
+#[cfg(not(feature = "typed_dates"))]
+pub type ISODate = String;
+#[cfg(not(feature = "typed_dates"))]
+pub type ISODateTime = String;
+#[cfg(not(feature = "typed_dates"))]
+pub type ISOTime = String;
+
+/// Time zone of a date or time, kept as written: `Z` and `+00:00` are told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeZone {
+    /// Written `Z`.
+    Utc,
+    /// Written `+hh:mm` or `-hh:mm`, in minutes east of UTC.
+    Offset(i16),
+}
+
+/// An `xs:date`, e.g. `2024-02-29` or `2024-02-29+01:00`.
+///
+/// Parsing only checks the lexical form, so that a message holding `2024-02-30` can still be read
+/// and have the date reported by `validate()` along with every other violation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+    time_zone: Option<TimeZone>,
+}
+
+/// An `xs:time`, e.g. `11:56:35.890+02:00`. The fraction of a second keeps its written digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    fraction_digits: u8,
+    time_zone: Option<TimeZone>,
+}
+
+/// An `xs:dateTime`, e.g. `2021-04-28T11:56:35.890+02:00`, whose time zone is that of its [`Time`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+}
+
+/// Error returned when a date or time is not in its lexical form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDateTimeError {
+    value: String,
+}
+
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid date or time {:?}", self.value)
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+impl Date {
+    pub const fn new(year: i32, month: u8, day: u8) -> Self {
+        Date { year, month, day, time_zone: None }
+    }
+
+    pub fn with_time_zone(self, time_zone: Option<TimeZone>) -> Self {
+        Date { time_zone, ..self }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn time_zone(&self) -> Option<TimeZone> {
+        self.time_zone
+    }
+
+    /// Returns `true` when the day exists in the calendar and the time zone is within ±14:00.
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month)
+            && time_zone_is_valid(self.time_zone)
+    }
+
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        if !self.is_valid() {
+            validator.report(ValidationError::invalid_date_time(&self.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Time {
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Time { hour, minute, second, nanosecond: 0, fraction_digits: 0, time_zone: None }
+    }
+
+    /// Sets the fraction of the second, written with `digits` digits, at most 9.
+    pub fn with_nanosecond(self, nanosecond: u32, digits: u8) -> Self {
+        Time { nanosecond, fraction_digits: digits.min(9), ..self }
+    }
+
+    pub fn with_time_zone(self, time_zone: Option<TimeZone>) -> Self {
+        Time { time_zone, ..self }
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    pub fn time_zone(&self) -> Option<TimeZone> {
+        self.time_zone
+    }
+
+    /// Returns `true` for a time of the day, `24:00:00` included, with a time zone within ±14:00.
+    pub fn is_valid(&self) -> bool {
+        let end_of_day = self.hour == 24 && self.minute == 0 && self.second == 0 && self.nanosecond == 0;
+        (end_of_day || (self.hour < 24 && self.minute < 60 && self.second < 60))
+            && self.nanosecond < 1_000_000_000
+            && time_zone_is_valid(self.time_zone)
+    }
+
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        if !self.is_valid() {
+            validator.report(ValidationError::invalid_date_time(&self.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+impl DateTime {
+    pub const fn new(date: Date, time: Time) -> Self {
+        DateTime { date: Date { time_zone: None, ..date }, time }
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    pub fn time_zone(&self) -> Option<TimeZone> {
+        self.time.time_zone
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.date.is_valid() && self.time.is_valid()
+    }
+
+    /// Reports a date or time out of range and, as ISO 20022 expects, a missing time zone.
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        if !self.is_valid() {
+            validator.report(ValidationError::invalid_date_time(&self.to_string()))?;
+        } else if self.time_zone().is_none() {
+            validator.report(ValidationError::missing_time_zone(&self.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn time_zone_is_valid(time_zone: Option<TimeZone>) -> bool {
+    match time_zone {
+        Some(TimeZone::Offset(minutes)) => minutes.abs() <= 14 * 60,
+        _ => true,
+    }
+}
+
+// `n` ASCII digits at the start of `s`, and the rest of it.
+fn digits(s: &str, n: usize) -> Option<(u32, &str)> {
+    let head = s.get(..n)?;
+    if !head.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((head.parse().ok()?, &s[n..]))
+}
+
+// `-?YYYY-MM-DD` at the start of `s`, years of more than four digits without leading zeros.
+fn parse_date(s: &str) -> Option<(Date, &str)> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let width = s.bytes().take_while(u8::is_ascii_digit).count();
+    if !(4..=9).contains(&width) || (width > 4 && s.starts_with('0')) {
+        return None;
+    }
+    let (year, s) = digits(s, width)?;
+    let (month, s) = digits(s.strip_prefix('-')?, 2)?;
+    let (day, s) = digits(s.strip_prefix('-')?, 2)?;
+    let year = if negative { -(year as i32) } else { year as i32 };
+    Some((Date::new(year, month as u8, day as u8), s))
+}
+
+// `hh:mm:ss(.s+)?` at the start of `s`.
+fn parse_time(s: &str) -> Option<(Time, &str)> {
+    let (hour, s) = digits(s, 2)?;
+    let (minute, s) = digits(s.strip_prefix(':')?, 2)?;
+    let (second, s) = digits(s.strip_prefix(':')?, 2)?;
+    let mut time = Time::new(hour as u8, minute as u8, second as u8);
+    let Some(fraction) = s.strip_prefix('.') else {
+        return Some((time, s));
+    };
+    let width = fraction.bytes().take_while(u8::is_ascii_digit).count();
+    if width == 0 || width > 9 {
+        return None;
+    }
+    let (value, s) = digits(fraction, width)?;
+    time = time.with_nanosecond(value * 10u32.pow(9 - width as u32), width as u8);
+    Some((time, s))
+}
+
+// `Z`, `(+|-)hh:mm` or nothing, making up the whole of `s`.
+fn parse_time_zone(s: &str) -> Option<Option<TimeZone>> {
+    if s.is_empty() {
+        return Some(None);
+    }
+    if s == "Z" {
+        return Some(Some(TimeZone::Utc));
+    }
+    let sign = match s.as_bytes()[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let (hours, rest) = digits(&s[1..], 2)?;
+    let (minutes, rest) = digits(rest.strip_prefix(':')?, 2)?;
+    if !rest.is_empty() || minutes > 59 {
+        return None;
+    }
+    Some(Some(TimeZone::Offset(sign * (hours * 60 + minutes) as i16)))
+}
+
+impl FromStr for Date {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDateTimeError { value: s.to_string() };
+        let (date, rest) = parse_date(s.trim()).ok_or_else(error)?;
+        let time_zone = parse_time_zone(rest).ok_or_else(error)?;
+        Ok(date.with_time_zone(time_zone))
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDateTimeError { value: s.to_string() };
+        let (time, rest) = parse_time(s.trim()).ok_or_else(error)?;
+        let time_zone = parse_time_zone(rest).ok_or_else(error)?;
+        Ok(time.with_time_zone(time_zone))
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDateTimeError { value: s.to_string() };
+        let (date, rest) = parse_date(s.trim()).ok_or_else(error)?;
+        let (time, rest) = parse_time(rest.strip_prefix('T').ok_or_else(error)?).ok_or_else(error)?;
+        let time_zone = parse_time_zone(rest).ok_or_else(error)?;
+        Ok(DateTime::new(date, time.with_time_zone(time_zone)))
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimeZone::Utc => write!(f, "Z"),
+            TimeZone::Offset(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                write!(f, "{}{:02}:{:02}", sign, minutes.unsigned_abs() / 60, minutes.unsigned_abs() % 60)
+            }
+        }
+    }
+}
+
+fn write_time_zone(f: &mut fmt::Formatter, time_zone: Option<TimeZone>) -> fmt::Result {
+    match time_zone {
+        Some(time_zone) => write!(f, "{}", time_zone),
+        None => Ok(()),
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.year < 0 { "-" } else { "" };
+        write!(f, "{}{:04}-{:02}-{:02}", sign, self.year.unsigned_abs(), self.month, self.day)?;
+        write_time_zone(f, self.time_zone)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.fraction_digits > 0 {
+            let fraction = self.nanosecond / 10u32.pow(9 - self.fraction_digits as u32);
+            write!(f, ".{:0width$}", fraction, width = self.fraction_digits as usize)?;
+        }
+        write_time_zone(f, self.time_zone)
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+macro_rules! serde_as_string {
+    ($($ty:ident: $expecting:literal),*) => {$(
+        #[cfg(feature = "derive_serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "derive_serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = $ty;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<$ty, E> {
+                        value.parse().map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+    )*};
+}
+
+serde_as_string!(Date: "an xs:date value", Time: "an xs:time value", DateTime: "an xs:dateTime value");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ValidationErrorKind, ValidationReport};
+
+    // The text of every element of `xml` that is an `xs:dateTime`.
+    fn date_times(xml: &str) -> Vec<&str> {
+        xml.split('>').filter_map(|s| s.split('<').next()).filter(|text| text.parse::<DateTime>().is_ok()).collect()
+    }
+
+    #[test]
+    fn sample_date_times_round_trip() {
+        let xml = include_str!("../../samples/Time_Fields_TimeZone_00_00.xml");
+        let values = date_times(xml);
+        assert_eq!(values, ["2020-06-21T10:37:00+00:00", "2020-03-21T10:37:00+00:00"]);
+        for value in values {
+            let date_time: DateTime = value.parse().unwrap();
+            assert_eq!(date_time.time_zone(), Some(TimeZone::Offset(0)));
+            assert_eq!(date_time.to_string(), value);
+        }
+    }
+
+    #[test]
+    fn utc_and_zero_offset_stay_distinct() {
+        let utc: DateTime = "2020-06-21T10:37:00Z".parse().unwrap();
+        let offset: DateTime = "2020-06-21T10:37:00+00:00".parse().unwrap();
+        assert_eq!(utc.time_zone(), Some(TimeZone::Utc));
+        assert_ne!(utc, offset);
+        assert_eq!(utc.to_string(), "2020-06-21T10:37:00Z");
+        assert_eq!(offset.to_string(), "2020-06-21T10:37:00+00:00");
+    }
+
+    #[test]
+    fn fraction_keeps_its_digits() {
+        for value in ["2021-04-28T11:56:35.890+02:00", "2021-04-28T11:56:35.000000001-05:30", "2021-04-28T24:00:00Z"] {
+            assert_eq!(value.parse::<DateTime>().unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn impossible_date_parses_and_fails_validation() {
+        let date: Date = "2024-02-30".parse().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 30));
+        assert!(!date.is_valid());
+        let report = ValidationReport::collect(|validator| date.validate_with(validator));
+        assert_eq!(report.errors.len(), 1);
+        assert!(matches!(&*report.errors[0].kind, ValidationErrorKind::InvalidDateTime { value: Some(value) } if value == "2024-02-30"));
+        assert!("2024-02-29".parse::<Date>().unwrap().is_valid());
+        assert!(!"2023-02-29".parse::<Date>().unwrap().is_valid());
+    }
+
+    #[test]
+    fn date_time_without_time_zone_is_reported() {
+        let date_time: DateTime = "2021-04-28T11:56:35.890".parse().unwrap();
+        assert_eq!(date_time.time_zone(), None);
+        assert_eq!(date_time.to_string(), "2021-04-28T11:56:35.890");
+        let report = ValidationReport::collect(|validator| date_time.validate_with(validator));
+        assert_eq!(report.errors.len(), 1);
+        assert!(matches!(&*report.errors[0].kind, ValidationErrorKind::MissingTimeZone { value: Some(value) } if value == "2021-04-28T11:56:35.890"));
+    }
+
+    #[test]
+    fn malformed_values_do_not_parse() {
+        for value in ["2024-2-01", "24-02-01", "2024-02-01T11:56", "2024-02-01T11:56:35.", "2024-02-01T11:56:35+0200", "2024-02-01Z1"] {
+            assert!(value.parse::<DateTime>().is_err(), "{}", value);
+        }
+    }
+}
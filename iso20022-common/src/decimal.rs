@@ -0,0 +1,385 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Exact `xs:decimal` values for amounts, rates and counters.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// An exact `xs:decimal` value.
+///
+/// The value is kept as `mantissa / 10^scale` with the scale it was written with, so `100.10`
+/// is written back as `100.10`. Comparison and equality are numeric: `100.10 == 100.1`.
+/// Values are serialized as strings, in XML as in JSON, so no digit is lost to a binary float.
+/// JSON numbers are read as well: exactly with the `arbitrary_precision` feature of `serde_json`,
+/// otherwise through an `f64`, which keeps `10.0` but not the trailing zero of `100.10`.
+#[derive(Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// Error returned when parsing a [`Decimal`] fails.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDecimalError {
+    value: String,
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid decimal {:?}", self.value)
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal { mantissa: 0, scale: 0 };
+
+    /// Creates the value `mantissa / 10^scale`.
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        Decimal { mantissa, scale }
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of fraction digits as written, trailing zeros included.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Number of significant digits, as constrained by the `totalDigits` facet.
+    pub fn total_digits(&self) -> u32 {
+        let normalized = self.normalize();
+        let digits = normalized.mantissa.unsigned_abs().checked_ilog10().map_or(1, |log| log + 1);
+        digits.max(normalized.scale)
+    }
+
+    /// Number of fraction digits without trailing zeros, as constrained by the `fractionDigits` facet.
+    pub fn fraction_digits(&self) -> u32 {
+        self.normalize().scale
+    }
+
+    /// Returns the same value without trailing fraction zeros.
+    pub fn normalize(&self) -> Decimal {
+        let mut value = *self;
+        while value.scale > 0 && value.mantissa % 10 == 0 {
+            value.mantissa /= 10;
+            value.scale -= 1;
+        }
+        value
+    }
+
+    /// Adds `other`, keeping the larger scale of both. Returns `None` on overflow.
+    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        let left = self.rescale(scale)?;
+        let right = other.rescale(scale)?;
+        Some(Decimal { mantissa: left.checked_add(right)?, scale })
+    }
+
+    /// Nearest `f64`, for display or arithmetic where exactness does not matter.
+    pub fn to_f64(self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    // Mantissa of the value written with `scale` fraction digits, `scale` >= `self.scale`.
+    fn rescale(&self, scale: u32) -> Option<i128> {
+        10i128.checked_pow(scale - self.scale).and_then(|factor| self.mantissa.checked_mul(factor))
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parses the `xs:decimal` lexical form: an optional sign, digits and an optional fraction.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDecimalError { value: s.to_string() };
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.as_bytes().first() {
+            Some(b'-') => (true, &trimmed[1..]),
+            Some(b'+') => (false, &trimmed[1..]),
+            _ => (false, trimmed),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(error());
+        }
+        let mut mantissa: i128 = 0;
+        for c in integer.chars().chain(fraction.chars()) {
+            let digit = c.to_digit(10).ok_or_else(error)?;
+            mantissa = mantissa.checked_mul(10).and_then(|m| m.checked_add(digit as i128)).ok_or_else(error)?;
+        }
+        let scale = fraction.len() as u32;
+        Ok(Decimal { mantissa: if negative { -mantissa } else { mantissa }, scale })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            // Only the side that overflowed can be larger in magnitude than the other.
+            (None, _) => if self.is_negative() { Ordering::Less } else { Ordering::Greater },
+            (_, None) => if other.is_negative() { Ordering::Greater } else { Ordering::Less },
+        }
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.mantissa.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal { mantissa: value as i128, scale: 0 }
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(value: u64) -> Self {
+        Decimal { mantissa: value as i128, scale: 0 }
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl serde::Serialize for Decimal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> serde::Deserialize<'de> for Decimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Key under which `serde_json` hands over the digits of a number with `arbitrary_precision`.
+        const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+        struct DecimalVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DecimalVisitor {
+            type Value = Decimal;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an xs:decimal value")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Decimal, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Decimal, E> {
+                Ok(Decimal::from(value))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Decimal, E> {
+                Ok(Decimal::from(value))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Decimal, E> {
+                if !value.is_finite() {
+                    return Err(E::custom(format!("invalid decimal {}", value)));
+                }
+                // The shortest digits reading back as `value`, `10.0` staying `10.0`.
+                let digits = format!("{:?}", value);
+                match digits.split_once('e') {
+                    None => digits.parse().map_err(E::custom),
+                    Some((significand, exponent)) => {
+                        let error = || E::custom(format!("decimal {} out of range", digits));
+                        let significand: Decimal = significand.parse().map_err(E::custom)?;
+                        let exponent: i64 = exponent.parse().map_err(E::custom)?;
+                        // `m / 10^s * 10^e` is written with `s - e` fraction digits, if any.
+                        let scale = significand.scale as i64 - exponent;
+                        if scale >= 0 {
+                            Ok(Decimal { scale: u32::try_from(scale).map_err(|_| error())?, ..significand })
+                        } else {
+                            let factor = u32::try_from(-scale).ok().and_then(|power| 10i128.checked_pow(power)).ok_or_else(error)?;
+                            let mantissa = significand.mantissa.checked_mul(factor).ok_or_else(error)?;
+                            Ok(Decimal { mantissa, scale: 0 })
+                        }
+                    }
+                }
+            }
+
+            // An XML element read as a map of its attributes and `$text`, or a number of
+            // `serde_json` with `arbitrary_precision`.
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Decimal, A::Error> {
+                let mut digits = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "$text" || key == NUMBER_TOKEN {
+                        digits = Some(map.next_value::<String>()?);
+                    } else {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+                match digits {
+                    Some(digits) => digits.parse().map_err(serde::de::Error::custom),
+                    None => Err(serde::de::Error::invalid_type(serde::de::Unexpected::Map, &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn written_form_round_trips() {
+        for value in ["100.10", "0.00", "-0.5", "12345678901234567.89", "7", "0.000001"] {
+            assert_eq!(decimal(value).to_string(), value);
+        }
+        assert_eq!(decimal("+1.50").to_string(), "1.50");
+        assert_eq!(decimal(".5").to_string(), "0.5");
+        assert_eq!(decimal("5.").to_string(), "5");
+    }
+
+    #[test]
+    fn malformed_values_do_not_parse() {
+        for value in ["", "-", ".", "1.2.3", "1,5", "1e5", "abc", "--1", "1 000", "170141183460469231731687303715884105728"] {
+            assert!(value.parse::<Decimal>().is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn digit_facets() {
+        assert_eq!((decimal("100.10").total_digits(), decimal("100.10").fraction_digits()), (4, 1));
+        assert_eq!((decimal("0.001").total_digits(), decimal("0.001").fraction_digits()), (3, 3));
+        assert_eq!((decimal("1000").total_digits(), decimal("1000").fraction_digits()), (4, 0));
+        assert_eq!((decimal("0").total_digits(), decimal("0.00").fraction_digits()), (1, 0));
+    }
+
+    #[test]
+    fn equality_order_and_hash_are_numeric() {
+        assert_eq!(decimal("1.0"), decimal("1.00"));
+        assert_eq!(decimal("1.0"), decimal("1"));
+        assert!(decimal("1.01") > decimal("1.009"));
+        assert!(decimal("-2") < decimal("-1.5"));
+        let set: HashSet<Decimal> = ["1", "1.0", "1.00", "2.50", "2.5"].iter().map(|s| decimal(s)).collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn checked_add_keeps_the_larger_scale() {
+        assert_eq!(decimal("1.5").checked_add(decimal("2.25")).map(|sum| sum.to_string()), Some("3.75".to_string()));
+        assert_eq!(decimal("10").checked_add(decimal("0.10")).map(|sum| sum.to_string()), Some("10.10".to_string()));
+        assert_eq!(Decimal::new(i128::MAX, 0).checked_add(decimal("1")), None);
+        // Rescaling to the other scale overflows as well
+        assert_eq!(Decimal::new(i128::MAX / 10, 0).checked_add(decimal("0.01")), None);
+    }
+
+    #[cfg(feature = "derive_serde")]
+    #[test]
+    fn json_strings_and_numbers() {
+        let amounts: Vec<Decimal> = serde_json::from_str(r#"["100.10", 10.0, 7, -3, 0.25, 1e-7, 1.5e20]"#).unwrap();
+        let written: Vec<String> = amounts.iter().map(Decimal::to_string).collect();
+        assert_eq!(written, ["100.10", "10.0", "7", "-3", "0.25", "0.0000001", "150000000000000000000"]);
+        assert_eq!(serde_json::to_string(&amounts[0]).unwrap(), r#""100.10""#);
+        assert!(serde_json::from_str::<Decimal>(r#""1,5""#).is_err());
+        assert!(serde_json::from_str::<Decimal>("true").is_err());
+        assert!(serde_json::from_str::<Decimal>(r#"{"value": 1}"#).is_err());
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn xml_text_and_attributes() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Amount {
+            #[serde(rename = "@Ccy")]
+            ccy: String,
+            #[serde(rename = "$value")]
+            value: Decimal,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Message {
+            #[serde(rename = "@Rate")]
+            rate: Decimal,
+            #[serde(rename = "Amt")]
+            amt: Amount,
+            #[serde(rename = "CtrlSum")]
+            ctrl_sum: Option<Decimal>,
+        }
+
+        let xml = r#"<Message Rate="1.250"><Amt Ccy="EUR">100.10</Amt><CtrlSum>0.50</CtrlSum></Message>"#;
+        let message: Message = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!((message.rate.to_string(), message.amt.value.to_string()), ("1.250".to_string(), "100.10".to_string()));
+        assert_eq!(quick_xml::se::to_string(&message).unwrap(), xml);
+    }
+}
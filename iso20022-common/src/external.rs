@@ -0,0 +1,155 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! ISO 20022 External Code Sets, e.g. the status reasons of `ExternalStatusReason1Code`.
+//!
+//! The schemas only constrain these codes by length, the codes themselves are published
+//! separately and change every quarter. The bundled codes, see [`RELEASE`], can be looked up
+//! for their name and definition, and are checked by the generated validators when the
+//! validator was built with [`Validator::with_code_sets`]. Code sets that are not bundled
+//! are not checked.
+
+use crate::{ValidationError, Validator};
+
+/// A code of an External Code Set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalCode {
+    pub code: &'static str,
+    /// Name of the code, e.g. `IncorrectAccountNumber` for `AC01`.
+    pub name: &'static str,
+    pub definition: &'static str,
+}
+
+/// An External Code Set with its codes, named after the schema type, e.g. `ExternalPurpose1Code`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalCodeSet {
+    pub name: &'static str,
+    pub codes: &'static [ExternalCode],
+}
+
+impl ExternalCodeSet {
+    /// Every bundled code set, sorted by name.
+    pub fn all() -> &'static [ExternalCodeSet] {
+        CODE_SETS
+    }
+
+    /// Looks up a bundled code set by the name of its schema type.
+    pub fn find(name: &str) -> Option<&'static ExternalCodeSet> {
+        CODE_SETS.binary_search_by(|code_set| code_set.name.cmp(name)).ok().map(|i| &CODE_SETS[i])
+    }
+
+    /// Looks up a code of the set.
+    pub fn lookup(&self, code: &str) -> Option<&'static ExternalCode> {
+        self.codes.binary_search_by(|entry| entry.code.cmp(code)).ok().map(|i| &self.codes[i])
+    }
+}
+
+/// Looks up `code` in the bundled code set `code_set`, e.g. `lookup("ExternalStatusReason1Code", "AC01")`.
+pub fn lookup(code_set: &str, code: &str) -> Option<&'static ExternalCode> {
+    ExternalCodeSet::find(code_set)?.lookup(code)
+}
+
+/// Checks the `value` of the child `element` (Rust `field`) against the bundled `code_set` when
+/// the validator checks code sets, see [`Validator::with_code_sets`].
+pub fn validate(validator: &mut Validator, element: &str, field: &str, code_set: &'static str, value: &str) -> Result<(), ValidationError> {
+    if !validator.checks_code_sets() {
+        return Ok(());
+    }
+    match ExternalCodeSet::find(code_set) {
+        Some(codes) if codes.lookup(value).is_none() => validator.report_at(element, field, ValidationError::unknown_code(code_set, value)),
+        _ => Ok(()),
+    }
+}
+
+include!("external_code_sets.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ValidationErrorKind, ValidationReport};
+
+    fn check(validator: Validator, code_set: &'static str, value: &str) -> ValidationReport {
+        let mut validator = validator;
+        let _ = validator.element("StsRsnInf", "sts_rsn_inf", |validator| {
+            validator.element("Rsn", "rsn", |validator| validate(validator, "Cd", "cd", code_set, value))
+        });
+        validator.into_report()
+    }
+
+    #[test]
+    fn code_sets_are_sorted() {
+        assert!(CODE_SETS.windows(2).all(|pair| pair[0].name < pair[1].name));
+        for code_set in ExternalCodeSet::all() {
+            assert_eq!(ExternalCodeSet::find(code_set.name), Some(code_set));
+            assert!(code_set.codes.windows(2).all(|pair| pair[0].code < pair[1].code), "{}", code_set.name);
+        }
+    }
+
+    #[test]
+    fn lookup_finds_bundled_codes() {
+        let code = lookup("ExternalStatusReason1Code", "AC01").unwrap();
+        assert_eq!(code.code, "AC01");
+        assert_eq!(code.name, "IncorrectAccountNumber");
+        assert_eq!(lookup("ExternalStatusReason1Code", "AM04").unwrap().name, "InsufficientFunds");
+        // The same code means different things in different sets.
+        assert_eq!(lookup("ExternalCashAccountType1Code", "CASH").unwrap().name, "CashPayment");
+        assert_eq!(lookup("ExternalCategoryPurpose1Code", "CASH").unwrap().name, "CashManagementTransfer");
+    }
+
+    #[test]
+    fn lookup_misses_unknown_codes_and_sets() {
+        assert_eq!(lookup("ExternalStatusReason1Code", "AC0l"), None);
+        assert_eq!(lookup("ExternalStatusReason1Code", "ac01"), None);
+        assert_eq!(lookup("ExternalNoSuch1Code", "AC01"), None);
+    }
+
+    #[test]
+    fn codes_are_checked_only_with_code_sets() {
+        assert!(check(Validator::accumulate(), "ExternalStatusReason1Code", "AC0l").is_valid());
+        assert!(check(Validator::accumulate().with_code_sets(), "ExternalStatusReason1Code", "AC01").is_valid());
+    }
+
+    #[test]
+    fn misspelt_code_is_reported() {
+        let report = check(Validator::accumulate().with_code_sets(), "ExternalStatusReason1Code", "AC0l");
+        assert_eq!(report.errors.len(), 1);
+        let error = &report.errors[0];
+        assert_eq!(error.code, 1014);
+        assert_eq!(error.path, "/StsRsnInf/Rsn/Cd");
+        assert_eq!(error.field_path, "sts_rsn_inf.rsn.cd");
+        match &*error.kind {
+            ValidationErrorKind::UnknownCode { code_set, code } => {
+                assert_eq!(*code_set, "ExternalStatusReason1Code");
+                assert_eq!(code, "AC0l");
+            }
+            kind => panic!("{:?}", kind),
+        }
+    }
+
+    #[test]
+    fn sets_that_are_not_bundled_are_not_checked() {
+        assert!(ExternalCodeSet::find("ExternalServiceLevel1Code").is_none());
+        assert!(check(Validator::accumulate().with_code_sets(), "ExternalServiceLevel1Code", "XXXX").is_valid());
+    }
+}
@@ -0,0 +1,432 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+// Generated by generate-codesets.py from codesets/ExternalCodeSets.tsv, see external.rs.
+
+/// Release of the External Code Sets the bundled codes were taken from.
+pub const RELEASE: &str = "2Q2024";
+
+static CODE_SETS: &[ExternalCodeSet] = &[
+	ExternalCodeSet { name: "ExternalAccountIdentification1Code", codes: &[
+		ExternalCode { code: "AIIN", name: "IssuerIdentificationNumber", definition: "Issuer Identification Number (IIN) - identifies a card issuing institution in an international interchange environment. Issued by ABA (American Bankers Association)." },
+		ExternalCode { code: "BBAN", name: "BBANIdentifier", definition: "Basic Bank Account Number (BBAN) - identifier used nationally by financial institutions, ie, in individual countries, generally as part of a National Account Numbering Scheme(s), to uniquely identify the account of a customer." },
+		ExternalCode { code: "CUID", name: "CHIPSUniversalIdentifier", definition: "(United States) Clearing House Interbank Payments System (CHIPS) Universal Identification (UID) - identifies entities that own accounts at CHIPS participating financial institutions, through which CHIPS payments are effected. The CHIPS UID is assigned by the New York Clearing House." },
+		ExternalCode { code: "UPIC", name: "UPICIdentifier", definition: "Universal Payment Identification Code (UPIC) - identifier used by the New York Clearing House to mask confidential data, such as bank accounts and bank routing numbers. UPIC numbers remain with business customers, regardless of banking relationship changes." },
+	] },
+	ExternalCodeSet { name: "ExternalCashAccountType1Code", codes: &[
+		ExternalCode { code: "CACC", name: "Current", definition: "Account used to post debits and credits when no specific account has been nominated." },
+		ExternalCode { code: "CARD", name: "CardAccount", definition: "Account used for card payments only." },
+		ExternalCode { code: "CASH", name: "CashPayment", definition: "Account used for the payment of cash." },
+		ExternalCode { code: "CHAR", name: "Charges", definition: "Account used for charges if different from the account for payment." },
+		ExternalCode { code: "CISH", name: "CashIncome", definition: "Account used for payment of income if different from the current cash account." },
+		ExternalCode { code: "COMM", name: "Commission", definition: "Account used for commission if different from the account for payment." },
+		ExternalCode { code: "CPAC", name: "ClearingParticipantSettlementAccount", definition: "Account used to post settlement debit and credit entries on behalf of a designated Clearing Participant." },
+		ExternalCode { code: "LLSV", name: "LimitedLiquiditySavingsAccount", definition: "Account used for savings with special interest and withdrawal terms." },
+		ExternalCode { code: "LOAN", name: "Loan", definition: "Account used for loans." },
+		ExternalCode { code: "MGLD", name: "MarginalLending", definition: "Account used for a marginal lending facility." },
+		ExternalCode { code: "MOMA", name: "MoneyMarket", definition: "Account used for money markets if different from the cash account." },
+		ExternalCode { code: "NFCA", name: "NonResidentForeignCurrencyAccount", definition: "Non-Resident Individual / Entity Foreign Current held domestically." },
+		ExternalCode { code: "NREX", name: "NonResidentExternal", definition: "Account used for non-resident external." },
+		ExternalCode { code: "ODFT", name: "Overdraft", definition: "Account is used for overdrafts." },
+		ExternalCode { code: "ONDP", name: "OverNightDeposit", definition: "Account used for overnight deposits." },
+		ExternalCode { code: "OTHR", name: "OtherAccount", definition: "Account not otherwise specified." },
+		ExternalCode { code: "SACC", name: "Settlement", definition: "Account used to post debit and credit entries, as a result of transactions cleared and settled through a specific clearing and settlement system." },
+		ExternalCode { code: "SLRY", name: "Salary", definition: "Accounts used for salary payments." },
+		ExternalCode { code: "SVGS", name: "Savings", definition: "Account used for savings." },
+		ExternalCode { code: "TAXE", name: "Tax", definition: "Account used for taxes if different from the account for payment." },
+		ExternalCode { code: "TRAN", name: "TransactingAccount", definition: "A transacting account is the most basic type of bank account that you can get. The main difference between transaction and cheque accounts is that you usually do not get a cheque book with your transacting account and neither are you offered an overdraft facility." },
+		ExternalCode { code: "TRAS", name: "CashTrading", definition: "Account used for trading if different from the current cash account." },
+		ExternalCode { code: "VACC", name: "VirtualAccount", definition: "Account created virtually to facilitate collection and reconciliation." },
+	] },
+	ExternalCodeSet { name: "ExternalCategoryPurpose1Code", codes: &[
+		ExternalCode { code: "BONU", name: "BonusPayment", definition: "Transaction is the payment of a bonus." },
+		ExternalCode { code: "CASH", name: "CashManagementTransfer", definition: "Transaction is a general cash management instruction." },
+		ExternalCode { code: "CBLK", name: "CardBulkClearing", definition: "A service that is settling money for a bulk of card transactions, while referring to a specific transaction file or other information like terminal ID, card acceptor ID or other transaction details." },
+		ExternalCode { code: "CCRD", name: "CreditCardPayment", definition: "Transaction is related to a payment of credit card." },
+		ExternalCode { code: "CORT", name: "TradeSettlementPayment", definition: "Transaction is related to settlement of a trade, eg a foreign exchange deal or a securities transaction." },
+		ExternalCode { code: "DCRD", name: "DebitCardPayment", definition: "Transaction is related to a payment of debit card." },
+		ExternalCode { code: "DIVI", name: "Dividend", definition: "Transaction is the payment of dividends." },
+		ExternalCode { code: "DVPM", name: "DeliverAgainstPayment", definition: "Code used to pre-advise the account servicer of a forthcoming deliver against payment instruction." },
+		ExternalCode { code: "EPAY", name: "Epayment", definition: "Transaction is related to ePayment." },
+		ExternalCode { code: "FCDT", name: "ForeignCurrencyDomesticTransfer", definition: "Foreign Currency Transaction that is processed between two domestic financial institutions." },
+		ExternalCode { code: "FCIN", name: "FeeCollectionAndInterest", definition: "Transaction is related to the payment of a fee and interest." },
+		ExternalCode { code: "FCOL", name: "FeeCollection", definition: "A service that is settling card transaction related fees between two parties." },
+		ExternalCode { code: "GOVT", name: "GovernmentPayment", definition: "Transaction is a payment to or from a government department." },
+		ExternalCode { code: "GP2P", name: "PersonToPersonPayment", definition: "General Person-to-Person Payment. Debtor and Creditor are natural persons." },
+		ExternalCode { code: "HEDG", name: "Hedging", definition: "Transaction is related to the payment of a hedging operation." },
+		ExternalCode { code: "ICCP", name: "IrrevocableCreditCardPayment", definition: "Transaction is reimbursement of credit card payment." },
+		ExternalCode { code: "IDCP", name: "IrrevocableDebitCardPayment", definition: "Transaction is reimbursement of debit card payment." },
+		ExternalCode { code: "INTC", name: "IntraCompanyPayment", definition: "Transaction is an intra-company payment, ie, a payment between two companies belonging to the same group." },
+		ExternalCode { code: "INTE", name: "Interest", definition: "Transaction is the payment of interest." },
+		ExternalCode { code: "LBOX", name: "LockboxTransactions", definition: "Transaction is related to identify cash handling via Night Safe or Lockbox by bank or vendor on behalf of a physical store." },
+		ExternalCode { code: "LOAN", name: "Loan", definition: "Transaction is related to the transfer of a loan to a borrower." },
+		ExternalCode { code: "MP2B", name: "Commercial", definition: "Mobile P2B Payment." },
+		ExternalCode { code: "MP2P", name: "Consumer", definition: "Mobile P2P Payment." },
+		ExternalCode { code: "OTHR", name: "OtherPayment", definition: "Other payment purpose." },
+		ExternalCode { code: "PENS", name: "PensionPayment", definition: "Transaction is the payment of pension." },
+		ExternalCode { code: "RPRE", name: "Represented", definition: "Collection used to re-present previously reversed or returned direct debit transactions." },
+		ExternalCode { code: "RRCT", name: "ReimbursementReceivedCreditTransfer", definition: "Transaction is related to a reimbursement for commercial reasons of a correctly received credit transfer." },
+		ExternalCode { code: "RVPM", name: "ReceiveAgainstPayment", definition: "Code used to pre-advise the account servicer of a forthcoming receive against payment instruction." },
+		ExternalCode { code: "SALA", name: "SalaryPayment", definition: "Transaction is the payment of salaries." },
+		ExternalCode { code: "SECU", name: "Securities", definition: "Transaction is the payment of securities." },
+		ExternalCode { code: "SSBE", name: "SocialSecurityBenefit", definition: "Transaction is a social security benefit, ie payment made by a government to support individuals." },
+		ExternalCode { code: "SUPP", name: "SupplierPayment", definition: "Transaction is related to a payment to a supplier." },
+		ExternalCode { code: "TAXS", name: "TaxPayment", definition: "Transaction is the payment of taxes." },
+		ExternalCode { code: "TRAD", name: "Trade", definition: "Transaction is related to the payment of a trade finance transaction." },
+		ExternalCode { code: "TREA", name: "TreasuryPayment", definition: "Transaction is related to treasury operations. E.g. financial contract settlement." },
+		ExternalCode { code: "VATX", name: "ValueAddedTaxPayment", definition: "Transaction is the payment of value added tax." },
+		ExternalCode { code: "VOST", name: "CrossborderMIPayments", definition: "Transaction to be processed as a domestic payment instruction originated from a foreign bank." },
+		ExternalCode { code: "WHLD", name: "WithHolding", definition: "Transaction is the payment of withholding tax." },
+		ExternalCode { code: "ZABA", name: "ZeroBalanceAccount", definition: "Transaction is related to cash management instruction, requesting to zero balance the account." },
+	] },
+	ExternalCodeSet { name: "ExternalOrganisationIdentification1Code", codes: &[
+		ExternalCode { code: "BANK", name: "BankPartyIdentification", definition: "Unique and unambiguous assignment made by a specific bank or similar financial institution to identify a relationship as defined between the bank and its client." },
+		ExternalCode { code: "BDID", name: "BusinessDomainIdentifier", definition: "Identifier of the business domain in which the organisation is active." },
+		ExternalCode { code: "BOID", name: "BusinessOtherIdentification", definition: "Other identification of the organisation." },
+		ExternalCode { code: "CBID", name: "CentralBankIdentificationNumber", definition: "A unique identification number assigned by a central bank to identify an organisation." },
+		ExternalCode { code: "CHID", name: "ClearingIdentificationNumber", definition: "A unique identification number assigned by a clearing house to identify an organisation." },
+		ExternalCode { code: "CINC", name: "CertificateOfIncorporationNumber", definition: "A unique identification number assigned by a designated authority to a certificate of incorporation and used to identify an organisation." },
+		ExternalCode { code: "COID", name: "CountryIdentificationCode", definition: "Country authority given organisation identification (e.g., corporate registration number)." },
+		ExternalCode { code: "CUST", name: "CustomerNumber", definition: "Number assigned by an issuer to identify a customer." },
+		ExternalCode { code: "DUNS", name: "DataUniversalNumberingSystem", definition: "A unique identification number provided by Dun & Bradstreet to identify an organisation." },
+		ExternalCode { code: "EMPL", name: "EmployerIdentificationNumber", definition: "Number assigned by a registration authority to an employer." },
+		ExternalCode { code: "GS1G", name: "GS1GLNIdentifier", definition: "Global Location Number. A non-significant reference number used to identify legal entities, functional entities, or physical entities according to GS1 numbering scheme rules." },
+		ExternalCode { code: "SREN", name: "SIREN", definition: "The SIREN number is a 9 digit code assigned by INSEE, the French National Institute for Statistics and Economic Studies, to identify an organisation in France." },
+		ExternalCode { code: "SRET", name: "SIRET", definition: "The SIRET number is a 14 digit code assigned by INSEE, the French National Institute for Statistics and Economic Studies, to identify an organisation unit in France. It consists of the SIREN number, followed by a five digit classification number, to identify the local geographical unit of that entity." },
+		ExternalCode { code: "TXID", name: "TaxIdentificationNumber", definition: "Number assigned by a tax authority to identify an organisation." },
+	] },
+	ExternalCodeSet { name: "ExternalPaymentGroupStatus1Code", codes: &[
+		ExternalCode { code: "ACCC", name: "AcceptedSettlementCompletedCreditorAccount", definition: "Settlement on the creditor's account has been completed." },
+		ExternalCode { code: "ACCP", name: "AcceptedCustomerProfile", definition: "Preceding check of technical validation was successful. Customer profile check was also successful." },
+		ExternalCode { code: "ACSC", name: "AcceptedSettlementCompletedDebitorAccount", definition: "Settlement on the debtor's account has been completed." },
+		ExternalCode { code: "ACSP", name: "AcceptedSettlementInProcess", definition: "All preceding checks such as technical validation and customer profile were successful and therefore the payment initiation has been accepted for execution." },
+		ExternalCode { code: "ACTC", name: "AcceptedTechnicalValidation", definition: "Authentication and syntactical and semantical validation are successful." },
+		ExternalCode { code: "ACWC", name: "AcceptedWithChange", definition: "Instruction is accepted but a change will be made, such as date or remittance not sent." },
+		ExternalCode { code: "PART", name: "PartiallyAccepted", definition: "A number of transactions have been accepted, whereas another number of transactions have not yet achieved 'accepted' status." },
+		ExternalCode { code: "PDNG", name: "Pending", definition: "Payment initiation or individual transaction included in the payment initiation is pending. Further checks and status update will be performed." },
+		ExternalCode { code: "RCVD", name: "Received", definition: "Payment initiation has been received by the receiving agent." },
+		ExternalCode { code: "RJCT", name: "Rejected", definition: "Payment initiation or individual transaction included in the payment initiation has been rejected." },
+	] },
+	ExternalCodeSet { name: "ExternalPaymentTransactionStatus1Code", codes: &[
+		ExternalCode { code: "ACCC", name: "AcceptedSettlementCompletedCreditorAccount", definition: "Settlement on the creditor's account has been completed." },
+		ExternalCode { code: "ACCP", name: "AcceptedCustomerProfile", definition: "Preceding check of technical validation was successful. Customer profile check was also successful." },
+		ExternalCode { code: "ACFC", name: "AcceptedFundsChecked", definition: "Preceding check of technical validation and customer profile was successful and an automatic funds check was positive." },
+		ExternalCode { code: "ACIS", name: "AcceptedandChequeIssued", definition: "Payment instruction to issue a cheque has been accepted, and the cheque has been issued but not yet been deposited or cleared." },
+		ExternalCode { code: "ACPD", name: "AcceptedClearingProcessed", definition: "Status of transaction released from the Debtor Agent and accepted by the clearing." },
+		ExternalCode { code: "ACSC", name: "AcceptedSettlementCompletedDebitorAccount", definition: "Settlement on the debtor's account has been completed." },
+		ExternalCode { code: "ACSP", name: "AcceptedSettlementInProcess", definition: "All preceding checks such as technical validation and customer profile were successful and therefore the payment initiation has been accepted for execution." },
+		ExternalCode { code: "ACTC", name: "AcceptedTechnicalValidation", definition: "Authentication and syntactical and semantical validation are successful." },
+		ExternalCode { code: "ACWC", name: "AcceptedWithChange", definition: "Instruction is accepted but a change will be made, such as date or remittance not sent." },
+		ExternalCode { code: "ACWP", name: "AcceptedWithoutPosting", definition: "Payment instruction included in the credit transfer is accepted without being posted to the creditor customer's account." },
+		ExternalCode { code: "BLCK", name: "Blocked", definition: "Payment transaction previously reported with status 'ACWP' is blocked, for example, funds will neither be posted to the Creditor's account, nor be returned to the Debtor." },
+		ExternalCode { code: "CANC", name: "Cancelled", definition: "Payment initiation has been successfully cancelled after having received a request for cancellation." },
+		ExternalCode { code: "PATC", name: "PartiallyAcceptedTechnicalCorrect", definition: "Payment initiation needs multiple authentications, where some but not yet all have been performed." },
+		ExternalCode { code: "PDNG", name: "Pending", definition: "Payment initiation or individual transaction included in the payment initiation is pending. Further checks and status update will be performed." },
+		ExternalCode { code: "PRES", name: "Presented", definition: "Request for Payment has been presented to the Debtor." },
+		ExternalCode { code: "RCVD", name: "Received", definition: "Payment initiation has been received by the receiving agent." },
+		ExternalCode { code: "RJCT", name: "Rejected", definition: "Payment initiation or individual transaction included in the payment initiation has been rejected." },
+	] },
+	ExternalCodeSet { name: "ExternalPersonIdentification1Code", codes: &[
+		ExternalCode { code: "ARNU", name: "AlienRegistrationNumber", definition: "Number assigned by a social security agency to identify a non-resident person." },
+		ExternalCode { code: "CCPT", name: "PassportNumber", definition: "Number assigned by an authority to identify the passport number of a person." },
+		ExternalCode { code: "CUST", name: "CustomerIdentificationNumber", definition: "Number assigned by an issuer to identify a customer." },
+		ExternalCode { code: "DRLC", name: "DriversLicenseNumber", definition: "Number assigned by an authority to identify a driver's license." },
+		ExternalCode { code: "EMPL", name: "EmployeeIdentificationNumber", definition: "Number assigned by a registration authority to an employee." },
+		ExternalCode { code: "NIDN", name: "NationalIdentityNumber", definition: "Number assigned by an authority to identify the national identity number of a person." },
+		ExternalCode { code: "POID", name: "PersonCommercialIdentification", definition: "Commercial identification of the person." },
+		ExternalCode { code: "SOSE", name: "SocialSecurityNumber", definition: "Number assigned by an authority to identify the social security number of a person." },
+		ExternalCode { code: "TELE", name: "TelephoneNumber", definition: "Number assigned by a telephone or mobile phone operator to identify a person. A person may have multiple phone numbers." },
+		ExternalCode { code: "TXID", name: "TaxIdentificationNumber", definition: "Number assigned by a tax authority to identify a person." },
+	] },
+	ExternalCodeSet { name: "ExternalStatusReason1Code", codes: &[
+		ExternalCode { code: "AB01", name: "AbortedClearingTimeout", definition: "Clearing process aborted due to timeout." },
+		ExternalCode { code: "AB02", name: "AbortedClearingFatalError", definition: "Clearing process aborted due to a fatal error." },
+		ExternalCode { code: "AB03", name: "AbortedSettlementTimeout", definition: "Settlement aborted due to timeout." },
+		ExternalCode { code: "AB04", name: "AbortedSettlementFatalError", definition: "Settlement process aborted due to a fatal error." },
+		ExternalCode { code: "AB05", name: "TimeoutCreditorAgent", definition: "Transaction stopped due to timeout at the Creditor Agent." },
+		ExternalCode { code: "AB06", name: "TimeoutInstructedAgent", definition: "Transaction stopped due to timeout at the Instructed Agent." },
+		ExternalCode { code: "AB07", name: "OfflineAgent", definition: "Agent of message is not online." },
+		ExternalCode { code: "AB08", name: "OfflineCreditorAgent", definition: "Creditor Agent is not online." },
+		ExternalCode { code: "AB09", name: "ErrorCreditorAgent", definition: "Transaction stopped due to error at the Creditor Agent." },
+		ExternalCode { code: "AB10", name: "ErrorInstructedAgent", definition: "Transaction stopped due to error at the Instructed Agent." },
+		ExternalCode { code: "AB11", name: "TimeoutDebtorAgent", definition: "Transaction stopped due to timeout at the Debtor Agent." },
+		ExternalCode { code: "AC01", name: "IncorrectAccountNumber", definition: "Account number is invalid or missing." },
+		ExternalCode { code: "AC02", name: "InvalidDebtorAccountNumber", definition: "Debtor account number invalid or missing." },
+		ExternalCode { code: "AC03", name: "InvalidCreditorAccountNumber", definition: "Creditor account number invalid or missing." },
+		ExternalCode { code: "AC04", name: "ClosedAccountNumber", definition: "Account number specified has been closed on the bank of account's books." },
+		ExternalCode { code: "AC05", name: "ClosedDebtorAccountNumber", definition: "Debtor account number closed." },
+		ExternalCode { code: "AC06", name: "BlockedAccount", definition: "Account specified is blocked, prohibiting posting of transactions against it." },
+		ExternalCode { code: "AC07", name: "ClosedCreditorAccountNumber", definition: "Creditor account number closed." },
+		ExternalCode { code: "AC08", name: "InvalidBranchCode", definition: "Branch code is invalid or missing." },
+		ExternalCode { code: "AC09", name: "InvalidAccountCurrency", definition: "Account currency is invalid or missing." },
+		ExternalCode { code: "AC10", name: "InvalidDebtorAccountCurrency", definition: "Debtor account currency is invalid or missing." },
+		ExternalCode { code: "AC11", name: "InvalidCreditorAccountCurrency", definition: "Creditor account currency is invalid or missing." },
+		ExternalCode { code: "AC12", name: "InvalidAccountType", definition: "Account type missing or invalid." },
+		ExternalCode { code: "AC13", name: "InvalidDebtorAccountType", definition: "Debtor account type missing or invalid." },
+		ExternalCode { code: "AC14", name: "InvalidCreditorAccountType", definition: "Creditor account type missing or invalid." },
+		ExternalCode { code: "AC15", name: "AccountDetailsChanged", definition: "The account details for the counterparty have changed." },
+		ExternalCode { code: "AC16", name: "CardNumberInvalid", definition: "Credit or debit card number is invalid." },
+		ExternalCode { code: "AEXR", name: "AlreadyExpiredRTP", definition: "Request-to-pay Expiry Date and Time has already passed." },
+		ExternalCode { code: "AG01", name: "TransactionForbidden", definition: "Transaction forbidden on this type of account (formerly NoAgreement)." },
+		ExternalCode { code: "AG02", name: "InvalidBankOperationCode", definition: "Bank Operation code specified in the message is not valid for receiver." },
+		ExternalCode { code: "AG03", name: "TransactionNotSupported", definition: "Transaction type not supported/authorized on this account." },
+		ExternalCode { code: "AG04", name: "InvalidAgentCountry", definition: "Agent country code is missing or invalid." },
+		ExternalCode { code: "AG05", name: "InvalidDebtorAgentCountry", definition: "Debtor agent country code is missing or invalid." },
+		ExternalCode { code: "AG06", name: "InvalidCreditorAgentCountry", definition: "Creditor agent country code is missing or invalid." },
+		ExternalCode { code: "AG07", name: "UnsuccesfulDirectDebit", definition: "Debtor account cannot be debited for a generic reason." },
+		ExternalCode { code: "AG08", name: "InvalidAccessRights", definition: "Transaction failed due to invalid or missing user or access right." },
+		ExternalCode { code: "AG09", name: "PaymentNotReceived", definition: "Original payment never received." },
+		ExternalCode { code: "AG10", name: "AgentSuspended", definition: "Agent of message is suspended from the Real Time Payment system." },
+		ExternalCode { code: "AG11", name: "CreditorAgentSuspended", definition: "Creditor Agent of message is suspended from the Real Time Payment system." },
+		ExternalCode { code: "AG12", name: "NotAllowedBookTransfer", definition: "Payment orders made by transferring funds from one account to another at the same financial institution (bank or payment institution) are not allowed." },
+		ExternalCode { code: "AG13", name: "ForbiddenReturnPayment", definition: "Returned payments derived from previously returned transactions are not allowed." },
+		ExternalCode { code: "AGNT", name: "IncorrectAgent", definition: "Agent in the payment workflow is incorrect." },
+		ExternalCode { code: "ALAC", name: "AlreadyAcceptedRTP", definition: "Request-to-pay has already been accepted by the Debtor." },
+		ExternalCode { code: "AM01", name: "ZeroAmount", definition: "Specified message amount is equal to zero." },
+		ExternalCode { code: "AM02", name: "NotAllowedAmount", definition: "Specific transaction/message amount is greater than allowed maximum." },
+		ExternalCode { code: "AM03", name: "NotAllowedCurrency", definition: "Specified message amount is an non processable currency outside of existing agreement." },
+		ExternalCode { code: "AM04", name: "InsufficientFunds", definition: "Amount of funds available to cover specified message amount is insufficient." },
+		ExternalCode { code: "AM05", name: "Duplication", definition: "Duplication." },
+		ExternalCode { code: "AM06", name: "TooLowAmount", definition: "Specified transaction amount is less than agreed minimum." },
+		ExternalCode { code: "AM07", name: "BlockedAmount", definition: "Amount specified in message has been blocked by regulatory authorities." },
+		ExternalCode { code: "AM09", name: "WrongAmount", definition: "Amount received is not the amount agreed or expected." },
+		ExternalCode { code: "AM10", name: "InvalidControlSum", definition: "Sum of instructed amounts does not equal the control sum." },
+		ExternalCode { code: "AM11", name: "InvalidTransactionCurrency", definition: "Transaction currency is invalid or missing." },
+		ExternalCode { code: "AM12", name: "InvalidAmount", definition: "Amount is invalid or missing." },
+		ExternalCode { code: "AM13", name: "AmountExceedsClearingSystemLimit", definition: "Transaction amount exceeds limits set by clearing system." },
+		ExternalCode { code: "AM14", name: "AmountExceedsAgreedLimit", definition: "Transaction amount exceeds limits agreed between bank and client." },
+		ExternalCode { code: "AM15", name: "AmountBelowClearingSystemMinimum", definition: "Transaction amount below minimum set by clearing system." },
+		ExternalCode { code: "AM16", name: "InvalidGroupControlSum", definition: "Control Sum at the Group level is invalid." },
+		ExternalCode { code: "AM17", name: "InvalidPaymentInfoControlSum", definition: "Control Sum at the Payment Information level is invalid." },
+		ExternalCode { code: "AM18", name: "InvalidNumberOfTransactions", definition: "Number of transactions is invalid or missing." },
+		ExternalCode { code: "AM19", name: "InvalidGroupNumberOfTransactions", definition: "Number of transactions at the Group level is invalid or missing." },
+		ExternalCode { code: "AM20", name: "InvalidPaymentInfoNumberOfTransactions", definition: "Number of transactions at the Payment Information level is invalid." },
+		ExternalCode { code: "AM21", name: "LimitExceeded", definition: "Transaction amount exceeds limits agreed between bank and client." },
+		ExternalCode { code: "AM22", name: "ZeroAmountNotApplied", definition: "Unable to apply zero amount to designated account. For example, where the rules of a service allow the use of zero amount payments, however the back-office system is unable to apply the funds to the account." },
+		ExternalCode { code: "AM23", name: "AmountExceedsSettlementLimit", definition: "Transaction amount exceeds settlement limit." },
+		ExternalCode { code: "APAR", name: "AlreadyPaidRTP", definition: "Request-to-pay has already been paid by the Debtor." },
+		ExternalCode { code: "ARFR", name: "AlreadyRefusedRTP", definition: "Request-to-pay has already been refused by the Debtor." },
+		ExternalCode { code: "ARJR", name: "AlreadyRejectedRTP", definition: "Request-to-pay has already been rejected." },
+		ExternalCode { code: "ATNS", name: "AttachementsNotSupported", definition: "Attachments to the request-to-pay are not supported." },
+		ExternalCode { code: "BE01", name: "InconsistenWithEndCustomer", definition: "Identification of end customer is not consistent with associated account number (formerly CreditorConsistency)." },
+		ExternalCode { code: "BE04", name: "MissingCreditorAddress", definition: "Specification of creditor's address, which is required for payment, is missing/not correct (formerly IncorrectCreditorAddress)." },
+		ExternalCode { code: "BE05", name: "UnrecognisedInitiatingParty", definition: "Party who initiated the message is not recognised by the end customer." },
+		ExternalCode { code: "BE06", name: "UnknownEndCustomer", definition: "End customer specified is not known at associated Sort/National Bank Code or does no longer exist in the books." },
+		ExternalCode { code: "BE07", name: "MissingDebtorAddress", definition: "Specification of debtor's address, which is required for payment, is missing/not correct." },
+		ExternalCode { code: "BE08", name: "MissingDebtorName", definition: "Debtor name is missing." },
+		ExternalCode { code: "BE09", name: "InvalidCountry", definition: "Country code is missing or invalid." },
+		ExternalCode { code: "BE10", name: "InvalidDebtorCountry", definition: "Debtor country code is missing or invalid." },
+		ExternalCode { code: "BE11", name: "InvalidCreditorCountry", definition: "Creditor country code is missing or invalid." },
+		ExternalCode { code: "BE12", name: "InvalidCountryOfResidence", definition: "Country code of residence is missing or invalid." },
+		ExternalCode { code: "BE13", name: "InvalidDebtorCountryOfResidence", definition: "Country code of debtor's residence is missing or invalid." },
+		ExternalCode { code: "BE14", name: "InvalidCreditorCountryOfResidence", definition: "Country code of creditor's residence is missing or invalid." },
+		ExternalCode { code: "BE15", name: "InvalidIdentificationCode", definition: "Identification code missing or invalid." },
+		ExternalCode { code: "BE16", name: "InvalidDebtorIdentificationCode", definition: "Debtor or Ultimate Debtor identification code missing or invalid." },
+		ExternalCode { code: "BE17", name: "InvalidCreditorIdentificationCode", definition: "Creditor or Ultimate Creditor identification code missing or invalid." },
+		ExternalCode { code: "BE18", name: "InvalidContactDetails", definition: "Contact details missing or invalid." },
+		ExternalCode { code: "BE19", name: "InvalidChargeBearerCode", definition: "Charge bearer code for transaction type is invalid." },
+		ExternalCode { code: "BE20", name: "InvalidNameLength", definition: "Name length exceeds local rules for payment type." },
+		ExternalCode { code: "BE21", name: "MissingName", definition: "Name missing or invalid. Generic usage if cannot specifically identify debtor or creditor." },
+		ExternalCode { code: "BE22", name: "MissingCreditorName", definition: "Creditor name is missing." },
+		ExternalCode { code: "BE23", name: "AccountProxyInvalid", definition: "Phone number or email address, or any other proxy, used as the account proxy is unknown or invalid." },
+		ExternalCode { code: "CERI", name: "CheckERI", definition: "Credit transfer is not tagged as an Extended Remittance Information (ERI) transaction but contains ERI." },
+		ExternalCode { code: "CH03", name: "RequestedExecutionDateOrRequestedCollectionDateTooFarInFuture", definition: "Value in Requested Execution Date or Requested Collection Date is too far in the future." },
+		ExternalCode { code: "CH04", name: "RequestedExecutionDateOrRequestedCollectionDateTooFarInPast", definition: "Value in Requested Execution Date or Requested Collection Date is too far in the past." },
+		ExternalCode { code: "CH07", name: "ElementIsNotToBeUsedAtB-andC-Level", definition: "Element is not to be used at B- and C-Level." },
+		ExternalCode { code: "CH09", name: "MandateChangesNotAllowed", definition: "Mandate changes are not allowed." },
+		ExternalCode { code: "CH10", name: "InformationOnMandateChangesMissing", definition: "Information on mandate changes are missing." },
+		ExternalCode { code: "CH11", name: "CreditorIdentifierIncorrect", definition: "Value in Creditor Identifier is incorrect." },
+		ExternalCode { code: "CH12", name: "CreditorIdentifierNotUnambiguouslyAtTransaction-Level", definition: "Creditor Identifier is ambiguous at Transaction Level." },
+		ExternalCode { code: "CH13", name: "OriginalDebtorAccountIsNotToBeUsed", definition: "Original Debtor Account is not to be used." },
+		ExternalCode { code: "CH14", name: "OriginalDebtorAgentIsNotToBeUsed", definition: "Original Debtor Agent is not to be used." },
+		ExternalCode { code: "CH15", name: "ElementContentIncludesMoreThan140Characters", definition: "Content Remittance Information/Structured includes more than 140 characters." },
+		ExternalCode { code: "CH16", name: "ElementContentFormallyIncorrect", definition: "Content is incorrect." },
+		ExternalCode { code: "CH17", name: "ElementNotAdmitted", definition: "Element is not allowed." },
+		ExternalCode { code: "CH19", name: "ValuesWillBeSetToNextTARGETday", definition: "Values in Interbank Settlement Date or Requested Collection Date will be set to the next TARGET day." },
+		ExternalCode { code: "CH20", name: "DecimalPointsNotCompatibleWithCurrency", definition: "Number of decimal points not compatible with the currency." },
+		ExternalCode { code: "CH21", name: "RequiredCompulsoryElementMissing", definition: "Mandatory element is missing." },
+		ExternalCode { code: "CH22", name: "COREandB2BwithinOnemessage", definition: "SDD CORE and B2B not permitted within one message." },
+		ExternalCode { code: "CHQC", name: "ChequeSettledOnCreditorAccount", definition: "Cheque has been presented in cheque clearing and settled on the creditor's account." },
+		ExternalCode { code: "CN01", name: "AuthorisationCancelled", definition: "Authorisation is cancelled." },
+		ExternalCode { code: "CNOR", name: "CreditorBankIsNotRegistered", definition: "Creditor bank is not registered under this BIC in the CSM." },
+		ExternalCode { code: "CURR", name: "IncorrectCurrency", definition: "Currency of the payment is incorrect." },
+		ExternalCode { code: "CUST", name: "RequestedByCustomer", definition: "Cancellation requested by the Debtor." },
+		ExternalCode { code: "DC02", name: "SettlementNotReceived", definition: "Rejection of a payment due to covering FI settlement not being received." },
+		ExternalCode { code: "DNOR", name: "DebtorBankIsNotRegistered", definition: "Debtor bank is not registered under this BIC in the CSM." },
+		ExternalCode { code: "DS01", name: "ElectronicSignaturesCorrect", definition: "The electronic signature(s) is/are correct." },
+		ExternalCode { code: "DS02", name: "OrderCancelled", definition: "An authorized user has cancelled the order." },
+		ExternalCode { code: "DS03", name: "OrderNotCancelled", definition: "The user's attempt to cancel the order was not successful." },
+		ExternalCode { code: "DS04", name: "OrderRejected", definition: "The order was rejected by the bank side (for reasons concerning content)." },
+		ExternalCode { code: "DS05", name: "OrderForwardedForPostprocessing", definition: "The order was correct and could be forwarded for postprocessing." },
+		ExternalCode { code: "DS06", name: "TransferOrder", definition: "The order was transferred to VEU." },
+		ExternalCode { code: "DS07", name: "ProcessingOK", definition: "All actions concerning the order could be done by the EBICS bank server." },
+		ExternalCode { code: "DS08", name: "DecompressionError", definition: "The decompression of the file was not successful." },
+		ExternalCode { code: "DS09", name: "DecryptionError", definition: "The decryption of the file was not successful." },
+		ExternalCode { code: "DS0A", name: "DataSignRequested", definition: "Data signature is required." },
+		ExternalCode { code: "DS0B", name: "UnknownDataSignFormat", definition: "Data signature for the format is not available or invalid." },
+		ExternalCode { code: "DS0C", name: "SignerCertificateRevoked", definition: "The signer certificate is revoked." },
+		ExternalCode { code: "DS0D", name: "SignerCertificateNotValid", definition: "The signer certificate is not valid (revoked or not active)." },
+		ExternalCode { code: "DS0E", name: "IncorrectSignerCertificate", definition: "The signer certificate is not present." },
+		ExternalCode { code: "DS0F", name: "SignerCertificationAuthoritySignerNotValid", definition: "The authority of the signer certification sending the certificate is unknown." },
+		ExternalCode { code: "DS0G", name: "NotAllowedPayment", definition: "Signer is not allowed to sign this operation type." },
+		ExternalCode { code: "DS0H", name: "NotAllowedAccount", definition: "Signer is not allowed to sign for this account." },
+		ExternalCode { code: "DS0K", name: "NotAllowedNumberOfTransaction", definition: "The number of transaction is over the number allowed for this signer." },
+		ExternalCode { code: "DS10", name: "Signer1CertificateRevoked", definition: "The certificate is revoked for the first signer." },
+		ExternalCode { code: "DS11", name: "Signer1CertificateNotValid", definition: "The certificate is not valid for the first signer." },
+		ExternalCode { code: "DS12", name: "IncorrectSigner1Certificate", definition: "The certificate is not present for the first signer." },
+		ExternalCode { code: "DS13", name: "SignerCertificationAuthoritySigner1NotValid", definition: "The authority of signer certification sending the certificate is unknown for the first signer." },
+		ExternalCode { code: "DS14", name: "UserDoesNotExist", definition: "The user is unknown on the server." },
+		ExternalCode { code: "DS15", name: "IdenticalSignatureFound", definition: "The same signature has already been sent to the bank." },
+		ExternalCode { code: "DS16", name: "PublicKeyVersionIncorrect", definition: "The public key version is not correct. This code is returned when a customer sends signature files to the financial institution after conversion from an older program version (old ES format) to a new program version (new ES format) without having carried out re-initialisation with regard to a public key change." },
+		ExternalCode { code: "DS17", name: "DifferentOrderDataInSignatures", definition: "Order data and signatures do not match." },
+		ExternalCode { code: "DS18", name: "RepeatOrder", definition: "File cannot be tested, the complete order has to be repeated. This code is returned in the event of a malfunction during the signature check, e.g. not enough storage space." },
+		ExternalCode { code: "DS19", name: "ElectronicSignatureRightsInsufficient", definition: "The user's rights (concerning his signature) are insufficient to execute the order." },
+		ExternalCode { code: "DS20", name: "Signer2CertificateRevoked", definition: "The certificate is revoked for the second signer." },
+		ExternalCode { code: "DS21", name: "Signer2CertificateNotValid", definition: "The certificate is not valid for the second signer." },
+		ExternalCode { code: "DS22", name: "IncorrectSigner2Certificate", definition: "The certificate is not present for the second signer." },
+		ExternalCode { code: "DS23", name: "SignerCertificationAuthoritySigner2NotValid", definition: "The authority of signer certification sending the certificate is unknown for the second signer." },
+		ExternalCode { code: "DS24", name: "WaitingTimeExpired", definition: "Waiting time expired due to incomplete order." },
+		ExternalCode { code: "DS25", name: "OrderFileDeleted", definition: "The order file was deleted by the bank server." },
+		ExternalCode { code: "DS26", name: "UserSignedMultipleTimes", definition: "The same user has signed multiple times." },
+		ExternalCode { code: "DS27", name: "UserNotYetActivated", definition: "The user is not yet activated (technically)." },
+		ExternalCode { code: "DS28", name: "ReturnForTechnicalReason", definition: "Return following technical problems resulting in erroneous transaction." },
+		ExternalCode { code: "DT01", name: "InvalidDate", definition: "Invalid date (eg, wrong or missing settlement date)." },
+		ExternalCode { code: "DT02", name: "InvalidCreationDate", definition: "Invalid creation date and time in Group Header (eg, historic date)." },
+		ExternalCode { code: "DT03", name: "InvalidNonProcessingDate", definition: "Invalid non bank processing date (eg, weekend or local public holiday)." },
+		ExternalCode { code: "DT04", name: "FutureDateNotSupported", definition: "Future date not supported." },
+		ExternalCode { code: "DT05", name: "InvalidCutOffDate", definition: "Associated message, payment information block or transaction was received after agreed processing cut-off date, i.e., date in the past." },
+		ExternalCode { code: "DT06", name: "ExecutionDateChanged", definition: "Execution Date has been modified in order for transaction to be processed." },
+		ExternalCode { code: "DU01", name: "DuplicateMessageID", definition: "Message Identification is not unique." },
+		ExternalCode { code: "DU02", name: "DuplicatePaymentInformationID", definition: "Payment Information Block is not unique." },
+		ExternalCode { code: "DU03", name: "DuplicateTransaction", definition: "Transaction is not unique." },
+		ExternalCode { code: "DU04", name: "DuplicateEndToEndID", definition: "End To End ID is not unique." },
+		ExternalCode { code: "DU05", name: "DuplicateInstructionID", definition: "Instruction ID is not unique." },
+		ExternalCode { code: "DU06", name: "DuplicateUETR", definition: "Unique End-to-end Transaction Reference (UETR) is not unique." },
+		ExternalCode { code: "DUPL", name: "DuplicatePayment", definition: "Payment is a duplicate of another payment." },
+		ExternalCode { code: "ED01", name: "CorrespondentBankNotPossible", definition: "Correspondent bank not possible." },
+		ExternalCode { code: "ED03", name: "BalanceInfoRequest", definition: "Balance of payments complementary info is requested." },
+		ExternalCode { code: "ED05", name: "SettlementFailed", definition: "Settlement of the transaction has failed." },
+		ExternalCode { code: "ED06", name: "SettlementSystemNotAvailable", definition: "Interbank settlement system not available." },
+		ExternalCode { code: "ERIN", name: "ERIOptionNotSupported", definition: "Extended Remittance Information (ERI) option is not supported." },
+		ExternalCode { code: "FF01", name: "InvalidFileFormat", definition: "File Format incomplete or invalid." },
+		ExternalCode { code: "FF02", name: "SyntaxError", definition: "Syntax error reason is provided as narrative information in the additional reason information." },
+		ExternalCode { code: "FF03", name: "InvalidPaymentTypeInformation", definition: "Payment Type Information is missing or invalid." },
+		ExternalCode { code: "FF04", name: "InvalidServiceLevelCode", definition: "Service Level code is missing or invalid." },
+		ExternalCode { code: "FF05", name: "InvalidLocalInstrumentCode", definition: "Local Instrument code is missing or invalid." },
+		ExternalCode { code: "FF06", name: "InvalidCategoryPurposeCode", definition: "Category Purpose code is missing or invalid." },
+		ExternalCode { code: "FF07", name: "InvalidPurpose", definition: "Purpose is missing or invalid." },
+		ExternalCode { code: "FF08", name: "InvalidEndToEndId", definition: "End to End Id missing or invalid." },
+		ExternalCode { code: "FF09", name: "InvalidChequeNumber", definition: "Cheque number missing or invalid." },
+		ExternalCode { code: "FF10", name: "BankSystemProcessingError", definition: "File or transaction cannot be processed due to technical issues at the bank side." },
+		ExternalCode { code: "FF11", name: "ClearingRequestAborted", definition: "Clearing request rejected due it being subject to an abort operation." },
+		ExternalCode { code: "FF12", name: "OriginalTransactionNeverReceived", definition: "Original payment never received." },
+		ExternalCode { code: "FF13", name: "RequestForCancellationNotFound", definition: "No record of request for cancellation found." },
+		ExternalCode { code: "FOCR", name: "FollowingCancellationRequest", definition: "Return following a cancellation request." },
+		ExternalCode { code: "FR01", name: "Fraud", definition: "Returned as a result of fraud." },
+		ExternalCode { code: "FRTR", name: "FinalResponseMandateCancelled", definition: "Final response/tracking is recalled as mandate is cancelled." },
+		ExternalCode { code: "G000", name: "PaymentTransferredAndTracked", definition: "In an FI To FI Customer Credit Transfer: The Status Originator transferred the payment to the next Agent or to a Market Infrastructure maintaining the transaction's service obligations." },
+		ExternalCode { code: "G001", name: "PaymentTransferredAndNotTracked", definition: "In an FI To FI Customer Credit Transfer: The Status Originator transferred the payment to the next Agent or to a Market Infrastructure where the transaction's service obligations may no longer be guaranteed." },
+		ExternalCode { code: "G002", name: "CreditDebitNotConfirmed", definition: "In a FI To FI Customer Credit Transfer: Credit to the creditor's account may not be confirmed same day." },
+		ExternalCode { code: "G003", name: "CreditPendingDocuments", definition: "In a FI To FI Customer Credit Transfer: Credit to creditor's account is pending receipt of required documents." },
+		ExternalCode { code: "G004", name: "CreditPendingFunds", definition: "In a FI To FI Customer Credit Transfer: Credit to the creditor's account is pending, status Originator is waiting for funds provided via a cover." },
+		ExternalCode { code: "G005", name: "DeliveredWithServiceLevel", definition: "Payment has been delivered to creditor agent with service level." },
+		ExternalCode { code: "G006", name: "DeliveredWIthoutServiceLevel", definition: "Payment has been delivered to creditor agent without service level." },
+		ExternalCode { code: "ID01", name: "CorrespondingOriginalFileStillNotSent", definition: "Signature file was sent to the bank but the corresponding original file has not been sent yet." },
+		ExternalCode { code: "MD01", name: "NoMandate", definition: "No Mandate." },
+		ExternalCode { code: "MD02", name: "MissingMandatoryInformationInMandate", definition: "Mandate related information data required by the scheme is missing." },
+		ExternalCode { code: "MD05", name: "CollectionNotDue", definition: "Creditor or creditor's agent should not have collected the direct debit." },
+		ExternalCode { code: "MD06", name: "RefundRequestByEndCustomer", definition: "Return of funds requested by end customer." },
+		ExternalCode { code: "MD07", name: "EndCustomerDeceased", definition: "End customer is deceased." },
+		ExternalCode { code: "MS02", name: "NotSpecifiedReasonCustomerGenerated", definition: "Reason has not been specified by end customer." },
+		ExternalCode { code: "MS03", name: "NotSpecifiedReasonAgentGenerated", definition: "Reason has not been specified by agent." },
+		ExternalCode { code: "NARR", name: "Narrative", definition: "Reason is provided as narrative information in the additional reason information." },
+		ExternalCode { code: "NERI", name: "NoERI", definition: "Credit transfer is tagged as an Extended Remittance Information (ERI) transaction but does not contain ERI." },
+		ExternalCode { code: "NOAR", name: "NonAgreedRTP", definition: "No existing agreement for receiving request-to-pay messages." },
+		ExternalCode { code: "NOAS", name: "NoAnswerFromCustomer", definition: "No response from Beneficiary." },
+		ExternalCode { code: "NOCM", name: "NotCompliantGeneric", definition: "Customer account is not compliant with regulatory requirements, for example FICA (in South Africa) or any other regulatory requirements which render an account inactive for certain processing." },
+		ExternalCode { code: "NOCR", name: "NoCancellationReceived", definition: "No cancellation request has been received." },
+		ExternalCode { code: "NOOR", name: "NoOriginalTransactionReceived", definition: "Original transaction (subject to cancellation) never received." },
+		ExternalCode { code: "NOPG", name: "NoPaymentGuarantee", definition: "Requested payment guarantee (by Creditor) related to a request-to-pay cannot be provided." },
+		ExternalCode { code: "NRCH", name: "PayerOrPayerRSPNotReachable", definition: "Recipient side of the request-to-pay (payer or its request-to-pay service provider) is not reachable." },
+		ExternalCode { code: "PINS", name: "TypeOfPaymentInstrumentNotSupported", definition: "Type of payment requested in the request-to-pay is not supported by the payer." },
+		ExternalCode { code: "RC01", name: "BankIdentifierIncorrect", definition: "Bank identifier code specified in the message has an incorrect format (formerly IncorrectFormatForRoutingCode)." },
+		ExternalCode { code: "RC02", name: "InvalidBankIdentifier", definition: "Bank identifier is invalid or missing." },
+		ExternalCode { code: "RC03", name: "InvalidDebtorBankIdentifier", definition: "Debtor bank identifier is invalid or missing." },
+		ExternalCode { code: "RC04", name: "InvalidCreditorBankIdentifier", definition: "Creditor bank identifier is invalid or missing." },
+		ExternalCode { code: "RC05", name: "InvalidBICIdentifier", definition: "BIC identifier is invalid or missing." },
+		ExternalCode { code: "RC06", name: "InvalidDebtorBICIdentifier", definition: "Debtor BIC identifier is invalid or missing." },
+		ExternalCode { code: "RC07", name: "InvalidCreditorBICIdentifier", definition: "Creditor BIC identifier is invalid or missing." },
+		ExternalCode { code: "RC08", name: "InvalidClearingSystemMemberIdentifier", definition: "Clearing system member identifier is invalid or missing." },
+		ExternalCode { code: "RC09", name: "InvalidDebtorClearingSystemMemberIdentifier", definition: "Debtor clearing system member identifier is invalid or missing." },
+		ExternalCode { code: "RC10", name: "InvalidCreditorClearingSystemMemberIdentifier", definition: "Creditor clearing system member identifier is invalid or missing." },
+		ExternalCode { code: "RC11", name: "InvalidIntermediaryAgent", definition: "Intermediary Agent is invalid or missing." },
+		ExternalCode { code: "RC12", name: "MissingCreditorSchemeId", definition: "Creditor Scheme Id is invalid or missing." },
+		ExternalCode { code: "RCON", name: "RMessageConflict", definition: "Conflict with R-Message." },
+		ExternalCode { code: "RECI", name: "ReceiverCustomerInformation", definition: "Further information regarding the intended recipient." },
+		ExternalCode { code: "RF01", name: "NotUniqueTransactionReference", definition: "Transaction reference is not unique within the message." },
+		ExternalCode { code: "RR01", name: "MissingDebtorAccountOrIdentification", definition: "Specification of the debtor's account or unique identification needed for reasons of regulatory requirements is insufficient or missing." },
+		ExternalCode { code: "RR02", name: "MissingDebtorNameOrAddress", definition: "Specification of the debtor's name and/or address needed for regulatory requirements is insufficient or missing." },
+		ExternalCode { code: "RR03", name: "MissingCreditorNameOrAddress", definition: "Specification of the creditor's name and/or address needed for regulatory requirements is insufficient or missing." },
+		ExternalCode { code: "RR04", name: "RegulatoryReason", definition: "Regulatory Reason." },
+		ExternalCode { code: "RR05", name: "RegulatoryInformationInvalid", definition: "Regulatory or Central Bank Reporting information missing, incomplete or invalid." },
+		ExternalCode { code: "RR06", name: "TaxInformationInvalid", definition: "Tax information missing, incomplete or invalid." },
+		ExternalCode { code: "RR07", name: "RemittanceInformationInvalid", definition: "Remittance information structure does not comply with rules for payment type." },
+		ExternalCode { code: "RR08", name: "RemittanceInformationTruncated", definition: "Remittance information truncated to comply with rules for payment type." },
+		ExternalCode { code: "RR09", name: "InvalidStructuredCreditorReference", definition: "Structured creditor reference invalid or missing." },
+		ExternalCode { code: "RR10", name: "InvalidCharacterSet", definition: "Character set supplied not valid for the country and payment type." },
+		ExternalCode { code: "RR11", name: "InvalidDebtorAgentServiceID", definition: "Invalid or missing identification of a bank proprietary service." },
+		ExternalCode { code: "RR12", name: "InvalidPartyID", definition: "Invalid or missing identification required within a particular country or payment type." },
+		ExternalCode { code: "S000", name: "ValidRequestForCancellationAcknowledged", definition: "Request for Cancellation is acknowledged following validation." },
+		ExternalCode { code: "S001", name: "UETRFlaggedForCancellation", definition: "Unique End-to-end Transaction Reference (UETR) relating to a payment has been identified as being associated with a Request for Cancellation." },
+		ExternalCode { code: "S002", name: "NetworkStopOfUETR", definition: "Unique End-to-end Transaction Reference (UETR) relating to a payment has been prevented from traveling across a messaging network." },
+		ExternalCode { code: "S003", name: "RequestForCancellationForwarded", definition: "Request for Cancellation has been forwarded to the payment processing/last payment processing agent." },
+		ExternalCode { code: "S004", name: "RequestForCancellationDeliveryAcknowledgement", definition: "Request for Cancellation has been acknowledged as delivered to the payment processing/last payment processing agent." },
+		ExternalCode { code: "SL01", name: "SpecificServiceOfferedByDebtorAgent", definition: "Due to specific service offered by the Debtor Agent." },
+		ExternalCode { code: "SL02", name: "SpecificServiceOfferedByCreditorAgent", definition: "Due to specific service offered by the Creditor Agent." },
+		ExternalCode { code: "SL03", name: "ServiceofClearingSystem", definition: "Due to a specific service offered by the clearing system." },
+		ExternalCode { code: "SL11", name: "CreditorNotOnWhitelistOfDebtor", definition: "Whitelisting service offered by the Debtor Agent; Debtor has not included the Creditor on its \"Whitelist\" (yet)." },
+		ExternalCode { code: "SL12", name: "CreditorOnBlacklistOfDebtor", definition: "Blacklisting service offered by the Debtor Agent; Debtor included the Creditor on his \"Blacklist\"." },
+		ExternalCode { code: "SL13", name: "MaximumNumberOfDirectDebitTransactionsExceeded", definition: "Due to Maximum allowed Direct Debit Transactions per period service offered by the Debtor Agent." },
+		ExternalCode { code: "SL14", name: "MaximumDirectDebitTransactionAmountExceeded", definition: "Due to Maximum allowed Direct Debit Transaction amount service offered by the Debtor Agent." },
+		ExternalCode { code: "SPII", name: "RTPServiceProviderIdentifierIncorrect", definition: "Identifier of the request-to-pay service provider is incorrect." },
+		ExternalCode { code: "TA01", name: "TransmissonAborted", definition: "The transmission of the file was not successful, it had to be aborted (for technical reasons)." },
+		ExternalCode { code: "TD01", name: "NoDataAvailable", definition: "There is no data available (for download)." },
+		ExternalCode { code: "TD02", name: "FileNonReadable", definition: "The file cannot be read (e.g. unknown format)." },
+		ExternalCode { code: "TD03", name: "IncorrectFileStructure", definition: "The file format is incomplete or invalid." },
+		ExternalCode { code: "TK01", name: "TokenInvalid", definition: "Token is invalid." },
+		ExternalCode { code: "TK02", name: "SenderTokenNotFound", definition: "Token used for the sender does not exist." },
+		ExternalCode { code: "TK03", name: "ReceiverTokenNotFound", definition: "Token used for the receiver does not exist." },
+		ExternalCode { code: "TK09", name: "TokenMissing", definition: "Token required for request is missing." },
+		ExternalCode { code: "TKCM", name: "TokenCounterpartyMismatch", definition: "Token found with counterparty mismatch." },
+		ExternalCode { code: "TKSG", name: "TokenSingleUse", definition: "Single Use Token already used." },
+		ExternalCode { code: "TKSP", name: "TokenSuspended", definition: "Token found with suspended status." },
+		ExternalCode { code: "TKVE", name: "TokenValueLimitExceeded", definition: "Token found with value limit rule violation." },
+		ExternalCode { code: "TKXP", name: "TokenExpired", definition: "Token expired." },
+		ExternalCode { code: "TM01", name: "InvalidCutOffTime", definition: "Associated message, payment information block, or transaction was received after agreed processing cut-off time." },
+		ExternalCode { code: "TS01", name: "TransmissionSuccessful", definition: "The (technical) transmission of the file was successful." },
+		ExternalCode { code: "TS04", name: "TransferToSignByHand", definition: "The order was transferred to pass by accompanying note signed by hand." },
+		ExternalCode { code: "UCRD", name: "UnknownCreditor", definition: "Unknown Creditor." },
+		ExternalCode { code: "UPAY", name: "UnduePayment", definition: "Payment is not justified." },
+	] },
+];
@@ -0,0 +1,291 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Semantic checks of the IBAN, BIC, LEI and UETR identifiers, beyond their pattern facets.
+//!
+//! The checks are an optional layer of the validation: the generated validators run them on
+//! every identifier field only when the validator was built with [`Validator::with_identifiers`].
+
+use std::fmt;
+
+use crate::{ValidationError, Validator};
+
+/// The identifier schemes with a semantic check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierScheme {
+    /// ISO 13616 International Bank Account Number, e.g. `IBAN2007Identifier`.
+    Iban,
+    /// ISO 9362 Business Identifier Code, e.g. `BICFIDec2014Identifier` or `AnyBICDec2014Identifier`.
+    Bic,
+    /// ISO 17442 Legal Entity Identifier, `LEIIdentifier`.
+    Lei,
+    /// RFC 4122 version 4 UUID, `UUIDv4Identifier`, as used by the Unique End-to-end Transaction Reference.
+    Uetr,
+}
+
+impl IdentifierScheme {
+    /// Runs the check of the scheme on `value`.
+    pub fn check(self, value: &str) -> Result<(), IdentifierError> {
+        match self {
+            IdentifierScheme::Iban => check_iban(value),
+            IdentifierScheme::Bic => check_bic(value),
+            IdentifierScheme::Lei => check_lei(value),
+            IdentifierScheme::Uetr => check_uetr(value),
+        }
+    }
+}
+
+impl fmt::Display for IdentifierScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            IdentifierScheme::Iban => "IBAN",
+            IdentifierScheme::Bic => "BIC",
+            IdentifierScheme::Lei => "LEI",
+            IdentifierScheme::Uetr => "UETR",
+        })
+    }
+}
+
+/// Why an identifier failed its check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The characters or their number do not follow the structure of the scheme.
+    Format,
+    /// The IBAN does not have the length of its country.
+    Length { expected: usize, actual: usize },
+    /// The country code is not an ISO 3166 code, or for an IBAN not a country of the IBAN registry.
+    UnknownCountry { country: String },
+    /// The check digits do not match.
+    Checksum,
+    /// The UUID is not of version 4 or not of the RFC 4122 variant.
+    Version,
+}
+
+impl fmt::Display for IdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IdentifierError::Format => write!(f, "malformed"),
+            IdentifierError::Length { expected, actual } => write!(f, "length {} instead of {}", actual, expected),
+            IdentifierError::UnknownCountry { country } => write!(f, "unknown country {}", country),
+            IdentifierError::Checksum => write!(f, "check digits do not match"),
+            IdentifierError::Version => write!(f, "not a version 4 UUID"),
+        }
+    }
+}
+
+/// Checks the country, the length for that country and the ISO 7064 mod 97-10 check digits of an IBAN.
+pub fn check_iban(value: &str) -> Result<(), IdentifierError> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 5
+        || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        || !bytes[4..].iter().all(u8::is_ascii_alphanumeric)
+    {
+        return Err(IdentifierError::Format);
+    }
+    let country = &value[..2];
+    let Ok(i) = IBAN_LENGTHS.binary_search_by(|&(code, _)| code.cmp(country)) else {
+        return Err(IdentifierError::UnknownCountry { country: country.to_string() });
+    };
+    let expected = IBAN_LENGTHS[i].1;
+    if bytes.len() != expected {
+        return Err(IdentifierError::Length { expected, actual: bytes.len() });
+    }
+    // The country code and check digits are moved after the account number
+    if mod97(value[4..].bytes().chain(value[..4].bytes())) != 1 {
+        return Err(IdentifierError::Checksum);
+    }
+    Ok(())
+}
+
+/// Checks the structure of a BIC: a four character party prefix, an ISO 3166 country code,
+/// a two character party suffix and an optional three character branch code.
+pub fn check_bic(value: &str) -> Result<(), IdentifierError> {
+    let bytes = value.as_bytes();
+    let alphanumeric = |b: &u8| b.is_ascii_uppercase() || b.is_ascii_digit();
+    if (bytes.len() != 8 && bytes.len() != 11)
+        || !bytes[..4].iter().all(alphanumeric)
+        || !bytes[4..6].iter().all(u8::is_ascii_uppercase)
+        || !bytes[6..].iter().all(alphanumeric)
+    {
+        return Err(IdentifierError::Format);
+    }
+    let country = &value[4..6];
+    if COUNTRIES.binary_search(&country).is_err() {
+        return Err(IdentifierError::UnknownCountry { country: country.to_string() });
+    }
+    Ok(())
+}
+
+/// Checks the structure and the ISO 7064 mod 97-10 check digits of an LEI.
+pub fn check_lei(value: &str) -> Result<(), IdentifierError> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 20 || !bytes[..18].iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) || !bytes[18..].iter().all(u8::is_ascii_digit) {
+        return Err(IdentifierError::Format);
+    }
+    if mod97(value.bytes()) != 1 {
+        return Err(IdentifierError::Checksum);
+    }
+    Ok(())
+}
+
+/// Checks that a UETR is a hyphenated UUID with the version 4 and RFC 4122 variant bits.
+pub fn check_uetr(value: &str) -> Result<(), IdentifierError> {
+    let bytes = value.as_bytes();
+    let well_formed = bytes.len() == 36 && bytes.iter().enumerate().all(|(i, b)| match i {
+        8 | 13 | 18 | 23 => *b == b'-',
+        _ => b.is_ascii_hexdigit(),
+    });
+    if !well_formed {
+        return Err(IdentifierError::Format);
+    }
+    if bytes[14] != b'4' || !matches!(bytes[19].to_ascii_lowercase(), b'8' | b'9' | b'a' | b'b') {
+        return Err(IdentifierError::Version);
+    }
+    Ok(())
+}
+
+/// Checks the identifier `value` of the child `element` (Rust `field`) when the validator runs
+/// the identifier checks, see [`Validator::with_identifiers`].
+///
+/// Malformed values are left to the pattern facet of the field, so they are not reported twice.
+pub fn validate(validator: &mut Validator, element: &str, field: &str, scheme: IdentifierScheme, value: &str) -> Result<(), ValidationError> {
+    if !validator.checks_identifiers() {
+        return Ok(());
+    }
+    match scheme.check(value) {
+        Ok(()) | Err(IdentifierError::Format) => Ok(()),
+        Err(reason) => validator.report_at(element, field, ValidationError::invalid_identifier(scheme, reason, value)),
+    }
+}
+
+// Remainder by 97 of the number written by the characters, letters counting as 10 to 35.
+fn mod97(chars: impl Iterator<Item = u8>) -> u32 {
+    chars.fold(0, |remainder, c| match c {
+        b'0'..=b'9' => (remainder * 10 + (c - b'0') as u32) % 97,
+        _ => (remainder * 100 + (c.to_ascii_uppercase() - b'A') as u32 + 10) % 97,
+    })
+}
+
+// Countries of the SWIFT IBAN registry and the length of their IBANs, sorted by code.
+// Dependent territories using the IBAN of their country, e.g. Jersey with `GB`, are not listed.
+static IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24), ("AE", 23), ("AL", 28), ("AT", 20), ("AZ", 28), ("BA", 20), ("BE", 16), ("BG", 22),
+    ("BH", 22), ("BI", 27), ("BR", 29), ("BY", 28), ("CH", 21), ("CR", 22), ("CY", 28), ("CZ", 24),
+    ("DE", 22), ("DJ", 27), ("DK", 18), ("DO", 28), ("EE", 20), ("EG", 29), ("ES", 24), ("FI", 18),
+    ("FK", 18), ("FO", 18), ("FR", 27), ("GB", 22), ("GE", 22), ("GI", 23), ("GL", 18), ("GR", 27),
+    ("GT", 28), ("HN", 28), ("HR", 21), ("HU", 28), ("IE", 22), ("IL", 23), ("IQ", 23), ("IS", 26),
+    ("IT", 27), ("JO", 30), ("KW", 30), ("KZ", 20), ("LB", 28), ("LC", 32), ("LI", 21), ("LT", 20),
+    ("LU", 20), ("LV", 21), ("LY", 25), ("MC", 27), ("MD", 24), ("ME", 22), ("MK", 19), ("MN", 20),
+    ("MR", 27), ("MT", 31), ("MU", 30), ("NI", 28), ("NL", 18), ("NO", 15), ("OM", 23), ("PK", 24),
+    ("PL", 28), ("PS", 29), ("PT", 25), ("QA", 29), ("RO", 24), ("RS", 22), ("RU", 33), ("SA", 24),
+    ("SC", 31), ("SD", 18), ("SE", 24), ("SI", 19), ("SK", 24), ("SM", 27), ("SO", 23), ("ST", 25),
+    ("SV", 28), ("TL", 23), ("TN", 24), ("TR", 26), ("UA", 29), ("VA", 22), ("VG", 24), ("XK", 20),
+    ("YE", 30),
+];
+
+// ISO 3166-1 alpha-2 country codes, sorted, with the user-assigned `XK` that SWIFT uses for Kosovo.
+static COUNTRIES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidationErrorKind;
+
+    #[test]
+    fn iban() {
+        assert_eq!(check_iban("GB82WEST12345698765432"), Ok(()));
+        assert_eq!(check_iban("DE89370400440532013000"), Ok(()));
+        assert_eq!(check_iban("GB82WEST12345698765433"), Err(IdentifierError::Checksum));
+        assert_eq!(check_iban("GB82WEST1234569876543"), Err(IdentifierError::Length { expected: 22, actual: 21 }));
+        assert_eq!(check_iban("QQ82WEST12345698765432"), Err(IdentifierError::UnknownCountry { country: "QQ".to_string() }));
+        assert_eq!(check_iban("gb82WEST12345698765432"), Err(IdentifierError::Format));
+        assert_eq!(check_iban("GB82WEST12345698765-32"), Err(IdentifierError::Format));
+    }
+
+    #[test]
+    fn bic() {
+        assert_eq!(check_bic("DEUTDEFF"), Ok(()));
+        assert_eq!(check_bic("DEUTDEFF500"), Ok(()));
+        assert_eq!(check_bic("DEUTQQFF"), Err(IdentifierError::UnknownCountry { country: "QQ".to_string() }));
+        assert_eq!(check_bic("DEUTDEFF5"), Err(IdentifierError::Format));
+        assert_eq!(check_bic("DEUT12FF"), Err(IdentifierError::Format));
+    }
+
+    #[test]
+    fn lei() {
+        assert_eq!(check_lei("5493001KJTIIGC8Y1R12"), Ok(()));
+        assert_eq!(check_lei("5493001KJTIIGC8Y1R13"), Err(IdentifierError::Checksum));
+        assert_eq!(check_lei("5493001KJTIIGC8Y1R1"), Err(IdentifierError::Format));
+        assert_eq!(check_lei("5493001KJTIIGC8Y1RAB"), Err(IdentifierError::Format));
+    }
+
+    #[test]
+    fn uetr() {
+        assert_eq!(check_uetr("d0afc93f-1add-40f5-9a7e-1f72d9d08c63"), Ok(()));
+        assert_eq!(check_uetr("D0AFC93F-1ADD-40F5-BA7E-1F72D9D08C63"), Ok(()));
+        // Version 1 instead of 4
+        assert_eq!(check_uetr("d0afc93f-1add-10f5-9a7e-1f72d9d08c63"), Err(IdentifierError::Version));
+        // Variant bits `110` of a Microsoft GUID instead of `10`
+        assert_eq!(check_uetr("d0afc93f-1add-40f5-ca7e-1f72d9d08c63"), Err(IdentifierError::Version));
+        assert_eq!(check_uetr("d0afc93f1add-40f5-9a7e-1f72d9d08c63"), Err(IdentifierError::Format));
+        assert_eq!(check_uetr("d0afc93f-1add-40f5-9a7e-1f72d9d08c6g"), Err(IdentifierError::Format));
+    }
+
+    #[test]
+    fn validate_reports_only_when_enabled() {
+        let iban = "GB82WEST12345698765433";
+        let mut validator = Validator::accumulate();
+        validate(&mut validator, "IBAN", "iban", IdentifierScheme::Iban, iban).unwrap();
+        assert!(validator.into_report().errors.is_empty());
+
+        let mut validator = Validator::accumulate().with_identifiers();
+        validate(&mut validator, "IBAN", "iban", IdentifierScheme::Iban, iban).unwrap();
+        // Malformed values are left to the pattern facet
+        validate(&mut validator, "IBAN", "iban", IdentifierScheme::Iban, "GB82").unwrap();
+        let errors = validator.into_report().errors;
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &*errors[0].kind,
+            ValidationErrorKind::InvalidIdentifier { scheme: IdentifierScheme::Iban, reason: IdentifierError::Checksum, .. }
+        ));
+    }
+}
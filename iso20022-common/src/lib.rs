@@ -23,17 +23,515 @@
 // https://github.com/Open-Payments/iso20022-rs
 
 pub mod common;
+pub mod currency;
+pub mod datetime;
+pub mod decimal;
+pub mod external;
+pub mod identifier;
+pub mod pattern;
+pub mod rules;
+#[cfg(feature = "streaming")]
+pub mod stream;
+pub mod text;
 
 pub use common::*;
+pub use currency::Currency;
+pub use datetime::{Date, DateTime, ISODate, ISODateTime, ISOTime, Time};
+pub use decimal::Decimal;
+pub use external::{ExternalCode, ExternalCodeSet};
+pub use identifier::{IdentifierError, IdentifierScheme};
+pub use pattern::Pattern;
+
+use std::fmt;
+
+/// The facet or rule a [`ValidationError`] reports, with the data needed to act on it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationErrorKind {
+    /// The value is shorter than its `minLength` facet.
+    MinLength { min: usize, actual: usize },
+    /// The value is longer than its `maxLength` facet.
+    MaxLength { max: usize, actual: usize },
+    /// The value does not match its `pattern` facet. `value` is `None` once redacted.
+    Pattern { pattern: &'static str, value: Option<String> },
+    /// The decimal value has more significant digits than its `totalDigits` facet.
+    TotalDigits { max: u32, actual: u32 },
+    /// The decimal value has more fraction digits than its `fractionDigits` facet.
+    FractionDigits { max: u32, actual: u32 },
+    /// The decimal value is below its `minInclusive` facet. `value` is `None` once redacted, as for the bounds below.
+    MinInclusive { min: Decimal, value: Option<Decimal> },
+    /// The decimal value is above its `maxInclusive` facet.
+    MaxInclusive { max: Decimal, value: Option<Decimal> },
+    /// The decimal value is not above its `minExclusive` facet.
+    MinExclusive { min: Decimal, value: Option<Decimal> },
+    /// The decimal value is not below its `maxExclusive` facet.
+    MaxExclusive { max: Decimal, value: Option<Decimal> },
+    /// The currency code is not in the bundled ISO 4217 table.
+    UnknownCurrency { currency: String },
+    /// The currency code is withdrawn where an active one is required.
+    HistoricCurrency { currency: String },
+    /// The amount has more fraction digits than the minor units of its currency.
+    CurrencyMinorUnits { currency: String, max: u32, actual: u32 },
+    /// The date or time does not exist, e.g. `2024-02-30`. `value` is `None` once redacted, as for the time zone below.
+    InvalidDateTime { value: Option<String> },
+    /// The date-time has no time zone offset, e.g. `2021-04-28T11:56:35.890`.
+    MissingTimeZone { value: Option<String> },
+    /// The IBAN, BIC, LEI or UETR fails its semantic check. `value` is `None` once redacted.
+    InvalidIdentifier { scheme: IdentifierScheme, reason: IdentifierError, value: Option<String> },
+    /// The code is not in its bundled External Code Set, e.g. `AC0l` for `ExternalStatusReason1Code`.
+    UnknownCode { code_set: &'static str, code: String },
+    /// The message breaks a cross-field rule, e.g. `TotalInterbankSettlementAmountAndSumRule`.
+    Rule { rule: String },
+    /// The element required by the schema at this position is missing, e.g. `MsgId` in `GrpHdr`.
+    MissingElement { element: String },
+    /// The element is not allowed by the schema at this position, or not in this order.
+    UnexpectedElement { element: String },
+    /// The attribute required by the schema is missing, e.g. the `Ccy` of an amount.
+    MissingAttribute { attribute: String },
+    /// The value is not one of its XSD type, e.g. outside its enumeration or not a boolean. `value` is `None` once redacted.
+    InvalidValue { type_name: String, value: Option<String> },
+    /// The document holds a message that is not compiled in.
+    UnknownDocument { root_element: String },
+    /// Any other violation, identified by the code of the error only.
+    Other,
+}
+
+impl ValidationErrorKind {
+    /// Numeric code of the kind, as carried by [`ValidationError::code`].
+    pub fn code(&self) -> u32 {
+        match self {
+            ValidationErrorKind::MinLength { .. } => 1001,
+            ValidationErrorKind::MaxLength { .. } => 1002,
+            ValidationErrorKind::MinInclusive { .. } | ValidationErrorKind::MinExclusive { .. } => 1003,
+            ValidationErrorKind::MaxInclusive { .. } | ValidationErrorKind::MaxExclusive { .. } => 1004,
+            ValidationErrorKind::Pattern { .. } => 1005,
+            ValidationErrorKind::TotalDigits { .. } => 1006,
+            ValidationErrorKind::FractionDigits { .. } => 1007,
+            ValidationErrorKind::UnknownCurrency { .. } => 1008,
+            ValidationErrorKind::HistoricCurrency { .. } => 1009,
+            ValidationErrorKind::CurrencyMinorUnits { .. } => 1010,
+            ValidationErrorKind::InvalidDateTime { .. } => 1011,
+            ValidationErrorKind::MissingTimeZone { .. } => 1012,
+            ValidationErrorKind::InvalidIdentifier { .. } => 1013,
+            ValidationErrorKind::UnknownCode { .. } => 1014,
+            ValidationErrorKind::Rule { .. } => 1015,
+            ValidationErrorKind::MissingElement { .. } => 1016,
+            ValidationErrorKind::UnexpectedElement { .. } => 1017,
+            ValidationErrorKind::MissingAttribute { .. } => 1018,
+            ValidationErrorKind::InvalidValue { .. } => 1019,
+            ValidationErrorKind::UnknownDocument { .. } => 9999,
+            ValidationErrorKind::Other => 0,
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationErrorKind::MinLength { min, actual } => write!(f, "length {} is below the minimum of {}", actual, min),
+            ValidationErrorKind::MaxLength { max, actual } => write!(f, "length {} exceeds the maximum of {}", actual, max),
+            ValidationErrorKind::Pattern { pattern, value: Some(value) } => write!(f, "{:?} does not match the pattern {}", value, pattern),
+            ValidationErrorKind::Pattern { pattern, value: None } => write!(f, "value does not match the pattern {}", pattern),
+            ValidationErrorKind::TotalDigits { max, actual } => write!(f, "{} digits exceed the total of {}", actual, max),
+            ValidationErrorKind::FractionDigits { max, actual } => write!(f, "{} fraction digits exceed the maximum of {}", actual, max),
+            ValidationErrorKind::MinInclusive { min, .. } => write!(f, "value is below the minimum of {}", min),
+            ValidationErrorKind::MaxInclusive { max, .. } => write!(f, "value is above the maximum of {}", max),
+            ValidationErrorKind::MinExclusive { min, .. } => write!(f, "value is not above {}", min),
+            ValidationErrorKind::MaxExclusive { max, .. } => write!(f, "value is not below {}", max),
+            ValidationErrorKind::UnknownCurrency { currency } => write!(f, "unknown currency {}", currency),
+            ValidationErrorKind::HistoricCurrency { currency } => write!(f, "currency {} is no longer active", currency),
+            ValidationErrorKind::CurrencyMinorUnits { currency, max, actual } => write!(f, "{} fraction digits exceed the {} minor units of {}", actual, max, currency),
+            ValidationErrorKind::InvalidDateTime { value: Some(value) } => write!(f, "{} is not a valid date or time", value),
+            ValidationErrorKind::InvalidDateTime { value: None } => write!(f, "value is not a valid date or time"),
+            ValidationErrorKind::MissingTimeZone { value: Some(value) } => write!(f, "{} has no time zone offset", value),
+            ValidationErrorKind::MissingTimeZone { value: None } => write!(f, "value has no time zone offset"),
+            ValidationErrorKind::InvalidIdentifier { scheme, reason, value: Some(value) } => write!(f, "{} is not a valid {}: {}", value, scheme, reason),
+            ValidationErrorKind::InvalidIdentifier { scheme, reason, value: None } => write!(f, "value is not a valid {}: {}", scheme, reason),
+            ValidationErrorKind::UnknownCode { code_set, code } => write!(f, "{} is not a code of {}", code, code_set),
+            ValidationErrorKind::Rule { rule } => write!(f, "violates {}", rule),
+            ValidationErrorKind::MissingElement { element } => write!(f, "missing element {}", element),
+            ValidationErrorKind::UnexpectedElement { element } => write!(f, "unexpected element {}", element),
+            ValidationErrorKind::MissingAttribute { attribute } => write!(f, "missing attribute {}", attribute),
+            ValidationErrorKind::InvalidValue { type_name, value: Some(value) } => write!(f, "{:?} is not a valid {}", value, type_name),
+            ValidationErrorKind::InvalidValue { type_name, value: None } => write!(f, "value is not a valid {}", type_name),
+            ValidationErrorKind::UnknownDocument { root_element } => write!(f, "unknown message {}", root_element),
+            ValidationErrorKind::Other => write!(f, "invalid value"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationErrorKind {}
 
 #[derive(Debug)]
 pub struct ValidationError {
     pub code: u32,
+    /// Boxed, so that the `Result` every validator returns stays small.
+    pub kind: Box<ValidationErrorKind>,
     pub message: String,
+    /// XML element path of the violation, e.g. `/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[42]/Dbtr/Nm`.
+    /// Repeated elements carry their XPath position, starting at 1.
+    pub path: String,
+    /// Rust field path of the violation, e.g. `cdt_trf_tx_inf[41].dbtr.nm`, indices starting at 0.
+    pub field_path: String,
 }
 
 impl ValidationError {
+    /// Creates an error of kind [`ValidationErrorKind::Other`].
     pub fn new(code: u32, message: String) -> Self {
-        ValidationError { code, message }
+        ValidationError { code, kind: Box::new(ValidationErrorKind::Other), message, path: String::new(), field_path: String::new() }
+    }
+
+    pub fn with_kind(kind: ValidationErrorKind, message: String) -> Self {
+        ValidationError { code: kind.code(), kind: Box::new(kind), message, path: String::new(), field_path: String::new() }
+    }
+
+    /// `field` holds `actual` characters, fewer than its `minLength` of `min`.
+    pub fn min_length(field: &str, min: usize, actual: usize) -> Self {
+        let message = format!("{} is shorter than the minimum length of {}", field, min);
+        Self::with_kind(ValidationErrorKind::MinLength { min, actual }, message)
+    }
+
+    /// `field` holds `actual` characters, more than its `maxLength` of `max`.
+    pub fn max_length(field: &str, max: usize, actual: usize) -> Self {
+        let message = format!("{} exceeds the maximum length of {}", field, max);
+        Self::with_kind(ValidationErrorKind::MaxLength { max, actual }, message)
+    }
+
+    /// `value` of `field` does not match `pattern`.
+    pub fn pattern(field: &str, pattern: &'static str, value: &str) -> Self {
+        let message = format!("{} does not match the required pattern", field);
+        Self::with_kind(ValidationErrorKind::Pattern { pattern, value: Some(value.to_string()) }, message)
+    }
+
+    /// `value` of `field` has more significant digits than its `totalDigits` of `max`.
+    pub fn total_digits(field: &str, max: u32, value: &Decimal) -> Self {
+        let message = format!("{} exceeds the total number of digits of {}", field, max);
+        Self::with_kind(ValidationErrorKind::TotalDigits { max, actual: value.total_digits() }, message)
+    }
+
+    /// `value` of `field` has more fraction digits than its `fractionDigits` of `max`.
+    pub fn fraction_digits(field: &str, max: u32, value: &Decimal) -> Self {
+        let message = format!("{} exceeds the number of fraction digits of {}", field, max);
+        Self::with_kind(ValidationErrorKind::FractionDigits { max, actual: value.fraction_digits() }, message)
+    }
+
+    pub fn min_inclusive(field: &str, min: Decimal, value: &Decimal) -> Self {
+        let message = format!("{} is less than the minimum value of {}", field, min);
+        Self::with_kind(ValidationErrorKind::MinInclusive { min, value: Some(*value) }, message)
+    }
+
+    pub fn max_inclusive(field: &str, max: Decimal, value: &Decimal) -> Self {
+        let message = format!("{} exceeds the maximum value of {}", field, max);
+        Self::with_kind(ValidationErrorKind::MaxInclusive { max, value: Some(*value) }, message)
+    }
+
+    pub fn min_exclusive(field: &str, min: Decimal, value: &Decimal) -> Self {
+        let message = format!("{} must be greater than {}", field, min);
+        Self::with_kind(ValidationErrorKind::MinExclusive { min, value: Some(*value) }, message)
+    }
+
+    pub fn max_exclusive(field: &str, max: Decimal, value: &Decimal) -> Self {
+        let message = format!("{} must be less than {}", field, max);
+        Self::with_kind(ValidationErrorKind::MaxExclusive { max, value: Some(*value) }, message)
+    }
+
+    /// `currency` of `field` is not an ISO 4217 code.
+    pub fn unknown_currency(field: &str, currency: &str) -> Self {
+        let message = format!("{} is not an ISO 4217 currency code", field);
+        Self::with_kind(ValidationErrorKind::UnknownCurrency { currency: currency.to_string() }, message)
+    }
+
+    /// `currency` of `field` is a historic code where an active one is required.
+    pub fn historic_currency(field: &str, currency: &str) -> Self {
+        let message = format!("{} is not an active currency code", field);
+        Self::with_kind(ValidationErrorKind::HistoricCurrency { currency: currency.to_string() }, message)
+    }
+
+    /// `value` of `field` has more fraction digits than the `minor_units` of its `currency`.
+    pub fn currency_minor_units(field: &str, currency: &str, minor_units: u32, value: &Decimal) -> Self {
+        let message = format!("{} exceeds the {} minor units of {}", field, minor_units, currency);
+        let kind = ValidationErrorKind::CurrencyMinorUnits { currency: currency.to_string(), max: minor_units, actual: value.fraction_digits() };
+        Self::with_kind(kind, message)
+    }
+
+    /// `value` is not a date or time of the calendar, or its time zone is beyond ±14:00.
+    pub fn invalid_date_time(value: &str) -> Self {
+        let message = "date or time is out of range".to_string();
+        Self::with_kind(ValidationErrorKind::InvalidDateTime { value: Some(value.to_string()) }, message)
+    }
+
+    /// The date-time `value` has no time zone offset.
+    pub fn missing_time_zone(value: &str) -> Self {
+        let message = "date-time has no time zone offset".to_string();
+        Self::with_kind(ValidationErrorKind::MissingTimeZone { value: Some(value.to_string()) }, message)
+    }
+
+    /// `value` fails the check of its identifier `scheme` for `reason`.
+    pub fn invalid_identifier(scheme: IdentifierScheme, reason: IdentifierError, value: &str) -> Self {
+        let message = format!("not a valid {}: {}", scheme, reason);
+        Self::with_kind(ValidationErrorKind::InvalidIdentifier { scheme, reason, value: Some(value.to_string()) }, message)
+    }
+
+    /// `code` is not in the bundled External Code Set `code_set`.
+    pub fn unknown_code(code_set: &'static str, code: &str) -> Self {
+        let message = format!("not a code of {}", code_set);
+        Self::with_kind(ValidationErrorKind::UnknownCode { code_set, code: code.to_string() }, message)
+    }
+
+    /// The message breaks `rule`, as explained by `message`.
+    pub fn rule(rule: &str, message: String) -> Self {
+        Self::with_kind(ValidationErrorKind::Rule { rule: rule.to_string() }, message)
+    }
+
+    /// The child `element` required by the schema is missing.
+    pub fn missing_element(element: &str) -> Self {
+        let message = format!("missing element {}", element);
+        Self::with_kind(ValidationErrorKind::MissingElement { element: element.to_string() }, message)
+    }
+
+    /// The child `element` is not allowed by the schema at its position.
+    pub fn unexpected_element(element: &str) -> Self {
+        let message = format!("unexpected element {}", element);
+        Self::with_kind(ValidationErrorKind::UnexpectedElement { element: element.to_string() }, message)
+    }
+
+    /// The `attribute` required by the schema is missing.
+    pub fn missing_attribute(attribute: &str) -> Self {
+        let message = format!("missing attribute {}", attribute);
+        Self::with_kind(ValidationErrorKind::MissingAttribute { attribute: attribute.to_string() }, message)
+    }
+
+    /// `value` is not a value of the XSD type `type_name` for `reason`, e.g. `does not match the pattern [A-Z]{3,3}`.
+    pub fn invalid_value(type_name: &str, value: &str, reason: &str) -> Self {
+        let message = format!("not a valid {}: {}", type_name, reason);
+        Self::with_kind(ValidationErrorKind::InvalidValue { type_name: type_name.to_string(), value: Some(value.to_string()) }, message)
+    }
+
+    pub fn unknown_document(root_element: &str) -> Self {
+        let message = format!("unknown message {} cannot be validated", root_element);
+        Self::with_kind(ValidationErrorKind::UnknownDocument { root_element: root_element.to_string() }, message)
+    }
+
+    /// Drops the offending value carried by the kind, e.g. before the error leaves the system.
+    pub fn redact(&mut self) {
+        match *self.kind {
+            ValidationErrorKind::Pattern { ref mut value, .. }
+            | ValidationErrorKind::InvalidDateTime { ref mut value }
+            | ValidationErrorKind::MissingTimeZone { ref mut value }
+            | ValidationErrorKind::InvalidIdentifier { ref mut value, .. }
+            | ValidationErrorKind::InvalidValue { ref mut value, .. } => *value = None,
+            ValidationErrorKind::MinInclusive { ref mut value, .. }
+            | ValidationErrorKind::MaxInclusive { ref mut value, .. }
+            | ValidationErrorKind::MinExclusive { ref mut value, .. }
+            | ValidationErrorKind::MaxExclusive { ref mut value, .. } => *value = None,
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Every violation found by a `validate_all()` run, in document order.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Runs `validate` with an accumulating [`Validator`] and returns everything it reported.
+    pub fn collect<F>(validate: F) -> Self
+    where
+        F: FnOnce(&mut Validator) -> Result<(), ValidationError>,
+    {
+        let mut validator = Validator::accumulate();
+        // An accumulating validator never stops the walk, so the result is always `Ok`.
+        let _ = validate(&mut validator);
+        validator.into_report()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns `Ok(())` when no violation was found, the report otherwise.
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        if self.errors.is_empty() { Ok(()) } else { Err(self) }
+    }
+
+    /// Drops the offending values of every error, see [`ValidationError::redact`].
+    pub fn redact(&mut self) {
+        self.errors.iter_mut().for_each(ValidationError::redact);
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// Receives the violations found while walking a message.
+///
+/// A fail-fast validator hands the first error back to the caller, so `?` ends the walk,
+/// as `validate()` does. An accumulating validator records it and lets the walk go on,
+/// as `validate_all()` does.
+///
+/// The validator also tracks where the walk is, so every reported error carries the
+/// XML element path and the Rust field path of the value it is about.
+///
+/// On top of the schema facets, a validator built with [`Validator::with_identifiers`] runs the
+/// semantic checks of the IBAN, BIC, LEI and UETR fields, see [`identifier`], and one built with
+/// [`Validator::with_code_sets`] checks codes against the External Code Sets, see [`external`].
+/// One built with [`Validator::with_rules`] also has the cross-field rules of the messages checked,
+/// see [`rules`].
+#[derive(Debug)]
+pub struct Validator {
+    fail_fast: bool,
+    identifiers: bool,
+    code_sets: bool,
+    rules: bool,
+    errors: Vec<ValidationError>,
+    path: String,
+    field_path: String,
+}
+
+impl Validator {
+    pub fn fail_fast() -> Self {
+        Validator { fail_fast: true, identifiers: false, code_sets: false, rules: false, errors: Vec::new(), path: String::new(), field_path: String::new() }
+    }
+
+    pub fn accumulate() -> Self {
+        Validator { fail_fast: false, identifiers: false, code_sets: false, rules: false, errors: Vec::new(), path: String::new(), field_path: String::new() }
+    }
+
+    /// Enables the semantic checks of the identifier fields, e.g. the country, length and check
+    /// digits of an IBAN.
+    pub fn with_identifiers(mut self) -> Self {
+        self.identifiers = true;
+        self
+    }
+
+    /// Whether the semantic checks of the identifier fields run.
+    pub fn checks_identifiers(&self) -> bool {
+        self.identifiers
+    }
+
+    /// Enables the checks of the codes of External Code Sets, e.g. status reasons or purposes,
+    /// against the bundled code sets.
+    pub fn with_code_sets(mut self) -> Self {
+        self.code_sets = true;
+        self
+    }
+
+    /// Whether the codes of External Code Sets are checked.
+    pub fn checks_code_sets(&self) -> bool {
+        self.code_sets
+    }
+
+    /// Enables the cross-field rules of the messages, e.g. the `NbOfTxs` of the group header
+    /// counting the transactions.
+    pub fn with_rules(mut self) -> Self {
+        self.rules = true;
+        self
+    }
+
+    /// Whether the cross-field rules of the messages are checked.
+    pub fn checks_rules(&self) -> bool {
+        self.rules
+    }
+
+    /// XML element path of the current position.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Rust field path of the current position.
+    pub fn field_path(&self) -> &str {
+        &self.field_path
+    }
+
+    /// Reports a violation at the current position, returning it as `Err` when the validator is fail-fast.
+    pub fn report(&mut self, mut error: ValidationError) -> Result<(), ValidationError> {
+        if error.path.is_empty() {
+            error.path = self.path.clone();
+        }
+        if error.field_path.is_empty() {
+            error.field_path = self.field_path.clone();
+        }
+        if self.fail_fast {
+            return Err(error);
+        }
+        self.errors.push(error);
+        Ok(())
+    }
+
+    /// Reports a violation of the child `element` (Rust `field`) of the current position.
+    pub fn report_at(&mut self, element: &str, field: &str, error: ValidationError) -> Result<(), ValidationError> {
+        self.element(element, field, |validator| validator.report(error))
+    }
+
+    /// Runs `validate` on the child `element` (Rust `field`) of the current position.
+    /// An empty `element` or `field` leaves that path unchanged, e.g. for `$value` content.
+    pub fn element<F>(&mut self, element: &str, field: &str, validate: F) -> Result<(), ValidationError>
+    where
+        F: FnOnce(&mut Validator) -> Result<(), ValidationError>,
+    {
+        self.enter(element, field, None, validate)
+    }
+
+    /// Runs `validate` on the repeated child `element` (Rust `field`) at `index`, starting at 0.
+    pub fn item<F>(&mut self, element: &str, field: &str, index: usize, validate: F) -> Result<(), ValidationError>
+    where
+        F: FnOnce(&mut Validator) -> Result<(), ValidationError>,
+    {
+        self.enter(element, field, Some(index), validate)
+    }
+
+    fn enter<F>(&mut self, element: &str, field: &str, index: Option<usize>, validate: F) -> Result<(), ValidationError>
+    where
+        F: FnOnce(&mut Validator) -> Result<(), ValidationError>,
+    {
+        let (path_len, field_path_len) = (self.path.len(), self.field_path.len());
+        if !element.is_empty() {
+            self.path.push('/');
+            self.path.push_str(element);
+            if let Some(index) = index {
+                self.path.push_str(&format!("[{}]", index + 1));
+            }
+        }
+        if !field.is_empty() {
+            if !self.field_path.is_empty() {
+                self.field_path.push('.');
+            }
+            self.field_path.push_str(field);
+            if let Some(index) = index {
+                self.field_path.push_str(&format!("[{}]", index));
+            }
+        }
+        let result = validate(self);
+        self.path.truncate(path_len);
+        self.field_path.truncate(field_path_len);
+        result
+    }
+
+    pub fn into_report(self) -> ValidationReport {
+        ValidationReport { errors: self.errors }
     }
 }
@@ -0,0 +1,273 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! XSD `pattern` facets, compiled once and matched against the whole value.
+
+use std::sync::OnceLock;
+use regex::Regex;
+
+/// An XSD `pattern` facet, meant to be held in a `static` by the generated validators:
+///
+/// ```ignore
+/// static PATTERN_CCY: Pattern = Pattern::new("[A-Z]{3,3}");
+/// if !PATTERN_CCY.is_match(&self.ccy) { ... }
+/// ```
+///
+/// The pattern is compiled on first use only. Sequences of character classes with fixed
+/// repetitions, such as currency and country codes, IBAN, LEI or UETR, are matched by a
+/// hand-written scanner; every other pattern by an anchored [`Regex`].
+pub struct Pattern {
+    pattern: &'static str,
+    matcher: OnceLock<Matcher>,
+}
+
+enum Matcher {
+    Simple(Vec<Atom>),
+    Regex(Regex),
+}
+
+// A character class repeated `min` to `max` times.
+struct Atom {
+    ranges: Vec<(char, char)>,
+    min: usize,
+    max: usize,
+}
+
+impl Atom {
+    fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|&(from, to)| from <= c && c <= to)
+    }
+}
+
+impl Pattern {
+    pub const fn new(pattern: &'static str) -> Self {
+        Pattern { pattern, matcher: OnceLock::new() }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// Returns `true` when the whole of `value` matches the pattern, as XSD requires.
+    pub fn is_match(&self, value: &str) -> bool {
+        match self.matcher.get_or_init(|| compile(self.pattern)) {
+            Matcher::Simple(atoms) => match_atoms(atoms, value),
+            Matcher::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+fn compile(pattern: &str) -> Matcher {
+    match parse_atoms(pattern) {
+        Some(atoms) => Matcher::Simple(atoms),
+        None => Matcher::Regex(
+            Regex::new(&format!("^(?:{})$", pattern)).expect("invalid XSD pattern facet"),
+        ),
+    }
+}
+
+// Every atom but the last must have a fixed length, so the scanner never has to backtrack.
+fn match_atoms(atoms: &[Atom], value: &str) -> bool {
+    let mut chars = value.chars().peekable();
+    for (i, atom) in atoms.iter().enumerate() {
+        let max = if i + 1 == atoms.len() { atom.max } else { atom.min };
+        let mut count = 0;
+        while count < max {
+            match chars.peek() {
+                Some(&c) if atom.contains(c) => {
+                    chars.next();
+                    count += 1;
+                }
+                _ => break,
+            }
+        }
+        if count < atom.min {
+            return false;
+        }
+    }
+    chars.next().is_none()
+}
+
+// Parses patterns made of literal characters and `[...]` classes with optional quantifiers,
+// returns `None` for anything else.
+fn parse_atoms(pattern: &str) -> Option<Vec<Atom>> {
+    let mut atoms = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let ranges = match c {
+            '[' => parse_class(&mut chars)?,
+            '\\' => {
+                let c = literal_escape(chars.next()?)?;
+                vec![(c, c)]
+            }
+            '.' | '(' | ')' | '|' | '*' | '+' | '?' | '{' | '}' | '^' | '$' | ']' => return None,
+            c => vec![(c, c)],
+        };
+        let (min, max) = match chars.peek() {
+            Some('{') => {
+                chars.next();
+                let mut bounds = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    bounds.push(c);
+                }
+                match bounds.split_once(',') {
+                    None => {
+                        let n = bounds.trim().parse().ok()?;
+                        (n, n)
+                    }
+                    Some((min, "")) => (min.trim().parse().ok()?, usize::MAX),
+                    Some((min, max)) => (min.trim().parse().ok()?, max.trim().parse().ok()?),
+                }
+            }
+            Some('?') => { chars.next(); (0, 1) }
+            Some('*') => { chars.next(); (0, usize::MAX) }
+            Some('+') => { chars.next(); (1, usize::MAX) }
+            _ => (1, 1),
+        };
+        if min > max {
+            return None;
+        }
+        atoms.push(Atom { ranges, min, max });
+    }
+    if atoms.iter().rev().skip(1).any(|atom| atom.min != atom.max) {
+        return None;
+    }
+    Some(atoms)
+}
+
+fn parse_class(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<Vec<(char, char)>> {
+    if chars.peek() == Some(&'^') {
+        return None;
+    }
+    let mut ranges = Vec::new();
+    loop {
+        let from = match chars.next()? {
+            ']' => break,
+            '[' => return None,
+            '\\' => literal_escape(chars.next()?)?,
+            c => c,
+        };
+        let mut lookahead = chars.clone();
+        if lookahead.next() == Some('-') && !matches!(lookahead.peek(), Some(']') | None) {
+            chars.next();
+            let to = match chars.next()? {
+                '\\' => literal_escape(chars.next()?)?,
+                '[' => return None,
+                c => c,
+            };
+            if from > to {
+                return None;
+            }
+            ranges.push((from, to));
+        } else {
+            ranges.push((from, from));
+        }
+    }
+    if ranges.is_empty() { None } else { Some(ranges) }
+}
+
+// Escaped punctuation stands for itself, escapes such as `\d` or `\p{L}` are left to the regex engine.
+fn literal_escape(c: char) -> Option<char> {
+    if c.is_ascii_punctuation() { Some(c) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The patterns of the ISO 20022 identifier and code types.
+    const IBAN: &str = "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}";
+    const BIC: &str = "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}";
+    const LEI: &str = "[A-Z0-9]{18,18}[0-9]{2,2}";
+    const UETR: &str = "[a-f0-9]{8}-[a-f0-9]{4}-4[a-f0-9]{3}-[89ab][a-f0-9]{3}-[a-f0-9]{12}";
+    const CURRENCY: &str = "[A-Z]{3,3}";
+    const COUNTRY: &str = "[A-Z]{2,2}";
+
+    fn check(pattern: &'static str, valid: &[&str], invalid: &[&str]) {
+        let pattern = Pattern::new(pattern);
+        for value in valid {
+            assert!(pattern.is_match(value), "{} should match {}", value, pattern.as_str());
+        }
+        for value in invalid {
+            assert!(!pattern.is_match(value), "{} should not match {}", value, pattern.as_str());
+        }
+    }
+
+    #[test]
+    fn whole_value_must_match() {
+        check("[a-zA-Z0-9]{4}", &["ABCD", "a1B2"], &["ABCDEFG!", "ABCDE", "ABC", "AB!D", ""]);
+        // Anchored around the alternation, not only its first and last branches
+        check("[A-Z]{3}|[0-9]{2}", &["ABC", "12"], &["ABC1", "X12", "ABC12"]);
+    }
+
+    #[test]
+    fn iban() {
+        check(IBAN, &["GB82WEST12345698765432", "DE89370400440532013000", "NL91abna0417164300"], &[
+            "GB82WEST12345698765432 ",
+            "GB82WEST12345698765432!",
+            "GB82WEST12345698765432123456789012345",
+            "gb82WEST12345698765432",
+            "GB8",
+            "GB82 WEST 1234 5698 7654 32",
+        ]);
+    }
+
+    #[test]
+    fn bic() {
+        check(BIC, &["DEUTDEFF", "DEUTDEFF500", "1234DE12"], &["DEUTDEFF5", "DEUTDEFF50", "DEUTDEFF5000", "DEUT12FF", "deutdeff", "DEUTDEFF!"]);
+    }
+
+    #[test]
+    fn lei() {
+        check(LEI, &["5493001KJTIIGC8Y1R12", "7437002WC51RDNI14T18"], &["5493001KJTIIGC8Y1R1", "5493001KJTIIGC8Y1R123", "5493001KJTIIGC8Y1RAB", "5493001kjtiigc8y1r12"]);
+    }
+
+    #[test]
+    fn uetr() {
+        check(UETR, &["d0afc93f-1add-40f5-9a7e-1f72d9d08c63"], &[
+            "d0afc93f-1add-40f5-9a7e-1f72d9d08c63-",
+            "D0AFC93F-1ADD-40F5-9A7E-1F72D9D08C63",
+            "d0afc93f-1add-10f5-9a7e-1f72d9d08c63",
+            "d0afc93f-1add-40f5-ca7e-1f72d9d08c63",
+            "d0afc93f1add40f59a7e1f72d9d08c63",
+        ]);
+    }
+
+    #[test]
+    fn currency_and_country() {
+        check(CURRENCY, &["EUR", "USD"], &["EURO", "EU", "eur", "EU1", ""]);
+        check(COUNTRY, &["DE", "US"], &["DEU", "D", "de", "D1"]);
+    }
+
+    #[test]
+    fn fixed_length_classes_use_the_scanner() {
+        for pattern in [IBAN, LEI, UETR, CURRENCY, COUNTRY, "[a-zA-Z0-9]{4}"] {
+            assert!(matches!(compile(pattern), Matcher::Simple(_)), "{}", pattern);
+        }
+        assert!(matches!(compile(BIC), Matcher::Regex(_)));
+    }
+}
@@ -0,0 +1,81 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Cross-field rules of the ISO 20022 message definitions, e.g. the `NbOfTxs` of a group
+//! header counting the transactions of the message.
+//!
+//! The schemas only constrain each field on its own, so these rules are written by hand in the
+//! `rules` module of the pacs, pain and camt crates, as `validate_rules()` methods of the
+//! messages. Each violation is reported as [`ValidationErrorKind::Rule`](crate::ValidationErrorKind::Rule)
+//! carrying the name of the rule in the message definition report (MDR), e.g.
+//! `TotalInterbankSettlementAmountRule`. Checks the MDR only states in the definition of an
+//! element, such as `NbOfTxs` counting the transactions, are named after it, e.g.
+//! `NumberOfTransactionsRule`. `Document::validate_with` runs them when the validator was built
+//! with [`Validator::with_rules`].
+
+use crate::{Decimal, ValidationError, Validator};
+
+/// Reports a violation of `rule` at the child `element` (Rust `field`) of the current position
+/// unless the rule `holds`. The `message` is only built for a violation.
+pub fn check<F>(validator: &mut Validator, element: &str, field: &str, rule: &'static str, holds: bool, message: F) -> Result<(), ValidationError>
+where
+    F: FnOnce() -> String,
+{
+    if holds {
+        return Ok(());
+    }
+    validator.report_at(element, field, ValidationError::rule(rule, message()))
+}
+
+/// Whether the numeric text `number`, e.g. a `NbOfTxs`, equals `count`. A value that is not a
+/// number is left to its pattern facet.
+pub fn counts(number: &str, count: usize) -> bool {
+    number.parse::<u64>().map_or(true, |number| number == count as u64)
+}
+
+/// Sum of `amounts`, `None` on overflow.
+pub fn sum<I>(amounts: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    amounts.into_iter().try_fold(Decimal::ZERO, Decimal::checked_add)
+}
+
+/// Checks the `NbOfTxs` of the current element against the `count` transactions it stands for.
+pub fn number_of_transactions(validator: &mut Validator, nb_of_txs: &str, count: usize) -> Result<(), ValidationError> {
+    check(validator, "NbOfTxs", "nb_of_txs", "NumberOfTransactionsRule", counts(nb_of_txs, count), || {
+        format!("NbOfTxs does not match the number of transactions, {}", count)
+    })
+}
+
+/// Checks the `CtrlSum` of the current element against the sum of the `amounts` it stands for,
+/// irrespective of their currencies.
+pub fn control_sum<I>(validator: &mut Validator, ctrl_sum: &Decimal, amounts: I) -> Result<(), ValidationError>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    check(validator, "CtrlSum", "ctrl_sum", "ControlSumRule", sum(amounts) == Some(*ctrl_sum), || {
+        "CtrlSum does not equal the sum of the amounts".to_string()
+    })
+}
@@ -0,0 +1,120 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Building blocks shared by the streaming readers and writers of the message crates.
+
+use std::io::{BufRead, Seek, SeekFrom, Write};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{DeError, Reader, SeError, Writer};
+use serde::de::DeserializeOwned;
+use crate::Decimal;
+
+/// Copies the element opened by `start` and all of its content from `reader`.
+pub fn copy_element<R: BufRead>(reader: &mut Reader<R>, start: Event<'static>) -> Result<Vec<u8>, DeError> {
+	let mut writer = Writer::new(Vec::new());
+	let mut depth = match start {
+		Event::Start(_) => 1,
+		_ => 0,
+	};
+	writer.write_event(start).map_err(quick_xml::Error::from)?;
+	let mut buf = Vec::new();
+	while depth > 0 {
+		buf.clear();
+		let event = reader.read_event_into(&mut buf)?;
+		match event {
+			Event::Start(_) => depth += 1,
+			Event::End(_) => depth -= 1,
+			Event::Eof => return Err(DeError::UnexpectedEof),
+			_ => {}
+		}
+		writer.write_event(event).map_err(quick_xml::Error::from)?;
+	}
+	Ok(writer.into_inner())
+}
+
+/// Deserializes the element opened by `start`, reading `reader` up to its end tag.
+pub fn read_element<R: BufRead, T: DeserializeOwned>(reader: &mut Reader<R>, start: BytesStart<'static>) -> Result<T, DeError> {
+	let xml = copy_element(reader, Event::Start(start))?;
+	quick_xml::de::from_reader(xml.as_slice())
+}
+
+/// Deserializes `content` wrapped in a `tag` element.
+pub fn read_wrapped<T: DeserializeOwned>(tag: &[u8], content: &[u8]) -> Result<T, DeError> {
+	let mut xml = Vec::with_capacity(content.len() + 2 * tag.len() + 5);
+	xml.push(b'<');
+	xml.extend_from_slice(tag);
+	xml.push(b'>');
+	xml.extend_from_slice(content);
+	xml.extend_from_slice(b"</");
+	xml.extend_from_slice(tag);
+	xml.push(b'>');
+	quick_xml::de::from_reader(xml.as_slice())
+}
+
+/// Fails with an error naming `tag` when the message element was never found.
+pub fn missing_element(tag: &[u8]) -> DeError {
+	DeError::Custom(format!("missing {} element", String::from_utf8_lossy(tag)))
+}
+
+/// Adds `amount` to the running `CtrlSum` `sum`, failing instead of overflowing on untrusted amounts.
+pub fn add_to_sum(sum: &mut Decimal, amount: Decimal) -> Result<(), SeError> {
+	*sum = sum.checked_add(amount).ok_or_else(|| SeError::Custom(format!("CtrlSum overflows adding {}", amount)))?;
+	Ok(())
+}
+
+/// Span reserved for a simple element whose value is only known once the file is written,
+/// such as `NbOfTxs` or `CtrlSum` in a group header. Whitespace after the element pads the span.
+pub struct Placeholder {
+	offset: u64,
+	tag: &'static str,
+	len: usize,
+}
+
+impl Placeholder {
+	/// Pads the first `tag` element of `xml` so that `width` characters of content fit,
+	/// `offset` is the position of `xml` in the output. Returns `None` when `xml` has no such element.
+	pub fn reserve(xml: &mut String, offset: u64, tag: &'static str, width: usize) -> Option<Placeholder> {
+		let open = format!("<{}>", tag);
+		let close = format!("</{}>", tag);
+		let start = xml.find(&open)?;
+		let end = start + xml[start..].find(&close)? + close.len();
+		let len = (open.len() + width + close.len()).max(end - start);
+		xml.insert_str(end, &" ".repeat(len - (end - start)));
+		Some(Placeholder { offset: offset + start as u64, tag, len })
+	}
+
+	/// Writes `value` into the reserved span and moves `writer` back to where it was.
+	pub fn fill<W: Write + Seek>(&self, writer: &mut W, value: &str) -> Result<(), SeError> {
+		let element = format!("<{}>{}</{}>", self.tag, value, self.tag);
+		if element.len() > self.len {
+			return Err(SeError::Custom(format!("{} value {} exceeds the reserved width", self.tag, value)));
+		}
+		let position = writer.stream_position()?;
+		writer.seek(SeekFrom::Start(self.offset))?;
+		writer.write_all(element.as_bytes())?;
+		writer.write_all(" ".repeat(self.len - element.len()).as_bytes())?;
+		writer.seek(SeekFrom::Start(position))?;
+		Ok(())
+	}
+}
@@ -0,0 +1,224 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+//! Text and identifier types constrained by length or pattern facets, such as `Max35Text`,
+//! `IBAN2007Identifier` or `UUIDv4Identifier`.
+//!
+//! Without the `typed_text` feature each type is an alias of `String`. With it, each one is a
+//! newtype that can only be built from a value satisfying its facets, through `TryFrom<&str>`,
+//! `TryFrom<String>`, `FromStr` or deserialization, so that a message built from them cannot
+//! violate them. The `Default` value is the empty string, which `validate()` still reports
+//! where the type requires a minimum length.
+
+#[cfg(feature = "typed_text")]
+use crate::{Pattern, ValidationError};
+
+/// Checks `value` against the facets of the text type `name`.
+#[cfg(feature = "typed_text")]
+fn check(name: &str, value: &str, min: usize, max: usize, pattern: Option<&Pattern>) -> Result<(), ValidationError> {
+    let length = value.chars().count();
+    if length < min {
+        return Err(ValidationError::min_length(name, min, length));
+    }
+    if length > max {
+        return Err(ValidationError::max_length(name, max, length));
+    }
+    match pattern {
+        Some(pattern) if !pattern.is_match(value) => Err(ValidationError::pattern(name, pattern.as_str(), value)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(feature = "typed_text"))]
+macro_rules! text_types {
+    ($($(#[$doc:meta])* $name:ident($min:expr, $max:expr, $pattern:expr);)*) => {$(
+        $(#[$doc])*
+        pub type $name = String;
+    )*};
+}
+
+#[cfg(feature = "typed_text")]
+macro_rules! text_types {
+    ($($(#[$doc:meta])* $name:ident($min:expr, $max:expr, $pattern:expr);)*) => {$(
+        $(#[$doc])*
+        ///
+        /// `Default` gives the empty string, which does not satisfy a minimum length of 1.
+        #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: String) -> Result<Self, ValidationError> {
+                static PATTERN: Option<Pattern> = match $pattern {
+                    Some(pattern) => Some(Pattern::new(pattern)),
+                    None => None,
+                };
+                check(stringify!($name), &value, $min, $max, PATTERN.as_ref())?;
+                Ok($name(value))
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: &str) -> Result<Self, ValidationError> {
+                Self::try_from(value.to_string())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = ValidationError;
+
+            fn from_str(value: &str) -> Result<Self, ValidationError> {
+                Self::try_from(value)
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> String {
+                value.0
+            }
+        }
+
+        #[cfg(feature = "derive_serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        #[cfg(feature = "derive_serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Self::try_from(value).map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+include!("text_types.rs");
+
+#[cfg(all(test, feature = "typed_text"))]
+mod tests {
+    use super::*;
+    use crate::ValidationErrorKind;
+
+    #[test]
+    fn valid_values_are_accepted() {
+        let name = Max35Text::try_from("Acme Corp").unwrap();
+        assert_eq!(name, "Acme Corp");
+        assert_eq!(name.as_str(), "Acme Corp");
+        assert_eq!("Acme Corp".parse::<Max35Text>().unwrap(), name);
+        assert_eq!(String::from(name), "Acme Corp");
+        assert!(Max35Text::try_from("x".repeat(35)).is_ok());
+        assert!(CountryCode::try_from("DE").is_ok());
+    }
+
+    #[test]
+    fn values_too_long_are_rejected() {
+        let error = Max35Text::try_from("x".repeat(36)).unwrap_err();
+        assert!(matches!(*error.kind, ValidationErrorKind::MaxLength { max: 35, actual: 36 }));
+        // Length is counted in characters, not bytes.
+        assert!(Max35Text::try_from("é".repeat(35)).is_ok());
+    }
+
+    #[test]
+    fn empty_values_are_rejected() {
+        let error = "".parse::<Max35Text>().unwrap_err();
+        assert!(matches!(*error.kind, ValidationErrorKind::MinLength { min: 1, actual: 0 }));
+        assert!(Max140Text::try_from(Max140Text::default().as_str()).is_err());
+    }
+
+    #[test]
+    fn values_breaking_the_pattern_are_rejected() {
+        for value in ["de", "DEU", "D1", ""] {
+            let error = CountryCode::try_from(value).unwrap_err();
+            match &*error.kind {
+                ValidationErrorKind::Pattern { pattern, value: actual } => {
+                    assert_eq!(*pattern, "[A-Z]{2,2}");
+                    assert_eq!(actual.as_deref(), Some(value));
+                }
+                kind => panic!("{}: {:?}", value, kind),
+            }
+        }
+        assert!("ABCDEFGH1XX".parse::<AnyBICDec2014Identifier>().is_ok());
+        assert!("ABCD-FGH".parse::<AnyBICDec2014Identifier>().is_err());
+    }
+
+    #[cfg(feature = "derive_serde")]
+    #[test]
+    fn serde_round_trip() {
+        let name = Max35Text::try_from("Acme Corp").unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, "\"Acme Corp\"");
+        assert_eq!(serde_json::from_str::<Max35Text>(&json).unwrap(), name);
+        assert!(serde_json::from_str::<Max35Text>("\"\"").is_err());
+        assert!(serde_json::from_str::<CountryCode>("\"de\"").is_err());
+    }
+}
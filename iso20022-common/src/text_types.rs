@@ -0,0 +1,468 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+// Generated by generate-text.py from the XSDs, see text.rs.
+
+text_types! {
+	/// `ActiveCurrencyCode`: matching `[A-Z]{3,3}`.
+	ActiveCurrencyCode(0, usize::MAX, Some("[A-Z]{3,3}"));
+	/// `ActiveOrHistoricCurrencyCode`: matching `[A-Z]{3,3}`.
+	ActiveOrHistoricCurrencyCode(0, usize::MAX, Some("[A-Z]{3,3}"));
+	/// `AnyBICDec2014Identifier`: matching `[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}`.
+	AnyBICDec2014Identifier(0, usize::MAX, Some("[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}"));
+	/// `AnyBICIdentifier`: matching `[A-Z]{6,6}[A-Z2-9][A-NP-Z0-9]([A-Z0-9]{3,3}){0,1}`.
+	AnyBICIdentifier(0, usize::MAX, Some("[A-Z]{6,6}[A-Z2-9][A-NP-Z0-9]([A-Z0-9]{3,3}){0,1}"));
+	/// `AustrianBankleitzahlIdentifier`: matching `AT[0-9]{5,5}`.
+	AustrianBankleitzahlIdentifier(0, usize::MAX, Some("AT[0-9]{5,5}"));
+	/// `BICFIDec2014Identifier`: matching `[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}`.
+	BICFIDec2014Identifier(0, usize::MAX, Some("[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}"));
+	/// `BICFIIdentifier`: matching `[A-Z]{6,6}[A-Z2-9][A-NP-Z0-9]([A-Z0-9]{3,3}){0,1}`.
+	BICFIIdentifier(0, usize::MAX, Some("[A-Z]{6,6}[A-Z2-9][A-NP-Z0-9]([A-Z0-9]{3,3}){0,1}"));
+	/// `Bloomberg2Identifier`: matching `(BBG)[BCDFGHJKLMNPQRSTVWXYZ\d]{8}\d`.
+	Bloomberg2Identifier(0, usize::MAX, Some("(BBG)[BCDFGHJKLMNPQRSTVWXYZ\\d]{8}\\d"));
+	/// `BloombergIdentifier`: 1 to 35 characters.
+	BloombergIdentifier(1, 35, None);
+	/// `CFIOct2015Identifier`: matching `[A-Z]{6,6}`.
+	CFIOct2015Identifier(0, usize::MAX, Some("[A-Z]{6,6}"));
+	/// `CHIPSParticipantIdentifier`: matching `CP[0-9]{4,4}`.
+	CHIPSParticipantIdentifier(0, usize::MAX, Some("CP[0-9]{4,4}"));
+	/// `CHIPSUniversalIdentifier`: matching `CH[0-9]{6,6}`.
+	CHIPSUniversalIdentifier(0, usize::MAX, Some("CH[0-9]{6,6}"));
+	/// `CanadianPaymentsARNIdentifier`: matching `CA[0-9]{9,9}`.
+	CanadianPaymentsARNIdentifier(0, usize::MAX, Some("CA[0-9]{9,9}"));
+	/// `ConsolidatedTapeAssociationIdentifier`: 1 to 35 characters.
+	ConsolidatedTapeAssociationIdentifier(1, 35, None);
+	/// `CountryCode`: matching `[A-Z]{2,2}`.
+	CountryCode(0, usize::MAX, Some("[A-Z]{2,2}"));
+	/// `CountrySubDivisionCode`: matching `[A-Z]{2,2}\-[0-9A-Z]{1,3}`.
+	CountrySubDivisionCode(0, usize::MAX, Some("[A-Z]{2,2}\\-[0-9A-Z]{1,3}"));
+	/// `CurrencyCode`: matching `[A-Z]{3,3}`.
+	CurrencyCode(0, usize::MAX, Some("[A-Z]{3,3}"));
+	/// `DTI2021Identifier`: matching `[1-9B-DF-HJ-NP-XZ][0-9B-DF-HJ-NP-XZ]{8,8}`.
+	DTI2021Identifier(0, usize::MAX, Some("[1-9B-DF-HJ-NP-XZ][0-9B-DF-HJ-NP-XZ]{8,8}"));
+	/// `DateOffsetText`: matching `0|-1|-2`.
+	DateOffsetText(0, usize::MAX, Some("0|-1|-2"));
+	/// `EICIdentifier`: matching `[A-Z0-9\-]{16}`.
+	EICIdentifier(0, usize::MAX, Some("[A-Z0-9\\-]{16}"));
+	/// `EntryTypeIdentifier`: matching `[BEOVW]{1,1}[0-9]{2,2}|DUM`.
+	EntryTypeIdentifier(0, usize::MAX, Some("[BEOVW]{1,1}[0-9]{2,2}|DUM"));
+	/// `EuroclearClearstreamIdentifier`: 1 to 12 characters.
+	EuroclearClearstreamIdentifier(1, 12, None);
+	/// `Exact1NumericText`: matching `[0-9]`.
+	Exact1NumericText(0, usize::MAX, Some("[0-9]"));
+	/// `Exact2NumericText`: matching `[0-9]{2}`.
+	Exact2NumericText(0, usize::MAX, Some("[0-9]{2}"));
+	/// `Exact2UpperCaseAlphaText`: matching `[A-Z]{2}`.
+	Exact2UpperCaseAlphaText(0, usize::MAX, Some("[A-Z]{2}"));
+	/// `Exact3NumericText`: matching `[0-9]{3}`.
+	Exact3NumericText(0, usize::MAX, Some("[0-9]{3}"));
+	/// `Exact4AlphaNumericText`: matching `[a-zA-Z0-9]{4}`.
+	Exact4AlphaNumericText(0, usize::MAX, Some("[a-zA-Z0-9]{4}"));
+	/// `Exact4AlphaNumericUnderscoreText`: matching `[a-zA-Z0-9]{1}[a-zA-Z0-9_]{3}`.
+	Exact4AlphaNumericUnderscoreText(0, usize::MAX, Some("[a-zA-Z0-9]{1}[a-zA-Z0-9_]{3}"));
+	/// `Exact4NumericText`: matching `[0-9]{4}`.
+	Exact4NumericText(0, usize::MAX, Some("[0-9]{4}"));
+	/// `Exact5NumericText`: matching `[0-9]{5}`.
+	Exact5NumericText(0, usize::MAX, Some("[0-9]{5}"));
+	/// `Extended350Code`: 1 to 350 characters.
+	Extended350Code(1, 350, None);
+	/// `ExtensiveBranchNetworkIdentifier`: matching `AU[0-9]{6,6}`.
+	ExtensiveBranchNetworkIdentifier(0, usize::MAX, Some("AU[0-9]{6,6}"));
+	/// `ExternalAcceptedReason1Code`: 1 to 4 characters.
+	ExternalAcceptedReason1Code(1, 4, None);
+	/// `ExternalAccountIdentification1Code`: 1 to 4 characters.
+	ExternalAccountIdentification1Code(1, 4, None);
+	/// `ExternalAgentInstruction1Code`: 1 to 4 characters.
+	ExternalAgentInstruction1Code(1, 4, None);
+	/// `ExternalAgreementType1Code`: 1 to 4 characters.
+	ExternalAgreementType1Code(1, 4, None);
+	/// `ExternalAuthenticationChannel1Code`: 1 to 4 characters.
+	ExternalAuthenticationChannel1Code(1, 4, None);
+	/// `ExternalAuthorityExchangeReason1Code`: 1 to 4 characters.
+	ExternalAuthorityExchangeReason1Code(1, 4, None);
+	/// `ExternalAuthorityIdentification1Code`: 1 to 4 characters.
+	ExternalAuthorityIdentification1Code(1, 4, None);
+	/// `ExternalBalanceSubType1Code`: 1 to 4 characters.
+	ExternalBalanceSubType1Code(1, 4, None);
+	/// `ExternalBalanceType1Code`: 1 to 4 characters.
+	ExternalBalanceType1Code(1, 4, None);
+	/// `ExternalBankTransactionDomain1Code`: 1 to 4 characters.
+	ExternalBankTransactionDomain1Code(1, 4, None);
+	/// `ExternalBankTransactionFamily1Code`: 1 to 4 characters.
+	ExternalBankTransactionFamily1Code(1, 4, None);
+	/// `ExternalBankTransactionSubFamily1Code`: 1 to 4 characters.
+	ExternalBankTransactionSubFamily1Code(1, 4, None);
+	/// `ExternalBenchmarkCurveName1Code`: 1 to 4 characters.
+	ExternalBenchmarkCurveName1Code(1, 4, None);
+	/// `ExternalBillingBalanceType1Code`: 1 to 4 characters.
+	ExternalBillingBalanceType1Code(1, 4, None);
+	/// `ExternalBillingCompensationType1Code`: 1 to 4 characters.
+	ExternalBillingCompensationType1Code(1, 4, None);
+	/// `ExternalBillingRateIdentification1Code`: 1 to 4 characters.
+	ExternalBillingRateIdentification1Code(1, 4, None);
+	/// `ExternalCancellationReason1Code`: 1 to 4 characters.
+	ExternalCancellationReason1Code(1, 4, None);
+	/// `ExternalCardTransactionCategory1Code`: 1 to 4 characters.
+	ExternalCardTransactionCategory1Code(1, 4, None);
+	/// `ExternalCashAccountType1Code`: 1 to 4 characters.
+	ExternalCashAccountType1Code(1, 4, None);
+	/// `ExternalCashClearingSystem1Code`: 1 to 3 characters.
+	ExternalCashClearingSystem1Code(1, 3, None);
+	/// `ExternalCategoryPurpose1Code`: 1 to 4 characters.
+	ExternalCategoryPurpose1Code(1, 4, None);
+	/// `ExternalChargeType1Code`: 1 to 4 characters.
+	ExternalChargeType1Code(1, 4, None);
+	/// `ExternalChequeAgentInstruction1Code`: 1 to 4 characters.
+	ExternalChequeAgentInstruction1Code(1, 4, None);
+	/// `ExternalChequeCancellationReason1Code`: 1 to 4 characters.
+	ExternalChequeCancellationReason1Code(1, 4, None);
+	/// `ExternalChequeCancellationStatus1Code`: 1 to 4 characters.
+	ExternalChequeCancellationStatus1Code(1, 4, None);
+	/// `ExternalClaimNonReceiptRejection1Code`: 1 to 4 characters.
+	ExternalClaimNonReceiptRejection1Code(1, 4, None);
+	/// `ExternalClearingSystemIdentification1Code`: 1 to 5 characters.
+	ExternalClearingSystemIdentification1Code(1, 5, None);
+	/// `ExternalCommunicationFormat1Code`: 1 to 4 characters.
+	ExternalCommunicationFormat1Code(1, 4, None);
+	/// `ExternalContractBalanceType1Code`: 1 to 4 characters.
+	ExternalContractBalanceType1Code(1, 4, None);
+	/// `ExternalContractClosureReason1Code`: 1 to 4 characters.
+	ExternalContractClosureReason1Code(1, 4, None);
+	/// `ExternalCreditLineType1Code`: 1 to 4 characters.
+	ExternalCreditLineType1Code(1, 4, None);
+	/// `ExternalCreditorAgentInstruction1Code`: 1 to 4 characters.
+	ExternalCreditorAgentInstruction1Code(1, 4, None);
+	/// `ExternalCreditorEnrolmentAmendmentReason1Code`: 1 to 4 characters.
+	ExternalCreditorEnrolmentAmendmentReason1Code(1, 4, None);
+	/// `ExternalCreditorEnrolmentCancellationReason1Code`: 1 to 4 characters.
+	ExternalCreditorEnrolmentCancellationReason1Code(1, 4, None);
+	/// `ExternalCreditorEnrolmentStatusReason1Code`: 1 to 4 characters.
+	ExternalCreditorEnrolmentStatusReason1Code(1, 4, None);
+	/// `ExternalCreditorReferenceType1Code`: 1 to 4 characters.
+	ExternalCreditorReferenceType1Code(1, 4, None);
+	/// `ExternalDateType1Code`: 1 to 4 characters.
+	ExternalDateType1Code(1, 4, None);
+	/// `ExternalDebtorActivationAmendmentReason1Code`: 1 to 4 characters.
+	ExternalDebtorActivationAmendmentReason1Code(1, 4, None);
+	/// `ExternalDebtorActivationCancellationReason1Code`: 1 to 4 characters.
+	ExternalDebtorActivationCancellationReason1Code(1, 4, None);
+	/// `ExternalDebtorActivationStatusReason1Code`: 1 to 4 characters.
+	ExternalDebtorActivationStatusReason1Code(1, 4, None);
+	/// `ExternalDebtorAgentInstruction1Code`: 1 to 4 characters.
+	ExternalDebtorAgentInstruction1Code(1, 4, None);
+	/// `ExternalDiscountAmountType1Code`: 1 to 4 characters.
+	ExternalDiscountAmountType1Code(1, 4, None);
+	/// `ExternalDocumentAmountType1Code`: 1 to 4 characters.
+	ExternalDocumentAmountType1Code(1, 4, None);
+	/// `ExternalDocumentFormat1Code`: 1 to 4 characters.
+	ExternalDocumentFormat1Code(1, 4, None);
+	/// `ExternalDocumentLineType1Code`: 1 to 4 characters.
+	ExternalDocumentLineType1Code(1, 4, None);
+	/// `ExternalDocumentType1Code`: 1 to 4 characters.
+	ExternalDocumentType1Code(1, 4, None);
+	/// `ExternalEffectiveDateParameter1Code`: 1 to 4 characters.
+	ExternalEffectiveDateParameter1Code(1, 4, None);
+	/// `ExternalEmissionAllowanceSubProductType1Code`: 1 to 4 characters.
+	ExternalEmissionAllowanceSubProductType1Code(1, 4, None);
+	/// `ExternalEnquiryRequestType1Code`: 1 to 4 characters.
+	ExternalEnquiryRequestType1Code(1, 4, None);
+	/// `ExternalEntryStatus1Code`: 1 to 4 characters.
+	ExternalEntryStatus1Code(1, 4, None);
+	/// `ExternalFinancialInstitutionIdentification1Code`: 1 to 4 characters.
+	ExternalFinancialInstitutionIdentification1Code(1, 4, None);
+	/// `ExternalFinancialInstrumentIdentificationType1Code`: 1 to 4 characters.
+	ExternalFinancialInstrumentIdentificationType1Code(1, 4, None);
+	/// `ExternalFinancialInstrumentProductType1Code`: 1 to 4 characters.
+	ExternalFinancialInstrumentProductType1Code(1, 4, None);
+	/// `ExternalGarnishmentType1Code`: 1 to 4 characters.
+	ExternalGarnishmentType1Code(1, 4, None);
+	/// `ExternalInstructedAgentInstruction1Code`: 1 to 4 characters.
+	ExternalInstructedAgentInstruction1Code(1, 4, None);
+	/// `ExternalInvestigationAction1Code`: 1 to 4 characters.
+	ExternalInvestigationAction1Code(1, 4, None);
+	/// `ExternalInvestigationActionReason1Code`: 1 to 4 characters.
+	ExternalInvestigationActionReason1Code(1, 4, None);
+	/// `ExternalInvestigationExecutionConfirmation1Code`: 1 to 4 characters.
+	ExternalInvestigationExecutionConfirmation1Code(1, 4, None);
+	/// `ExternalInvestigationInstrument1Code`: 1 to 4 characters.
+	ExternalInvestigationInstrument1Code(1, 4, None);
+	/// `ExternalInvestigationReason1Code`: 1 to 4 characters.
+	ExternalInvestigationReason1Code(1, 4, None);
+	/// `ExternalInvestigationReasonSubType1Code`: 1 to 4 characters.
+	ExternalInvestigationReasonSubType1Code(1, 4, None);
+	/// `ExternalInvestigationServiceLevel1Code`: 1 to 4 characters.
+	ExternalInvestigationServiceLevel1Code(1, 4, None);
+	/// `ExternalInvestigationStatus1Code`: 1 to 4 characters.
+	ExternalInvestigationStatus1Code(1, 4, None);
+	/// `ExternalInvestigationStatusReason1Code`: 1 to 4 characters.
+	ExternalInvestigationStatusReason1Code(1, 4, None);
+	/// `ExternalInvestigationSubType1Code`: 1 to 4 characters.
+	ExternalInvestigationSubType1Code(1, 4, None);
+	/// `ExternalInvestigationType1Code`: 1 to 4 characters.
+	ExternalInvestigationType1Code(1, 4, None);
+	/// `ExternalLetterType1Code`: 1 to 4 characters.
+	ExternalLetterType1Code(1, 4, None);
+	/// `ExternalLocalInstrument1Code`: 1 to 35 characters.
+	ExternalLocalInstrument1Code(1, 35, None);
+	/// `ExternalMandateReason1Code`: 1 to 4 characters.
+	ExternalMandateReason1Code(1, 4, None);
+	/// `ExternalMandateSetupReason1Code`: 1 to 4 characters.
+	ExternalMandateSetupReason1Code(1, 4, None);
+	/// `ExternalMandateStatus1Code`: 1 to 4 characters.
+	ExternalMandateStatus1Code(1, 4, None);
+	/// `ExternalMandateSuspensionReason1Code`: 1 to 4 characters.
+	ExternalMandateSuspensionReason1Code(1, 4, None);
+	/// `ExternalMarketArea1Code`: 1 to 4 characters.
+	ExternalMarketArea1Code(1, 4, None);
+	/// `ExternalMarketInfrastructure1Code`: 1 to 3 characters.
+	ExternalMarketInfrastructure1Code(1, 3, None);
+	/// `ExternalNotificationCancellationReason1Code`: 1 to 4 characters.
+	ExternalNotificationCancellationReason1Code(1, 4, None);
+	/// `ExternalNotificationType1Code`: 1 to 4 characters.
+	ExternalNotificationType1Code(1, 4, None);
+	/// `ExternalOrganisationIdentification1Code`: 1 to 4 characters.
+	ExternalOrganisationIdentification1Code(1, 4, None);
+	/// `ExternalPartyRelationshipType1Code`: 1 to 4 characters.
+	ExternalPartyRelationshipType1Code(1, 4, None);
+	/// `ExternalPaymentCancellationRejection1Code`: 1 to 4 characters.
+	ExternalPaymentCancellationRejection1Code(1, 4, None);
+	/// `ExternalPaymentCompensationReason1Code`: 1 to 4 characters.
+	ExternalPaymentCompensationReason1Code(1, 4, None);
+	/// `ExternalPaymentControlRequestType1Code`: 1 to 4 characters.
+	ExternalPaymentControlRequestType1Code(1, 4, None);
+	/// `ExternalPaymentGroupStatus1Code`: 1 to 4 characters.
+	ExternalPaymentGroupStatus1Code(1, 4, None);
+	/// `ExternalPaymentModificationRejection1Code`: 1 to 4 characters.
+	ExternalPaymentModificationRejection1Code(1, 4, None);
+	/// `ExternalPaymentRole1Code`: 1 to 4 characters.
+	ExternalPaymentRole1Code(1, 4, None);
+	/// `ExternalPaymentTransactionStatus1Code`: 1 to 4 characters.
+	ExternalPaymentTransactionStatus1Code(1, 4, None);
+	/// `ExternalPendingProcessingReason1Code`: 1 to 4 characters.
+	ExternalPendingProcessingReason1Code(1, 4, None);
+	/// `ExternalPersonIdentification1Code`: 1 to 4 characters.
+	ExternalPersonIdentification1Code(1, 4, None);
+	/// `ExternalProductType1Code`: 1 to 4 characters.
+	ExternalProductType1Code(1, 4, None);
+	/// `ExternalProxyAccountType1Code`: 1 to 4 characters.
+	ExternalProxyAccountType1Code(1, 4, None);
+	/// `ExternalPurpose1Code`: 1 to 4 characters.
+	ExternalPurpose1Code(1, 4, None);
+	/// `ExternalRatesAndTenors1Code`: 1 to 4 characters.
+	ExternalRatesAndTenors1Code(1, 4, None);
+	/// `ExternalRePresentmentReason1Code`: 1 to 4 characters.
+	ExternalRePresentmentReason1Code(1, 4, None);
+	/// `ExternalReceivedReason1Code`: 1 to 4 characters.
+	ExternalReceivedReason1Code(1, 4, None);
+	/// `ExternalRejectedReason1Code`: 1 to 4 characters.
+	ExternalRejectedReason1Code(1, 4, None);
+	/// `ExternalReportingSource1Code`: 1 to 4 characters.
+	ExternalReportingSource1Code(1, 4, None);
+	/// `ExternalRequestStatus1Code`: 1 to 4 characters.
+	ExternalRequestStatus1Code(1, 4, None);
+	/// `ExternalReservationType1Code`: 1 to 4 characters.
+	ExternalReservationType1Code(1, 4, None);
+	/// `ExternalReturnReason1Code`: 1 to 4 characters.
+	ExternalReturnReason1Code(1, 4, None);
+	/// `ExternalReversalReason1Code`: 1 to 4 characters.
+	ExternalReversalReason1Code(1, 4, None);
+	/// `ExternalSecuritiesLendingType1Code`: 1 to 4 characters.
+	ExternalSecuritiesLendingType1Code(1, 4, None);
+	/// `ExternalSecuritiesPurpose1Code`: 1 to 4 characters.
+	ExternalSecuritiesPurpose1Code(1, 4, None);
+	/// `ExternalSecuritiesUpdateReason1Code`: 1 to 4 characters.
+	ExternalSecuritiesUpdateReason1Code(1, 4, None);
+	/// `ExternalServiceLevel1Code`: 1 to 4 characters.
+	ExternalServiceLevel1Code(1, 4, None);
+	/// `ExternalShipmentCondition1Code`: 1 to 4 characters.
+	ExternalShipmentCondition1Code(1, 4, None);
+	/// `ExternalStatusReason1Code`: 1 to 4 characters.
+	ExternalStatusReason1Code(1, 4, None);
+	/// `ExternalSystemBalanceType1Code`: 1 to 4 characters.
+	ExternalSystemBalanceType1Code(1, 4, None);
+	/// `ExternalSystemErrorHandling1Code`: 1 to 4 characters.
+	ExternalSystemErrorHandling1Code(1, 4, None);
+	/// `ExternalSystemEventType1Code`: 1 to 4 characters.
+	ExternalSystemEventType1Code(1, 4, None);
+	/// `ExternalSystemMemberType1Code`: 1 to 4 characters.
+	ExternalSystemMemberType1Code(1, 4, None);
+	/// `ExternalSystemPartyType1Code`: 1 to 4 characters.
+	ExternalSystemPartyType1Code(1, 4, None);
+	/// `ExternalTaxAmountType1Code`: 1 to 4 characters.
+	ExternalTaxAmountType1Code(1, 4, None);
+	/// `ExternalTechnicalInputChannel1Code`: 1 to 4 characters.
+	ExternalTechnicalInputChannel1Code(1, 4, None);
+	/// `ExternalUnableToApplyIncorrectData1Code`: 1 to 4 characters.
+	ExternalUnableToApplyIncorrectData1Code(1, 4, None);
+	/// `ExternalUnableToApplyMissingData1Code`: 1 to 4 characters.
+	ExternalUnableToApplyMissingData1Code(1, 4, None);
+	/// `ExternalUnitOfMeasure1Code`: 1 to 4 characters.
+	ExternalUnitOfMeasure1Code(1, 4, None);
+	/// `ExternalValidationRuleIdentification1Code`: 1 to 4 characters.
+	ExternalValidationRuleIdentification1Code(1, 4, None);
+	/// `ExternalVerificationReason1Code`: 1 to 4 characters.
+	ExternalVerificationReason1Code(1, 4, None);
+	/// `FedwireRoutingNumberIdentifier`: matching `FW[0-9]{9,9}`.
+	FedwireRoutingNumberIdentifier(0, usize::MAX, Some("FW[0-9]{9,9}"));
+	/// `GermanBankleitzahlIdentifier`: matching `BL[0-9]{8,8}`.
+	GermanBankleitzahlIdentifier(0, usize::MAX, Some("BL[0-9]{8,8}"));
+	/// `HongKongBankIdentifier`: matching `HK[0-9]{3,3}`.
+	HongKongBankIdentifier(0, usize::MAX, Some("HK[0-9]{3,3}"));
+	/// `IBAN2007Identifier`: matching `[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}`.
+	IBAN2007Identifier(0, usize::MAX, Some("[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}"));
+	/// `ISIN2021Identifier`: matching `[A-Z]{2,2}[A-Z0-9]{9,9}[0-9]{1,1}`.
+	ISIN2021Identifier(0, usize::MAX, Some("[A-Z]{2,2}[A-Z0-9]{9,9}[0-9]{1,1}"));
+	/// `ISINIdentifier`: matching `[A-Z0-9]{12,12}`.
+	ISINIdentifier(0, usize::MAX, Some("[A-Z0-9]{12,12}"));
+	/// `ISINOct2015Identifier`: matching `[A-Z]{2,2}[A-Z0-9]{9,9}[0-9]{1,1}`.
+	ISINOct2015Identifier(0, usize::MAX, Some("[A-Z]{2,2}[A-Z0-9]{9,9}[0-9]{1,1}"));
+	/// `ISO20022MessageIdentificationText`: matching `[a-z]{4}\.[0-9]{3}\.[0-9]{3}\.[0-9]{2}`.
+	ISO20022MessageIdentificationText(0, usize::MAX, Some("[a-z]{4}\\.[0-9]{3}\\.[0-9]{3}\\.[0-9]{2}"));
+	/// `ISO2ALanguageCode`: matching `[a-z]{2,2}`.
+	ISO2ALanguageCode(0, usize::MAX, Some("[a-z]{2,2}"));
+	/// `IrishNSCIdentifier`: matching `IE[0-9]{6,6}`.
+	IrishNSCIdentifier(0, usize::MAX, Some("IE[0-9]{6,6}"));
+	/// `ItalianDomesticIdentifier`: matching `IT[0-9]{10,10}`.
+	ItalianDomesticIdentifier(0, usize::MAX, Some("IT[0-9]{10,10}"));
+	/// `LEIIdentifier`: matching `[A-Z0-9]{18,18}[0-9]{2,2}`.
+	LEIIdentifier(0, usize::MAX, Some("[A-Z0-9]{18,18}[0-9]{2,2}"));
+	/// `MICIdentifier`: matching `[A-Z0-9]{4,4}`.
+	MICIdentifier(0, usize::MAX, Some("[A-Z0-9]{4,4}"));
+	/// `Max1000Text`: 1 to 1000 characters.
+	Max1000Text(1, 1000, None);
+	/// `Max100Text`: 1 to 100 characters.
+	Max100Text(1, 100, None);
+	/// `Max1025Text`: 1 to 1025 characters.
+	Max1025Text(1, 1025, None);
+	/// `Max105Text`: 1 to 105 characters.
+	Max105Text(1, 105, None);
+	/// `Max10NumericText`: matching `[0-9]{1,10}`.
+	Max10NumericText(0, usize::MAX, Some("[0-9]{1,10}"));
+	/// `Max10Text`: 1 to 10 characters.
+	Max10Text(1, 10, None);
+	/// `Max128Text`: 1 to 128 characters.
+	Max128Text(1, 128, None);
+	/// `Max12Text`: 1 to 12 characters.
+	Max12Text(1, 12, None);
+	/// `Max140Text`: 1 to 140 characters.
+	Max140Text(1, 140, None);
+	/// `Max15NumericText`: matching `[0-9]{1,15}`.
+	Max15NumericText(0, usize::MAX, Some("[0-9]{1,15}"));
+	/// `Max15PlusSignedNumericText`: matching `[\+]{0,1}[0-9]{1,15}`.
+	Max15PlusSignedNumericText(0, usize::MAX, Some("[\\+]{0,1}[0-9]{1,15}"));
+	/// `Max16Text`: 1 to 16 characters.
+	Max16Text(1, 16, None);
+	/// `Max20000Text`: 1 to 20000 characters.
+	Max20000Text(1, 20000, None);
+	/// `Max2000Text`: 1 to 2000 characters.
+	Max2000Text(1, 2000, None);
+	/// `Max2048Text`: 1 to 2048 characters.
+	Max2048Text(1, 2048, None);
+	/// `Max20Text`: 1 to 20 characters.
+	Max20Text(1, 20, None);
+	/// `Max210Text`: 1 to 210 characters.
+	Max210Text(1, 210, None);
+	/// `Max256Text`: 1 to 256 characters.
+	Max256Text(1, 256, None);
+	/// `Max25Text`: 1 to 25 characters.
+	Max25Text(1, 25, None);
+	/// `Max2NumericText`: matching `[0-9]{1,2}`.
+	Max2NumericText(0, usize::MAX, Some("[0-9]{1,2}"));
+	/// `Max30Text`: at most 30 characters.
+	Max30Text(0, 30, None);
+	/// `Max34Text`: 1 to 34 characters.
+	Max34Text(1, 34, None);
+	/// `Max350Text`: 1 to 350 characters.
+	Max350Text(1, 350, None);
+	/// `Max35Text`: 1 to 35 characters.
+	Max35Text(1, 35, None);
+	/// `Max3NumericText`: matching `[0-9]{1,3}`.
+	Max3NumericText(0, usize::MAX, Some("[0-9]{1,3}"));
+	/// `Max3Text`: 1 to 3 characters.
+	Max3Text(1, 3, None);
+	/// `Max40Text`: 1 to 40 characters.
+	Max40Text(1, 40, None);
+	/// `Max450Text`: 1 to 450 characters.
+	Max450Text(1, 450, None);
+	/// `Max4AlphaNumericText`: 1 to 4 characters, matching `[a-zA-Z0-9]{1,4}`.
+	Max4AlphaNumericText(1, 4, Some("[a-zA-Z0-9]{1,4}"));
+	/// `Max4Text`: 1 to 4 characters.
+	Max4Text(1, 4, None);
+	/// `Max500Text`: 1 to 500 characters.
+	Max500Text(1, 500, None);
+	/// `Max50Text`: 1 to 50 characters.
+	Max50Text(1, 50, None);
+	/// `Max52Text`: 1 to 52 characters.
+	Max52Text(1, 52, None);
+	/// `Max5NumericText`: matching `[0-9]{1,5}`.
+	Max5NumericText(0, usize::MAX, Some("[0-9]{1,5}"));
+	/// `Max6Text`: 1 to 6 characters.
+	Max6Text(1, 6, None);
+	/// `Max70Text`: 1 to 70 characters.
+	Max70Text(1, 70, None);
+	/// `Max72Text`: 1 to 72 characters.
+	Max72Text(1, 72, None);
+	/// `Max8Text`: 1 to 8 characters.
+	Max8Text(1, 8, None);
+	/// `MerchantCategoryCodeIdentifier`: matching `[0-9]{4,4}`.
+	MerchantCategoryCodeIdentifier(0, usize::MAX, Some("[0-9]{4,4}"));
+	/// `Min2Max3NumericText`: matching `[0-9]{2,3}`.
+	Min2Max3NumericText(0, usize::MAX, Some("[0-9]{2,3}"));
+	/// `Min3Max4NumericText`: matching `[0-9]{3,4}`.
+	Min3Max4NumericText(0, usize::MAX, Some("[0-9]{3,4}"));
+	/// `Min8Max28NumericText`: matching `[0-9]{8,28}`.
+	Min8Max28NumericText(0, usize::MAX, Some("[0-9]{8,28}"));
+	/// `NACEDomainIdentifier`: matching `[A-U]{1,1}`.
+	NACEDomainIdentifier(0, usize::MAX, Some("[A-U]{1,1}"));
+	/// `NewZealandNCCIdentifier`: matching `NZ[0-9]{6,6}`.
+	NewZealandNCCIdentifier(0, usize::MAX, Some("NZ[0-9]{6,6}"));
+	/// `PhoneNumber`: matching `\+[0-9]{1,3}-[0-9()+\-]{1,30}`.
+	PhoneNumber(0, usize::MAX, Some("\\+[0-9]{1,3}-[0-9()+\\-]{1,30}"));
+	/// `PortugueseNCCIdentifier`: matching `PT[0-9]{8,8}`.
+	PortugueseNCCIdentifier(0, usize::MAX, Some("PT[0-9]{8,8}"));
+	/// `RICIdentifier`: 1 to 35 characters.
+	RICIdentifier(1, 35, None);
+	/// `RussianCentralBankIdentificationCodeIdentifier`: matching `RU[0-9]{9,9}`.
+	RussianCentralBankIdentificationCodeIdentifier(0, usize::MAX, Some("RU[0-9]{9,9}"));
+	/// `SmallNetworkIdentifier`: matching `AU[0-9]{6,6}`.
+	SmallNetworkIdentifier(0, usize::MAX, Some("AU[0-9]{6,6}"));
+	/// `SouthAfricanNCCIdentifier`: matching `ZA[0-9]{6,6}`.
+	SouthAfricanNCCIdentifier(0, usize::MAX, Some("ZA[0-9]{6,6}"));
+	/// `SpanishDomesticInterbankingIdentifier`: matching `ES[0-9]{8,9}`.
+	SpanishDomesticInterbankingIdentifier(0, usize::MAX, Some("ES[0-9]{8,9}"));
+	/// `SwissBCIdentifier`: matching `SW[0-9]{3,5}`.
+	SwissBCIdentifier(0, usize::MAX, Some("SW[0-9]{3,5}"));
+	/// `SwissSICIdentifier`: matching `SW[0-9]{6,6}`.
+	SwissSICIdentifier(0, usize::MAX, Some("SW[0-9]{6,6}"));
+	/// `TickerIdentifier`: 1 to 35 characters.
+	TickerIdentifier(1, 35, None);
+	/// `UKDomesticSortCodeIdentifier`: matching `SC[0-9]{6,6}`.
+	UKDomesticSortCodeIdentifier(0, usize::MAX, Some("SC[0-9]{6,6}"));
+	/// `UTIIdentifier`: matching `[A-Z0-9]{18}[0-9]{2}[A-Z0-9]{0,32}`.
+	UTIIdentifier(0, usize::MAX, Some("[A-Z0-9]{18}[0-9]{2}[A-Z0-9]{0,32}"));
+	/// `UUIDv4Identifier`: matching `[a-f0-9]{8}-[a-f0-9]{4}-4[a-f0-9]{3}-[89ab][a-f0-9]{3}-[a-f0-9]{12}`.
+	UUIDv4Identifier(0, usize::MAX, Some("[a-f0-9]{8}-[a-f0-9]{4}-4[a-f0-9]{3}-[89ab][a-f0-9]{3}-[a-f0-9]{12}"));
+	/// `Unlimited9Text`: exactly 9 characters, matching `UNLIMITED`.
+	Unlimited9Text(9, 9, Some("UNLIMITED"));
+}
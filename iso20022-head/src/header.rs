@@ -0,0 +1,675 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Business Application Header and business file types, written by hand since the generated
+//! `head_*` modules carry none of them. The versions are those of the schemas bundled under
+//! `xsd/iso20022/head`: `head.001.001.02`, `head.001.001.04` and `head.002.001.01`.
+
+#![allow(unused_imports)]
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// Business Application Header `head.001.001.02`, the `<AppHdr>` element.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessApplicationHeaderV02 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CharSet", skip_serializing_if = "Option::is_none") )]
+	pub char_set: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Fr") )]
+	pub fr: Party44Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "To") )]
+	pub to: Party44Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizMsgIdr") )]
+	pub biz_msg_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgDefIdr") )]
+	pub msg_def_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizSvc", skip_serializing_if = "Option::is_none") )]
+	pub biz_svc: Option<Max35Text>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MktPrctc", skip_serializing_if = "Option::is_none") )]
+	pub mkt_prctc: Option<ImplementationSpecification1>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDt") )]
+	pub cre_dt: ISODateTime,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizPrcgDt", skip_serializing_if = "Option::is_none") )]
+	pub biz_prcg_dt: Option<ISODateTime>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CpyDplct", skip_serializing_if = "Option::is_none") )]
+	pub cpy_dplct: Option<CopyDuplicate1Code>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PssblDplct", skip_serializing_if = "Option::is_none") )]
+	pub pssbl_dplct: Option<bool>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Prty", skip_serializing_if = "Option::is_none") )]
+	pub prty: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Sgntr", skip_serializing_if = "Option::is_none") )]
+	pub sgntr: Option<SignatureEnvelope>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Rltd", skip_serializing_if = "Option::is_none") )]
+	pub rltd: Option<Vec<BusinessApplicationHeader5>>,
+}
+
+impl BusinessApplicationHeaderV02 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Fr", "fr", |validator| self.fr.validate_with(validator))?;
+		validator.element("To", "to", |validator| self.to.validate_with(validator))?;
+		if self.biz_msg_idr.chars().count() < 1 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::min_length("biz_msg_idr", 1, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.biz_msg_idr.chars().count() > 35 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::max_length("biz_msg_idr", 35, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() < 1 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::min_length("msg_def_idr", 1, self.msg_def_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() > 35 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::max_length("msg_def_idr", 35, self.msg_def_idr.chars().count()))?;
+		}
+		if let Some(ref val) = self.biz_svc {
+			if val.chars().count() < 1 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::min_length("biz_svc", 1, val.chars().count()))?;
+			}
+			if val.chars().count() > 35 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::max_length("biz_svc", 35, val.chars().count()))?;
+			}
+		}
+		if let Some(ref val) = self.mkt_prctc { validator.element("MktPrctc", "mkt_prctc", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.cpy_dplct { validator.element("CpyDplct", "cpy_dplct", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.sgntr { validator.element("Sgntr", "sgntr", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.rltd { for (i, item) in vec.iter().enumerate() { validator.item("Rltd", "rltd", i, |validator| item.validate_with(validator))? } }
+		#[cfg(feature = "typed_dates")]
+		validator.element("CreDt", "cre_dt", |validator| self.cre_dt.validate_with(validator))?;
+		#[cfg(feature = "typed_dates")]
+		if let Some(ref val) = self.biz_prcg_dt { validator.element("BizPrcgDt", "biz_prcg_dt", |validator| val.validate_with(validator))? }
+		Ok(())
+	}
+}
+
+/// Header of a message related to a `head.001.001.02` message (`<Rltd>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessApplicationHeader5 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CharSet", skip_serializing_if = "Option::is_none") )]
+	pub char_set: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Fr") )]
+	pub fr: Party44Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "To") )]
+	pub to: Party44Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizMsgIdr") )]
+	pub biz_msg_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgDefIdr") )]
+	pub msg_def_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizSvc", skip_serializing_if = "Option::is_none") )]
+	pub biz_svc: Option<Max35Text>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDt") )]
+	pub cre_dt: ISODateTime,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CpyDplct", skip_serializing_if = "Option::is_none") )]
+	pub cpy_dplct: Option<CopyDuplicate1Code>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PssblDplct", skip_serializing_if = "Option::is_none") )]
+	pub pssbl_dplct: Option<bool>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Prty", skip_serializing_if = "Option::is_none") )]
+	pub prty: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Sgntr", skip_serializing_if = "Option::is_none") )]
+	pub sgntr: Option<SignatureEnvelope>,
+}
+
+impl BusinessApplicationHeader5 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Fr", "fr", |validator| self.fr.validate_with(validator))?;
+		validator.element("To", "to", |validator| self.to.validate_with(validator))?;
+		if self.biz_msg_idr.chars().count() < 1 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::min_length("biz_msg_idr", 1, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.biz_msg_idr.chars().count() > 35 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::max_length("biz_msg_idr", 35, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() < 1 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::min_length("msg_def_idr", 1, self.msg_def_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() > 35 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::max_length("msg_def_idr", 35, self.msg_def_idr.chars().count()))?;
+		}
+		if let Some(ref val) = self.biz_svc {
+			if val.chars().count() < 1 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::min_length("biz_svc", 1, val.chars().count()))?;
+			}
+			if val.chars().count() > 35 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::max_length("biz_svc", 35, val.chars().count()))?;
+			}
+		}
+		if let Some(ref val) = self.cpy_dplct { validator.element("CpyDplct", "cpy_dplct", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.sgntr { validator.element("Sgntr", "sgntr", |validator| val.validate_with(validator))? }
+		#[cfg(feature = "typed_dates")]
+		validator.element("CreDt", "cre_dt", |validator| self.cre_dt.validate_with(validator))?;
+		Ok(())
+	}
+}
+
+/// Market practice the message conforms to (`<MktPrctc>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct ImplementationSpecification1 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Regy") )]
+	pub regy: Max350Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Id") )]
+	pub id: Max2048Text,
+}
+
+impl ImplementationSpecification1 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if self.regy.chars().count() < 1 {
+			validator.report_at("Regy", "regy", ValidationError::min_length("regy", 1, self.regy.chars().count()))?;
+		}
+		if self.regy.chars().count() > 350 {
+			validator.report_at("Regy", "regy", ValidationError::max_length("regy", 350, self.regy.chars().count()))?;
+		}
+		if self.id.chars().count() < 1 {
+			validator.report_at("Id", "id", ValidationError::min_length("id", 1, self.id.chars().count()))?;
+		}
+		if self.id.chars().count() > 2048 {
+			validator.report_at("Id", "id", ValidationError::max_length("id", 2048, self.id.chars().count()))?;
+		}
+		Ok(())
+	}
+}
+
+/// Sender or receiver of a `head.001.001.02` message, an organisation or a financial institution.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct Party44Choice {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "OrgId", skip_serializing_if = "Option::is_none") )]
+	pub org_id: Option<PartyIdentification135>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "FIId", skip_serializing_if = "Option::is_none") )]
+	pub fi_id: Option<BranchAndFinancialInstitutionIdentification6>,
+}
+
+impl Party44Choice {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.org_id { validator.element("OrgId", "org_id", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.fi_id { validator.element("FIId", "fi_id", |validator| val.validate_with(validator))? }
+		Ok(())
+	}
+}
+
+/// Digital signature of the message (`<Sgntr>`), its content is not read.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct SignatureEnvelope {
+}
+
+impl SignatureEnvelope {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, _validator: &mut Validator) -> Result<(), ValidationError> {
+		Ok(())
+	}
+}
+
+/// Business Application Header `head.001.001.04`, the `<AppHdr>` element.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessApplicationHeaderV04 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CharSet", skip_serializing_if = "Option::is_none") )]
+	pub char_set: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Fr") )]
+	pub fr: Party51Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "To") )]
+	pub to: Party51Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizMsgIdr") )]
+	pub biz_msg_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgDefIdr") )]
+	pub msg_def_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizSvc", skip_serializing_if = "Option::is_none") )]
+	pub biz_svc: Option<Max35Text>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MktPrctc", skip_serializing_if = "Option::is_none") )]
+	pub mkt_prctc: Option<ImplementationSpecification1>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDt") )]
+	pub cre_dt: ISODateTime,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizPrcgDt", skip_serializing_if = "Option::is_none") )]
+	pub biz_prcg_dt: Option<ISODateTime>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CpyDplct", skip_serializing_if = "Option::is_none") )]
+	pub cpy_dplct: Option<CopyDuplicate1Code>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PssblDplct", skip_serializing_if = "Option::is_none") )]
+	pub pssbl_dplct: Option<bool>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Prty", skip_serializing_if = "Option::is_none") )]
+	pub prty: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Sgntr", skip_serializing_if = "Option::is_none") )]
+	pub sgntr: Option<SignatureEnvelope>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Rltd", skip_serializing_if = "Option::is_none") )]
+	pub rltd: Option<Vec<BusinessApplicationHeader8>>,
+}
+
+impl BusinessApplicationHeaderV04 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Fr", "fr", |validator| self.fr.validate_with(validator))?;
+		validator.element("To", "to", |validator| self.to.validate_with(validator))?;
+		if self.biz_msg_idr.chars().count() < 1 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::min_length("biz_msg_idr", 1, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.biz_msg_idr.chars().count() > 35 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::max_length("biz_msg_idr", 35, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() < 1 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::min_length("msg_def_idr", 1, self.msg_def_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() > 35 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::max_length("msg_def_idr", 35, self.msg_def_idr.chars().count()))?;
+		}
+		if let Some(ref val) = self.biz_svc {
+			if val.chars().count() < 1 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::min_length("biz_svc", 1, val.chars().count()))?;
+			}
+			if val.chars().count() > 35 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::max_length("biz_svc", 35, val.chars().count()))?;
+			}
+		}
+		if let Some(ref val) = self.mkt_prctc { validator.element("MktPrctc", "mkt_prctc", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.cpy_dplct { validator.element("CpyDplct", "cpy_dplct", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.sgntr { validator.element("Sgntr", "sgntr", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.rltd { for (i, item) in vec.iter().enumerate() { validator.item("Rltd", "rltd", i, |validator| item.validate_with(validator))? } }
+		#[cfg(feature = "typed_dates")]
+		validator.element("CreDt", "cre_dt", |validator| self.cre_dt.validate_with(validator))?;
+		#[cfg(feature = "typed_dates")]
+		if let Some(ref val) = self.biz_prcg_dt { validator.element("BizPrcgDt", "biz_prcg_dt", |validator| val.validate_with(validator))? }
+		Ok(())
+	}
+}
+
+/// Header of a message related to a `head.001.001.04` message (`<Rltd>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessApplicationHeader8 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CharSet", skip_serializing_if = "Option::is_none") )]
+	pub char_set: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Fr") )]
+	pub fr: Party51Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "To") )]
+	pub to: Party51Choice,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizMsgIdr") )]
+	pub biz_msg_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgDefIdr") )]
+	pub msg_def_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizSvc", skip_serializing_if = "Option::is_none") )]
+	pub biz_svc: Option<Max35Text>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MktPrctc", skip_serializing_if = "Option::is_none") )]
+	pub mkt_prctc: Option<ImplementationSpecification1>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDt") )]
+	pub cre_dt: ISODateTime,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "BizPrcgDt", skip_serializing_if = "Option::is_none") )]
+	pub biz_prcg_dt: Option<ISODateTime>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CpyDplct", skip_serializing_if = "Option::is_none") )]
+	pub cpy_dplct: Option<CopyDuplicate1Code>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PssblDplct", skip_serializing_if = "Option::is_none") )]
+	pub pssbl_dplct: Option<bool>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Prty", skip_serializing_if = "Option::is_none") )]
+	pub prty: Option<String>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Sgntr", skip_serializing_if = "Option::is_none") )]
+	pub sgntr: Option<SignatureEnvelope>,
+}
+
+impl BusinessApplicationHeader8 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Fr", "fr", |validator| self.fr.validate_with(validator))?;
+		validator.element("To", "to", |validator| self.to.validate_with(validator))?;
+		if self.biz_msg_idr.chars().count() < 1 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::min_length("biz_msg_idr", 1, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.biz_msg_idr.chars().count() > 35 {
+			validator.report_at("BizMsgIdr", "biz_msg_idr", ValidationError::max_length("biz_msg_idr", 35, self.biz_msg_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() < 1 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::min_length("msg_def_idr", 1, self.msg_def_idr.chars().count()))?;
+		}
+		if self.msg_def_idr.chars().count() > 35 {
+			validator.report_at("MsgDefIdr", "msg_def_idr", ValidationError::max_length("msg_def_idr", 35, self.msg_def_idr.chars().count()))?;
+		}
+		if let Some(ref val) = self.biz_svc {
+			if val.chars().count() < 1 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::min_length("biz_svc", 1, val.chars().count()))?;
+			}
+			if val.chars().count() > 35 {
+				validator.report_at("BizSvc", "biz_svc", ValidationError::max_length("biz_svc", 35, val.chars().count()))?;
+			}
+		}
+		if let Some(ref val) = self.mkt_prctc { validator.element("MktPrctc", "mkt_prctc", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.cpy_dplct { validator.element("CpyDplct", "cpy_dplct", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.sgntr { validator.element("Sgntr", "sgntr", |validator| val.validate_with(validator))? }
+		#[cfg(feature = "typed_dates")]
+		validator.element("CreDt", "cre_dt", |validator| self.cre_dt.validate_with(validator))?;
+		#[cfg(feature = "typed_dates")]
+		if let Some(ref val) = self.biz_prcg_dt { validator.element("BizPrcgDt", "biz_prcg_dt", |validator| val.validate_with(validator))? }
+		Ok(())
+	}
+}
+
+/// Sender or receiver of a `head.001.001.04` message, an organisation or a financial institution.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct Party51Choice {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "OrgId", skip_serializing_if = "Option::is_none") )]
+	pub org_id: Option<PartyIdentification272>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "FIId", skip_serializing_if = "Option::is_none") )]
+	pub fi_id: Option<BranchAndFinancialInstitutionIdentification8>,
+}
+
+impl Party51Choice {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.org_id { validator.element("OrgId", "org_id", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.fi_id { validator.element("FIId", "fi_id", |validator| val.validate_with(validator))? }
+		Ok(())
+	}
+}
+
+/// Business file header `head.002.001.01`, the `<Xchg>` element.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessFileHeaderV01 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PyldDesc") )]
+	pub pyld_desc: PayloadDescription2,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Pyld", skip_serializing_if = "Option::is_none") )]
+	pub pyld: Option<Vec<LaxPayload>>,
+}
+
+impl BusinessFileHeaderV01 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("PyldDesc", "pyld_desc", |validator| self.pyld_desc.validate_with(validator))?;
+		if let Some(ref vec) = self.pyld { for (i, item) in vec.iter().enumerate() { validator.item("Pyld", "pyld", i, |validator| item.validate_with(validator))? } }
+		Ok(())
+	}
+}
+
+/// Application specific information of a business file (`<ApplSpcfcs>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct ApplicationSpecifics1 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "SysUsr", skip_serializing_if = "Option::is_none") )]
+	pub sys_usr: Option<Max140Text>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Sgntr", skip_serializing_if = "Option::is_none") )]
+	pub sgntr: Option<SignatureEnvelope>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "TtlNbOfDocs") )]
+	pub ttl_nb_of_docs: Decimal,
+}
+
+impl ApplicationSpecifics1 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.sys_usr {
+			if val.chars().count() < 1 {
+				validator.report_at("SysUsr", "sys_usr", ValidationError::min_length("sys_usr", 1, val.chars().count()))?;
+			}
+			if val.chars().count() > 140 {
+				validator.report_at("SysUsr", "sys_usr", ValidationError::max_length("sys_usr", 140, val.chars().count()))?;
+			}
+		}
+		if let Some(ref val) = self.sgntr { validator.element("Sgntr", "sgntr", |validator| val.validate_with(validator))? }
+		if self.ttl_nb_of_docs.total_digits() > 18 {
+			validator.report_at("TtlNbOfDocs", "ttl_nb_of_docs", ValidationError::total_digits("ttl_nb_of_docs", 18, &self.ttl_nb_of_docs))?;
+		}
+		if self.ttl_nb_of_docs.fraction_digits() > 0 {
+			validator.report_at("TtlNbOfDocs", "ttl_nb_of_docs", ValidationError::fraction_digits("ttl_nb_of_docs", 0, &self.ttl_nb_of_docs))?;
+		}
+		Ok(())
+	}
+}
+
+/// Message carried in a business file payload, its content is not read.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct LaxPayload {
+}
+
+impl LaxPayload {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, _validator: &mut Validator) -> Result<(), ValidationError> {
+		Ok(())
+	}
+}
+
+/// Number of documents of one type in a business file (`<MnfstData>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct ManifestData2 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "DocTp") )]
+	pub doc_tp: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "NbOfDocs") )]
+	pub nb_of_docs: Decimal,
+}
+
+impl ManifestData2 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if self.doc_tp.chars().count() < 1 {
+			validator.report_at("DocTp", "doc_tp", ValidationError::min_length("doc_tp", 1, self.doc_tp.chars().count()))?;
+		}
+		if self.doc_tp.chars().count() > 35 {
+			validator.report_at("DocTp", "doc_tp", ValidationError::max_length("doc_tp", 35, self.doc_tp.chars().count()))?;
+		}
+		if self.nb_of_docs.total_digits() > 18 {
+			validator.report_at("NbOfDocs", "nb_of_docs", ValidationError::total_digits("nb_of_docs", 18, &self.nb_of_docs))?;
+		}
+		if self.nb_of_docs.fraction_digits() > 0 {
+			validator.report_at("NbOfDocs", "nb_of_docs", ValidationError::fraction_digits("nb_of_docs", 0, &self.nb_of_docs))?;
+		}
+		Ok(())
+	}
+}
+
+/// Identification of a business file payload (`<PyldData>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct PayloadData2 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PyldIdr") )]
+	pub pyld_idr: Max35Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDtAndTm") )]
+	pub cre_dt_and_tm: ISODateTime,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PssblDplctFlg", skip_serializing_if = "Option::is_none") )]
+	pub pssbl_dplct_flg: Option<bool>,
+}
+
+impl PayloadData2 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if self.pyld_idr.chars().count() < 1 {
+			validator.report_at("PyldIdr", "pyld_idr", ValidationError::min_length("pyld_idr", 1, self.pyld_idr.chars().count()))?;
+		}
+		if self.pyld_idr.chars().count() > 35 {
+			validator.report_at("PyldIdr", "pyld_idr", ValidationError::max_length("pyld_idr", 35, self.pyld_idr.chars().count()))?;
+		}
+		#[cfg(feature = "typed_dates")]
+		validator.element("CreDtAndTm", "cre_dt_and_tm", |validator| self.cre_dt_and_tm.validate_with(validator))?;
+		Ok(())
+	}
+}
+
+/// Description of the payloads of a business file (`<PyldDesc>`).
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct PayloadDescription2 {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PyldData") )]
+	pub pyld_data: PayloadData2,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "ApplSpcfcs", skip_serializing_if = "Option::is_none") )]
+	pub appl_spcfcs: Option<ApplicationSpecifics1>,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PyldTp") )]
+	pub pyld_tp: Max256Text,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "MnfstData", skip_serializing_if = "Option::is_none") )]
+	pub mnfst_data: Option<Vec<ManifestData2>>,
+}
+
+impl PayloadDescription2 {
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("PyldData", "pyld_data", |validator| self.pyld_data.validate_with(validator))?;
+		if let Some(ref val) = self.appl_spcfcs { validator.element("ApplSpcfcs", "appl_spcfcs", |validator| val.validate_with(validator))? }
+		if self.pyld_tp.chars().count() < 1 {
+			validator.report_at("PyldTp", "pyld_tp", ValidationError::min_length("pyld_tp", 1, self.pyld_tp.chars().count()))?;
+		}
+		if self.pyld_tp.chars().count() > 256 {
+			validator.report_at("PyldTp", "pyld_tp", ValidationError::max_length("pyld_tp", 256, self.pyld_tp.chars().count()))?;
+		}
+		if let Some(ref vec) = self.mnfst_data { for (i, item) in vec.iter().enumerate() { validator.item("MnfstData", "mnfst_data", i, |validator| item.validate_with(validator))? } }
+		Ok(())
+	}
+}
@@ -26,3 +26,4 @@
 pub mod head_001_001_02;
 pub mod head_001_001_04;
 pub mod head_002_001_01;
+pub mod header;
@@ -33,3 +33,6 @@ pub mod pacs_009_001_11;
 pub mod pacs_010_001_06;
 pub mod pacs_028_001_06;
 pub mod pacs_029_001_02;
+pub mod rules;
+#[cfg(feature = "streaming")]
+pub mod stream;
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,10 +48,18 @@ pub struct FIToFIPaymentStatusReportV14 {
 
 impl FIToFIPaymentStatusReportV14 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.grp_hdr.validate()?;
-		if let Some(ref vec) = self.orgnl_grp_inf_and_sts { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.tx_inf_and_sts { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("GrpHdr", "grp_hdr", |validator| self.grp_hdr.validate_with(validator))?;
+		if let Some(ref vec) = self.orgnl_grp_inf_and_sts { for (i, item) in vec.iter().enumerate() { validator.item("OrgnlGrpInfAndSts", "orgnl_grp_inf_and_sts", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.tx_inf_and_sts { for (i, item) in vec.iter().enumerate() { validator.item("TxInfAndSts", "tx_inf_and_sts", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
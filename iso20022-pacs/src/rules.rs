@@ -0,0 +1,165 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Cross-field rules of the pacs message definitions, see [`iso20022_common::rules`].
+//!
+//! The group header of these messages counts and sums their transactions and may hold
+//! elements for all of them, e.g. the `IntrBkSttlmDt`, which the transactions must then not
+//! repeat. Returns and reversals without transactions, e.g. of a whole group, are not checked.
+
+use iso20022_common::rules;
+use iso20022_common::{ValidationError, Validator};
+use crate::pacs_003_001_11::FIToFICustomerDirectDebitV11;
+use crate::pacs_004_001_13::PaymentReturnV13;
+use crate::pacs_007_001_13::FIToFIPaymentReversalV13;
+use crate::pacs_008_001_12::FIToFICustomerCreditTransferV12;
+use crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11;
+
+/// Implements `validate_rules()` for a message of a `GrpHdr` and its transactions: the `NbOfTxs`,
+/// `CtrlSum` and total amount of the group header, the `group` elements a transaction must not
+/// repeat when the group header holds them and the `transaction` elements it must hold when the
+/// group header does not.
+macro_rules! interbank_rules {
+    (@transactions $transactions:expr) => { Some(&$transactions[..]) };
+    (@transactions $transactions:expr, optional) => { $transactions.as_deref() };
+    ($($message:ident {
+        transactions: $element:literal $field:ident $(($optional:ident))?,
+        amount: $amount:ident,
+        total: $total_element:literal $total:ident, $currency_rule:literal, $sum_rule:literal,
+        group: [$($group_element:literal $group_field:ident $group_rule:literal,)*],
+        transaction: [$($tx_element:literal $tx_field:ident $tx_rule:literal,)*],
+    })*) => {$(
+        impl $message {
+            /// Checks the cross-field rules of the message definition.
+            pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+                let grp_hdr = &self.grp_hdr;
+                let transactions = match interbank_rules!(@transactions self.$field $(, $optional)?) {
+                    Some(transactions) => transactions,
+                    None => return Ok(()),
+                };
+                validator.element("GrpHdr", "grp_hdr", |validator| {
+                    rules::number_of_transactions(validator, &grp_hdr.nb_of_txs, transactions.len())?;
+                    if let Some(ref ctrl_sum) = grp_hdr.ctrl_sum {
+                        rules::control_sum(validator, ctrl_sum, transactions.iter().map(|tx| tx.$amount.value))?;
+                    }
+                    if let Some(ref total) = grp_hdr.$total {
+                        let same_currency = transactions.iter().all(|tx| tx.$amount.ccy.as_str() == total.ccy.as_str());
+                        rules::check(validator, $total_element, stringify!($total), $currency_rule, same_currency, || {
+                            format!("{} and the amounts of the transactions differ in currency", $total_element)
+                        })?;
+                        let sum = rules::sum(transactions.iter().map(|tx| tx.$amount.value));
+                        rules::check(validator, $total_element, stringify!($total), $sum_rule, sum == Some(total.value), || {
+                            format!("{} does not equal the sum of the amounts of the transactions", $total_element)
+                        })?;
+                    }
+                    Ok(())
+                })?;
+                for (i, tx) in transactions.iter().enumerate() {
+                    validator.item($element, stringify!($field), i, |validator| {
+                        $(rules::check(validator, $group_element, stringify!($group_field), $group_rule, grp_hdr.$group_field.is_none() || tx.$group_field.is_none(), || {
+                            format!("{} is not allowed when the group header holds it", $group_element)
+                        })?;)*
+                        $(rules::check(validator, $tx_element, stringify!($tx_field), $tx_rule, grp_hdr.$tx_field.is_some() || tx.$tx_field.is_some(), || {
+                            format!("{} is required when the group header does not hold it", $tx_element)
+                        })?;)*
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            }
+        }
+    )*};
+}
+
+interbank_rules! {
+    FIToFICustomerCreditTransferV12 {
+        transactions: "CdtTrfTxInf" cdt_trf_tx_inf,
+        amount: intr_bk_sttlm_amt,
+        total: "TtlIntrBkSttlmAmt" ttl_intr_bk_sttlm_amt, "TotalInterbankSettlementAmountRule", "TotalInterbankSettlementAmountAndSumRule",
+        group: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "GroupHeaderInterbankSettlementDateRule",
+            "PmtTpInf" pmt_tp_inf "PaymentTypeInformationRule",
+            "InstgAgt" instg_agt "InstructingAgentRule",
+            "InstdAgt" instd_agt "InstructedAgentRule",
+        ],
+        transaction: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "TransactionInterbankSettlementDateRule",
+        ],
+    }
+
+    FinancialInstitutionCreditTransferV11 {
+        transactions: "CdtTrfTxInf" cdt_trf_tx_inf,
+        amount: intr_bk_sttlm_amt,
+        total: "TtlIntrBkSttlmAmt" ttl_intr_bk_sttlm_amt, "TotalInterbankSettlementAmountRule", "TotalInterbankSettlementAmountAndSumRule",
+        group: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "GroupHeaderInterbankSettlementDateRule",
+            "PmtTpInf" pmt_tp_inf "PaymentTypeInformationRule",
+            "InstgAgt" instg_agt "InstructingAgentRule",
+            "InstdAgt" instd_agt "InstructedAgentRule",
+        ],
+        transaction: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "TransactionInterbankSettlementDateRule",
+        ],
+    }
+
+    FIToFICustomerDirectDebitV11 {
+        transactions: "DrctDbtTxInf" drct_dbt_tx_inf,
+        amount: intr_bk_sttlm_amt,
+        total: "TtlIntrBkSttlmAmt" ttl_intr_bk_sttlm_amt, "TotalInterbankSettlementAmountRule", "TotalInterbankSettlementAmountAndSumRule",
+        group: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "GroupHeaderInterbankSettlementDateRule",
+            "PmtTpInf" pmt_tp_inf "PaymentTypeInformationRule",
+            "InstgAgt" instg_agt "InstructingAgentRule",
+            "InstdAgt" instd_agt "InstructedAgentRule",
+        ],
+        transaction: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "TransactionInterbankSettlementDateRule",
+        ],
+    }
+
+    PaymentReturnV13 {
+        transactions: "TxInf" tx_inf (optional),
+        amount: rtrd_intr_bk_sttlm_amt,
+        total: "TtlRtrdIntrBkSttlmAmt" ttl_rtrd_intr_bk_sttlm_amt, "TotalReturnedInterbankSettlementAmountRule", "TotalReturnedInterbankSettlementAmountAndSumRule",
+        group: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "GroupHeaderInterbankSettlementDateRule",
+            "PmtTpInf" pmt_tp_inf "PaymentTypeInformationRule",
+            "InstgAgt" instg_agt "InstructingAgentRule",
+            "InstdAgt" instd_agt "InstructedAgentRule",
+        ],
+        transaction: [],
+    }
+
+    FIToFIPaymentReversalV13 {
+        transactions: "TxInf" tx_inf (optional),
+        amount: rvsd_intr_bk_sttlm_amt,
+        total: "TtlRvsdIntrBkSttlmAmt" ttl_rvsd_intr_bk_sttlm_amt, "TotalReversedInterbankSettlementAmountRule", "TotalReversedInterbankSettlementAmountAndSumRule",
+        group: [
+            "IntrBkSttlmDt" intr_bk_sttlm_dt "GroupHeaderInterbankSettlementDateRule",
+            "InstgAgt" instg_agt "InstructingAgentRule",
+            "InstdAgt" instd_agt "InstructedAgentRule",
+        ],
+        transaction: [],
+    }
+}
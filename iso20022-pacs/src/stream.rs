@@ -0,0 +1,157 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Streaming writer and reader for bulk pacs.008 files.
+//!
+//! Transactions are written and read one `CdtTrfTxInf` at a time, so memory stays bounded
+//! by the largest single transaction rather than the whole file.
+
+use std::io::{BufRead, Seek, Write};
+use iso20022_common::common::{CreditTransferTransaction64, GroupHeader113, SupplementaryData1};
+use iso20022_common::stream::{add_to_sum, missing_element, read_element, Placeholder};
+use iso20022_common::Decimal;
+use quick_xml::events::Event;
+use quick_xml::{DeError, Reader, SeError};
+
+const NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12";
+const MESSAGE: &str = "FIToFICstmrCdtTrf";
+
+/// Incremental writer of a pacs.008 `FIToFICstmrCdtTrf` document.
+///
+/// `NbOfTxs` and, when present in the group header, `CtrlSum` are patched by [`CreditTransferWriter::finish`]
+/// from the transactions actually written.
+pub struct CreditTransferWriter<W: Write + Seek> {
+	writer: W,
+	nb_of_txs: Placeholder,
+	ctrl_sum: Option<Placeholder>,
+	count: u64,
+	sum: Decimal,
+}
+
+impl<W: Write + Seek> CreditTransferWriter<W> {
+	/// Writes the start of the document and the group header.
+	pub fn new(mut writer: W, grp_hdr: &GroupHeader113) -> Result<Self, SeError> {
+		let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Document xmlns=\"{}\"><{}>", NAMESPACE, MESSAGE);
+		let offset = writer.stream_position()? + xml.len() as u64;
+		let mut header = quick_xml::se::to_string_with_root("GrpHdr", grp_hdr)?;
+		let nb_of_txs = Placeholder::reserve(&mut header, offset, "NbOfTxs", 15)
+			.ok_or_else(|| SeError::Custom("GrpHdr has no NbOfTxs element".to_string()))?;
+		let ctrl_sum = Placeholder::reserve(&mut header, offset, "CtrlSum", 20);
+		xml.push_str(&header);
+		writer.write_all(xml.as_bytes())?;
+		Ok(CreditTransferWriter { writer, nb_of_txs, ctrl_sum, count: 0, sum: Decimal::ZERO })
+	}
+
+	/// Writes a single `CdtTrfTxInf` element.
+	pub fn write_transaction(&mut self, tx: &CreditTransferTransaction64) -> Result<(), SeError> {
+		let xml = quick_xml::se::to_string_with_root("CdtTrfTxInf", tx)?;
+		self.writer.write_all(xml.as_bytes())?;
+		add_to_sum(&mut self.sum, tx.intr_bk_sttlm_amt.value)?;
+		self.count += 1;
+		Ok(())
+	}
+
+	/// Writes the end of the document, patches the group header and returns the underlying writer.
+	pub fn finish(mut self) -> Result<W, SeError> {
+		write!(self.writer, "</{}></Document>", MESSAGE)?;
+		self.nb_of_txs.fill(&mut self.writer, &self.count.to_string())?;
+		if let Some(ref ctrl_sum) = self.ctrl_sum {
+			ctrl_sum.fill(&mut self.writer, &self.sum.to_string())?;
+		}
+		self.writer.flush()?;
+		Ok(self.writer)
+	}
+}
+
+/// An item read from a pacs.008 message, in document order.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub enum CreditTransferEvent {
+	/// The `GrpHdr` of the message.
+	GroupHeader(Box<GroupHeader113>),
+	/// A single `CdtTrfTxInf`.
+	Transaction(Box<CreditTransferTransaction64>),
+	/// A message level `SplmtryData` element.
+	SupplementaryData(Box<SupplementaryData1>),
+}
+
+/// Streaming reader over a pacs.008 `FIToFICstmrCdtTrf` message.
+pub struct CreditTransferReader<R> {
+	reader: Reader<R>,
+	buf: Vec<u8>,
+	in_message: bool,
+	done: bool,
+}
+
+impl<R: BufRead> CreditTransferReader<R> {
+	pub fn new(reader: R) -> Self {
+		CreditTransferReader { reader: Reader::from_reader(reader), buf: Vec::new(), in_message: false, done: false }
+	}
+
+	fn next_event(&mut self) -> Result<Option<CreditTransferEvent>, DeError> {
+		loop {
+			self.buf.clear();
+			let event = self.reader.read_event_into(&mut self.buf)?.into_owned();
+			match event {
+				Event::Start(e) if self.in_message => match e.local_name().as_ref() {
+					b"GrpHdr" => return Ok(Some(CreditTransferEvent::GroupHeader(Box::new(read_element(&mut self.reader, e)?)))),
+					b"CdtTrfTxInf" => return Ok(Some(CreditTransferEvent::Transaction(Box::new(read_element(&mut self.reader, e)?)))),
+					b"SplmtryData" => return Ok(Some(CreditTransferEvent::SupplementaryData(Box::new(read_element(&mut self.reader, e)?)))),
+					_ => {
+						let end = e.to_end().into_owned();
+						self.reader.read_to_end_into(end.name(), &mut self.buf)?;
+					}
+				},
+				Event::End(_) if self.in_message => {
+					self.in_message = false;
+					self.done = true;
+					return Ok(None);
+				}
+				Event::Start(e) if e.local_name().as_ref() == MESSAGE.as_bytes() => self.in_message = true,
+				Event::Eof if !self.in_message => return Err(missing_element(MESSAGE.as_bytes())),
+				Event::Eof => return Err(DeError::UnexpectedEof),
+				_ => {}
+			}
+		}
+	}
+}
+
+impl<R: BufRead> Iterator for CreditTransferReader<R> {
+	type Item = Result<CreditTransferEvent, DeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.next_event() {
+			Ok(Some(event)) => Some(Ok(event)),
+			Ok(None) => None,
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
@@ -35,3 +35,6 @@ pub mod pain_013_001_11;
 pub mod pain_014_001_11;
 pub mod pain_017_001_04;
 pub mod pain_018_001_04;
+pub mod rules;
+#[cfg(feature = "streaming")]
+pub mod stream;
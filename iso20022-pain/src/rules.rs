@@ -0,0 +1,99 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Cross-field rules of the pain message definitions, see [`iso20022_common::rules`].
+//!
+//! The `NbOfTxs` and `CtrlSum` of the group header count and sum the transactions of all
+//! payment information blocks, those of a `PmtInf` the transactions of that block only.
+
+use iso20022_common::common::{CreditTransferTransaction61, DirectDebitTransactionInformation32};
+use iso20022_common::rules;
+use iso20022_common::{Decimal, ValidationError, Validator};
+use crate::pain_001_001_12::CustomerCreditTransferInitiationV12;
+use crate::pain_008_001_11::CustomerDirectDebitInitiationV11;
+
+impl CustomerCreditTransferInitiationV12 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        let transactions = || self.pmt_inf.iter().flat_map(|pmt_inf| pmt_inf.cdt_trf_tx_inf.iter());
+        validator.element("GrpHdr", "grp_hdr", |validator| {
+            rules::number_of_transactions(validator, &self.grp_hdr.nb_of_txs, transactions().count())?;
+            match self.grp_hdr.ctrl_sum {
+                Some(ref ctrl_sum) => rules::control_sum(validator, ctrl_sum, transactions().map(credit_transfer_amount)),
+                None => Ok(()),
+            }
+        })?;
+        for (i, pmt_inf) in self.pmt_inf.iter().enumerate() {
+            validator.item("PmtInf", "pmt_inf", i, |validator| {
+                if let Some(ref nb_of_txs) = pmt_inf.nb_of_txs {
+                    rules::number_of_transactions(validator, nb_of_txs, pmt_inf.cdt_trf_tx_inf.len())?;
+                }
+                match pmt_inf.ctrl_sum {
+                    Some(ref ctrl_sum) => rules::control_sum(validator, ctrl_sum, pmt_inf.cdt_trf_tx_inf.iter().map(credit_transfer_amount)),
+                    None => Ok(()),
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl CustomerDirectDebitInitiationV11 {
+    /// Checks the cross-field rules of the message definition.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        let transactions = || self.pmt_inf.iter().flat_map(|pmt_inf| pmt_inf.drct_dbt_tx_inf.iter());
+        validator.element("GrpHdr", "grp_hdr", |validator| {
+            rules::number_of_transactions(validator, &self.grp_hdr.nb_of_txs, transactions().count())?;
+            match self.grp_hdr.ctrl_sum {
+                Some(ref ctrl_sum) => rules::control_sum(validator, ctrl_sum, transactions().map(direct_debit_amount)),
+                None => Ok(()),
+            }
+        })?;
+        for (i, pmt_inf) in self.pmt_inf.iter().enumerate() {
+            validator.item("PmtInf", "pmt_inf", i, |validator| {
+                if let Some(ref nb_of_txs) = pmt_inf.nb_of_txs {
+                    rules::number_of_transactions(validator, nb_of_txs, pmt_inf.drct_dbt_tx_inf.len())?;
+                }
+                match pmt_inf.ctrl_sum {
+                    Some(ref ctrl_sum) => rules::control_sum(validator, ctrl_sum, pmt_inf.drct_dbt_tx_inf.iter().map(direct_debit_amount)),
+                    None => Ok(()),
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The `InstdAmt`, or else the `EqvtAmt`, of a credit transfer.
+fn credit_transfer_amount(tx: &CreditTransferTransaction61) -> Decimal {
+    match (&tx.amt.instd_amt, &tx.amt.eqvt_amt) {
+        (Some(instd_amt), _) => instd_amt.value,
+        (None, Some(eqvt_amt)) => eqvt_amt.amt.value,
+        (None, None) => Decimal::ZERO,
+    }
+}
+
+fn direct_debit_amount(tx: &DirectDebitTransactionInformation32) -> Decimal {
+    tx.instd_amt.value
+}
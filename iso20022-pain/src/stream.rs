@@ -0,0 +1,272 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Streaming writer and reader for bulk pain.001 files.
+//!
+//! Transactions are written and read one `CdtTrfTxInf` at a time, so memory stays bounded
+//! by the largest single transaction rather than the whole file.
+
+use std::io::{BufRead, Seek, Write};
+use iso20022_common::common::{CreditTransferTransaction61, GroupHeader114, PaymentInstruction44, SupplementaryData1};
+use iso20022_common::stream::{add_to_sum, copy_element, missing_element, read_element, read_wrapped, Placeholder};
+use iso20022_common::Decimal;
+use quick_xml::events::Event;
+use quick_xml::{DeError, Reader, SeError};
+
+const NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.12";
+const MESSAGE: &str = "CstmrCdtTrfInitn";
+
+// Amount of a transaction counted in `CtrlSum`.
+fn amount(tx: &CreditTransferTransaction61) -> Decimal {
+	if let Some(ref amt) = tx.amt.instd_amt {
+		amt.value
+	} else if let Some(ref amt) = tx.amt.eqvt_amt {
+		amt.amt.value
+	} else {
+		Decimal::ZERO
+	}
+}
+
+// Counters of the `PmtInf` element being written.
+struct OpenPaymentInstruction {
+	nb_of_txs: Option<Placeholder>,
+	ctrl_sum: Option<Placeholder>,
+	count: u64,
+	sum: Decimal,
+}
+
+/// Incremental writer of a pain.001 `CstmrCdtTrfInitn` document.
+///
+/// `NbOfTxs` and `CtrlSum` of the group header and of each `PmtInf` are patched from the
+/// transactions actually written, `CtrlSum` only when it is present in the written element.
+pub struct CreditTransferInitiationWriter<W: Write + Seek> {
+	writer: W,
+	nb_of_txs: Placeholder,
+	ctrl_sum: Option<Placeholder>,
+	count: u64,
+	sum: Decimal,
+	pmt_inf: Option<OpenPaymentInstruction>,
+}
+
+impl<W: Write + Seek> CreditTransferInitiationWriter<W> {
+	/// Writes the start of the document and the group header.
+	pub fn new(mut writer: W, grp_hdr: &GroupHeader114) -> Result<Self, SeError> {
+		let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Document xmlns=\"{}\"><{}>", NAMESPACE, MESSAGE);
+		let offset = writer.stream_position()? + xml.len() as u64;
+		let mut header = quick_xml::se::to_string_with_root("GrpHdr", grp_hdr)?;
+		let nb_of_txs = Placeholder::reserve(&mut header, offset, "NbOfTxs", 15)
+			.ok_or_else(|| SeError::Custom("GrpHdr has no NbOfTxs element".to_string()))?;
+		let ctrl_sum = Placeholder::reserve(&mut header, offset, "CtrlSum", 20);
+		xml.push_str(&header);
+		writer.write_all(xml.as_bytes())?;
+		Ok(CreditTransferInitiationWriter { writer, nb_of_txs, ctrl_sum, count: 0, sum: Decimal::ZERO, pmt_inf: None })
+	}
+
+	/// Closes the current `PmtInf` and opens a new one. Transactions already held by `pmt_inf`
+	/// are written with it, further ones are added by [`CreditTransferInitiationWriter::write_transaction`].
+	pub fn start_payment_instruction(&mut self, pmt_inf: &PaymentInstruction44) -> Result<(), SeError> {
+		self.end_payment_instruction()?;
+		let offset = self.writer.stream_position()?;
+		let mut xml = quick_xml::se::to_string_with_root("PmtInf", pmt_inf)?;
+		match xml.strip_suffix("</PmtInf>") {
+			Some(open) => xml.truncate(open.len()),
+			None => return Err(SeError::Custom("PmtInf was written as an empty element".to_string())),
+		}
+		let nb_of_txs = Placeholder::reserve(&mut xml, offset, "NbOfTxs", 15);
+		let ctrl_sum = Placeholder::reserve(&mut xml, offset, "CtrlSum", 20);
+		self.writer.write_all(xml.as_bytes())?;
+		let count = pmt_inf.cdt_trf_tx_inf.len() as u64;
+		let mut sum = Decimal::ZERO;
+		for tx in &pmt_inf.cdt_trf_tx_inf {
+			add_to_sum(&mut sum, amount(tx))?;
+		}
+		add_to_sum(&mut self.sum, sum)?;
+		self.count += count;
+		self.pmt_inf = Some(OpenPaymentInstruction { nb_of_txs, ctrl_sum, count, sum });
+		Ok(())
+	}
+
+	/// Writes a single `CdtTrfTxInf` element into the current `PmtInf`.
+	pub fn write_transaction(&mut self, tx: &CreditTransferTransaction61) -> Result<(), SeError> {
+		let pmt_inf = self.pmt_inf.as_mut()
+			.ok_or_else(|| SeError::Custom("CdtTrfTxInf written outside of a PmtInf".to_string()))?;
+		let xml = quick_xml::se::to_string_with_root("CdtTrfTxInf", tx)?;
+		self.writer.write_all(xml.as_bytes())?;
+		let amount = amount(tx);
+		add_to_sum(&mut pmt_inf.sum, amount)?;
+		add_to_sum(&mut self.sum, amount)?;
+		pmt_inf.count += 1;
+		self.count += 1;
+		Ok(())
+	}
+
+	/// Writes the end of the document, patches the group header and returns the underlying writer.
+	pub fn finish(mut self) -> Result<W, SeError> {
+		self.end_payment_instruction()?;
+		write!(self.writer, "</{}></Document>", MESSAGE)?;
+		self.nb_of_txs.fill(&mut self.writer, &self.count.to_string())?;
+		if let Some(ref ctrl_sum) = self.ctrl_sum {
+			ctrl_sum.fill(&mut self.writer, &self.sum.to_string())?;
+		}
+		self.writer.flush()?;
+		Ok(self.writer)
+	}
+
+	fn end_payment_instruction(&mut self) -> Result<(), SeError> {
+		if let Some(pmt_inf) = self.pmt_inf.take() {
+			self.writer.write_all(b"</PmtInf>")?;
+			if let Some(ref nb_of_txs) = pmt_inf.nb_of_txs {
+				nb_of_txs.fill(&mut self.writer, &pmt_inf.count.to_string())?;
+			}
+			if let Some(ref ctrl_sum) = pmt_inf.ctrl_sum {
+				ctrl_sum.fill(&mut self.writer, &pmt_inf.sum.to_string())?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// An item read from a pain.001 message, in document order.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub enum CreditTransferInitiationEvent {
+	/// The `GrpHdr` of the message.
+	GroupHeader(Box<GroupHeader114>),
+	/// A `PmtInf` without its transactions, `cdt_trf_tx_inf` is always empty.
+	PaymentInstruction(Box<PaymentInstruction44>),
+	/// A single `CdtTrfTxInf` of the current `PmtInf`.
+	Transaction(Box<CreditTransferTransaction61>),
+	/// A message level `SplmtryData` element.
+	SupplementaryData(Box<SupplementaryData1>),
+}
+
+/// Streaming reader over a pain.001 `CstmrCdtTrfInitn` message.
+pub struct CreditTransferInitiationReader<R> {
+	reader: Reader<R>,
+	buf: Vec<u8>,
+	in_message: bool,
+	in_pmt_inf: bool,
+	pmt_inf_sent: bool,
+	done: bool,
+	// Content of the current `PmtInf` preceding its transactions.
+	header: Vec<u8>,
+	pending: Option<CreditTransferInitiationEvent>,
+}
+
+impl<R: BufRead> CreditTransferInitiationReader<R> {
+	pub fn new(reader: R) -> Self {
+		CreditTransferInitiationReader {
+			reader: Reader::from_reader(reader),
+			buf: Vec::new(),
+			in_message: false,
+			in_pmt_inf: false,
+			pmt_inf_sent: false,
+			done: false,
+			header: Vec::new(),
+			pending: None,
+		}
+	}
+
+	fn next_event(&mut self) -> Result<Option<CreditTransferInitiationEvent>, DeError> {
+		if let Some(event) = self.pending.take() {
+			return Ok(Some(event));
+		}
+		loop {
+			self.buf.clear();
+			let event = self.reader.read_event_into(&mut self.buf)?.into_owned();
+			match event {
+				Event::Start(e) if self.in_pmt_inf && e.local_name().as_ref() == b"CdtTrfTxInf" => {
+					if self.pmt_inf_sent {
+						return Ok(Some(CreditTransferInitiationEvent::Transaction(Box::new(read_element(&mut self.reader, e)?))));
+					}
+					// `CdtTrfTxInf` is mandatory, so the first transaction is read along with the `PmtInf`.
+					let tx = copy_element(&mut self.reader, Event::Start(e))?;
+					self.header.extend_from_slice(&tx);
+					let mut pmt_inf: PaymentInstruction44 = read_wrapped(b"PmtInf", &self.header)?;
+					self.pmt_inf_sent = true;
+					if let Some(tx) = pmt_inf.cdt_trf_tx_inf.pop() {
+						self.pending = Some(CreditTransferInitiationEvent::Transaction(Box::new(tx)));
+					}
+					return Ok(Some(CreditTransferInitiationEvent::PaymentInstruction(Box::new(pmt_inf))));
+				}
+				Event::Start(e) if self.in_pmt_inf => {
+					let content = copy_element(&mut self.reader, Event::Start(e))?;
+					self.header.extend_from_slice(&content);
+				}
+				Event::Empty(e) if self.in_pmt_inf => {
+					let content = copy_element(&mut self.reader, Event::Empty(e))?;
+					self.header.extend_from_slice(&content);
+				}
+				Event::Text(e) if self.in_pmt_inf => self.header.extend_from_slice(&e),
+				Event::End(_) if self.in_pmt_inf => {
+					self.in_pmt_inf = false;
+					if !self.pmt_inf_sent {
+						return Err(DeError::Custom("PmtInf without CdtTrfTxInf".to_string()));
+					}
+				}
+				Event::Start(e) if self.in_message => match e.local_name().as_ref() {
+					b"GrpHdr" => return Ok(Some(CreditTransferInitiationEvent::GroupHeader(Box::new(read_element(&mut self.reader, e)?)))),
+					b"SplmtryData" => return Ok(Some(CreditTransferInitiationEvent::SupplementaryData(Box::new(read_element(&mut self.reader, e)?)))),
+					b"PmtInf" => {
+						self.in_pmt_inf = true;
+						self.pmt_inf_sent = false;
+						self.header.clear();
+					}
+					_ => {
+						let end = e.to_end().into_owned();
+						self.reader.read_to_end_into(end.name(), &mut self.buf)?;
+					}
+				},
+				Event::End(_) if self.in_message => {
+					self.in_message = false;
+					self.done = true;
+					return Ok(None);
+				}
+				Event::Start(e) if e.local_name().as_ref() == MESSAGE.as_bytes() => self.in_message = true,
+				Event::Eof if !self.in_message => return Err(missing_element(MESSAGE.as_bytes())),
+				Event::Eof => return Err(DeError::UnexpectedEof),
+				_ => {}
+			}
+		}
+	}
+}
+
+impl<R: BufRead> Iterator for CreditTransferInitiationReader<R> {
+	type Item = Result<CreditTransferInitiationEvent, DeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done && self.pending.is_none() {
+			return None;
+		}
+		match self.next_event() {
+			Ok(Some(event)) => Some(Ok(event)),
+			Ok(None) => None,
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -47,11 +47,11 @@ pub struct PriceReportV04 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgPgntn") )]
 	pub msg_pgntn: Pagination,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "PricRptId") )]
-	pub pric_rpt_id: String,
+	pub pric_rpt_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Fctn") )]
 	pub fctn: PriceReportFunction1Code,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CxlId", skip_serializing_if = "Option::is_none") )]
-	pub cxl_id: Option<String>,
+	pub cxl_id: Option<Max35Text>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "PricValtnDtls") )]
 	pub pric_valtn_dtls: Vec<PriceValuation4>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Xtnsn", skip_serializing_if = "Option::is_none") )]
@@ -60,28 +60,36 @@ pub struct PriceReportV04 {
 
 impl PriceReportV04 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.pool_ref { val.validate()? }
-		if let Some(ref vec) = self.prvs_ref { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.rltd_ref { val.validate()? }
-		self.msg_pgntn.validate()?;
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.pool_ref { validator.element("PoolRef", "pool_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.prvs_ref { for (i, item) in vec.iter().enumerate() { validator.item("PrvsRef", "prvs_ref", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.rltd_ref { validator.element("RltdRef", "rltd_ref", |validator| val.validate_with(validator))? }
+		validator.element("MsgPgntn", "msg_pgntn", |validator| self.msg_pgntn.validate_with(validator))?;
 		if self.pric_rpt_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "pric_rpt_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("PricRptId", "pric_rpt_id", ValidationError::min_length("pric_rpt_id", 1, self.pric_rpt_id.chars().count()))?;
 		}
 		if self.pric_rpt_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "pric_rpt_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("PricRptId", "pric_rpt_id", ValidationError::max_length("pric_rpt_id", 35, self.pric_rpt_id.chars().count()))?;
 		}
-		self.fctn.validate()?;
+		validator.element("Fctn", "fctn", |validator| self.fctn.validate_with(validator))?;
 		if let Some(ref val) = self.cxl_id {
 			if val.chars().count() < 1 {
-				return Err(ValidationError::new(1001, "cxl_id is shorter than the minimum length of 1".to_string()));
+				validator.report_at("CxlId", "cxl_id", ValidationError::min_length("cxl_id", 1, val.chars().count()))?;
 			}
 			if val.chars().count() > 35 {
-				return Err(ValidationError::new(1002, "cxl_id exceeds the maximum length of 35".to_string()));
+				validator.report_at("CxlId", "cxl_id", ValidationError::max_length("cxl_id", 35, val.chars().count()))?;
 			}
 		}
-		for item in &self.pric_valtn_dtls { item.validate()? }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		for (i, item) in self.pric_valtn_dtls.iter().enumerate() { validator.item("PricValtnDtls", "pric_valtn_dtls", i, |validator| item.validate_with(validator))? }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,11 +45,11 @@ pub struct PriceReportCancellationV04 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgPgntn") )]
 	pub msg_pgntn: Pagination,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "PricRptId") )]
-	pub pric_rpt_id: String,
+	pub pric_rpt_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CxlId") )]
-	pub cxl_id: String,
+	pub cxl_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CxlRsn", skip_serializing_if = "Option::is_none") )]
-	pub cxl_rsn: Option<String>,
+	pub cxl_rsn: Option<Max350Text>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "XpctdPricCrrctnDt", skip_serializing_if = "Option::is_none") )]
 	pub xpctd_pric_crrctn_dt: Option<DateAndDateTime1Choice>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CmpltPricCxl") )]
@@ -62,33 +62,41 @@ pub struct PriceReportCancellationV04 {
 
 impl PriceReportCancellationV04 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.pool_ref { val.validate()? }
-		if let Some(ref val) = self.prvs_ref { val.validate()? }
-		self.msg_pgntn.validate()?;
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.pool_ref { validator.element("PoolRef", "pool_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.prvs_ref { validator.element("PrvsRef", "prvs_ref", |validator| val.validate_with(validator))? }
+		validator.element("MsgPgntn", "msg_pgntn", |validator| self.msg_pgntn.validate_with(validator))?;
 		if self.pric_rpt_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "pric_rpt_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("PricRptId", "pric_rpt_id", ValidationError::min_length("pric_rpt_id", 1, self.pric_rpt_id.chars().count()))?;
 		}
 		if self.pric_rpt_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "pric_rpt_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("PricRptId", "pric_rpt_id", ValidationError::max_length("pric_rpt_id", 35, self.pric_rpt_id.chars().count()))?;
 		}
 		if self.cxl_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "cxl_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("CxlId", "cxl_id", ValidationError::min_length("cxl_id", 1, self.cxl_id.chars().count()))?;
 		}
 		if self.cxl_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "cxl_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("CxlId", "cxl_id", ValidationError::max_length("cxl_id", 35, self.cxl_id.chars().count()))?;
 		}
 		if let Some(ref val) = self.cxl_rsn {
 			if val.chars().count() < 1 {
-				return Err(ValidationError::new(1001, "cxl_rsn is shorter than the minimum length of 1".to_string()));
+				validator.report_at("CxlRsn", "cxl_rsn", ValidationError::min_length("cxl_rsn", 1, val.chars().count()))?;
 			}
 			if val.chars().count() > 350 {
-				return Err(ValidationError::new(1002, "cxl_rsn exceeds the maximum length of 350".to_string()));
+				validator.report_at("CxlRsn", "cxl_rsn", ValidationError::max_length("cxl_rsn", 350, val.chars().count()))?;
 			}
 		}
-		if let Some(ref val) = self.xpctd_pric_crrctn_dt { val.validate()? }
-		if let Some(ref vec) = self.canc_pric_valtn_dtls { for item in vec { item.validate()? } }
-		if let Some(ref vec) = self.xtnsn { for item in vec { item.validate()? } }
+		if let Some(ref val) = self.xpctd_pric_crrctn_dt { validator.element("XpctdPricCrrctnDt", "xpctd_pric_crrctn_dt", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.canc_pric_valtn_dtls { for (i, item) in vec.iter().enumerate() { validator.item("CancPricValtnDtls", "canc_pric_valtn_dtls", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref vec) = self.xtnsn { for (i, item) in vec.iter().enumerate() { validator.item("Xtnsn", "xtnsn", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -43,25 +43,33 @@ pub struct FundReferenceDataReportV07 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RltdRef", skip_serializing_if = "Option::is_none") )]
 	pub rltd_ref: Option<AdditionalReference10>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "FndRefDataRptId", skip_serializing_if = "Option::is_none") )]
-	pub fnd_ref_data_rpt_id: Option<String>,
+	pub fnd_ref_data_rpt_id: Option<Max35Text>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "Rpt") )]
 	pub rpt: Vec<FundReferenceDataReport5>,
 }
 
 impl FundReferenceDataReportV07 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref vec) = self.prvs_ref { for item in vec { item.validate()? } }
-		if let Some(ref val) = self.rltd_ref { val.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref vec) = self.prvs_ref { for (i, item) in vec.iter().enumerate() { validator.item("PrvsRef", "prvs_ref", i, |validator| item.validate_with(validator))? } }
+		if let Some(ref val) = self.rltd_ref { validator.element("RltdRef", "rltd_ref", |validator| val.validate_with(validator))? }
 		if let Some(ref val) = self.fnd_ref_data_rpt_id {
 			if val.chars().count() < 1 {
-				return Err(ValidationError::new(1001, "fnd_ref_data_rpt_id is shorter than the minimum length of 1".to_string()));
+				validator.report_at("FndRefDataRptId", "fnd_ref_data_rpt_id", ValidationError::min_length("fnd_ref_data_rpt_id", 1, val.chars().count()))?;
 			}
 			if val.chars().count() > 35 {
-				return Err(ValidationError::new(1002, "fnd_ref_data_rpt_id exceeds the maximum length of 35".to_string()));
+				validator.report_at("FndRefDataRptId", "fnd_ref_data_rpt_id", ValidationError::max_length("fnd_ref_data_rpt_id", 35, val.chars().count()))?;
 			}
 		}
-		for item in &self.rpt { item.validate()? }
+		for (i, item) in self.rpt.iter().enumerate() { validator.item("Rpt", "rpt", i, |validator| item.validate_with(validator))? }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,10 +48,18 @@ pub struct InvestmentFundReportRequestV03 {
 
 impl InvestmentFundReportRequestV03 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.msg_id.validate()?;
-		if let Some(ref val) = self.prvs_ref { val.validate()? }
-		if let Some(ref val) = self.rltd_ref { val.validate()? }
-		for item in &self.rpt_req { item.validate()? }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("MsgId", "msg_id", |validator| self.msg_id.validate_with(validator))?;
+		if let Some(ref val) = self.prvs_ref { validator.element("PrvsRef", "prvs_ref", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.rltd_ref { validator.element("RltdRef", "rltd_ref", |validator| val.validate_with(validator))? }
+		for (i, item) in self.rpt_req.iter().enumerate() { validator.item("RptReq", "rpt_req", i, |validator| item.validate_with(validator))? }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct SecurityMaintenanceRequestV01 {
 
 impl SecurityMaintenanceRequestV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.msg_hdr { val.validate()? }
-		self.upd_tp.validate()?;
-		if let Some(ref val) = self.upd_rsn { val.validate()? }
-		self.fin_instrm_id.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.msg_hdr { validator.element("MsgHdr", "msg_hdr", |validator| val.validate_with(validator))? }
+		validator.element("UpdTp", "upd_tp", |validator| self.upd_tp.validate_with(validator))?;
+		if let Some(ref val) = self.upd_rsn { validator.element("UpdRsn", "upd_rsn", |validator| val.validate_with(validator))? }
+		validator.element("FinInstrmId", "fin_instrm_id", |validator| self.fin_instrm_id.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -50,11 +50,19 @@ pub struct SecurityQueryV01 {
 
 impl SecurityQueryV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.msg_hdr { val.validate()? }
-		if let Some(ref val) = self.req_tp { val.validate()? }
-		self.sch_crit.validate()?;
-		if let Some(ref val) = self.sml_set_rtr_crit { val.validate()? }
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.msg_hdr { validator.element("MsgHdr", "msg_hdr", |validator| val.validate_with(validator))? }
+		if let Some(ref val) = self.req_tp { validator.element("ReqTp", "req_tp", |validator| val.validate_with(validator))? }
+		validator.element("SchCrit", "sch_crit", |validator| self.sch_crit.validate_with(validator))?;
+		if let Some(ref val) = self.sml_set_rtr_crit { validator.element("SmlSetRtrCrit", "sml_set_rtr_crit", |validator| val.validate_with(validator))? }
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,7 +37,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct StandingSettlementInstructionDeletionV01 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "MsgRefId") )]
-	pub msg_ref_id: String,
+	pub msg_ref_id: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "FctvDtDtls", skip_serializing_if = "Option::is_none") )]
 	pub fctv_dt_dtls: Option<EffectiveDate1>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctId") )]
@@ -52,17 +52,25 @@ pub struct StandingSettlementInstructionDeletionV01 {
 
 impl StandingSettlementInstructionDeletionV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
 		if self.msg_ref_id.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "msg_ref_id is shorter than the minimum length of 1".to_string()));
+			validator.report_at("MsgRefId", "msg_ref_id", ValidationError::min_length("msg_ref_id", 1, self.msg_ref_id.chars().count()))?;
 		}
 		if self.msg_ref_id.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "msg_ref_id exceeds the maximum length of 35".to_string()));
+			validator.report_at("MsgRefId", "msg_ref_id", ValidationError::max_length("msg_ref_id", 35, self.msg_ref_id.chars().count()))?;
 		}
-		if let Some(ref val) = self.fctv_dt_dtls { val.validate()? }
-		for item in &self.acct_id { item.validate()? }
-		self.mkt_id.validate()?;
-		self.sttlm_dtls.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		if let Some(ref val) = self.fctv_dt_dtls { validator.element("FctvDtDtls", "fctv_dt_dtls", |validator| val.validate_with(validator))? }
+		for (i, item) in self.acct_id.iter().enumerate() { validator.item("AcctId", "acct_id", i, |validator| item.validate_with(validator))? }
+		validator.element("MktId", "mkt_id", |validator| self.mkt_id.validate_with(validator))?;
+		validator.element("SttlmDtls", "sttlm_dtls", |validator| self.sttlm_dtls.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
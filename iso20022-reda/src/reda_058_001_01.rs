@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,7 +45,7 @@ pub struct StandingSettlementInstructionStatusAdviceV01 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmDtls") )]
 	pub sttlm_dtls: PartyOrCurrency1Choice,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RltdMsgRef") )]
-	pub rltd_msg_ref: String,
+	pub rltd_msg_ref: Max35Text,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "PrcgSts") )]
 	pub prcg_sts: ProcessingStatus43Choice,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "SplmtryData", skip_serializing_if = "Option::is_none") )]
@@ -54,18 +54,26 @@ pub struct StandingSettlementInstructionStatusAdviceV01 {
 
 impl StandingSettlementInstructionStatusAdviceV01 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		if let Some(ref val) = self.fctv_dt_dtls { val.validate()? }
-		for item in &self.acct_id { item.validate()? }
-		self.mkt_id.validate()?;
-		self.sttlm_dtls.validate()?;
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		if let Some(ref val) = self.fctv_dt_dtls { validator.element("FctvDtDtls", "fctv_dt_dtls", |validator| val.validate_with(validator))? }
+		for (i, item) in self.acct_id.iter().enumerate() { validator.item("AcctId", "acct_id", i, |validator| item.validate_with(validator))? }
+		validator.element("MktId", "mkt_id", |validator| self.mkt_id.validate_with(validator))?;
+		validator.element("SttlmDtls", "sttlm_dtls", |validator| self.sttlm_dtls.validate_with(validator))?;
 		if self.rltd_msg_ref.chars().count() < 1 {
-			return Err(ValidationError::new(1001, "rltd_msg_ref is shorter than the minimum length of 1".to_string()));
+			validator.report_at("RltdMsgRef", "rltd_msg_ref", ValidationError::min_length("rltd_msg_ref", 1, self.rltd_msg_ref.chars().count()))?;
 		}
 		if self.rltd_msg_ref.chars().count() > 35 {
-			return Err(ValidationError::new(1002, "rltd_msg_ref exceeds the maximum length of 35".to_string()));
+			validator.report_at("RltdMsgRef", "rltd_msg_ref", ValidationError::max_length("rltd_msg_ref", 35, self.rltd_msg_ref.chars().count()))?;
 		}
-		self.prcg_sts.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		validator.element("PrcgSts", "prcg_sts", |validator| self.prcg_sts.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
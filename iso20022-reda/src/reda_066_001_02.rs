@@ -25,7 +25,7 @@
 
 #![allow(unused_imports)]
 use regex::Regex;
-use iso20022_common::{common::*, ValidationError};
+use iso20022_common::{common::*, currency::{self, CurrencyCodes}, external, identifier::{self, IdentifierScheme}, Decimal, ISODate, ISODateTime, ISOTime, Pattern, ValidationError, ValidationReport, Validator, text::*};
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,10 +48,18 @@ pub struct RequestToPayCreditorEnrolmentRequestV02 {
 
 impl RequestToPayCreditorEnrolmentRequestV02 {
 	pub fn validate(&self) -> Result<(), ValidationError> {
-		self.hdr.validate()?;
-		for item in &self.cdtr_enrlmnt { item.validate()? }
-		self.actvtn_data.validate()?;
-		if let Some(ref vec) = self.splmtry_data { for item in vec { item.validate()? } }
+		self.validate_with(&mut Validator::fail_fast())
+	}
+
+	pub fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| self.validate_with(validator))
+	}
+
+	pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+		validator.element("Hdr", "hdr", |validator| self.hdr.validate_with(validator))?;
+		for (i, item) in self.cdtr_enrlmnt.iter().enumerate() { validator.item("CdtrEnrlmnt", "cdtr_enrlmnt", i, |validator| item.validate_with(validator))? }
+		validator.element("ActvtnData", "actvtn_data", |validator| self.actvtn_data.validate_with(validator))?;
+		if let Some(ref vec) = self.splmtry_data { for (i, item) in vec.iter().enumerate() { validator.item("SplmtryData", "splmtry_data", i, |validator| item.validate_with(validator))? } }
 		Ok(())
 	}
 }
@@ -0,0 +1,61 @@
+// Benchmark of the generated validation of a pacs.008 carrying 100,000 transactions.
+// Run it on two commits to compare the validators they generate.
+//
+// cargo bench --bench validate-pacs008 --features pacs,derive_default,derive_clone
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use iso20022_common::common::{CreditTransferTransaction64, GroupHeader113};
+use iso20022_common::Decimal;
+use open_payments_iso20022_pacs::pacs_008_001_12::FIToFICustomerCreditTransferV12;
+
+const TRANSACTIONS: usize = 100_000;
+const ITERATIONS: usize = 10;
+
+fn message() -> FIToFICustomerCreditTransferV12 {
+    let grp_hdr = GroupHeader113 {
+        msg_id: "BENCH-0001".parse().unwrap(),
+        nb_of_txs: TRANSACTIONS.to_string().parse().unwrap(),
+        ..Default::default()
+    };
+
+    // Text fields are parsed so that the benchmark also builds with the `typed_text` feature
+    let mut tx = CreditTransferTransaction64::default();
+    tx.pmt_id.end_to_end_id = "E2E-0001".parse().unwrap();
+    tx.pmt_id.uetr = Some("eb6305c9-1f7f-49de-aed0-16487c27b42d".parse().unwrap());
+    tx.intr_bk_sttlm_amt.ccy = "EUR".parse().unwrap();
+    tx.intr_bk_sttlm_amt.value = Decimal::new(10000, 2);
+
+    FIToFICustomerCreditTransferV12 {
+        grp_hdr,
+        cdt_trf_tx_inf: vec![tx; TRANSACTIONS],
+        splmtry_data: None,
+    }
+}
+
+// Runs `f` once to warm up, then `ITERATIONS` times, and prints the fastest and the mean run.
+fn bench<R>(name: &str, mut f: impl FnMut() -> R) {
+    black_box(f());
+    let mut runs = Vec::with_capacity(ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        black_box(f());
+        runs.push(start.elapsed());
+    }
+    let fastest = runs.iter().min().copied().unwrap_or_default();
+    let mean = runs.iter().sum::<Duration>() / ITERATIONS as u32;
+    println!(
+        "{:<14} {} transactions: fastest {:.2?}, mean {:.2?}, {:.0?} per transaction",
+        name, TRANSACTIONS, fastest, mean, fastest / TRANSACTIONS as u32
+    );
+}
+
+fn main() {
+    let message = message();
+    let report = message.validate_all();
+    println!("{} violations", report.errors.len());
+
+    bench("validate_all", || black_box(&message).validate_all());
+    bench("validate", || black_box(&message).validate());
+}
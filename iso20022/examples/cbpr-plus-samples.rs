@@ -0,0 +1,43 @@
+// Runs the CBPR+ validation profile over the XML messages of a directory,
+// `samples` unless another one is given, and prints whether each one passes
+// along with the rules it violates.
+
+use std::fs;
+use std::path::PathBuf;
+
+use open_payments_iso20022::cbpr_plus;
+
+fn main() {
+    let xml_directory = std::env::args().nth(1).unwrap_or_else(|| "samples".to_string());
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&xml_directory)
+        .expect("Unable to read directory")
+        .map(|entry| entry.expect("Unable to get directory entry").path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("xml"))
+        .collect();
+    paths.sort();
+
+    let (mut passed, mut failed) = (0, 0);
+    for path in &paths {
+        let xml = fs::read_to_string(path).expect("Unable to read file");
+        match cbpr_plus::validate_xml(&xml) {
+            Ok(report) if report.is_valid() => {
+                passed += 1;
+                println!("PASS {}", path.display());
+            }
+            Ok(report) => {
+                failed += 1;
+                println!("FAIL {}", path.display());
+                for error in &report.errors {
+                    println!("     {}", error);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                println!("FAIL {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed", passed, failed);
+}
@@ -2,25 +2,14 @@
 // This application reads XML files from a specified directory,
 // parses them using Serde, and converts them to JSON format.
 
-use quick_xml::de::Deserializer;
 use std::fs::{self, File};
 use std::io::{BufReader, Write};
 use std::path::Path;
-use serde_json;
 use std::time::Instant;
 use std::time::Duration;
 
 use open_payments_iso20022::document::Document;
 
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize)]
-#[serde(rename = "Document")]
-pub struct ISO20022Message {
-    #[serde(rename = "$value")]
-    document: Document,
-}
-
 fn main() {
     let mut total_duration = Duration::new(0, 0);
     let xml_directory = "samples";
@@ -43,9 +32,8 @@ fn parse_and_convert_to_json(xml_path: &Path, total_duration: &mut Duration) {
 
     let start = Instant::now();
 
-    // Create quick-xml deserializer and deserialize directly
-    let mut de = Deserializer::from_reader(reader);
-    let result: Result<ISO20022Message, quick_xml::de::DeError> = ISO20022Message::deserialize(&mut de);
+    // Parse the <Document> envelope, its namespace selects the message version
+    let result = Document::from_reader(reader);
 
     let duration = start.elapsed();
     *total_duration += duration;
@@ -58,10 +46,10 @@ fn parse_and_convert_to_json(xml_path: &Path, total_duration: &mut Duration) {
             let json_file_path = xml_path.with_extension("json");
             let mut json_file = File::create(&json_file_path).expect("Unable to create JSON file");
             json_file.write_all(json_data.as_bytes()).expect("Unable to write JSON data");
-            print!("\t[Successfully]\n");
+            println!("\t[Successfully]");
         },
         Err(e) => {
-            print!("\t[Failed]\n");
+            println!("\t[Failed]");
             println!("Error: {}", e);
         }
     }
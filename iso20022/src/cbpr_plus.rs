@@ -0,0 +1,373 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! The SWIFT CBPR+ usage guidelines, as a validation profile on top of the schema checks.
+//!
+//! CBPR+ restricts the messages exchanged between correspondent banks beyond their schemas,
+//! e.g. a structured postal address must name its town and country. The rules are checked on
+//! the XML of the message, so they also cover the versions that are not compiled in, such as
+//! the `pacs.008.001.08` CBPR+ is based on. Violations are reported as
+//! [`ValidationErrorKind::Rule`](iso20022_common::ValidationErrorKind::Rule) named after the
+//! CBPR+ rule, e.g. `CBPR_TownNameAndCountryRule`, and located by their XML element path.
+
+use iso20022_common::{Decimal, ValidationError, ValidationReport, Validator};
+use crate::document::Document;
+use crate::element::{self, Element};
+use crate::error::Error;
+use crate::identifier::MessageIdentifier;
+
+/// The messages the profile applies to. Other messages, and documents without a message namespace,
+/// are reported as not checked.
+pub const MESSAGES: &[&str] = &["pacs.004", "pacs.008", "pacs.009", "camt.029", "camt.054", "camt.055", "camt.056", "pain.001", "pain.002"];
+
+/// Elements of a structured postal address, besides `TwnNm` and `Ctry`.
+const STRUCTURED_ADDRESS: &[&str] = &[
+	"Dept", "SubDept", "StrtNm", "BldgNb", "BldgNm", "Flr", "UnitNb", "PstBx", "Room", "PstCd", "TwnLctnNm", "DstrctNm", "CtrySubDvsn", "CareOf",
+];
+
+/// The precious metal codes of ISO 4217, which are not settled through CBPR+.
+const METALS: &[&str] = &["XAU", "XAG", "XPD", "XPT"];
+
+type Rule = fn(&Element, &mut Validator) -> Result<(), ValidationError>;
+
+/// Every rule, each run on every element of the message.
+const RULES: &[Rule] = &[
+	town_name_and_country,
+	structured_vs_unstructured,
+	case_creator,
+	instruction_identification,
+	original_instruction_identification,
+	cancellation_identification,
+	case_identification,
+	reason_code_narr,
+	transaction_status_reject_reason,
+	remittance_mutually_exclusive,
+	related_remittance_mutually_exclusive,
+	instruction_for_creditor_agent,
+	cred_charges,
+	debt_charges,
+	debt_settlement_amount,
+	debtor_bic_presence,
+	originator_option_1,
+	originator_option_2,
+	original_requested_date,
+	interbank_settlement_currency,
+];
+
+/// Checks the CBPR+ rules of `doc` and returns every violation.
+pub fn validate(doc: &Document) -> Result<ValidationReport, Error> {
+	let document = Element::of_document(doc)?;
+	Ok(check(&document))
+}
+
+/// Checks the CBPR+ rules of the `<Document>` XML message `xml` and returns every violation.
+pub fn validate_xml(xml: &str) -> Result<ValidationReport, Error> {
+	let mut document = Element::parse(xml)?;
+	// A version compiled in knows its repeated fields, so that they are located as its typed validation does.
+	if let Ok(doc) = Document::from_xml_str(xml) {
+		if let Ok(value) = serde_json::to_value(&doc) {
+			document.mark_repeated(&value);
+		}
+	}
+	Ok(check(&document))
+}
+
+// A message the profile does not apply to is reported rather than passed, as it was not checked.
+fn check(document: &Element) -> ValidationReport {
+	let message = document.namespace.parse::<MessageIdentifier>().ok();
+	ValidationReport::collect(|validator| {
+		validator.element(&document.name, "", |validator| match message {
+			Some(id) if MESSAGES.contains(&format!("{}.{:03}", id.business_area, id.message).as_str()) => walk(document, validator),
+			Some(id) => validator.report(ValidationError::new(0, format!("CBPR+ does not apply to {}", id))),
+			None => {
+				let root = document.children.first().map_or(document.name.as_str(), |message| message.name.as_str());
+				validator.report(ValidationError::unknown_document(root))
+			}
+		})
+	})
+}
+
+// Runs every rule on `element` and its descendants. Items of repeated fields carry their position in the path.
+fn walk(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	for rule in RULES {
+		rule(element, validator)?;
+	}
+	for (child, item) in element::items(&element.children) {
+		match item {
+			Some(index) => validator.item(&child.name, "", index, |validator| walk(child, validator))?,
+			None => validator.element(&child.name, "", |validator| walk(child, validator))?,
+		}
+	}
+	Ok(())
+}
+
+/// `CBPR_TownNameAndCountryRule`: a postal address with structured elements holds `TwnNm` and `Ctry`.
+fn town_name_and_country(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "PstlAdr" || !element.children.iter().any(|child| STRUCTURED_ADDRESS.contains(&child.name.as_str())) {
+		return Ok(());
+	}
+	for name in ["TwnNm", "Ctry"] {
+		if !element.has(name) {
+			let message = format!("{} is required in a structured postal address", name);
+			validator.report(ValidationError::rule("CBPR_TownNameAndCountryRule", message))?;
+		}
+	}
+	Ok(())
+}
+
+/// `CBPR_Structured_vs_Unstructured_FormalRule`: `AdrLine` is only combined with `TwnNm` and `Ctry`.
+fn structured_vs_unstructured(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "PstlAdr" || !element.has("AdrLine") || !element.children.iter().any(|child| STRUCTURED_ADDRESS.contains(&child.name.as_str())) {
+		return Ok(());
+	}
+	let message = "AdrLine is not allowed with structured elements other than TwnNm and Ctry".to_string();
+	validator.report(ValidationError::rule("CBPR_Structured_vs_Unstructured_FormalRule", message))
+}
+
+/// The `Cretr` of a `Case` is identified by its BIC, or else by its name and postal address:
+/// `CBPR_PartyNameAnyBICRule`, `CBPR_PartyNamePostalAddressRule` and `CBPR_AgentNamePostalAddressRule`.
+fn case_creator(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "Cretr" {
+		return Ok(());
+	}
+	if let Some(pty) = element.child("Pty") {
+		let by_bic = any_bic(pty);
+		for name in ["Nm", "PstlAdr"] {
+			if by_bic && pty.has(name) {
+				let message = format!("{} is not allowed with AnyBIC", name);
+				validator.report_at("Pty", "", ValidationError::rule("CBPR_PartyNameAnyBICRule", message))?;
+			} else if !by_bic && !pty.has(name) {
+				let message = format!("{} is required without AnyBIC", name);
+				validator.report_at("Pty", "", ValidationError::rule("CBPR_PartyNamePostalAddressRule", message))?;
+			}
+		}
+	}
+	if let Some(fin_instn_id) = element.child("Agt").and_then(|agt| agt.child("FinInstnId")) {
+		if !fin_instn_id.has("BICFI") {
+			for name in ["Nm", "PstlAdr"] {
+				if !fin_instn_id.has(name) {
+					let message = format!("{} is required without BICFI", name);
+					validator.element("Agt", "", |validator| {
+						validator.report_at("FinInstnId", "", ValidationError::rule("CBPR_AgentNamePostalAddressRule", message))
+					})?;
+				}
+			}
+		}
+	}
+	Ok(())
+}
+
+/// `CBPR_InstructionIdentificationRule`: see [`slashes`].
+fn instruction_identification(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	slashes(element, "InstrId", "CBPR_InstructionIdentificationRule", validator)
+}
+
+/// `CBPR_OriginalInstructionIdentificationRule`: see [`slashes`].
+fn original_instruction_identification(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	slashes(element, "OrgnlInstrId", "CBPR_OriginalInstructionIdentificationRule", validator)
+}
+
+/// `CBPR_CancellationIdentification_Rule`: see [`slashes`].
+fn cancellation_identification(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	slashes(element, "CxlId", "CBPR_CancellationIdentification_Rule", validator)
+}
+
+/// `CBPR_CaseIdentification_Rule`: see [`slashes`].
+fn case_identification(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "Case" {
+		return Ok(());
+	}
+	slashes(element, "Id", "CBPR_CaseIdentification_Rule", validator)
+}
+
+/// The child `name` of `element`, a reference carried over to MT messages, does not start or end
+/// with a slash and does not contain two consecutive slashes.
+fn slashes(element: &Element, name: &str, rule: &str, validator: &mut Validator) -> Result<(), ValidationError> {
+	match element.text_of(name) {
+		Some(value) if value.starts_with('/') || value.ends_with('/') || value.contains("//") => {
+			let message = format!("{} must not start or end with '/' or contain '//'", name);
+			validator.report_at(name, "", ValidationError::rule(rule, message))
+		}
+		_ => Ok(()),
+	}
+}
+
+/// `CBPR_ReasonCodeNARR_AdditionalInformationRule`: a reason `NARR` is explained by `AddtlInf`.
+fn reason_code_narr(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	let narrative = element.child("Rsn").and_then(|rsn| rsn.text_of("Cd")) == Some("NARR");
+	if !narrative || element.has("AddtlInf") {
+		return Ok(());
+	}
+	let message = "AddtlInf is required with the reason code NARR".to_string();
+	validator.report(ValidationError::rule("CBPR_ReasonCodeNARR_AdditionalInformationRule", message))
+}
+
+/// `CBPR_TransactionStatusRejectReasonRule`: a rejected transaction carries the reason of the rejection.
+fn transaction_status_reject_reason(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.text_of("TxSts") != Some("RJCT") || element.children("StsRsnInf").any(|sts_rsn_inf| sts_rsn_inf.has("Rsn")) {
+		return Ok(());
+	}
+	let message = "StsRsnInf with Rsn is required when TxSts is RJCT".to_string();
+	validator.report(ValidationError::rule("CBPR_TransactionStatusRejectReasonRule", message))
+}
+
+/// `CBPR_Remittance_MutuallyExclusive`: remittance information is either unstructured or structured.
+fn remittance_mutually_exclusive(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "RmtInf" || !element.has("Ustrd") || !element.has("Strd") {
+		return Ok(());
+	}
+	let message = "Ustrd and Strd are mutually exclusive".to_string();
+	validator.report(ValidationError::rule("CBPR_Remittance_MutuallyExclusive", message))
+}
+
+/// `CBPR_RelatedRemitInfoRemitInfoMutuallyExclusive`: a transaction carries its remittance
+/// information or a reference to where it is sent separately, not both.
+fn related_remittance_mutually_exclusive(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if !element.has("RltdRmtInf") || !element.has("RmtInf") {
+		return Ok(());
+	}
+	let message = "RltdRmtInf and RmtInf are mutually exclusive".to_string();
+	validator.report(ValidationError::rule("CBPR_RelatedRemitInfoRemitInfoMutuallyExclusive", message))
+}
+
+/// `CBPR_InstructionForCreditorAgent1Rule` and `CBPR_InstructionForCreditorAgent2Rule`: the
+/// `InstrForCdtrAgt` codes `CHQB` and `HOLD`, and `PHOB` and `TELB`, exclude each other.
+fn instruction_for_creditor_agent(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	let codes: Vec<&str> = element.children("InstrForCdtrAgt").filter_map(|instr| instr.text_of("Cd")).collect();
+	for (rule, first, second) in [("CBPR_InstructionForCreditorAgent1Rule", "CHQB", "HOLD"), ("CBPR_InstructionForCreditorAgent2Rule", "PHOB", "TELB")] {
+		if codes.contains(&first) && codes.contains(&second) {
+			let message = format!("InstrForCdtrAgt codes {} and {} are mutually exclusive", first, second);
+			validator.report(ValidationError::rule(rule, message))?;
+		}
+	}
+	Ok(())
+}
+
+/// `CBPR_CRED_FormalRule`: an interbank transfer whose charges are borne by the creditor states
+/// the charges deducted in `ChrgsInf`, if only with a zero amount.
+fn cred_charges(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.text_of("ChrgBr") != Some("CRED") || !element.has("IntrBkSttlmAmt") || element.has("ChrgsInf") {
+		return Ok(());
+	}
+	let message = "ChrgsInf is required when ChrgBr is CRED".to_string();
+	validator.report_at("ChrgBr", "", ValidationError::rule("CBPR_CRED_FormalRule", message))
+}
+
+/// `CBPR_DEBT_FormalRule`: an interbank transfer whose charges are borne by the debtor states at
+/// most once, in `ChrgsInf`, the charges prepaid to the next agent.
+fn debt_charges(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.text_of("ChrgBr") != Some("DEBT") || element.children("ChrgsInf").count() <= 1 {
+		return Ok(());
+	}
+	let message = "ChrgsInf occurs at most once when ChrgBr is DEBT".to_string();
+	validator.report_at("ChrgBr", "", ValidationError::rule("CBPR_DEBT_FormalRule", message))
+}
+
+/// `CBPR_DEBTRule1`: the charges prepaid by the debtor are settled with the transfer, so the
+/// `IntrBkSttlmAmt` covers the `ChrgsInf` amounts in its currency.
+fn debt_settlement_amount(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.text_of("ChrgBr") != Some("DEBT") {
+		return Ok(());
+	}
+	let Some(settlement) = element.child("IntrBkSttlmAmt") else {
+		return Ok(());
+	};
+	let Ok(amount) = settlement.text.trim().parse::<Decimal>() else {
+		return Ok(());
+	};
+	let mut charges = Decimal::from(0i64);
+	for charge in element.children("ChrgsInf").filter_map(|chrgs_inf| chrgs_inf.child("Amt")) {
+		if charge.attribute("Ccy") != settlement.attribute("Ccy") {
+			continue;
+		}
+		match charge.text.trim().parse::<Decimal>().ok().and_then(|value| charges.checked_add(value)) {
+			Some(sum) => charges = sum,
+			None => return Ok(()),
+		}
+	}
+	if amount >= charges {
+		return Ok(());
+	}
+	let message = format!("IntrBkSttlmAmt {} does not cover the prepaid charges {}", amount, charges);
+	validator.report_at("IntrBkSttlmAmt", "", ValidationError::rule("CBPR_DEBTRule1", message))
+}
+
+/// `CBPR_Debtor_BIC_PresenceRule`: the debtor of a transfer, as opposed to the debtor of a tax
+/// remittance, is identified by its `AnyBIC` or else named.
+fn debtor_bic_presence(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	let Some(dbtr) = element.child("Dbtr").filter(|_| element.has("DbtrAgt")) else {
+		return Ok(());
+	};
+	if dbtr.has("FinInstnId") || dbtr.has("Nm") || any_bic(dbtr) {
+		return Ok(());
+	}
+	let message = "Nm is required without AnyBIC".to_string();
+	validator.report_at("Dbtr", "", ValidationError::rule("CBPR_Debtor_BIC_PresenceRule", message))
+}
+
+/// `CBPR_Originator_Option1Rule`: an `Orgtr` without `AnyBIC` is identified by its name.
+fn originator_option_1(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "Orgtr" || any_bic(element) || element.has("Nm") {
+		return Ok(());
+	}
+	let message = "Nm is required without AnyBIC".to_string();
+	validator.report(ValidationError::rule("CBPR_Originator_Option1Rule", message))
+}
+
+/// `CBPR_Originator_Option2Rule`: an `Orgtr` identified by `AnyBIC` may add its name, and its
+/// postal address only along with the name.
+fn originator_option_2(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if element.name != "Orgtr" || !any_bic(element) || !element.has("PstlAdr") || element.has("Nm") {
+		return Ok(());
+	}
+	let message = "PstlAdr is not allowed with AnyBIC without Nm".to_string();
+	validator.report(ValidationError::rule("CBPR_Originator_Option2Rule", message))
+}
+
+// Whether the party `element` is identified by `Id/OrgId/AnyBIC`.
+fn any_bic(element: &Element) -> bool {
+	element.child("Id").and_then(|id| id.child("OrgId")).is_some_and(|org_id| org_id.has("AnyBIC"))
+}
+
+/// `CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule`: the original
+/// transaction was a credit transfer or a direct debit, so it has an execution or a collection date.
+fn original_requested_date(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	if !element.has("OrgnlReqdExctnDt") || !element.has("OrgnlReqdColltnDt") {
+		return Ok(());
+	}
+	let message = "OrgnlReqdExctnDt and OrgnlReqdColltnDt are mutually exclusive".to_string();
+	let rule = "CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule";
+	validator.report(ValidationError::rule(rule, message))
+}
+
+/// `CBPR_InterbankSettlementCurrencyRule`: the `IntrBkSttlmAmt` is not in a precious metal.
+fn interbank_settlement_currency(element: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+	match element.attribute("Ccy") {
+		Some(currency) if element.name == "IntrBkSttlmAmt" && METALS.contains(&currency) => {
+			let message = format!("{} is not allowed as the interbank settlement currency", currency);
+			validator.report(ValidationError::rule("CBPR_InterbankSettlementCurrencyRule", message))
+		}
+		_ => Ok(()),
+	}
+}
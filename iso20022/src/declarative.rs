@@ -0,0 +1,406 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Rules declared in JSON rather than coded in Rust, so that simple constraints are added without a release.
+//!
+//! Each rule applies to the elements its `context` path selects, the `Document` element unless given,
+//! and `check`s the elements its `path` selects below them. With a `when` constraint, the rule only
+//! applies to the contexts where it holds:
+//!
+//! ```json
+//! { "rules": [
+//!     { "id": "eur-shared-charges", "context": "//CdtTrfTxInf",
+//!       "when": { "path": "IntrBkSttlmAmt/@Ccy", "in": ["EUR"] },
+//!       "check": { "path": "ChrgBr", "in": ["SHAR"] } },
+//!     { "id": "purpose-for-india", "context": "//CdtTrfTxInf",
+//!       "when": { "path": "Cdtr/PstlAdr/Ctry", "in": ["IN"] },
+//!       "check": { "path": "Purp/Cd", "required": true } },
+//!     { "id": "address-lines", "severity": "warning", "context": "//PstlAdr",
+//!       "check": { "path": "AdrLine", "max_occurs": 3 }, "message": "at most 3 AdrLine" }
+//! ] }
+//! ```
+//!
+//! Paths are element names without namespace prefix separated by `/`. `*` matches any element, a
+//! leading `/` starts at the `Document` element and `//` matches at any depth. The last step of a
+//! `path` may name an attribute, e.g. `@Ccy`. A constraint holds when what its `path` selects is:
+//!
+//! - `required`: present, or absent with `false`,
+//! - `min_occurs` / `max_occurs`: present as many times,
+//! - `in`: one of the listed values,
+//! - `min_length` / `max_length`: as many characters long,
+//! - `pattern`: matched as a whole by the regular expression.
+//!
+//! A `when` constraint other than `"required": false` also needs its `path` to be present. The rules
+//! are [`Rule`]s for a [`RuleSet`](crate::rule::RuleSet), and [`check`] reports them alongside the
+//! schema checks of [`Document::validate_with`]. Violations are [`ValidationErrorKind::Rule`]s
+//! named after the `id` of the rule, with its `message` if given, and located like the schema checks
+//! locate theirs, e.g. `/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/ChrgBr`.
+//!
+//! [`ValidationErrorKind::Rule`]: iso20022_common::ValidationErrorKind::Rule
+
+use std::fmt;
+use iso20022_common::{ValidationError, Validator};
+use regex::Regex;
+use serde::Deserialize;
+use crate::document::Document;
+use crate::element::{self, Element};
+use crate::rule::{Rule, Severity};
+
+/// A rules file as written, see the [module documentation](self).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleFile {
+	pub rules: Vec<RuleDefinition>,
+}
+
+/// A rule as written, compiled by [`DeclarativeRule::compile`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleDefinition {
+	pub id: String,
+	#[serde(default = "error_severity")]
+	pub severity: Severity,
+	/// Replaces the generated description of the violations.
+	#[serde(default)]
+	pub message: Option<String>,
+	#[serde(default)]
+	pub context: Option<String>,
+	#[serde(default)]
+	pub when: Option<Constraint>,
+	pub check: Constraint,
+}
+
+fn error_severity() -> Severity {
+	Severity::Error
+}
+
+/// The constraints on the elements or attributes `path` selects, every one given must hold.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Constraint {
+	pub path: String,
+	#[serde(default)]
+	pub required: Option<bool>,
+	#[serde(default)]
+	pub min_occurs: Option<usize>,
+	#[serde(default)]
+	pub max_occurs: Option<usize>,
+	#[serde(default, rename = "in")]
+	pub one_of: Option<Vec<String>>,
+	#[serde(default)]
+	pub min_length: Option<usize>,
+	#[serde(default)]
+	pub max_length: Option<usize>,
+	#[serde(default)]
+	pub pattern: Option<String>,
+}
+
+/// Errors raised while compiling rule definitions.
+#[derive(Debug)]
+pub enum DefinitionError {
+	/// The file is not JSON or does not follow the rule format.
+	Json(serde_json::Error),
+	/// A path of rule `rule` is malformed, e.g. empty or with an attribute before its last step.
+	Path { rule: String, path: String },
+	/// The `pattern` of rule `rule` is not a valid regular expression.
+	Pattern { rule: String, source: regex::Error },
+}
+
+impl fmt::Display for DefinitionError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			DefinitionError::Json(e) => write!(f, "invalid rules file: {}", e),
+			DefinitionError::Path { rule, path } => write!(f, "invalid path {:?} in rule {}", path, rule),
+			DefinitionError::Pattern { rule, source } => write!(f, "invalid pattern in rule {}: {}", rule, source),
+		}
+	}
+}
+
+impl std::error::Error for DefinitionError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			DefinitionError::Json(e) => Some(e),
+			DefinitionError::Path { .. } => None,
+			DefinitionError::Pattern { source, .. } => Some(source),
+		}
+	}
+}
+
+/// Compiles the rules of the JSON rules file `json`.
+pub fn from_json(json: &str) -> Result<Vec<DeclarativeRule>, DefinitionError> {
+	let file: RuleFile = serde_json::from_str(json).map_err(DefinitionError::Json)?;
+	file.rules.into_iter().map(DeclarativeRule::compile).collect()
+}
+
+/// Checks `rules` on `doc`, reporting violations to `validator` like [`Document::validate_with`] does,
+/// so that they are reported alongside the schema checks. The message is only parsed once for all
+/// rules, also when they are applied one by one as part of a [`RuleSet`](crate::rule::RuleSet).
+pub fn check(rules: &[DeclarativeRule], doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+	match Element::of_document(doc) {
+		Ok(document) => rules.iter().try_for_each(|rule| rule.check_element(&document, validator)),
+		Err(e) => validator.report(ValidationError::new(0, format!("message could not be checked: {}", e))),
+	}
+}
+
+/// A compiled rule, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DeclarativeRule {
+	id: String,
+	severity: Severity,
+	message: Option<String>,
+	context: Option<Path>,
+	when: Option<Check>,
+	check: Check,
+}
+
+impl DeclarativeRule {
+	pub fn compile(definition: RuleDefinition) -> Result<Self, DefinitionError> {
+		let id = definition.id;
+		let context = match definition.context {
+			Some(context) => match Path::parse(&context) {
+				Some(path) if path.attribute.is_none() => Some(path),
+				_ => return Err(DefinitionError::Path { rule: id, path: context }),
+			},
+			None => None,
+		};
+		let when = match definition.when {
+			Some(when) => Some(Check::compile(&id, when)?),
+			None => None,
+		};
+		let check = Check::compile(&id, definition.check)?;
+		Ok(DeclarativeRule { id, severity: definition.severity, message: definition.message, context, when, check })
+	}
+
+	fn check_element(&self, document: &Element, validator: &mut Validator) -> Result<(), ValidationError> {
+		let root = Node { element: document, path: format!("/{}", document.name) };
+		let contexts = match &self.context {
+			Some(context) => context.select(document, &root),
+			None => vec![root],
+		};
+		for context in &contexts {
+			if self.when.as_ref().is_some_and(|when| !when.holds(document, context)) {
+				continue;
+			}
+			for (path, message) in self.check.violations(document, context) {
+				let message = self.message.clone().unwrap_or(message);
+				let mut error = ValidationError::rule(&self.id, message);
+				error.path = path;
+				validator.report(error)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl Rule for DeclarativeRule {
+	fn id(&self) -> &str {
+		&self.id
+	}
+
+	fn severity(&self) -> Severity {
+		self.severity
+	}
+
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+		check(std::slice::from_ref(self), doc, validator)
+	}
+}
+
+/// A [`Constraint`] with its path parsed and its pattern compiled.
+#[derive(Debug, Clone)]
+struct Check {
+	path: Path,
+	constraint: Constraint,
+	pattern: Option<Regex>,
+}
+
+impl Check {
+	fn compile(rule: &str, constraint: Constraint) -> Result<Self, DefinitionError> {
+		let path = Path::parse(&constraint.path).ok_or_else(|| DefinitionError::Path { rule: rule.to_string(), path: constraint.path.clone() })?;
+		let pattern = match &constraint.pattern {
+			Some(pattern) => Some(Regex::new(&format!("^(?:{})$", pattern)).map_err(|source| DefinitionError::Pattern { rule: rule.to_string(), source })?),
+			None => None,
+		};
+		Ok(Check { path, constraint, pattern })
+	}
+
+	// Whether the constraint holds in `context`, what it constrains being present unless it requires its absence.
+	fn holds(&self, document: &Element, context: &Node) -> bool {
+		let present = !self.path.values(document, context).is_empty();
+		(present || self.constraint.required == Some(false)) && self.violations(document, context).is_empty()
+	}
+
+	// The element path and description of every violation of the constraint in `context`.
+	fn violations(&self, document: &Element, context: &Node) -> Vec<(String, String)> {
+		let constraint = &self.constraint;
+		let name = &constraint.path;
+		let values = self.path.values(document, context);
+		let mut violations = Vec::new();
+		match constraint.required {
+			Some(true) if values.is_empty() => violations.push((context.path.clone(), format!("{} is required", name))),
+			Some(false) => violations.extend(values.iter().map(|(path, _)| (path.clone(), format!("{} is not allowed", name)))),
+			_ => {}
+		}
+		if let Some(min) = constraint.min_occurs.filter(|min| values.len() < *min) {
+			violations.push((context.path.clone(), format!("{} occurs {} times, fewer than {}", name, values.len(), min)));
+		}
+		if let Some(max) = constraint.max_occurs.filter(|max| values.len() > *max) {
+			violations.push((context.path.clone(), format!("{} occurs {} times, more than {}", name, values.len(), max)));
+		}
+		for (path, value) in &values {
+			if let Some(one_of) = constraint.one_of.as_ref().filter(|one_of| !one_of.iter().any(|allowed| allowed == value)) {
+				violations.push((path.clone(), format!("{:?} is not one of {}", value, one_of.join(", "))));
+			}
+			let length = value.chars().count();
+			if let Some(min) = constraint.min_length.filter(|min| length < *min) {
+				violations.push((path.clone(), format!("{} is shorter than {} characters", name, min)));
+			}
+			if let Some(max) = constraint.max_length.filter(|max| length > *max) {
+				violations.push((path.clone(), format!("{} is longer than {} characters", name, max)));
+			}
+			if let Some(pattern) = self.pattern.as_ref().filter(|pattern| !pattern.is_match(value)) {
+				violations.push((path.clone(), format!("{:?} does not match {}", value, pattern.as_str())));
+			}
+		}
+		violations
+	}
+}
+
+/// An element of the message with its XML element path, e.g. `/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[2]`.
+#[derive(Debug, Clone)]
+struct Node<'a> {
+	element: &'a Element,
+	path: String,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+	Child(String),
+	Descendant(String),
+}
+
+/// A parsed path, see the [module documentation](self).
+#[derive(Debug, Clone)]
+struct Path {
+	absolute: bool,
+	steps: Vec<Step>,
+	attribute: Option<String>,
+}
+
+impl Path {
+	fn parse(path: &str) -> Option<Path> {
+		let (absolute, rest) = match path.strip_prefix('/') {
+			Some(rest) => (true, rest),
+			None => (false, path),
+		};
+		let mut segments = rest.split('/').peekable();
+		let mut parsed = Path { absolute, steps: Vec::new(), attribute: None };
+		let mut descendant = false;
+		while let Some(segment) = segments.next() {
+			if segment.is_empty() {
+				// The second `/` of a `//`, followed by a step
+				if descendant || segments.peek().is_none() {
+					return None;
+				}
+				descendant = true;
+			} else if let Some(attribute) = segment.strip_prefix('@') {
+				if attribute.is_empty() || descendant || segments.peek().is_some() {
+					return None;
+				}
+				parsed.attribute = Some(attribute.to_string());
+			} else if segment.contains('@') {
+				return None;
+			} else {
+				let name = segment.to_string();
+				parsed.steps.push(if descendant { Step::Descendant(name) } else { Step::Child(name) });
+				descendant = false;
+			}
+		}
+		if parsed.steps.is_empty() && (absolute || parsed.attribute.is_none()) {
+			return None;
+		}
+		Some(parsed)
+	}
+
+	// The elements the path selects from `context`, in document order.
+	fn select<'a>(&self, document: &'a Element, context: &Node<'a>) -> Vec<Node<'a>> {
+		let mut steps = self.steps.iter();
+		let mut nodes = match (self.absolute, steps.next()) {
+			(true, Some(step)) => {
+				let mut nodes = Vec::new();
+				step.apply("", std::slice::from_ref(document), &mut nodes);
+				nodes
+			}
+			(_, Some(step)) => {
+				let mut nodes = Vec::new();
+				step.apply(&context.path, &context.element.children, &mut nodes);
+				nodes
+			}
+			(_, None) => vec![context.clone()],
+		};
+		for step in steps {
+			let mut next = Vec::new();
+			for node in &nodes {
+				step.apply(&node.path, &node.element.children, &mut next);
+			}
+			nodes = next;
+		}
+		nodes
+	}
+
+	// The path and value of everything the path selects from `context`, the text of elements or the value of attributes.
+	fn values<'a>(&self, document: &'a Element, context: &Node<'a>) -> Vec<(String, &'a str)> {
+		let nodes = self.select(document, context);
+		match &self.attribute {
+			Some(attribute) => nodes
+				.into_iter()
+				.filter_map(|node| node.element.attribute(attribute).map(|value| (format!("{}/@{}", node.path, attribute), value)))
+				.collect(),
+			None => nodes.into_iter().map(|node| (node.path, node.element.text.as_str())).collect(),
+		}
+	}
+}
+
+impl Step {
+	// Pushes the `children` of the element at `parent` the step selects, or their descendants for `//`.
+	// Items of repeated fields carry their position in the path, as in the schema checks.
+	fn apply<'a>(&self, parent: &str, children: &'a [Element], nodes: &mut Vec<Node<'a>>) {
+		for (child, item) in element::items(children) {
+			let path = format!("{}/{}", parent, element::segment(&child.name, item));
+			match self {
+				Step::Child(name) => {
+					if name == "*" || *name == child.name {
+						nodes.push(Node { element: child, path });
+					}
+				}
+				Step::Descendant(name) => {
+					if name == "*" || *name == child.name {
+						nodes.push(Node { element: child, path: path.clone() });
+					}
+					self.apply(&path, &child.children, nodes);
+				}
+			}
+		}
+	}
+}
@@ -23,8 +23,23 @@
 // https://github.com/Open-Payments/iso20022-rs
 
 #[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-use iso20022_common::ValidationError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "derive_serde")]
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+#[cfg(feature = "derive_serde")]
+use serde::ser::SerializeMap;
+#[cfg(feature = "derive_serde")]
+use std::fmt;
+#[cfg(feature = "derive_serde")]
+use std::io::{BufRead, Read, Write};
+#[cfg(feature = "derive_serde")]
+use quick_xml::events::Event;
+#[cfg(feature = "derive_serde")]
+use quick_xml::name::ResolveResult;
+#[cfg(feature = "derive_serde")]
+use crate::error::Error;
+use iso20022_common::{ValidationError, ValidationReport, Validator};
+use crate::identifier::{MessageIdentifier, NAMESPACE_PREFIX};
 
 #[cfg(feature = "acmt")] use open_payments_iso20022_acmt::acmt_001_001_08::*;
 #[cfg(feature = "acmt")] use open_payments_iso20022_acmt::acmt_002_001_08::*;
@@ -330,1778 +345,4162 @@ use iso20022_common::ValidationError;
 #[cfg_attr(feature = "derive_debug", derive(Debug))]
 #[cfg_attr(feature = "derive_clone", derive(Clone))]
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
-#[cfg_attr(feature = "derive_default", derive(Default))]
-#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub enum Document {
-	#[cfg_attr( feature = "derive_serde", serde(rename = "admi.002.001.01"))]
 	#[cfg(feature = "admi")]
 	Admi00200101(Box<Admi00200101>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SysEvtNtfctn"))]
 	#[cfg(feature = "admi")]
 	SystemEventNotificationV02(Box<SystemEventNotificationV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RptQryReq"))]
 	#[cfg(feature = "admi")]
 	ReportQueryRequestV02(Box<ReportQueryRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RsndReq"))]
 	#[cfg(feature = "admi")]
 	ResendRequestV01(Box<ResendRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RctAck"))]
 	#[cfg(feature = "admi")]
 	ReceiptAcknowledgementV01(Box<ReceiptAcknowledgementV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StatcDataReq"))]
 	#[cfg(feature = "admi")]
 	StaticDataRequestV02(Box<StaticDataRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StatcDataRpt"))]
 	#[cfg(feature = "admi")]
 	StaticDataReportV02(Box<StaticDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SysEvtAck"))]
 	#[cfg(feature = "admi")]
 	SystemEventAcknowledgementV01(Box<SystemEventAcknowledgementV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PrcgReq"))]
 	#[cfg(feature = "admi")]
 	ProcessingRequestV02(Box<ProcessingRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnOfCrspdc"))]
 	#[cfg(feature = "admi")]
 	NotificationOfCorrespondenceV01(Box<NotificationOfCorrespondenceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFIPmtStsRpt"))]
 	#[cfg(feature = "pacs")]
 	FIToFIPaymentStatusReportV12(Box<FIToFIPaymentStatusReportV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFIPmtStsRpt"))]
 	#[cfg(feature = "pacs")]
 	FIToFIPaymentStatusReportV14(Box<FIToFIPaymentStatusReportV14>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFICstmrDrctDbt"))]
 	#[cfg(feature = "pacs")]
 	FIToFICustomerDirectDebitV11(Box<FIToFICustomerDirectDebitV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PmtRtr"))]
 	#[cfg(feature = "pacs")]
 	PaymentReturnV13(Box<PaymentReturnV13>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFIPmtRvsl"))]
 	#[cfg(feature = "pacs")]
 	FIToFIPaymentReversalV13(Box<FIToFIPaymentReversalV13>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFICstmrCdtTrf"))]
 	#[cfg(feature = "pacs")]
 	FIToFICustomerCreditTransferV12(Box<FIToFICustomerCreditTransferV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FICdtTrf"))]
 	#[cfg(feature = "pacs")]
 	FinancialInstitutionCreditTransferV11(Box<FinancialInstitutionCreditTransferV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIDrctDbt"))]
 	#[cfg(feature = "pacs")]
 	FinancialInstitutionDirectDebitV06(Box<FinancialInstitutionDirectDebitV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFIPmtStsReq"))]
 	#[cfg(feature = "pacs")]
 	FIToFIPaymentStatusRequestV06(Box<FIToFIPaymentStatusRequestV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MulSttlmReq"))]
 	#[cfg(feature = "pacs")]
 	MultilateralSettlementRequestV02(Box<MultilateralSettlementRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CstmrCdtTrfInitn"))]
 	#[cfg(feature = "pain")]
 	CustomerCreditTransferInitiationV12(Box<CustomerCreditTransferInitiationV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CstmrPmtStsRpt"))]
 	#[cfg(feature = "pain")]
 	CustomerPaymentStatusReportV14(Box<CustomerPaymentStatusReportV14>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CstmrPmtRvsl"))]
 	#[cfg(feature = "pain")]
 	CustomerPaymentReversalV12(Box<CustomerPaymentReversalV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CstmrDrctDbtInitn"))]
 	#[cfg(feature = "pain")]
 	CustomerDirectDebitInitiationV11(Box<CustomerDirectDebitInitiationV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtInitnReq"))]
 	#[cfg(feature = "pain")]
 	MandateInitiationRequestV08(Box<MandateInitiationRequestV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtAmdmntReq"))]
 	#[cfg(feature = "pain")]
 	MandateAmendmentRequestV08(Box<MandateAmendmentRequestV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtCxlReq"))]
 	#[cfg(feature = "pain")]
 	MandateCancellationRequestV08(Box<MandateCancellationRequestV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtAccptncRpt"))]
 	#[cfg(feature = "pain")]
 	MandateAcceptanceReportV08(Box<MandateAcceptanceReportV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CdtrPmtActvtnReq"))]
 	#[cfg(feature = "pain")]
 	CreditorPaymentActivationRequestV11(Box<CreditorPaymentActivationRequestV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CdtrPmtActvtnReqStsRpt"))]
 	#[cfg(feature = "pain")]
 	CreditorPaymentActivationRequestStatusReportV11(Box<CreditorPaymentActivationRequestStatusReportV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtCpyReq"))]
 	#[cfg(feature = "pain")]
 	MandateCopyRequestV04(Box<MandateCopyRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MndtSspnsnReq"))]
 	#[cfg(feature = "pain")]
 	MandateSuspensionRequestV04(Box<MandateSuspensionRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctOpngInstr"))]
 	#[cfg(feature = "acmt")]
 	AccountOpeningInstructionV08(Box<AccountOpeningInstructionV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctDtlsConf"))]
 	#[cfg(feature = "acmt")]
 	AccountDetailsConfirmationV08(Box<AccountDetailsConfirmationV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctModInstr"))]
 	#[cfg(feature = "acmt")]
 	AccountModificationInstructionV08(Box<AccountModificationInstructionV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqForAcctMgmtStsRpt"))]
 	#[cfg(feature = "acmt")]
 	RequestForAccountManagementStatusReportV06(Box<RequestForAccountManagementStatusReportV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctMgmtStsRpt"))]
 	#[cfg(feature = "acmt")]
 	AccountManagementStatusReportV07(Box<AccountManagementStatusReportV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctOpngReq"))]
 	#[cfg(feature = "acmt")]
 	AccountOpeningRequestV05(Box<AccountOpeningRequestV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctOpngAmdmntReq"))]
 	#[cfg(feature = "acmt")]
 	AccountOpeningAmendmentRequestV05(Box<AccountOpeningAmendmentRequestV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctOpngAddtlInfReq"))]
 	#[cfg(feature = "acmt")]
 	AccountOpeningAdditionalInformationRequestV04(Box<AccountOpeningAdditionalInformationRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctReqAck"))]
 	#[cfg(feature = "acmt")]
 	AccountRequestAcknowledgementV04(Box<AccountRequestAcknowledgementV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctReqRjctn"))]
 	#[cfg(feature = "acmt")]
 	AccountRequestRejectionV04(Box<AccountRequestRejectionV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctAddtlInfReq"))]
 	#[cfg(feature = "acmt")]
 	AccountAdditionalInformationRequestV04(Box<AccountAdditionalInformationRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctRptReq"))]
 	#[cfg(feature = "acmt")]
 	AccountReportRequestV04(Box<AccountReportRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctRpt"))]
 	#[cfg(feature = "acmt")]
 	AccountReportV05(Box<AccountReportV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctExcldMndtMntncReq"))]
 	#[cfg(feature = "acmt")]
 	AccountExcludedMandateMaintenanceRequestV04(Box<AccountExcludedMandateMaintenanceRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctExcldMndtMntncAmdmntReq"))]
 	#[cfg(feature = "acmt")]
 	AccountExcludedMandateMaintenanceAmendmentRequestV04(Box<AccountExcludedMandateMaintenanceAmendmentRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctMndtMntncReq"))]
 	#[cfg(feature = "acmt")]
 	AccountMandateMaintenanceRequestV04(Box<AccountMandateMaintenanceRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctMndtMntncAmdmntReq"))]
 	#[cfg(feature = "acmt")]
 	AccountMandateMaintenanceAmendmentRequestV04(Box<AccountMandateMaintenanceAmendmentRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctClsgReq"))]
 	#[cfg(feature = "acmt")]
 	AccountClosingRequestV04(Box<AccountClosingRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctClsgAmdmntReq"))]
 	#[cfg(feature = "acmt")]
 	AccountClosingAmendmentRequestV04(Box<AccountClosingAmendmentRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctClsgAddtlInfReq"))]
 	#[cfg(feature = "acmt")]
 	AccountClosingAdditionalInformationRequestV04(Box<AccountClosingAdditionalInformationRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IdModAdvc"))]
 	#[cfg(feature = "acmt")]
 	IdentificationModificationAdviceV04(Box<IdentificationModificationAdviceV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IdVrfctnReq"))]
 	#[cfg(feature = "acmt")]
 	IdentificationVerificationRequestV04(Box<IdentificationVerificationRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IdVrfctnRpt"))]
 	#[cfg(feature = "acmt")]
 	IdentificationVerificationReportV04(Box<IdentificationVerificationReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchInfReq"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchInformationRequestV05(Box<AccountSwitchInformationRequestV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchInfRspn"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchInformationResponseV05(Box<AccountSwitchInformationResponseV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchCclExstgPmt"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchCancelExistingPaymentV05(Box<AccountSwitchCancelExistingPaymentV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchReqRdrctn"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchRequestRedirectionV04(Box<AccountSwitchRequestRedirectionV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchReqBalTrf"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchRequestBalanceTransferV05(Box<AccountSwitchRequestBalanceTransferV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchBalTrfAck"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchBalanceTransferAcknowledgementV05(Box<AccountSwitchBalanceTransferAcknowledgementV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchNtfyAcctSwtchCmplt"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchNotifyAccountSwitchCompleteV02(Box<AccountSwitchNotifyAccountSwitchCompleteV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchReqPmt"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchRequestPaymentV05(Box<AccountSwitchRequestPaymentV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchPmtRspn"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchPaymentResponseV02(Box<AccountSwitchPaymentResponseV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchTermntnSwtch"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchTerminationSwitchV01(Box<AccountSwitchTerminationSwitchV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctSwtchTechRjctn"))]
 	#[cfg(feature = "acmt")]
 	AccountSwitchTechnicalRejectionV02(Box<AccountSwitchTechnicalRejectionV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PricRpt"))]
 	#[cfg(feature = "reda")]
 	PriceReportV04(Box<PriceReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PricRptCxl"))]
 	#[cfg(feature = "reda")]
 	PriceReportCancellationV04(Box<PriceReportCancellationV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndRefDataRpt"))]
 	#[cfg(feature = "reda")]
 	FundReferenceDataReportV07(Box<FundReferenceDataReportV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InvstmtFndRptReq"))]
 	#[cfg(feature = "reda")]
 	InvestmentFundReportRequestV03(Box<InvestmentFundReportRequestV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyCreReq"))]
 	#[cfg(feature = "reda")]
 	SecurityCreationRequestV01(Box<SecurityCreationRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyMntncReq"))]
 	#[cfg(feature = "reda")]
 	SecurityMaintenanceRequestV01(Box<SecurityMaintenanceRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyCreStsAdvc"))]
 	#[cfg(feature = "reda")]
 	SecurityCreationStatusAdviceV01(Box<SecurityCreationStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyActvtyAdvc"))]
 	#[cfg(feature = "reda")]
 	SecurityActivityAdviceV01(Box<SecurityActivityAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyQry"))]
 	#[cfg(feature = "reda")]
 	SecurityQueryV01(Box<SecurityQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyRpt"))]
 	#[cfg(feature = "reda")]
 	SecurityReportV01(Box<SecurityReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyDeltnReq"))]
 	#[cfg(feature = "reda")]
 	SecurityDeletionRequestV01(Box<SecurityDeletionRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyCreReq"))]
 	#[cfg(feature = "reda")]
 	PartyCreationRequestV02(Box<PartyCreationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyQry"))]
 	#[cfg(feature = "reda")]
 	PartyQueryV01(Box<PartyQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyStsAdvc"))]
 	#[cfg(feature = "reda")]
 	PartyStatusAdviceV01(Box<PartyStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyRpt"))]
 	#[cfg(feature = "reda")]
 	PartyReportV02(Box<PartyReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctCreReq"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountCreationRequestV01(Box<SecuritiesAccountCreationRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctQry"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountQueryV01(Box<SecuritiesAccountQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctStsAdvc"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountStatusAdviceV01(Box<SecuritiesAccountStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctRpt"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountReportV01(Box<SecuritiesAccountReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyModReq"))]
 	#[cfg(feature = "reda")]
 	PartyModificationRequestV02(Box<PartyModificationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctModReq"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountModificationRequestV01(Box<SecuritiesAccountModificationRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyMntncStsAdvc"))]
 	#[cfg(feature = "reda")]
 	SecurityMaintenanceStatusAdviceV01(Box<SecurityMaintenanceStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctyDeltnStsAdvc"))]
 	#[cfg(feature = "reda")]
 	SecurityDeletionStatusAdviceV01(Box<SecurityDeletionStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyDeltnReq"))]
 	#[cfg(feature = "reda")]
 	PartyDeletionRequestV01(Box<PartyDeletionRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctDeltnReq"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountDeletionRequestV01(Box<SecuritiesAccountDeletionRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAudtTrlQry"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAuditTrailQueryV01(Box<SecuritiesAuditTrailQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAudtTrlRpt"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAuditTrailReportV01(Box<SecuritiesAuditTrailReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctActvtyAdvc"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountActivityAdviceV01(Box<SecuritiesAccountActivityAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctAudtTrlQry"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountAuditTrailQueryV01(Box<SecuritiesAccountAuditTrailQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesAcctAudtTrlRpt"))]
 	#[cfg(feature = "reda")]
 	SecuritiesAccountAuditTrailReportV01(Box<SecuritiesAccountAuditTrailReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyActvtyAdvc"))]
 	#[cfg(feature = "reda")]
 	PartyActivityAdviceV02(Box<PartyActivityAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyAudtTrlQry"))]
 	#[cfg(feature = "reda")]
 	PartyAuditTrailQueryV01(Box<PartyAuditTrailQueryV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PtyAudtTrlRpt"))]
 	#[cfg(feature = "reda")]
 	PartyAuditTrailReportV02(Box<PartyAuditTrailReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StgSttlmInstr"))]
 	#[cfg(feature = "reda")]
 	StandingSettlementInstructionV01(Box<StandingSettlementInstructionV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StgSttlmInstrDeltn"))]
 	#[cfg(feature = "reda")]
 	StandingSettlementInstructionDeletionV01(Box<StandingSettlementInstructionDeletionV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StgSttlmInstrStsAdvc"))]
 	#[cfg(feature = "reda")]
 	StandingSettlementInstructionStatusAdviceV01(Box<StandingSettlementInstructionStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "StgSttlmInstrCxl"))]
 	#[cfg(feature = "reda")]
 	StandingSettlementInstructionCancellationV01(Box<StandingSettlementInstructionCancellationV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NetgCutOffRefDataUpdReq"))]
 	#[cfg(feature = "reda")]
 	NettingCutOffReferenceDataUpdateRequestV02(Box<NettingCutOffReferenceDataUpdateRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NetgCutOffRefDataRpt"))]
 	#[cfg(feature = "reda")]
 	NettingCutOffReferenceDataReportV02(Box<NettingCutOffReferenceDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CalQry"))]
 	#[cfg(feature = "reda")]
 	CalendarQueryV02(Box<CalendarQueryV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CalRpt"))]
 	#[cfg(feature = "reda")]
 	CalendarReportV02(Box<CalendarReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayCdtrEnrlmntReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayCreditorEnrolmentRequestV02(Box<RequestToPayCreditorEnrolmentRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayCdtrEnrlmntAmdmntReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayCreditorEnrolmentAmendmentRequestV02(Box<RequestToPayCreditorEnrolmentAmendmentRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayCdtrEnrlmntCxlReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayCreditorEnrolmentCancellationRequestV02(Box<RequestToPayCreditorEnrolmentCancellationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayCdtrEnrlmntStsRpt"))]
 	#[cfg(feature = "reda")]
 	RequestToPayCreditorEnrolmentStatusReportV02(Box<RequestToPayCreditorEnrolmentStatusReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayDbtrActvtnReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayDebtorActivationRequestV02(Box<RequestToPayDebtorActivationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayDbtrActvtnAmdmntReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayDebtorActivationAmendmentRequestV02(Box<RequestToPayDebtorActivationAmendmentRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayDbtrActvtnCxlReq"))]
 	#[cfg(feature = "reda")]
 	RequestToPayDebtorActivationCancellationRequestV02(Box<RequestToPayDebtorActivationCancellationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToPayDbtrActvtnStsRpt"))]
 	#[cfg(feature = "reda")]
 	RequestToPayDebtorActivationStatusReportV02(Box<RequestToPayDebtorActivationStatusReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RmtAdvc"))]
 	#[cfg(feature = "remt")]
 	RemittanceAdviceV06(Box<RemittanceAdviceV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RmtLctnAdvc"))]
 	#[cfg(feature = "remt")]
 	RemittanceLocationAdviceV03(Box<RemittanceLocationAdviceV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetAcct"))]
 	#[cfg(feature = "camt")]
 	GetAccountV08(Box<GetAccountV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrAcct"))]
 	#[cfg(feature = "camt")]
 	ReturnAccountV10(Box<ReturnAccountV10>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetTx"))]
 	#[cfg(feature = "camt")]
 	GetTransactionV11(Box<GetTransactionV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrTx"))]
 	#[cfg(feature = "camt")]
 	ReturnTransactionV11(Box<ReturnTransactionV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ModfyTx"))]
 	#[cfg(feature = "camt")]
 	ModifyTransactionV10(Box<ModifyTransactionV10>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CclTx"))]
 	#[cfg(feature = "camt")]
 	CancelTransactionV11(Box<CancelTransactionV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetLmt"))]
 	#[cfg(feature = "camt")]
 	GetLimitV08(Box<GetLimitV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrLmt"))]
 	#[cfg(feature = "camt")]
 	ReturnLimitV09(Box<ReturnLimitV09>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ModfyLmt"))]
 	#[cfg(feature = "camt")]
 	ModifyLimitV08(Box<ModifyLimitV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DelLmt"))]
 	#[cfg(feature = "camt")]
 	DeleteLimitV08(Box<DeleteLimitV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetMmb"))]
 	#[cfg(feature = "camt")]
 	GetMemberV04(Box<GetMemberV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrMmb"))]
 	#[cfg(feature = "camt")]
 	ReturnMemberV05(Box<ReturnMemberV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ModfyMmb"))]
 	#[cfg(feature = "camt")]
 	ModifyMemberV04(Box<ModifyMemberV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetCcyXchgRate"))]
 	#[cfg(feature = "camt")]
 	GetCurrencyExchangeRateV04(Box<GetCurrencyExchangeRateV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrCcyXchgRate"))]
 	#[cfg(feature = "camt")]
 	ReturnCurrencyExchangeRateV05(Box<ReturnCurrencyExchangeRateV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetBizDayInf"))]
 	#[cfg(feature = "camt")]
 	GetBusinessDayInformationV05(Box<GetBusinessDayInformationV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrBizDayInf"))]
 	#[cfg(feature = "camt")]
 	ReturnBusinessDayInformationV07(Box<ReturnBusinessDayInformationV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetGnlBizInf"))]
 	#[cfg(feature = "camt")]
 	GetGeneralBusinessInformationV04(Box<GetGeneralBusinessInformationV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrGnlBizInf"))]
 	#[cfg(feature = "camt")]
 	ReturnGeneralBusinessInformationV06(Box<ReturnGeneralBusinessInformationV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "BckpPmt"))]
 	#[cfg(feature = "camt")]
 	BackupPaymentV07(Box<BackupPaymentV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ModfyStgOrdr"))]
 	#[cfg(feature = "camt")]
 	ModifyStandingOrderV08(Box<ModifyStandingOrderV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "Rct"))]
 	#[cfg(feature = "camt")]
 	ReceiptV08(Box<ReceiptV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "UblToApply"))]
 	#[cfg(feature = "camt")]
 	UnableToApplyV10(Box<UnableToApplyV10>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ClmNonRct"))]
 	#[cfg(feature = "camt")]
 	ClaimNonReceiptV10(Box<ClaimNonReceiptV10>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AddtlPmtInf"))]
 	#[cfg(feature = "camt")]
 	AdditionalPaymentInformationV12(Box<AdditionalPaymentInformationV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RsltnOfInvstgtn"))]
 	#[cfg(feature = "camt")]
 	ResolutionOfInvestigationV13(Box<ResolutionOfInvestigationV13>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnOfCaseAssgnmt"))]
 	#[cfg(feature = "camt")]
 	NotificationOfCaseAssignmentV06(Box<NotificationOfCaseAssignmentV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RjctInvstgtn"))]
 	#[cfg(feature = "camt")]
 	RejectInvestigationV07(Box<RejectInvestigationV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CclCaseAssgnmt"))]
 	#[cfg(feature = "camt")]
 	CancelCaseAssignmentV05(Box<CancelCaseAssignmentV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqForDplct"))]
 	#[cfg(feature = "camt")]
 	RequestForDuplicateV07(Box<RequestForDuplicateV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "Dplct"))]
 	#[cfg(feature = "camt")]
 	DuplicateV07(Box<DuplicateV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PrtryFrmtInvstgtn"))]
 	#[cfg(feature = "camt")]
 	ProprietaryFormatInvestigationV06(Box<ProprietaryFormatInvestigationV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DbtAuthstnRspn"))]
 	#[cfg(feature = "camt")]
 	DebitAuthorisationResponseV06(Box<DebitAuthorisationResponseV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DbtAuthstnReq"))]
 	#[cfg(feature = "camt")]
 	DebitAuthorisationRequestV10(Box<DebitAuthorisationRequestV10>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CaseStsRptReq"))]
 	#[cfg(feature = "camt")]
 	CaseStatusReportRequestV05(Box<CaseStatusReportRequestV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CaseStsRpt"))]
 	#[cfg(feature = "camt")]
 	CaseStatusReportV06(Box<CaseStatusReportV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndEstmtdCshFcstRpt"))]
 	#[cfg(feature = "camt")]
 	FundEstimatedCashForecastReportV04(Box<FundEstimatedCashForecastReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndConfdCshFcstRpt"))]
 	#[cfg(feature = "camt")]
 	FundConfirmedCashForecastReportV04(Box<FundConfirmedCashForecastReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndDtldEstmtdCshFcstRpt"))]
 	#[cfg(feature = "camt")]
 	FundDetailedEstimatedCashForecastReportV04(Box<FundDetailedEstimatedCashForecastReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndDtldConfdCshFcstRpt"))]
 	#[cfg(feature = "camt")]
 	FundDetailedConfirmedCashForecastReportV04(Box<FundDetailedConfirmedCashForecastReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndConfdCshFcstRptCxl"))]
 	#[cfg(feature = "camt")]
 	FundConfirmedCashForecastReportCancellationV03(Box<FundConfirmedCashForecastReportCancellationV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FndDtldConfdCshFcstRptCxl"))]
 	#[cfg(feature = "camt")]
 	FundDetailedConfirmedCashForecastReportCancellationV03(Box<FundDetailedConfirmedCashForecastReportCancellationV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetRsvatn"))]
 	#[cfg(feature = "camt")]
 	GetReservationV08(Box<GetReservationV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrRsvatn"))]
 	#[cfg(feature = "camt")]
 	ReturnReservationV08(Box<ReturnReservationV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ModfyRsvatn"))]
 	#[cfg(feature = "camt")]
 	ModifyReservationV07(Box<ModifyReservationV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DelRsvatn"))]
 	#[cfg(feature = "camt")]
 	DeleteReservationV07(Box<DeleteReservationV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "LqdtyCdtTrf"))]
 	#[cfg(feature = "camt")]
 	LiquidityCreditTransferV07(Box<LiquidityCreditTransferV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "LqdtyDbtTrf"))]
 	#[cfg(feature = "camt")]
 	LiquidityDebitTransferV07(Box<LiquidityDebitTransferV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "BkToCstmrAcctRpt"))]
 	#[cfg(feature = "camt")]
 	BankToCustomerAccountReportV12(Box<BankToCustomerAccountReportV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "BkToCstmrStmt"))]
 	#[cfg(feature = "camt")]
 	BankToCustomerStatementV12(Box<BankToCustomerStatementV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "BkToCstmrDbtCdtNtfctn"))]
 	#[cfg(feature = "camt")]
 	BankToCustomerDebitCreditNotificationV12(Box<BankToCustomerDebitCreditNotificationV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CstmrPmtCxlReq"))]
 	#[cfg(feature = "camt")]
 	CustomerPaymentCancellationRequestV12(Box<CustomerPaymentCancellationRequestV12>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FIToFIPmtCxlReq"))]
 	#[cfg(feature = "camt")]
 	FIToFIPaymentCancellationRequestV11(Box<FIToFIPaymentCancellationRequestV11>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnToRcv"))]
 	#[cfg(feature = "camt")]
 	NotificationToReceiveV08(Box<NotificationToReceiveV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnToRcvCxlAdvc"))]
 	#[cfg(feature = "camt")]
 	NotificationToReceiveCancellationAdviceV09(Box<NotificationToReceiveCancellationAdviceV09>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnToRcvStsRpt"))]
 	#[cfg(feature = "camt")]
 	NotificationToReceiveStatusReportV08(Box<NotificationToReceiveStatusReportV08>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "AcctRptgReq"))]
 	#[cfg(feature = "camt")]
 	AccountReportingRequestV07(Box<AccountReportingRequestV07>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PayInCall"))]
 	#[cfg(feature = "camt")]
 	PayInCallV02(Box<PayInCallV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PayInSchdl"))]
 	#[cfg(feature = "camt")]
 	PayInScheduleV03(Box<PayInScheduleV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PayInEvtAck"))]
 	#[cfg(feature = "camt")]
 	PayInEventAcknowledgementV02(Box<PayInEventAcknowledgementV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntInstr"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementInstructionV02(Box<IntraBalanceMovementInstructionV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntStsAdvc"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementStatusAdviceV02(Box<IntraBalanceMovementStatusAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntConf"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementConfirmationV02(Box<IntraBalanceMovementConfirmationV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "GetStgOrdr"))]
 	#[cfg(feature = "camt")]
 	GetStandingOrderV05(Box<GetStandingOrderV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "RtrStgOrdr"))]
 	#[cfg(feature = "camt")]
 	ReturnStandingOrderV06(Box<ReturnStandingOrderV06>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DelStgOrdr"))]
 	#[cfg(feature = "camt")]
 	DeleteStandingOrderV05(Box<DeleteStandingOrderV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntModReq"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementModificationRequestV02(Box<IntraBalanceMovementModificationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntModReqStsAdvc"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementModificationRequestStatusAdviceV02(Box<IntraBalanceMovementModificationRequestStatusAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntCxlReq"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementCancellationRequestV02(Box<IntraBalanceMovementCancellationRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntCxlReqStsAdvc"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementCancellationRequestStatusAdviceV02(Box<IntraBalanceMovementCancellationRequestStatusAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntQry"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementQueryV02(Box<IntraBalanceMovementQueryV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntQryRspn"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementQueryResponseV02(Box<IntraBalanceMovementQueryResponseV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntModQry"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementModificationQueryV02(Box<IntraBalanceMovementModificationQueryV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntModRpt"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementModificationReportV02(Box<IntraBalanceMovementModificationReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntCxlQry"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementCancellationQueryV02(Box<IntraBalanceMovementCancellationQueryV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntCxlRpt"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementCancellationReportV02(Box<IntraBalanceMovementCancellationReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntPstngRpt"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementPostingReportV02(Box<IntraBalanceMovementPostingReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "IntraBalMvmntPdgRpt"))]
 	#[cfg(feature = "camt")]
 	IntraBalanceMovementPendingReportV02(Box<IntraBalanceMovementPendingReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "BkSvcsBllgStmt"))]
 	#[cfg(feature = "camt")]
 	BankServicesBillingStatementV05(Box<BankServicesBillingStatementV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ReqToModfyPmt"))]
 	#[cfg(feature = "camt")]
 	RequestToModifyPaymentV09(Box<RequestToModifyPaymentV09>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "NetRpt"))]
 	#[cfg(feature = "camt")]
 	NetReportV02(Box<NetReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CretLmt"))]
 	#[cfg(feature = "camt")]
 	CreateLimitV02(Box<CreateLimitV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CretStgOrdr"))]
 	#[cfg(feature = "camt")]
 	CreateStandingOrderV03(Box<CreateStandingOrderV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CretRsvatn"))]
 	#[cfg(feature = "camt")]
 	CreateReservationV03(Box<CreateReservationV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CretMmb"))]
 	#[cfg(feature = "camt")]
 	CreateMemberV01(Box<CreateMemberV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ChrgsPmtNtfctn"))]
 	#[cfg(feature = "camt")]
 	ChargesPaymentNotificationV02(Box<ChargesPaymentNotificationV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ChrgsPmtReq"))]
 	#[cfg(feature = "camt")]
 	ChargesPaymentRequestV02(Box<ChargesPaymentRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ChqPresntmntNtfctn"))]
 	#[cfg(feature = "camt")]
 	ChequePresentmentNotificationV02(Box<ChequePresentmentNotificationV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ChqCxlOrStopReq"))]
 	#[cfg(feature = "camt")]
 	ChequeCancellationOrStopRequestV02(Box<ChequeCancellationOrStopRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "ChqCxlOrStopRpt"))]
 	#[cfg(feature = "camt")]
 	ChequeCancellationOrStopReportV02(Box<ChequeCancellationOrStopReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InvstgtnReq"))]
 	#[cfg(feature = "camt")]
 	InvestigationRequestV01(Box<InvestigationRequestV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InvstgtnRspn"))]
 	#[cfg(feature = "camt")]
 	InvestigationResponseV01(Box<InvestigationResponseV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InfReqOpng"))]
 	#[cfg(feature = "auth")]
 	InformationRequestOpeningV02(Box<InformationRequestOpeningV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InfReqRspn"))]
 	#[cfg(feature = "auth")]
 	InformationRequestResponseV02(Box<InformationRequestResponseV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InfReqStsChngNtfctn"))]
 	#[cfg(feature = "auth")]
 	InformationRequestStatusChangeNotificationV01(Box<InformationRequestStatusChangeNotificationV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MnyMktScrdMktSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	MoneyMarketSecuredMarketStatisticalReportV02(Box<MoneyMarketSecuredMarketStatisticalReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MnyMktUscrdMktSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	MoneyMarketUnsecuredMarketStatisticalReportV02(Box<MoneyMarketUnsecuredMarketStatisticalReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MnyMktFXSwpsSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	MoneyMarketForeignExchangeSwapsStatisticalReportV02(Box<MoneyMarketForeignExchangeSwapsStatisticalReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MnyMktOvrnghtIndxSwpsSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	MoneyMarketOvernightIndexSwapsStatisticalReportV02(Box<MoneyMarketOvernightIndexSwapsStatisticalReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgTxRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingTransactionReportV03(Box<FinancialInstrumentReportingTransactionReportV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgRefDataRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingReferenceDataReportV02(Box<FinancialInstrumentReportingReferenceDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnReq"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationRequestV04(Box<ContractRegistrationRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnConf"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationConfirmationV04(Box<ContractRegistrationConfirmationV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnClsrReq"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationClosureRequestV04(Box<ContractRegistrationClosureRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnAmdmntReq"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationAmendmentRequestV04(Box<ContractRegistrationAmendmentRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnStmt"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationStatementV04(Box<ContractRegistrationStatementV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CtrctRegnStmtReq"))]
 	#[cfg(feature = "auth")]
 	ContractRegistrationStatementRequestV04(Box<ContractRegistrationStatementRequestV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "PmtRgltryInfNtfctn"))]
 	#[cfg(feature = "auth")]
 	PaymentRegulatoryInformationNotificationV04(Box<PaymentRegulatoryInformationNotificationV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CcyCtrlSpprtgDocDlvry"))]
 	#[cfg(feature = "auth")]
 	CurrencyControlSupportingDocumentDeliveryV04(Box<CurrencyControlSupportingDocumentDeliveryV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CcyCtrlReqOrLttr"))]
 	#[cfg(feature = "auth")]
 	CurrencyControlRequestOrLetterV04(Box<CurrencyControlRequestOrLetterV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CcyCtrlStsAdvc"))]
 	#[cfg(feature = "auth")]
 	CurrencyControlStatusAdviceV04(Box<CurrencyControlStatusAdviceV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "MnyMktSttstclRptStsAdvc"))]
 	#[cfg(feature = "auth")]
 	MoneyMarketStatisticalReportStatusAdviceV01(Box<MoneyMarketStatisticalReportStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradRptQry"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeReportQueryV05(Box<DerivativesTradeReportQueryV05>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeReportV04(Box<DerivativesTradeReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgStsAdvc"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingStatusAdviceV01(Box<FinancialInstrumentReportingStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgEqtyTrnsprncyDataRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingEquityTransparencyDataReportV01(Box<FinancialInstrumentReportingEquityTransparencyDataReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgNonEqtyTrnsprncyDataRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingNonEquityTransparencyDataReportV03(Box<FinancialInstrumentReportingNonEquityTransparencyDataReportV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InvcTaxRpt"))]
 	#[cfg(feature = "auth")]
 	InvoiceTaxReportV01(Box<InvoiceTaxReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgTradgVolCapDataRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingTradingVolumeCapDataReportV01(Box<FinancialInstrumentReportingTradingVolumeCapDataReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgRefDataDltaRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingReferenceDataDeltaReportV03(Box<FinancialInstrumentReportingReferenceDataDeltaReportV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "InvcTaxRptStsAdvc"))]
 	#[cfg(feature = "auth")]
 	InvoiceTaxReportStatusAdviceV01(Box<InvoiceTaxReportStatusAdviceV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgNonWorkgDayRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingNonWorkingDayReportV01(Box<FinancialInstrumentReportingNonWorkingDayReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgEqtyTradgActvtyRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingEquityTradingActivityReportV01(Box<FinancialInstrumentReportingEquityTradingActivityReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgNonEqtyTradgActvtyRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingNonEquityTradingActivityReportV01(Box<FinancialInstrumentReportingNonEquityTradingActivityReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgInvldRefDataRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingInvalidReferenceDataReportV02(Box<FinancialInstrumentReportingInvalidReferenceDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgRefDataIndxRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingReferenceDataIndexReportV01(Box<FinancialInstrumentReportingReferenceDataIndexReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgEqtyTradgActvtyRslt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingEquityTradingActivityResultV02(Box<FinancialInstrumentReportingEquityTradingActivityResultV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgNonEqtyTradgActvtyRslt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingNonEquityTradingActivityResultV03(Box<FinancialInstrumentReportingNonEquityTradingActivityResultV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgCtryCdRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingCountryCodeReportV01(Box<FinancialInstrumentReportingCountryCodeReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgCcyCdRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingCurrencyCodeReportV01(Box<FinancialInstrumentReportingCurrencyCodeReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgMktIdCdRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingMarketIdentificationCodeReportV02(Box<FinancialInstrumentReportingMarketIdentificationCodeReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgInstrmClssfctnRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingInstrumentClassificationReportV01(Box<FinancialInstrumentReportingInstrumentClassificationReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionReportV02(Box<SecuritiesFinancingReportingTransactionReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgTradgVolCapRsltRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingTradingVolumeCapResultReportV01(Box<FinancialInstrumentReportingTradingVolumeCapResultReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPClrMmbRpt"))]
 	#[cfg(feature = "auth")]
 	CCPClearingMemberReportV01(Box<CCPClearingMemberReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPMmbRqrmntsRpt"))]
 	#[cfg(feature = "auth")]
 	CCPMemberRequirementsReportV01(Box<CCPMemberRequirementsReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPMmbOblgtnsRpt"))]
 	#[cfg(feature = "auth")]
 	CCPMemberObligationsReportV01(Box<CCPMemberObligationsReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPPrtflStrssTstgDefRpt"))]
 	#[cfg(feature = "auth")]
 	CCPPortfolioStressTestingDefinitionReportV02(Box<CCPPortfolioStressTestingDefinitionReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPPrtflStrssTstgRsltRpt"))]
 	#[cfg(feature = "auth")]
 	CCPPortfolioStressTestingResultReportV01(Box<CCPPortfolioStressTestingResultReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPIncmStmtAndCptlAdqcyRpt"))]
 	#[cfg(feature = "auth")]
 	CCPIncomeStatementAndCapitalAdequacyReportV01(Box<CCPIncomeStatementAndCapitalAdequacyReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPDalyCshFlowsRpt"))]
 	#[cfg(feature = "auth")]
 	CCPDailyCashFlowsReportV02(Box<CCPDailyCashFlowsReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPInvstmtsRpt"))]
 	#[cfg(feature = "auth")]
 	CCPInvestmentsReportV01(Box<CCPInvestmentsReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPLqdtyStrssTstgDefRpt"))]
 	#[cfg(feature = "auth")]
 	CCPLiquidityStressTestingDefinitionReportV01(Box<CCPLiquidityStressTestingDefinitionReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPLqdtyStrssTstgRsltRpt"))]
 	#[cfg(feature = "auth")]
 	CCPLiquidityStressTestingResultReportV01(Box<CCPLiquidityStressTestingResultReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPAvlblFinRsrcsRpt"))]
 	#[cfg(feature = "auth")]
 	CCPAvailableFinancialResourcesReportV01(Box<CCPAvailableFinancialResourcesReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPBckTstgDefRpt"))]
 	#[cfg(feature = "auth")]
 	CCPBackTestingDefinitionReportV01(Box<CCPBackTestingDefinitionReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPBckTstgRsltRpt"))]
 	#[cfg(feature = "auth")]
 	CCPBackTestingResultReportV01(Box<CCPBackTestingResultReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPCollRpt"))]
 	#[cfg(feature = "auth")]
 	CCPCollateralReportV01(Box<CCPCollateralReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPAcctPosRpt"))]
 	#[cfg(feature = "auth")]
 	CCPAccountPositionReportV01(Box<CCPAccountPositionReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPClrdPdctRpt"))]
 	#[cfg(feature = "auth")]
 	CCPClearedProductReportV01(Box<CCPClearedProductReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxMrgnDataRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionMarginDataReportV02(Box<SecuritiesFinancingReportingTransactionMarginDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxReusdCollDataRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(Box<SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmIntlrRpt"))]
 	#[cfg(feature = "auth")]
 	SettlementInternaliserReportV01(Box<SettlementInternaliserReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinSprvsdPtyIdntyRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialSupervisedPartyIdentityReportV01(Box<FinancialSupervisedPartyIdentityReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinBchmkRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialBenchmarkReportV01(Box<FinancialBenchmarkReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgPairgReq"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingPairingRequestV02(Box<SecuritiesFinancingReportingPairingRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxStatRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionStateReportV02(Box<SecuritiesFinancingReportingTransactionStateReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgRcncltnStsAdvc"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingReconciliationStatusAdviceV02(Box<SecuritiesFinancingReportingReconciliationStatusAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgMssngCollReq"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingMissingCollateralRequestV02(Box<SecuritiesFinancingReportingMissingCollateralRequestV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxStsAdvc"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionStatusAdviceV02(Box<SecuritiesFinancingReportingTransactionStatusAdviceV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgMrgnDataTxStatRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingMarginDataTransactionStateReportV02(Box<SecuritiesFinancingReportingMarginDataTransactionStateReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgReusdCollDataTxStatRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(Box<SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradPosSetRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradePositionSetReportV02(Box<DerivativesTradePositionSetReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradRcncltnSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeReconciliationStatisticalReportV03(Box<DerivativesTradeReconciliationStatisticalReportV03>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradRjctnSttstclRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeRejectionStatisticalReportV04(Box<DerivativesTradeRejectionStatisticalReportV04>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgTxQry"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingTransactionQueryV02(Box<SecuritiesFinancingReportingTransactionQueryV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmFlsMnthlyRpt"))]
 	#[cfg(feature = "auth")]
 	SettlementFailsMonthlyReportV01(Box<SettlementFailsMonthlyReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SttlmFlsAnlRpt"))]
 	#[cfg(feature = "auth")]
 	SettlementFailsAnnualReportV01(Box<SettlementFailsAnnualReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "FinInstrmRptgCxlRpt"))]
 	#[cfg(feature = "auth")]
 	FinancialInstrumentReportingCancellationReportV01(Box<FinancialInstrumentReportingCancellationReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "SctiesFincgRptgPosSetRpt"))]
 	#[cfg(feature = "auth")]
 	SecuritiesFinancingReportingPositionSetReportV01(Box<SecuritiesFinancingReportingPositionSetReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradWrnngsRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeWarningsReportV01(Box<DerivativesTradeWarningsReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradStatRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeStateReportV02(Box<DerivativesTradeStateReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradMrgnDataRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeMarginDataReportV02(Box<DerivativesTradeMarginDataReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "DerivsTradMrgnDataTxStatRpt"))]
 	#[cfg(feature = "auth")]
 	DerivativesTradeMarginDataTransactionStateReportV02(Box<DerivativesTradeMarginDataTransactionStateReportV02>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "CCPIntrprbltyRpt"))]
 	#[cfg(feature = "auth")]
 	CCPInteroperabilityReportV01(Box<CCPInteroperabilityReportV01>),
 
-	#[cfg_attr( feature = "derive_serde", serde(rename = "OrdrBookRpt"))]
 	#[cfg(feature = "auth")]
 	OrderBookReportV01(Box<OrderBookReportV01>),
 
-	#[cfg_attr(feature = "derive_default", default)]
-	UNKNOWN,
+	/// A message that is not compiled in, or disabled by a feature flag.
+	Unknown(Box<UnknownMessage>),
+}
+
+/// A message without a typed representation, kept as found so it can still be routed,
+/// archived or forwarded. It serializes as a struct of its three fields.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct UnknownMessage {
+	/// The `xmlns` namespace of the `<Document>` element.
+	pub namespace: Option<String>,
+	/// The name of the message element wrapped by `<Document>`.
+	pub root_element: String,
+	/// The `<Document>` element as read by [`Document::from_xml_str`] and [`Document::from_reader`],
+	/// from its start tag to its end tag: an XML declaration, comments or an envelope around it
+	/// are not kept. Empty when the message was deserialized by other means.
+	pub raw_xml: String,
+}
+
+/// An empty [`Document::Unknown`], with no XML to write: `to_xml_string` fails with
+/// `Error::UnknownDocument` until a message is set.
+#[cfg(feature = "derive_default")]
+impl Default for Document {
+    fn default() -> Self {
+        Document::Unknown(Box::default())
+    }
 }
 
 impl Document {
     pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&mut Validator::fail_fast())
+    }
+
+    /// Validates the whole message and returns every violation instead of stopping at the first one.
+    pub fn validate_all(&self) -> ValidationReport {
+        ValidationReport::collect(|validator| self.validate_with(validator))
+    }
+
+    /// Validates the message, errors are located below `/Document/<root element>`.
+    /// A validator built with [`Validator::with_identifiers`] also checks every IBAN, BIC, LEI and UETR of the message,
+    /// one built with [`Validator::with_rules`] also its cross-field rules, see [`Document::validate_rules`].
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        let root = self.root_element().unwrap_or_default();
+        validator.element("Document", "", |validator| {
+            validator.element(root, "", |validator| {
+                self.validate_message(validator)?;
+                if validator.checks_rules() {
+                    self.validate_message_rules(validator)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    /// Checks only the cross-field rules of the message definition, e.g. that the `NbOfTxs` of a
+    /// pacs.008 group header counts its `CdtTrfTxInf`. Messages without such rules pass.
+    pub fn validate_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        let root = self.root_element().unwrap_or_default();
+        validator.element("Document", "", |validator| {
+            validator.element(root, "", |validator| self.validate_message_rules(validator))
+        })
+    }
+
+    fn validate_message(&self, validator: &mut Validator) -> Result<(), ValidationError> {
         match self {
 			#[cfg(feature = "admi")]
-			Document::Admi00200101(ref value) => value.validate(),
+			Document::Admi00200101(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::SystemEventNotificationV02(ref value) => value.validate(),
+			Document::SystemEventNotificationV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::ReportQueryRequestV02(ref value) => value.validate(),
+			Document::ReportQueryRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::ResendRequestV01(ref value) => value.validate(),
+			Document::ResendRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::ReceiptAcknowledgementV01(ref value) => value.validate(),
+			Document::ReceiptAcknowledgementV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::StaticDataRequestV02(ref value) => value.validate(),
+			Document::StaticDataRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::StaticDataReportV02(ref value) => value.validate(),
+			Document::StaticDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::SystemEventAcknowledgementV01(ref value) => value.validate(),
+			Document::SystemEventAcknowledgementV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::ProcessingRequestV02(ref value) => value.validate(),
+			Document::ProcessingRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "admi")]
-			Document::NotificationOfCorrespondenceV01(ref value) => value.validate(),
+			Document::NotificationOfCorrespondenceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFIPaymentStatusReportV12(ref value) => value.validate(),
+			Document::FIToFIPaymentStatusReportV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFIPaymentStatusReportV14(ref value) => value.validate(),
+			Document::FIToFIPaymentStatusReportV14(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFICustomerDirectDebitV11(ref value) => value.validate(),
+			Document::FIToFICustomerDirectDebitV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::PaymentReturnV13(ref value) => value.validate(),
+			Document::PaymentReturnV13(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFIPaymentReversalV13(ref value) => value.validate(),
+			Document::FIToFIPaymentReversalV13(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFICustomerCreditTransferV12(ref value) => value.validate(),
+			Document::FIToFICustomerCreditTransferV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FinancialInstitutionCreditTransferV11(ref value) => value.validate(),
+			Document::FinancialInstitutionCreditTransferV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FinancialInstitutionDirectDebitV06(ref value) => value.validate(),
+			Document::FinancialInstitutionDirectDebitV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::FIToFIPaymentStatusRequestV06(ref value) => value.validate(),
+			Document::FIToFIPaymentStatusRequestV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pacs")]
-			Document::MultilateralSettlementRequestV02(ref value) => value.validate(),
+			Document::MultilateralSettlementRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CustomerCreditTransferInitiationV12(ref value) => value.validate(),
+			Document::CustomerCreditTransferInitiationV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CustomerPaymentStatusReportV14(ref value) => value.validate(),
+			Document::CustomerPaymentStatusReportV14(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CustomerPaymentReversalV12(ref value) => value.validate(),
+			Document::CustomerPaymentReversalV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CustomerDirectDebitInitiationV11(ref value) => value.validate(),
+			Document::CustomerDirectDebitInitiationV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateInitiationRequestV08(ref value) => value.validate(),
+			Document::MandateInitiationRequestV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateAmendmentRequestV08(ref value) => value.validate(),
+			Document::MandateAmendmentRequestV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateCancellationRequestV08(ref value) => value.validate(),
+			Document::MandateCancellationRequestV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateAcceptanceReportV08(ref value) => value.validate(),
+			Document::MandateAcceptanceReportV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CreditorPaymentActivationRequestV11(ref value) => value.validate(),
+			Document::CreditorPaymentActivationRequestV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::CreditorPaymentActivationRequestStatusReportV11(ref value) => value.validate(),
+			Document::CreditorPaymentActivationRequestStatusReportV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateCopyRequestV04(ref value) => value.validate(),
+			Document::MandateCopyRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "pain")]
-			Document::MandateSuspensionRequestV04(ref value) => value.validate(),
+			Document::MandateSuspensionRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountOpeningInstructionV08(ref value) => value.validate(),
+			Document::AccountOpeningInstructionV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountDetailsConfirmationV08(ref value) => value.validate(),
+			Document::AccountDetailsConfirmationV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountModificationInstructionV08(ref value) => value.validate(),
+			Document::AccountModificationInstructionV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::RequestForAccountManagementStatusReportV06(ref value) => value.validate(),
+			Document::RequestForAccountManagementStatusReportV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountManagementStatusReportV07(ref value) => value.validate(),
+			Document::AccountManagementStatusReportV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountOpeningRequestV05(ref value) => value.validate(),
+			Document::AccountOpeningRequestV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountOpeningAmendmentRequestV05(ref value) => value.validate(),
+			Document::AccountOpeningAmendmentRequestV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountOpeningAdditionalInformationRequestV04(ref value) => value.validate(),
+			Document::AccountOpeningAdditionalInformationRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountRequestAcknowledgementV04(ref value) => value.validate(),
+			Document::AccountRequestAcknowledgementV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountRequestRejectionV04(ref value) => value.validate(),
+			Document::AccountRequestRejectionV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountAdditionalInformationRequestV04(ref value) => value.validate(),
+			Document::AccountAdditionalInformationRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountReportRequestV04(ref value) => value.validate(),
+			Document::AccountReportRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountReportV05(ref value) => value.validate(),
+			Document::AccountReportV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountExcludedMandateMaintenanceRequestV04(ref value) => value.validate(),
+			Document::AccountExcludedMandateMaintenanceRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(ref value) => value.validate(),
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountMandateMaintenanceRequestV04(ref value) => value.validate(),
+			Document::AccountMandateMaintenanceRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountMandateMaintenanceAmendmentRequestV04(ref value) => value.validate(),
+			Document::AccountMandateMaintenanceAmendmentRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountClosingRequestV04(ref value) => value.validate(),
+			Document::AccountClosingRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountClosingAmendmentRequestV04(ref value) => value.validate(),
+			Document::AccountClosingAmendmentRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountClosingAdditionalInformationRequestV04(ref value) => value.validate(),
+			Document::AccountClosingAdditionalInformationRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::IdentificationModificationAdviceV04(ref value) => value.validate(),
+			Document::IdentificationModificationAdviceV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::IdentificationVerificationRequestV04(ref value) => value.validate(),
+			Document::IdentificationVerificationRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::IdentificationVerificationReportV04(ref value) => value.validate(),
+			Document::IdentificationVerificationReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchInformationRequestV05(ref value) => value.validate(),
+			Document::AccountSwitchInformationRequestV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchInformationResponseV05(ref value) => value.validate(),
+			Document::AccountSwitchInformationResponseV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchCancelExistingPaymentV05(ref value) => value.validate(),
+			Document::AccountSwitchCancelExistingPaymentV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchRequestRedirectionV04(ref value) => value.validate(),
+			Document::AccountSwitchRequestRedirectionV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchRequestBalanceTransferV05(ref value) => value.validate(),
+			Document::AccountSwitchRequestBalanceTransferV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchBalanceTransferAcknowledgementV05(ref value) => value.validate(),
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchNotifyAccountSwitchCompleteV02(ref value) => value.validate(),
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchRequestPaymentV05(ref value) => value.validate(),
+			Document::AccountSwitchRequestPaymentV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchPaymentResponseV02(ref value) => value.validate(),
+			Document::AccountSwitchPaymentResponseV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchTerminationSwitchV01(ref value) => value.validate(),
+			Document::AccountSwitchTerminationSwitchV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "acmt")]
-			Document::AccountSwitchTechnicalRejectionV02(ref value) => value.validate(),
+			Document::AccountSwitchTechnicalRejectionV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PriceReportV04(ref value) => value.validate(),
+			Document::PriceReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PriceReportCancellationV04(ref value) => value.validate(),
+			Document::PriceReportCancellationV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::FundReferenceDataReportV07(ref value) => value.validate(),
+			Document::FundReferenceDataReportV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::InvestmentFundReportRequestV03(ref value) => value.validate(),
+			Document::InvestmentFundReportRequestV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityCreationRequestV01(ref value) => value.validate(),
+			Document::SecurityCreationRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityMaintenanceRequestV01(ref value) => value.validate(),
+			Document::SecurityMaintenanceRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityCreationStatusAdviceV01(ref value) => value.validate(),
+			Document::SecurityCreationStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityActivityAdviceV01(ref value) => value.validate(),
+			Document::SecurityActivityAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityQueryV01(ref value) => value.validate(),
+			Document::SecurityQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityReportV01(ref value) => value.validate(),
+			Document::SecurityReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityDeletionRequestV01(ref value) => value.validate(),
+			Document::SecurityDeletionRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyCreationRequestV02(ref value) => value.validate(),
+			Document::PartyCreationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyQueryV01(ref value) => value.validate(),
+			Document::PartyQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyStatusAdviceV01(ref value) => value.validate(),
+			Document::PartyStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyReportV02(ref value) => value.validate(),
+			Document::PartyReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountCreationRequestV01(ref value) => value.validate(),
+			Document::SecuritiesAccountCreationRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountQueryV01(ref value) => value.validate(),
+			Document::SecuritiesAccountQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountStatusAdviceV01(ref value) => value.validate(),
+			Document::SecuritiesAccountStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountReportV01(ref value) => value.validate(),
+			Document::SecuritiesAccountReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyModificationRequestV02(ref value) => value.validate(),
+			Document::PartyModificationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountModificationRequestV01(ref value) => value.validate(),
+			Document::SecuritiesAccountModificationRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityMaintenanceStatusAdviceV01(ref value) => value.validate(),
+			Document::SecurityMaintenanceStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecurityDeletionStatusAdviceV01(ref value) => value.validate(),
+			Document::SecurityDeletionStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyDeletionRequestV01(ref value) => value.validate(),
+			Document::PartyDeletionRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountDeletionRequestV01(ref value) => value.validate(),
+			Document::SecuritiesAccountDeletionRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAuditTrailQueryV01(ref value) => value.validate(),
+			Document::SecuritiesAuditTrailQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAuditTrailReportV01(ref value) => value.validate(),
+			Document::SecuritiesAuditTrailReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountActivityAdviceV01(ref value) => value.validate(),
+			Document::SecuritiesAccountActivityAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountAuditTrailQueryV01(ref value) => value.validate(),
+			Document::SecuritiesAccountAuditTrailQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::SecuritiesAccountAuditTrailReportV01(ref value) => value.validate(),
+			Document::SecuritiesAccountAuditTrailReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyActivityAdviceV02(ref value) => value.validate(),
+			Document::PartyActivityAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyAuditTrailQueryV01(ref value) => value.validate(),
+			Document::PartyAuditTrailQueryV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::PartyAuditTrailReportV02(ref value) => value.validate(),
+			Document::PartyAuditTrailReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::StandingSettlementInstructionV01(ref value) => value.validate(),
+			Document::StandingSettlementInstructionV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::StandingSettlementInstructionDeletionV01(ref value) => value.validate(),
+			Document::StandingSettlementInstructionDeletionV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::StandingSettlementInstructionStatusAdviceV01(ref value) => value.validate(),
+			Document::StandingSettlementInstructionStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::StandingSettlementInstructionCancellationV01(ref value) => value.validate(),
+			Document::StandingSettlementInstructionCancellationV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::NettingCutOffReferenceDataUpdateRequestV02(ref value) => value.validate(),
+			Document::NettingCutOffReferenceDataUpdateRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::NettingCutOffReferenceDataReportV02(ref value) => value.validate(),
+			Document::NettingCutOffReferenceDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::CalendarQueryV02(ref value) => value.validate(),
+			Document::CalendarQueryV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::CalendarReportV02(ref value) => value.validate(),
+			Document::CalendarReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayCreditorEnrolmentRequestV02(ref value) => value.validate(),
+			Document::RequestToPayCreditorEnrolmentRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(ref value) => value.validate(),
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(ref value) => value.validate(),
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayCreditorEnrolmentStatusReportV02(ref value) => value.validate(),
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayDebtorActivationRequestV02(ref value) => value.validate(),
+			Document::RequestToPayDebtorActivationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayDebtorActivationAmendmentRequestV02(ref value) => value.validate(),
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayDebtorActivationCancellationRequestV02(ref value) => value.validate(),
+			Document::RequestToPayDebtorActivationCancellationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "reda")]
-			Document::RequestToPayDebtorActivationStatusReportV02(ref value) => value.validate(),
+			Document::RequestToPayDebtorActivationStatusReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "remt")]
-			Document::RemittanceAdviceV06(ref value) => value.validate(),
+			Document::RemittanceAdviceV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "remt")]
-			Document::RemittanceLocationAdviceV03(ref value) => value.validate(),
+			Document::RemittanceLocationAdviceV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetAccountV08(ref value) => value.validate(),
+			Document::GetAccountV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnAccountV10(ref value) => value.validate(),
+			Document::ReturnAccountV10(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetTransactionV11(ref value) => value.validate(),
+			Document::GetTransactionV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnTransactionV11(ref value) => value.validate(),
+			Document::ReturnTransactionV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ModifyTransactionV10(ref value) => value.validate(),
+			Document::ModifyTransactionV10(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CancelTransactionV11(ref value) => value.validate(),
+			Document::CancelTransactionV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetLimitV08(ref value) => value.validate(),
+			Document::GetLimitV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnLimitV09(ref value) => value.validate(),
+			Document::ReturnLimitV09(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ModifyLimitV08(ref value) => value.validate(),
+			Document::ModifyLimitV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DeleteLimitV08(ref value) => value.validate(),
+			Document::DeleteLimitV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetMemberV04(ref value) => value.validate(),
+			Document::GetMemberV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnMemberV05(ref value) => value.validate(),
+			Document::ReturnMemberV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ModifyMemberV04(ref value) => value.validate(),
+			Document::ModifyMemberV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetCurrencyExchangeRateV04(ref value) => value.validate(),
+			Document::GetCurrencyExchangeRateV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnCurrencyExchangeRateV05(ref value) => value.validate(),
+			Document::ReturnCurrencyExchangeRateV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetBusinessDayInformationV05(ref value) => value.validate(),
+			Document::GetBusinessDayInformationV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnBusinessDayInformationV07(ref value) => value.validate(),
+			Document::ReturnBusinessDayInformationV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetGeneralBusinessInformationV04(ref value) => value.validate(),
+			Document::GetGeneralBusinessInformationV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnGeneralBusinessInformationV06(ref value) => value.validate(),
+			Document::ReturnGeneralBusinessInformationV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::BackupPaymentV07(ref value) => value.validate(),
+			Document::BackupPaymentV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ModifyStandingOrderV08(ref value) => value.validate(),
+			Document::ModifyStandingOrderV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReceiptV08(ref value) => value.validate(),
+			Document::ReceiptV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::UnableToApplyV10(ref value) => value.validate(),
+			Document::UnableToApplyV10(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ClaimNonReceiptV10(ref value) => value.validate(),
+			Document::ClaimNonReceiptV10(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::AdditionalPaymentInformationV12(ref value) => value.validate(),
+			Document::AdditionalPaymentInformationV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ResolutionOfInvestigationV13(ref value) => value.validate(),
+			Document::ResolutionOfInvestigationV13(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::NotificationOfCaseAssignmentV06(ref value) => value.validate(),
+			Document::NotificationOfCaseAssignmentV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::RejectInvestigationV07(ref value) => value.validate(),
+			Document::RejectInvestigationV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CancelCaseAssignmentV05(ref value) => value.validate(),
+			Document::CancelCaseAssignmentV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::RequestForDuplicateV07(ref value) => value.validate(),
+			Document::RequestForDuplicateV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DuplicateV07(ref value) => value.validate(),
+			Document::DuplicateV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ProprietaryFormatInvestigationV06(ref value) => value.validate(),
+			Document::ProprietaryFormatInvestigationV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DebitAuthorisationResponseV06(ref value) => value.validate(),
+			Document::DebitAuthorisationResponseV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DebitAuthorisationRequestV10(ref value) => value.validate(),
+			Document::DebitAuthorisationRequestV10(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CaseStatusReportRequestV05(ref value) => value.validate(),
+			Document::CaseStatusReportRequestV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CaseStatusReportV06(ref value) => value.validate(),
+			Document::CaseStatusReportV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundEstimatedCashForecastReportV04(ref value) => value.validate(),
+			Document::FundEstimatedCashForecastReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundConfirmedCashForecastReportV04(ref value) => value.validate(),
+			Document::FundConfirmedCashForecastReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundDetailedEstimatedCashForecastReportV04(ref value) => value.validate(),
+			Document::FundDetailedEstimatedCashForecastReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundDetailedConfirmedCashForecastReportV04(ref value) => value.validate(),
+			Document::FundDetailedConfirmedCashForecastReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundConfirmedCashForecastReportCancellationV03(ref value) => value.validate(),
+			Document::FundConfirmedCashForecastReportCancellationV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FundDetailedConfirmedCashForecastReportCancellationV03(ref value) => value.validate(),
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetReservationV08(ref value) => value.validate(),
+			Document::GetReservationV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnReservationV08(ref value) => value.validate(),
+			Document::ReturnReservationV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ModifyReservationV07(ref value) => value.validate(),
+			Document::ModifyReservationV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DeleteReservationV07(ref value) => value.validate(),
+			Document::DeleteReservationV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::LiquidityCreditTransferV07(ref value) => value.validate(),
+			Document::LiquidityCreditTransferV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::LiquidityDebitTransferV07(ref value) => value.validate(),
+			Document::LiquidityDebitTransferV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::BankToCustomerAccountReportV12(ref value) => value.validate(),
+			Document::BankToCustomerAccountReportV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::BankToCustomerStatementV12(ref value) => value.validate(),
+			Document::BankToCustomerStatementV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::BankToCustomerDebitCreditNotificationV12(ref value) => value.validate(),
+			Document::BankToCustomerDebitCreditNotificationV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CustomerPaymentCancellationRequestV12(ref value) => value.validate(),
+			Document::CustomerPaymentCancellationRequestV12(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::FIToFIPaymentCancellationRequestV11(ref value) => value.validate(),
+			Document::FIToFIPaymentCancellationRequestV11(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::NotificationToReceiveV08(ref value) => value.validate(),
+			Document::NotificationToReceiveV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::NotificationToReceiveCancellationAdviceV09(ref value) => value.validate(),
+			Document::NotificationToReceiveCancellationAdviceV09(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::NotificationToReceiveStatusReportV08(ref value) => value.validate(),
+			Document::NotificationToReceiveStatusReportV08(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::AccountReportingRequestV07(ref value) => value.validate(),
+			Document::AccountReportingRequestV07(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::PayInCallV02(ref value) => value.validate(),
+			Document::PayInCallV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::PayInScheduleV03(ref value) => value.validate(),
+			Document::PayInScheduleV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::PayInEventAcknowledgementV02(ref value) => value.validate(),
+			Document::PayInEventAcknowledgementV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementInstructionV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementInstructionV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementStatusAdviceV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementStatusAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementConfirmationV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementConfirmationV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::GetStandingOrderV05(ref value) => value.validate(),
+			Document::GetStandingOrderV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ReturnStandingOrderV06(ref value) => value.validate(),
+			Document::ReturnStandingOrderV06(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::DeleteStandingOrderV05(ref value) => value.validate(),
+			Document::DeleteStandingOrderV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementModificationRequestV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementModificationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementCancellationRequestV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementCancellationRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementQueryV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementQueryV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementQueryResponseV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementQueryResponseV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementModificationQueryV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementModificationQueryV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementModificationReportV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementModificationReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementCancellationQueryV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementCancellationQueryV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementCancellationReportV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementCancellationReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementPostingReportV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementPostingReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::IntraBalanceMovementPendingReportV02(ref value) => value.validate(),
+			Document::IntraBalanceMovementPendingReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::BankServicesBillingStatementV05(ref value) => value.validate(),
+			Document::BankServicesBillingStatementV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::RequestToModifyPaymentV09(ref value) => value.validate(),
+			Document::RequestToModifyPaymentV09(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::NetReportV02(ref value) => value.validate(),
+			Document::NetReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CreateLimitV02(ref value) => value.validate(),
+			Document::CreateLimitV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CreateStandingOrderV03(ref value) => value.validate(),
+			Document::CreateStandingOrderV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CreateReservationV03(ref value) => value.validate(),
+			Document::CreateReservationV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::CreateMemberV01(ref value) => value.validate(),
+			Document::CreateMemberV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ChargesPaymentNotificationV02(ref value) => value.validate(),
+			Document::ChargesPaymentNotificationV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ChargesPaymentRequestV02(ref value) => value.validate(),
+			Document::ChargesPaymentRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ChequePresentmentNotificationV02(ref value) => value.validate(),
+			Document::ChequePresentmentNotificationV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ChequeCancellationOrStopRequestV02(ref value) => value.validate(),
+			Document::ChequeCancellationOrStopRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::ChequeCancellationOrStopReportV02(ref value) => value.validate(),
+			Document::ChequeCancellationOrStopReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::InvestigationRequestV01(ref value) => value.validate(),
+			Document::InvestigationRequestV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "camt")]
-			Document::InvestigationResponseV01(ref value) => value.validate(),
+			Document::InvestigationResponseV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::InformationRequestOpeningV02(ref value) => value.validate(),
+			Document::InformationRequestOpeningV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::InformationRequestResponseV02(ref value) => value.validate(),
+			Document::InformationRequestResponseV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::InformationRequestStatusChangeNotificationV01(ref value) => value.validate(),
+			Document::InformationRequestStatusChangeNotificationV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::MoneyMarketSecuredMarketStatisticalReportV02(ref value) => value.validate(),
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(ref value) => value.validate(),
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(ref value) => value.validate(),
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(ref value) => value.validate(),
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingTransactionReportV03(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingTransactionReportV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingReferenceDataReportV02(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingReferenceDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationRequestV04(ref value) => value.validate(),
+			Document::ContractRegistrationRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationConfirmationV04(ref value) => value.validate(),
+			Document::ContractRegistrationConfirmationV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationClosureRequestV04(ref value) => value.validate(),
+			Document::ContractRegistrationClosureRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationAmendmentRequestV04(ref value) => value.validate(),
+			Document::ContractRegistrationAmendmentRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationStatementV04(ref value) => value.validate(),
+			Document::ContractRegistrationStatementV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::ContractRegistrationStatementRequestV04(ref value) => value.validate(),
+			Document::ContractRegistrationStatementRequestV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::PaymentRegulatoryInformationNotificationV04(ref value) => value.validate(),
+			Document::PaymentRegulatoryInformationNotificationV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CurrencyControlSupportingDocumentDeliveryV04(ref value) => value.validate(),
+			Document::CurrencyControlSupportingDocumentDeliveryV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CurrencyControlRequestOrLetterV04(ref value) => value.validate(),
+			Document::CurrencyControlRequestOrLetterV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CurrencyControlStatusAdviceV04(ref value) => value.validate(),
+			Document::CurrencyControlStatusAdviceV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::MoneyMarketStatisticalReportStatusAdviceV01(ref value) => value.validate(),
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeReportQueryV05(ref value) => value.validate(),
+			Document::DerivativesTradeReportQueryV05(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeReportV04(ref value) => value.validate(),
+			Document::DerivativesTradeReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingStatusAdviceV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::InvoiceTaxReportV01(ref value) => value.validate(),
+			Document::InvoiceTaxReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::InvoiceTaxReportStatusAdviceV01(ref value) => value.validate(),
+			Document::InvoiceTaxReportStatusAdviceV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingNonWorkingDayReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingCountryCodeReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingCountryCodeReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingCurrencyCodeReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPClearingMemberReportV01(ref value) => value.validate(),
+			Document::CCPClearingMemberReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPMemberRequirementsReportV01(ref value) => value.validate(),
+			Document::CCPMemberRequirementsReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPMemberObligationsReportV01(ref value) => value.validate(),
+			Document::CCPMemberObligationsReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPPortfolioStressTestingDefinitionReportV02(ref value) => value.validate(),
+			Document::CCPPortfolioStressTestingDefinitionReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPPortfolioStressTestingResultReportV01(ref value) => value.validate(),
+			Document::CCPPortfolioStressTestingResultReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(ref value) => value.validate(),
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPDailyCashFlowsReportV02(ref value) => value.validate(),
+			Document::CCPDailyCashFlowsReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPInvestmentsReportV01(ref value) => value.validate(),
+			Document::CCPInvestmentsReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPLiquidityStressTestingDefinitionReportV01(ref value) => value.validate(),
+			Document::CCPLiquidityStressTestingDefinitionReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPLiquidityStressTestingResultReportV01(ref value) => value.validate(),
+			Document::CCPLiquidityStressTestingResultReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPAvailableFinancialResourcesReportV01(ref value) => value.validate(),
+			Document::CCPAvailableFinancialResourcesReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPBackTestingDefinitionReportV01(ref value) => value.validate(),
+			Document::CCPBackTestingDefinitionReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPBackTestingResultReportV01(ref value) => value.validate(),
+			Document::CCPBackTestingResultReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPCollateralReportV01(ref value) => value.validate(),
+			Document::CCPCollateralReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPAccountPositionReportV01(ref value) => value.validate(),
+			Document::CCPAccountPositionReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPClearedProductReportV01(ref value) => value.validate(),
+			Document::CCPClearedProductReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SettlementInternaliserReportV01(ref value) => value.validate(),
+			Document::SettlementInternaliserReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialSupervisedPartyIdentityReportV01(ref value) => value.validate(),
+			Document::FinancialSupervisedPartyIdentityReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialBenchmarkReportV01(ref value) => value.validate(),
+			Document::FinancialBenchmarkReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingPairingRequestV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingPairingRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionStateReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradePositionSetReportV02(ref value) => value.validate(),
+			Document::DerivativesTradePositionSetReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeReconciliationStatisticalReportV03(ref value) => value.validate(),
+			Document::DerivativesTradeReconciliationStatisticalReportV03(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeRejectionStatisticalReportV04(ref value) => value.validate(),
+			Document::DerivativesTradeRejectionStatisticalReportV04(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingTransactionQueryV02(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingTransactionQueryV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SettlementFailsMonthlyReportV01(ref value) => value.validate(),
+			Document::SettlementFailsMonthlyReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SettlementFailsAnnualReportV01(ref value) => value.validate(),
+			Document::SettlementFailsAnnualReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::FinancialInstrumentReportingCancellationReportV01(ref value) => value.validate(),
+			Document::FinancialInstrumentReportingCancellationReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::SecuritiesFinancingReportingPositionSetReportV01(ref value) => value.validate(),
+			Document::SecuritiesFinancingReportingPositionSetReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeWarningsReportV01(ref value) => value.validate(),
+			Document::DerivativesTradeWarningsReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeStateReportV02(ref value) => value.validate(),
+			Document::DerivativesTradeStateReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeMarginDataReportV02(ref value) => value.validate(),
+			Document::DerivativesTradeMarginDataReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::DerivativesTradeMarginDataTransactionStateReportV02(ref value) => value.validate(),
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::CCPInteroperabilityReportV01(ref value) => value.validate(),
+			Document::CCPInteroperabilityReportV01(ref value) => value.validate_with(validator),
 			#[cfg(feature = "auth")]
-			Document::OrderBookReportV01(ref value) => value.validate(),
-			Document::UNKNOWN => {
-                // Return an error for the UNKNOWN case
-                Err(ValidationError::new(9999, "Unknown document type".to_string()))
+			Document::OrderBookReportV01(ref value) => value.validate_with(validator),
+			Document::Unknown(ref value) => {
+                validator.report(ValidationError::unknown_document(&value.root_element))
             }
         }
     }
-}
\ No newline at end of file
+
+    fn validate_message_rules(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        match self {
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(ref value) => value.validate_rules(validator),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(ref value) => value.validate_rules(validator),
+			_ => Ok(()),
+        }
+    }
+
+    /// Returns the XML namespace (`urn:iso:std:iso:20022:tech:xsd:...`) of the message
+    /// held by this document, or `None` for an unknown document without namespace.
+    pub fn namespace(&self) -> Option<&str> {
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.002.001.01"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.004.001.02"),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.005.001.02"),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.006.001.01"),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.007.001.01"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.009.001.02"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.010.001.02"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.011.001.01"),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.017.001.02"),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:admi.024.001.01"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.002.001.12"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.002.001.14"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.003.001.11"),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.004.001.13"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.007.001.13"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.009.001.11"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.010.001.06"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.028.001.06"),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:pacs.029.001.02"),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.001.001.12"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.002.001.14"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.007.001.12"),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.008.001.11"),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.009.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.010.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.011.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.012.001.08"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.013.001.11"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.014.001.11"),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.017.001.04"),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:pain.018.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.001.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.002.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.003.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.005.001.06"),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.006.001.07"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.007.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.008.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.009.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.010.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.011.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.012.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.013.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.014.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.015.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.016.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.017.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.018.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.019.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.020.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.021.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.022.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.023.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.024.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.027.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.028.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.029.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.030.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.031.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.032.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.033.001.02"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.034.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.035.001.02"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.036.001.01"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:acmt.037.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.001.001.04"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.002.001.04"),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.004.001.07"),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.005.001.03"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.006.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.007.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.008.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.009.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.010.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.012.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.013.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.014.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.015.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.016.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.017.001.02"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.018.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.019.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.020.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.021.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.022.001.02"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.023.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.029.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.030.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.031.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.032.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.033.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.034.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.035.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.036.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.037.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.041.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.042.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.043.001.02"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.056.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.057.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.058.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.059.001.01"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.060.001.02"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.061.001.02"),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.064.001.02"),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.065.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.066.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.067.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.068.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.069.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.070.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.071.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.072.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:reda.073.001.02"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:remt.001.001.06"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:remt.002.001.03"),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.003.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.004.001.10"),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.005.001.11"),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.006.001.11"),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.007.001.10"),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.008.001.11"),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.009.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.010.001.09"),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.011.001.08"),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.012.001.08"),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.013.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.014.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.015.001.04"),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.016.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.017.001.05"),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.018.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.019.001.07"),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.020.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.021.001.06"),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.023.001.07"),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.024.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.025.001.08"),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.026.001.10"),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.027.001.10"),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.028.001.12"),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.029.001.13"),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.030.001.06"),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.031.001.07"),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.032.001.05"),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.033.001.07"),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.034.001.07"),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.035.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.036.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.037.001.10"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.038.001.05"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.039.001.06"),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.040.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.041.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.042.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.043.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.044.001.03"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.045.001.03"),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.046.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.047.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.048.001.07"),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.049.001.07"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.050.001.07"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.051.001.07"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.052.001.12"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.053.001.12"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.054.001.12"),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.055.001.12"),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.056.001.11"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.057.001.08"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.058.001.09"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.059.001.08"),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.060.001.07"),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.061.001.02"),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.062.001.03"),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.063.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.066.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.067.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.068.001.02"),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.069.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.070.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.071.001.05"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.072.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.073.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.074.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.075.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.078.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.079.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.080.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.081.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.082.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.083.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.084.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.085.001.02"),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.086.001.05"),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.087.001.09"),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.088.001.02"),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.101.001.02"),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.102.001.03"),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.103.001.03"),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.104.001.01"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.105.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.106.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.107.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.108.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.109.001.02"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.110.001.01"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:camt.111.001.01"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.001.001.02"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.002.001.02"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.003.001.01"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.012.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.013.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.014.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.015.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.016.001.03"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.017.001.02"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.018.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.019.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.020.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.021.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.022.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.023.001.04"),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.024.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.025.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.026.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.027.001.04"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.028.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.029.001.05"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.030.001.04"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.031.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.032.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.033.001.03"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.034.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.035.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.036.001.03"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.038.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.039.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.040.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.041.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.042.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.043.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.044.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.045.001.03"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.047.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.048.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.049.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.050.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.052.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.053.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.054.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.055.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.056.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.057.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.058.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.059.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.060.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.061.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.062.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.063.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.064.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.065.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.066.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.067.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.068.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.069.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.070.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.071.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.072.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.076.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.077.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.078.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.079.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.080.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.083.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.084.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.085.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.086.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.090.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.091.001.03"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.092.001.04"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.094.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.100.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.101.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.102.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.105.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.106.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.107.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.108.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.109.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.112.001.01"),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(_) => Some("urn:iso:std:iso:20022:tech:xsd:auth.113.001.01"),
+			Document::Unknown(ref value) => value.namespace.as_deref(),
+        }
+    }
+
+    /// Returns the identifier of the message, e.g. `pacs.008.001.12`, taken from its namespace.
+    pub fn message_identifier(&self) -> Option<MessageIdentifier> {
+        self.namespace()?.parse().ok()
+    }
+
+    /// Returns the name of the message root element wrapped by `<Document>`,
+    /// or `None` for a default document.
+    pub fn root_element(&self) -> Option<&str> {
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(_) => Some("admi.002.001.01"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(_) => Some("SysEvtNtfctn"),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(_) => Some("RptQryReq"),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(_) => Some("RsndReq"),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(_) => Some("RctAck"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(_) => Some("StatcDataReq"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(_) => Some("StatcDataRpt"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(_) => Some("SysEvtAck"),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(_) => Some("PrcgReq"),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(_) => Some("NtfctnOfCrspdc"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(_) => Some("FIToFIPmtStsRpt"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(_) => Some("FIToFIPmtStsRpt"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(_) => Some("FIToFICstmrDrctDbt"),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(_) => Some("PmtRtr"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(_) => Some("FIToFIPmtRvsl"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(_) => Some("FIToFICstmrCdtTrf"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(_) => Some("FICdtTrf"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(_) => Some("FIDrctDbt"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(_) => Some("FIToFIPmtStsReq"),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(_) => Some("MulSttlmReq"),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(_) => Some("CstmrCdtTrfInitn"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(_) => Some("CstmrPmtStsRpt"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(_) => Some("CstmrPmtRvsl"),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(_) => Some("CstmrDrctDbtInitn"),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(_) => Some("MndtInitnReq"),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(_) => Some("MndtAmdmntReq"),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(_) => Some("MndtCxlReq"),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(_) => Some("MndtAccptncRpt"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(_) => Some("CdtrPmtActvtnReq"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(_) => Some("CdtrPmtActvtnReqStsRpt"),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(_) => Some("MndtCpyReq"),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(_) => Some("MndtSspnsnReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(_) => Some("AcctOpngInstr"),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(_) => Some("AcctDtlsConf"),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(_) => Some("AcctModInstr"),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(_) => Some("ReqForAcctMgmtStsRpt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(_) => Some("AcctMgmtStsRpt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(_) => Some("AcctOpngReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(_) => Some("AcctOpngAmdmntReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(_) => Some("AcctOpngAddtlInfReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(_) => Some("AcctReqAck"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(_) => Some("AcctReqRjctn"),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(_) => Some("AcctAddtlInfReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(_) => Some("AcctRptReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(_) => Some("AcctRpt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(_) => Some("AcctExcldMndtMntncReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(_) => Some("AcctExcldMndtMntncAmdmntReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(_) => Some("AcctMndtMntncReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(_) => Some("AcctMndtMntncAmdmntReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(_) => Some("AcctClsgReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(_) => Some("AcctClsgAmdmntReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(_) => Some("AcctClsgAddtlInfReq"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(_) => Some("IdModAdvc"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(_) => Some("IdVrfctnReq"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(_) => Some("IdVrfctnRpt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(_) => Some("AcctSwtchInfReq"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(_) => Some("AcctSwtchInfRspn"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(_) => Some("AcctSwtchCclExstgPmt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(_) => Some("AcctSwtchReqRdrctn"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(_) => Some("AcctSwtchReqBalTrf"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(_) => Some("AcctSwtchBalTrfAck"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(_) => Some("AcctSwtchNtfyAcctSwtchCmplt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(_) => Some("AcctSwtchReqPmt"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(_) => Some("AcctSwtchPmtRspn"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(_) => Some("AcctSwtchTermntnSwtch"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(_) => Some("AcctSwtchTechRjctn"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(_) => Some("PricRpt"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(_) => Some("PricRptCxl"),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(_) => Some("FndRefDataRpt"),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(_) => Some("InvstmtFndRptReq"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(_) => Some("SctyCreReq"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(_) => Some("SctyMntncReq"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(_) => Some("SctyCreStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(_) => Some("SctyActvtyAdvc"),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(_) => Some("SctyQry"),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(_) => Some("SctyRpt"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(_) => Some("SctyDeltnReq"),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(_) => Some("PtyCreReq"),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(_) => Some("PtyQry"),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(_) => Some("PtyStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(_) => Some("PtyRpt"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(_) => Some("SctiesAcctCreReq"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(_) => Some("SctiesAcctQry"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(_) => Some("SctiesAcctStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(_) => Some("SctiesAcctRpt"),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(_) => Some("PtyModReq"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(_) => Some("SctiesAcctModReq"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(_) => Some("SctyMntncStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(_) => Some("SctyDeltnStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(_) => Some("PtyDeltnReq"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(_) => Some("SctiesAcctDeltnReq"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(_) => Some("SctiesAudtTrlQry"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(_) => Some("SctiesAudtTrlRpt"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(_) => Some("SctiesAcctActvtyAdvc"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(_) => Some("SctiesAcctAudtTrlQry"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(_) => Some("SctiesAcctAudtTrlRpt"),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(_) => Some("PtyActvtyAdvc"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(_) => Some("PtyAudtTrlQry"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(_) => Some("PtyAudtTrlRpt"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(_) => Some("StgSttlmInstr"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(_) => Some("StgSttlmInstrDeltn"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(_) => Some("StgSttlmInstrStsAdvc"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(_) => Some("StgSttlmInstrCxl"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(_) => Some("NetgCutOffRefDataUpdReq"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(_) => Some("NetgCutOffRefDataRpt"),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(_) => Some("CalQry"),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(_) => Some("CalRpt"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(_) => Some("ReqToPayCdtrEnrlmntReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(_) => Some("ReqToPayCdtrEnrlmntAmdmntReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(_) => Some("ReqToPayCdtrEnrlmntCxlReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(_) => Some("ReqToPayCdtrEnrlmntStsRpt"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(_) => Some("ReqToPayDbtrActvtnReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(_) => Some("ReqToPayDbtrActvtnAmdmntReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(_) => Some("ReqToPayDbtrActvtnCxlReq"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(_) => Some("ReqToPayDbtrActvtnStsRpt"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(_) => Some("RmtAdvc"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(_) => Some("RmtLctnAdvc"),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(_) => Some("GetAcct"),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(_) => Some("RtrAcct"),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(_) => Some("GetTx"),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(_) => Some("RtrTx"),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(_) => Some("ModfyTx"),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(_) => Some("CclTx"),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(_) => Some("GetLmt"),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(_) => Some("RtrLmt"),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(_) => Some("ModfyLmt"),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(_) => Some("DelLmt"),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(_) => Some("GetMmb"),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(_) => Some("RtrMmb"),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(_) => Some("ModfyMmb"),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(_) => Some("GetCcyXchgRate"),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(_) => Some("RtrCcyXchgRate"),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(_) => Some("GetBizDayInf"),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(_) => Some("RtrBizDayInf"),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(_) => Some("GetGnlBizInf"),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(_) => Some("RtrGnlBizInf"),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(_) => Some("BckpPmt"),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(_) => Some("ModfyStgOrdr"),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(_) => Some("Rct"),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(_) => Some("UblToApply"),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(_) => Some("ClmNonRct"),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(_) => Some("AddtlPmtInf"),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(_) => Some("RsltnOfInvstgtn"),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(_) => Some("NtfctnOfCaseAssgnmt"),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(_) => Some("RjctInvstgtn"),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(_) => Some("CclCaseAssgnmt"),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(_) => Some("ReqForDplct"),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(_) => Some("Dplct"),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(_) => Some("PrtryFrmtInvstgtn"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(_) => Some("DbtAuthstnRspn"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(_) => Some("DbtAuthstnReq"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(_) => Some("CaseStsRptReq"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(_) => Some("CaseStsRpt"),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(_) => Some("FndEstmtdCshFcstRpt"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(_) => Some("FndConfdCshFcstRpt"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(_) => Some("FndDtldEstmtdCshFcstRpt"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(_) => Some("FndDtldConfdCshFcstRpt"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(_) => Some("FndConfdCshFcstRptCxl"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(_) => Some("FndDtldConfdCshFcstRptCxl"),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(_) => Some("GetRsvatn"),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(_) => Some("RtrRsvatn"),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(_) => Some("ModfyRsvatn"),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(_) => Some("DelRsvatn"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(_) => Some("LqdtyCdtTrf"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(_) => Some("LqdtyDbtTrf"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(_) => Some("BkToCstmrAcctRpt"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(_) => Some("BkToCstmrStmt"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(_) => Some("BkToCstmrDbtCdtNtfctn"),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(_) => Some("CstmrPmtCxlReq"),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(_) => Some("FIToFIPmtCxlReq"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(_) => Some("NtfctnToRcv"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(_) => Some("NtfctnToRcvCxlAdvc"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(_) => Some("NtfctnToRcvStsRpt"),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(_) => Some("AcctRptgReq"),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(_) => Some("PayInCall"),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(_) => Some("PayInSchdl"),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(_) => Some("PayInEvtAck"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(_) => Some("IntraBalMvmntInstr"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(_) => Some("IntraBalMvmntStsAdvc"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(_) => Some("IntraBalMvmntConf"),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(_) => Some("GetStgOrdr"),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(_) => Some("RtrStgOrdr"),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(_) => Some("DelStgOrdr"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(_) => Some("IntraBalMvmntModReq"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(_) => Some("IntraBalMvmntModReqStsAdvc"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(_) => Some("IntraBalMvmntCxlReq"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(_) => Some("IntraBalMvmntCxlReqStsAdvc"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(_) => Some("IntraBalMvmntQry"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(_) => Some("IntraBalMvmntQryRspn"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(_) => Some("IntraBalMvmntModQry"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(_) => Some("IntraBalMvmntModRpt"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(_) => Some("IntraBalMvmntCxlQry"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(_) => Some("IntraBalMvmntCxlRpt"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(_) => Some("IntraBalMvmntPstngRpt"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(_) => Some("IntraBalMvmntPdgRpt"),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(_) => Some("BkSvcsBllgStmt"),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(_) => Some("ReqToModfyPmt"),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(_) => Some("NetRpt"),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(_) => Some("CretLmt"),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(_) => Some("CretStgOrdr"),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(_) => Some("CretRsvatn"),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(_) => Some("CretMmb"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(_) => Some("ChrgsPmtNtfctn"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(_) => Some("ChrgsPmtReq"),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(_) => Some("ChqPresntmntNtfctn"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(_) => Some("ChqCxlOrStopReq"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(_) => Some("ChqCxlOrStopRpt"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(_) => Some("InvstgtnReq"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(_) => Some("InvstgtnRspn"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(_) => Some("InfReqOpng"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(_) => Some("InfReqRspn"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(_) => Some("InfReqStsChngNtfctn"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(_) => Some("MnyMktScrdMktSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(_) => Some("MnyMktUscrdMktSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(_) => Some("MnyMktFXSwpsSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(_) => Some("MnyMktOvrnghtIndxSwpsSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(_) => Some("FinInstrmRptgTxRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(_) => Some("FinInstrmRptgRefDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(_) => Some("CtrctRegnReq"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(_) => Some("CtrctRegnConf"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(_) => Some("CtrctRegnClsrReq"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(_) => Some("CtrctRegnAmdmntReq"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(_) => Some("CtrctRegnStmt"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(_) => Some("CtrctRegnStmtReq"),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(_) => Some("PmtRgltryInfNtfctn"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(_) => Some("CcyCtrlSpprtgDocDlvry"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(_) => Some("CcyCtrlReqOrLttr"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(_) => Some("CcyCtrlStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(_) => Some("MnyMktSttstclRptStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(_) => Some("DerivsTradRptQry"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(_) => Some("DerivsTradRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(_) => Some("FinInstrmRptgStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(_) => Some("FinInstrmRptgEqtyTrnsprncyDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(_) => Some("FinInstrmRptgNonEqtyTrnsprncyDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(_) => Some("InvcTaxRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(_) => Some("FinInstrmRptgTradgVolCapDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(_) => Some("FinInstrmRptgRefDataDltaRpt"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(_) => Some("InvcTaxRptStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(_) => Some("FinInstrmRptgNonWorkgDayRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(_) => Some("FinInstrmRptgEqtyTradgActvtyRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(_) => Some("FinInstrmRptgNonEqtyTradgActvtyRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(_) => Some("FinInstrmRptgInvldRefDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(_) => Some("FinInstrmRptgRefDataIndxRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(_) => Some("FinInstrmRptgEqtyTradgActvtyRslt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(_) => Some("FinInstrmRptgNonEqtyTradgActvtyRslt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(_) => Some("FinInstrmRptgCtryCdRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(_) => Some("FinInstrmRptgCcyCdRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(_) => Some("FinInstrmRptgMktIdCdRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(_) => Some("FinInstrmRptgInstrmClssfctnRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(_) => Some("SctiesFincgRptgTxRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(_) => Some("FinInstrmRptgTradgVolCapRsltRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(_) => Some("CCPClrMmbRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(_) => Some("CCPMmbRqrmntsRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(_) => Some("CCPMmbOblgtnsRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(_) => Some("CCPPrtflStrssTstgDefRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(_) => Some("CCPPrtflStrssTstgRsltRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(_) => Some("CCPIncmStmtAndCptlAdqcyRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(_) => Some("CCPDalyCshFlowsRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(_) => Some("CCPInvstmtsRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(_) => Some("CCPLqdtyStrssTstgDefRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(_) => Some("CCPLqdtyStrssTstgRsltRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(_) => Some("CCPAvlblFinRsrcsRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(_) => Some("CCPBckTstgDefRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(_) => Some("CCPBckTstgRsltRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(_) => Some("CCPCollRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(_) => Some("CCPAcctPosRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(_) => Some("CCPClrdPdctRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(_) => Some("SctiesFincgRptgTxMrgnDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(_) => Some("SctiesFincgRptgTxReusdCollDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(_) => Some("SttlmIntlrRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(_) => Some("FinSprvsdPtyIdntyRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(_) => Some("FinBchmkRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(_) => Some("SctiesFincgRptgPairgReq"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(_) => Some("SctiesFincgRptgTxStatRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(_) => Some("SctiesFincgRptgRcncltnStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(_) => Some("SctiesFincgRptgMssngCollReq"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(_) => Some("SctiesFincgRptgTxStsAdvc"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(_) => Some("SctiesFincgRptgMrgnDataTxStatRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(_) => Some("SctiesFincgRptgReusdCollDataTxStatRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(_) => Some("DerivsTradPosSetRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(_) => Some("DerivsTradRcncltnSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(_) => Some("DerivsTradRjctnSttstclRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(_) => Some("SctiesFincgRptgTxQry"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(_) => Some("SttlmFlsMnthlyRpt"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(_) => Some("SttlmFlsAnlRpt"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(_) => Some("FinInstrmRptgCxlRpt"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(_) => Some("SctiesFincgRptgPosSetRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(_) => Some("DerivsTradWrnngsRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(_) => Some("DerivsTradStatRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(_) => Some("DerivsTradMrgnDataRpt"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(_) => Some("DerivsTradMrgnDataTxStatRpt"),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(_) => Some("CCPIntrprbltyRpt"),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(_) => Some("OrdrBookRpt"),
+			Document::Unknown(ref value) if value.root_element.is_empty() => None,
+			Document::Unknown(ref value) => Some(&value.root_element),
+        }
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+pub(crate) const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+
+#[cfg(feature = "derive_serde")]
+impl Document {
+    /// Parses a `<Document>` XML message. The namespace of the `<Document>` element, declared
+    /// with `xmlns` or bound to its prefix, selects the message version, a leading XML declaration
+    /// is skipped. A message that is not compiled in is kept as [`Document::Unknown`] with its
+    /// original XML.
+    pub fn from_xml_str(xml: &str) -> Result<Self, Error> {
+        let mut de = quick_xml::de::Deserializer::from_str(xml);
+        let mut track = serde_path_to_error::Track::new();
+        let visitor = DocumentVisitor { namespace: document_namespace(xml) };
+        let mut document = visitor
+            .deserialize(serde_path_to_error::Deserializer::new(&mut de, &mut track))
+            .map_err(|e| serde_path_to_error::Error::new(track.path(), e))?;
+        if let Document::Unknown(ref mut value) = document {
+            value.raw_xml = element_span(xml, "Document").unwrap_or(xml).to_string();
+        }
+        Ok(document)
+    }
+
+    // Completes an unknown message read as part of an envelope from its `<Document>` element `xml`.
+    // A namespace bound to the element prefix is only known from the XML, so such a message is read again.
+    pub(crate) fn resolve_unknown(&mut self, xml: &str) -> Result<(), Error> {
+        if let Document::Unknown(ref mut value) = self {
+            if value.namespace.is_none() && document_namespace(xml).is_some() {
+                *self = Document::from_xml_str(xml)?;
+            } else {
+                value.raw_xml = xml.to_string();
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a `<Document>` XML message read from `reader`.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, Error> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+        Self::from_xml_str(&xml)
+    }
+
+    /// Writes the message as a `<Document>` XML string, starting with the XML declaration
+    /// and carrying the namespace of the message version. An unknown message is written
+    /// back as its `raw_xml` element, or fails with [`Error::UnknownDocument`] when it has none.
+    pub fn to_xml_string(&self) -> Result<String, Error> {
+        if let Document::Unknown(ref value) = self {
+            if value.raw_xml.is_empty() {
+                return Err(Error::UnknownDocument);
+            }
+            return Ok(format!("{}{}", XML_DECLARATION, value.raw_xml));
+        }
+        let mut xml = String::from(XML_DECLARATION);
+        let serializer = quick_xml::se::Serializer::with_root(&mut xml, Some("Document"))?;
+        serde_path_to_error::serialize(self, serializer)?;
+        Ok(xml)
+    }
+
+    /// Writes the message as a `<Document>` XML message to `writer`.
+    pub fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(self.to_xml_string()?.as_bytes())?;
+        Ok(())
+    }
+}
+
+// Returns the namespace of the first `<Document>` element of `xml`, either the default namespace
+// or the one bound to the prefix of the element, as in `<ns:Document xmlns:ns="...">`.
+#[cfg(feature = "derive_serde")]
+fn document_namespace(xml: &str) -> Option<String> {
+    let mut reader = quick_xml::NsReader::from_str(xml);
+    loop {
+        match reader.read_resolved_event().ok()? {
+            (namespace, Event::Start(e) | Event::Empty(e)) if e.local_name().as_ref() == b"Document" => {
+                return match namespace {
+                    ResolveResult::Bound(namespace) => String::from_utf8(namespace.into_inner().to_vec()).ok(),
+                    _ => None,
+                };
+            }
+            (_, Event::Eof) => return None,
+            _ => {}
+        }
+    }
+}
+
+// Returns the first `name` element of `xml`, from its start tag to its end tag.
+#[cfg(feature = "derive_serde")]
+pub(crate) fn element_span<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    element_spans(xml, name).into_iter().next()
+}
+
+// Returns every outermost `name` element of `xml` in document order, elements nested in
+// a match are part of its span. Stops at the first malformed part of the XML.
+#[cfg(feature = "derive_serde")]
+pub(crate) fn element_spans<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut spans = Vec::new();
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == name.as_bytes() => {
+                if reader.read_to_end(e.name()).is_err() {
+                    break;
+                }
+                spans.extend(xml.get(start..reader.buffer_position() as usize));
+            }
+            Ok(Event::Empty(e)) if e.local_name().as_ref() == name.as_bytes() => {
+                spans.extend(xml.get(start..reader.buffer_position() as usize));
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    spans
+}
+
+// The `<Document>` element is (de)serialized as a map holding the `xmlns` attribute
+// and the single message element. The namespace selects the exact message version,
+// since several versions of a message share the same root element name.
+// An unknown message has no typed content and is serialized as its `UnknownMessage`
+// instead, `to_xml_string` writes its original XML rather than that struct.
+#[cfg(feature = "derive_serde")]
+impl Serialize for Document {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Document::Unknown(ref value) = self {
+            return value.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(namespace) = self.namespace() {
+            map.serialize_entry("@xmlns", namespace)?;
+        }
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(ref value) => map.serialize_entry("admi.002.001.01", value)?,
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(ref value) => map.serialize_entry("SysEvtNtfctn", value)?,
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(ref value) => map.serialize_entry("RptQryReq", value)?,
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(ref value) => map.serialize_entry("RsndReq", value)?,
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(ref value) => map.serialize_entry("RctAck", value)?,
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(ref value) => map.serialize_entry("StatcDataReq", value)?,
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(ref value) => map.serialize_entry("StatcDataRpt", value)?,
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(ref value) => map.serialize_entry("SysEvtAck", value)?,
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(ref value) => map.serialize_entry("PrcgReq", value)?,
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(ref value) => map.serialize_entry("NtfctnOfCrspdc", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(ref value) => map.serialize_entry("FIToFIPmtStsRpt", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(ref value) => map.serialize_entry("FIToFIPmtStsRpt", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(ref value) => map.serialize_entry("FIToFICstmrDrctDbt", value)?,
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(ref value) => map.serialize_entry("PmtRtr", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(ref value) => map.serialize_entry("FIToFIPmtRvsl", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(ref value) => map.serialize_entry("FIToFICstmrCdtTrf", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(ref value) => map.serialize_entry("FICdtTrf", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(ref value) => map.serialize_entry("FIDrctDbt", value)?,
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(ref value) => map.serialize_entry("FIToFIPmtStsReq", value)?,
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(ref value) => map.serialize_entry("MulSttlmReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(ref value) => map.serialize_entry("CstmrCdtTrfInitn", value)?,
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(ref value) => map.serialize_entry("CstmrPmtStsRpt", value)?,
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(ref value) => map.serialize_entry("CstmrPmtRvsl", value)?,
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(ref value) => map.serialize_entry("CstmrDrctDbtInitn", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(ref value) => map.serialize_entry("MndtInitnReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(ref value) => map.serialize_entry("MndtAmdmntReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(ref value) => map.serialize_entry("MndtCxlReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(ref value) => map.serialize_entry("MndtAccptncRpt", value)?,
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(ref value) => map.serialize_entry("CdtrPmtActvtnReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(ref value) => map.serialize_entry("CdtrPmtActvtnReqStsRpt", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(ref value) => map.serialize_entry("MndtCpyReq", value)?,
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(ref value) => map.serialize_entry("MndtSspnsnReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(ref value) => map.serialize_entry("AcctOpngInstr", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(ref value) => map.serialize_entry("AcctDtlsConf", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(ref value) => map.serialize_entry("AcctModInstr", value)?,
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(ref value) => map.serialize_entry("ReqForAcctMgmtStsRpt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(ref value) => map.serialize_entry("AcctMgmtStsRpt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(ref value) => map.serialize_entry("AcctOpngReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(ref value) => map.serialize_entry("AcctOpngAmdmntReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(ref value) => map.serialize_entry("AcctOpngAddtlInfReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(ref value) => map.serialize_entry("AcctReqAck", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(ref value) => map.serialize_entry("AcctReqRjctn", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(ref value) => map.serialize_entry("AcctAddtlInfReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(ref value) => map.serialize_entry("AcctRptReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(ref value) => map.serialize_entry("AcctRpt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(ref value) => map.serialize_entry("AcctExcldMndtMntncReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(ref value) => map.serialize_entry("AcctExcldMndtMntncAmdmntReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(ref value) => map.serialize_entry("AcctMndtMntncReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(ref value) => map.serialize_entry("AcctMndtMntncAmdmntReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(ref value) => map.serialize_entry("AcctClsgReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(ref value) => map.serialize_entry("AcctClsgAmdmntReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(ref value) => map.serialize_entry("AcctClsgAddtlInfReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(ref value) => map.serialize_entry("IdModAdvc", value)?,
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(ref value) => map.serialize_entry("IdVrfctnReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(ref value) => map.serialize_entry("IdVrfctnRpt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(ref value) => map.serialize_entry("AcctSwtchInfReq", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(ref value) => map.serialize_entry("AcctSwtchInfRspn", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(ref value) => map.serialize_entry("AcctSwtchCclExstgPmt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(ref value) => map.serialize_entry("AcctSwtchReqRdrctn", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(ref value) => map.serialize_entry("AcctSwtchReqBalTrf", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(ref value) => map.serialize_entry("AcctSwtchBalTrfAck", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(ref value) => map.serialize_entry("AcctSwtchNtfyAcctSwtchCmplt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(ref value) => map.serialize_entry("AcctSwtchReqPmt", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(ref value) => map.serialize_entry("AcctSwtchPmtRspn", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(ref value) => map.serialize_entry("AcctSwtchTermntnSwtch", value)?,
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(ref value) => map.serialize_entry("AcctSwtchTechRjctn", value)?,
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(ref value) => map.serialize_entry("PricRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(ref value) => map.serialize_entry("PricRptCxl", value)?,
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(ref value) => map.serialize_entry("FndRefDataRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(ref value) => map.serialize_entry("InvstmtFndRptReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(ref value) => map.serialize_entry("SctyCreReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(ref value) => map.serialize_entry("SctyMntncReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(ref value) => map.serialize_entry("SctyCreStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(ref value) => map.serialize_entry("SctyActvtyAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(ref value) => map.serialize_entry("SctyQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(ref value) => map.serialize_entry("SctyRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(ref value) => map.serialize_entry("SctyDeltnReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(ref value) => map.serialize_entry("PtyCreReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(ref value) => map.serialize_entry("PtyQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(ref value) => map.serialize_entry("PtyStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(ref value) => map.serialize_entry("PtyRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(ref value) => map.serialize_entry("SctiesAcctCreReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(ref value) => map.serialize_entry("SctiesAcctQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(ref value) => map.serialize_entry("SctiesAcctStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(ref value) => map.serialize_entry("SctiesAcctRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(ref value) => map.serialize_entry("PtyModReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(ref value) => map.serialize_entry("SctiesAcctModReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(ref value) => map.serialize_entry("SctyMntncStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(ref value) => map.serialize_entry("SctyDeltnStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(ref value) => map.serialize_entry("PtyDeltnReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(ref value) => map.serialize_entry("SctiesAcctDeltnReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(ref value) => map.serialize_entry("SctiesAudtTrlQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(ref value) => map.serialize_entry("SctiesAudtTrlRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(ref value) => map.serialize_entry("SctiesAcctActvtyAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(ref value) => map.serialize_entry("SctiesAcctAudtTrlQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(ref value) => map.serialize_entry("SctiesAcctAudtTrlRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(ref value) => map.serialize_entry("PtyActvtyAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(ref value) => map.serialize_entry("PtyAudtTrlQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(ref value) => map.serialize_entry("PtyAudtTrlRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(ref value) => map.serialize_entry("StgSttlmInstr", value)?,
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(ref value) => map.serialize_entry("StgSttlmInstrDeltn", value)?,
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(ref value) => map.serialize_entry("StgSttlmInstrStsAdvc", value)?,
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(ref value) => map.serialize_entry("StgSttlmInstrCxl", value)?,
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(ref value) => map.serialize_entry("NetgCutOffRefDataUpdReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(ref value) => map.serialize_entry("NetgCutOffRefDataRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(ref value) => map.serialize_entry("CalQry", value)?,
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(ref value) => map.serialize_entry("CalRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(ref value) => map.serialize_entry("ReqToPayCdtrEnrlmntReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(ref value) => map.serialize_entry("ReqToPayCdtrEnrlmntAmdmntReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(ref value) => map.serialize_entry("ReqToPayCdtrEnrlmntCxlReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(ref value) => map.serialize_entry("ReqToPayCdtrEnrlmntStsRpt", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(ref value) => map.serialize_entry("ReqToPayDbtrActvtnReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(ref value) => map.serialize_entry("ReqToPayDbtrActvtnAmdmntReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(ref value) => map.serialize_entry("ReqToPayDbtrActvtnCxlReq", value)?,
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(ref value) => map.serialize_entry("ReqToPayDbtrActvtnStsRpt", value)?,
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(ref value) => map.serialize_entry("RmtAdvc", value)?,
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(ref value) => map.serialize_entry("RmtLctnAdvc", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(ref value) => map.serialize_entry("GetAcct", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(ref value) => map.serialize_entry("RtrAcct", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(ref value) => map.serialize_entry("GetTx", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(ref value) => map.serialize_entry("RtrTx", value)?,
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(ref value) => map.serialize_entry("ModfyTx", value)?,
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(ref value) => map.serialize_entry("CclTx", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(ref value) => map.serialize_entry("GetLmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(ref value) => map.serialize_entry("RtrLmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(ref value) => map.serialize_entry("ModfyLmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(ref value) => map.serialize_entry("DelLmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(ref value) => map.serialize_entry("GetMmb", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(ref value) => map.serialize_entry("RtrMmb", value)?,
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(ref value) => map.serialize_entry("ModfyMmb", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(ref value) => map.serialize_entry("GetCcyXchgRate", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(ref value) => map.serialize_entry("RtrCcyXchgRate", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(ref value) => map.serialize_entry("GetBizDayInf", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(ref value) => map.serialize_entry("RtrBizDayInf", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(ref value) => map.serialize_entry("GetGnlBizInf", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(ref value) => map.serialize_entry("RtrGnlBizInf", value)?,
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(ref value) => map.serialize_entry("BckpPmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(ref value) => map.serialize_entry("ModfyStgOrdr", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(ref value) => map.serialize_entry("Rct", value)?,
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(ref value) => map.serialize_entry("UblToApply", value)?,
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(ref value) => map.serialize_entry("ClmNonRct", value)?,
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(ref value) => map.serialize_entry("AddtlPmtInf", value)?,
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(ref value) => map.serialize_entry("RsltnOfInvstgtn", value)?,
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(ref value) => map.serialize_entry("NtfctnOfCaseAssgnmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(ref value) => map.serialize_entry("RjctInvstgtn", value)?,
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(ref value) => map.serialize_entry("CclCaseAssgnmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(ref value) => map.serialize_entry("ReqForDplct", value)?,
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(ref value) => map.serialize_entry("Dplct", value)?,
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(ref value) => map.serialize_entry("PrtryFrmtInvstgtn", value)?,
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(ref value) => map.serialize_entry("DbtAuthstnRspn", value)?,
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(ref value) => map.serialize_entry("DbtAuthstnReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(ref value) => map.serialize_entry("CaseStsRptReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(ref value) => map.serialize_entry("CaseStsRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(ref value) => map.serialize_entry("FndEstmtdCshFcstRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(ref value) => map.serialize_entry("FndConfdCshFcstRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(ref value) => map.serialize_entry("FndDtldEstmtdCshFcstRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(ref value) => map.serialize_entry("FndDtldConfdCshFcstRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(ref value) => map.serialize_entry("FndConfdCshFcstRptCxl", value)?,
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(ref value) => map.serialize_entry("FndDtldConfdCshFcstRptCxl", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(ref value) => map.serialize_entry("GetRsvatn", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(ref value) => map.serialize_entry("RtrRsvatn", value)?,
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(ref value) => map.serialize_entry("ModfyRsvatn", value)?,
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(ref value) => map.serialize_entry("DelRsvatn", value)?,
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(ref value) => map.serialize_entry("LqdtyCdtTrf", value)?,
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(ref value) => map.serialize_entry("LqdtyDbtTrf", value)?,
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(ref value) => map.serialize_entry("BkToCstmrAcctRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(ref value) => map.serialize_entry("BkToCstmrStmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(ref value) => map.serialize_entry("BkToCstmrDbtCdtNtfctn", value)?,
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(ref value) => map.serialize_entry("CstmrPmtCxlReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(ref value) => map.serialize_entry("FIToFIPmtCxlReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(ref value) => map.serialize_entry("NtfctnToRcv", value)?,
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(ref value) => map.serialize_entry("NtfctnToRcvCxlAdvc", value)?,
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(ref value) => map.serialize_entry("NtfctnToRcvStsRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(ref value) => map.serialize_entry("AcctRptgReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(ref value) => map.serialize_entry("PayInCall", value)?,
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(ref value) => map.serialize_entry("PayInSchdl", value)?,
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(ref value) => map.serialize_entry("PayInEvtAck", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(ref value) => map.serialize_entry("IntraBalMvmntInstr", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(ref value) => map.serialize_entry("IntraBalMvmntStsAdvc", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(ref value) => map.serialize_entry("IntraBalMvmntConf", value)?,
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(ref value) => map.serialize_entry("GetStgOrdr", value)?,
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(ref value) => map.serialize_entry("RtrStgOrdr", value)?,
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(ref value) => map.serialize_entry("DelStgOrdr", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(ref value) => map.serialize_entry("IntraBalMvmntModReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(ref value) => map.serialize_entry("IntraBalMvmntModReqStsAdvc", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(ref value) => map.serialize_entry("IntraBalMvmntCxlReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(ref value) => map.serialize_entry("IntraBalMvmntCxlReqStsAdvc", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(ref value) => map.serialize_entry("IntraBalMvmntQry", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(ref value) => map.serialize_entry("IntraBalMvmntQryRspn", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(ref value) => map.serialize_entry("IntraBalMvmntModQry", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(ref value) => map.serialize_entry("IntraBalMvmntModRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(ref value) => map.serialize_entry("IntraBalMvmntCxlQry", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(ref value) => map.serialize_entry("IntraBalMvmntCxlRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(ref value) => map.serialize_entry("IntraBalMvmntPstngRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(ref value) => map.serialize_entry("IntraBalMvmntPdgRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(ref value) => map.serialize_entry("BkSvcsBllgStmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(ref value) => map.serialize_entry("ReqToModfyPmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(ref value) => map.serialize_entry("NetRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(ref value) => map.serialize_entry("CretLmt", value)?,
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(ref value) => map.serialize_entry("CretStgOrdr", value)?,
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(ref value) => map.serialize_entry("CretRsvatn", value)?,
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(ref value) => map.serialize_entry("CretMmb", value)?,
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(ref value) => map.serialize_entry("ChrgsPmtNtfctn", value)?,
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(ref value) => map.serialize_entry("ChrgsPmtReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(ref value) => map.serialize_entry("ChqPresntmntNtfctn", value)?,
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(ref value) => map.serialize_entry("ChqCxlOrStopReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(ref value) => map.serialize_entry("ChqCxlOrStopRpt", value)?,
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(ref value) => map.serialize_entry("InvstgtnReq", value)?,
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(ref value) => map.serialize_entry("InvstgtnRspn", value)?,
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(ref value) => map.serialize_entry("InfReqOpng", value)?,
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(ref value) => map.serialize_entry("InfReqRspn", value)?,
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(ref value) => map.serialize_entry("InfReqStsChngNtfctn", value)?,
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(ref value) => map.serialize_entry("MnyMktScrdMktSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(ref value) => map.serialize_entry("MnyMktUscrdMktSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(ref value) => map.serialize_entry("MnyMktFXSwpsSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(ref value) => map.serialize_entry("MnyMktOvrnghtIndxSwpsSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(ref value) => map.serialize_entry("FinInstrmRptgTxRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(ref value) => map.serialize_entry("FinInstrmRptgRefDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(ref value) => map.serialize_entry("CtrctRegnReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(ref value) => map.serialize_entry("CtrctRegnConf", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(ref value) => map.serialize_entry("CtrctRegnClsrReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(ref value) => map.serialize_entry("CtrctRegnAmdmntReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(ref value) => map.serialize_entry("CtrctRegnStmt", value)?,
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(ref value) => map.serialize_entry("CtrctRegnStmtReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(ref value) => map.serialize_entry("PmtRgltryInfNtfctn", value)?,
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(ref value) => map.serialize_entry("CcyCtrlSpprtgDocDlvry", value)?,
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(ref value) => map.serialize_entry("CcyCtrlReqOrLttr", value)?,
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(ref value) => map.serialize_entry("CcyCtrlStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(ref value) => map.serialize_entry("MnyMktSttstclRptStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(ref value) => map.serialize_entry("DerivsTradRptQry", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(ref value) => map.serialize_entry("DerivsTradRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(ref value) => map.serialize_entry("FinInstrmRptgStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(ref value) => map.serialize_entry("FinInstrmRptgEqtyTrnsprncyDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(ref value) => map.serialize_entry("FinInstrmRptgNonEqtyTrnsprncyDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(ref value) => map.serialize_entry("InvcTaxRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(ref value) => map.serialize_entry("FinInstrmRptgTradgVolCapDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(ref value) => map.serialize_entry("FinInstrmRptgRefDataDltaRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(ref value) => map.serialize_entry("InvcTaxRptStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(ref value) => map.serialize_entry("FinInstrmRptgNonWorkgDayRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(ref value) => map.serialize_entry("FinInstrmRptgEqtyTradgActvtyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(ref value) => map.serialize_entry("FinInstrmRptgNonEqtyTradgActvtyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(ref value) => map.serialize_entry("FinInstrmRptgInvldRefDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(ref value) => map.serialize_entry("FinInstrmRptgRefDataIndxRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(ref value) => map.serialize_entry("FinInstrmRptgEqtyTradgActvtyRslt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(ref value) => map.serialize_entry("FinInstrmRptgNonEqtyTradgActvtyRslt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(ref value) => map.serialize_entry("FinInstrmRptgCtryCdRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(ref value) => map.serialize_entry("FinInstrmRptgCcyCdRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(ref value) => map.serialize_entry("FinInstrmRptgMktIdCdRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(ref value) => map.serialize_entry("FinInstrmRptgInstrmClssfctnRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(ref value) => map.serialize_entry("SctiesFincgRptgTxRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(ref value) => map.serialize_entry("FinInstrmRptgTradgVolCapRsltRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(ref value) => map.serialize_entry("CCPClrMmbRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(ref value) => map.serialize_entry("CCPMmbRqrmntsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(ref value) => map.serialize_entry("CCPMmbOblgtnsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(ref value) => map.serialize_entry("CCPPrtflStrssTstgDefRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(ref value) => map.serialize_entry("CCPPrtflStrssTstgRsltRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(ref value) => map.serialize_entry("CCPIncmStmtAndCptlAdqcyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(ref value) => map.serialize_entry("CCPDalyCshFlowsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(ref value) => map.serialize_entry("CCPInvstmtsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(ref value) => map.serialize_entry("CCPLqdtyStrssTstgDefRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(ref value) => map.serialize_entry("CCPLqdtyStrssTstgRsltRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(ref value) => map.serialize_entry("CCPAvlblFinRsrcsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(ref value) => map.serialize_entry("CCPBckTstgDefRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(ref value) => map.serialize_entry("CCPBckTstgRsltRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(ref value) => map.serialize_entry("CCPCollRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(ref value) => map.serialize_entry("CCPAcctPosRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(ref value) => map.serialize_entry("CCPClrdPdctRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(ref value) => map.serialize_entry("SctiesFincgRptgTxMrgnDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(ref value) => map.serialize_entry("SctiesFincgRptgTxReusdCollDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(ref value) => map.serialize_entry("SttlmIntlrRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(ref value) => map.serialize_entry("FinSprvsdPtyIdntyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(ref value) => map.serialize_entry("FinBchmkRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(ref value) => map.serialize_entry("SctiesFincgRptgPairgReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(ref value) => map.serialize_entry("SctiesFincgRptgTxStatRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(ref value) => map.serialize_entry("SctiesFincgRptgRcncltnStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(ref value) => map.serialize_entry("SctiesFincgRptgMssngCollReq", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(ref value) => map.serialize_entry("SctiesFincgRptgTxStsAdvc", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(ref value) => map.serialize_entry("SctiesFincgRptgMrgnDataTxStatRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(ref value) => map.serialize_entry("SctiesFincgRptgReusdCollDataTxStatRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(ref value) => map.serialize_entry("DerivsTradPosSetRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(ref value) => map.serialize_entry("DerivsTradRcncltnSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(ref value) => map.serialize_entry("DerivsTradRjctnSttstclRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(ref value) => map.serialize_entry("SctiesFincgRptgTxQry", value)?,
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(ref value) => map.serialize_entry("SttlmFlsMnthlyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(ref value) => map.serialize_entry("SttlmFlsAnlRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(ref value) => map.serialize_entry("FinInstrmRptgCxlRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(ref value) => map.serialize_entry("SctiesFincgRptgPosSetRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(ref value) => map.serialize_entry("DerivsTradWrnngsRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(ref value) => map.serialize_entry("DerivsTradStatRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(ref value) => map.serialize_entry("DerivsTradMrgnDataRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(ref value) => map.serialize_entry("DerivsTradMrgnDataTxStatRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(ref value) => map.serialize_entry("CCPIntrprbltyRpt", value)?,
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(ref value) => map.serialize_entry("OrdrBookRpt", value)?,
+			Document::Unknown(_) => unreachable!("serialized as UnknownMessage above"),
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> Deserialize<'de> for Document {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(DocumentVisitor { namespace: None })
+    }
+}
+
+// `namespace` is the namespace resolved from the raw XML, which takes precedence over the
+// attributes since serde only sees the local names of the elements.
+#[cfg(feature = "derive_serde")]
+struct DocumentVisitor {
+    namespace: Option<String>,
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> DeserializeSeed<'de> for DocumentVisitor {
+    type Value = Document;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Document, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> Visitor<'de> for DocumentVisitor {
+    type Value = Document;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an ISO 20022 Document element")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Document, A::Error> {
+        let mut namespace: Option<String> = None;
+        let mut prefixed = false;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "@xmlns" {
+                namespace = Some(map.next_value()?);
+                continue;
+            }
+            if key.starts_with("@xmlns:") {
+                let binding: String = map.next_value()?;
+                prefixed |= binding.starts_with(NAMESPACE_PREFIX);
+                continue;
+            }
+            if key.starts_with('@') {
+                // Other attributes, such as `xsi:schemaLocation`, carry no message content.
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+            let document = match self.namespace {
+                Some(ref resolved) => deserialize_message(Some(resolved), &key, &mut map)?,
+                None if prefixed => {
+                    // The message namespace is bound to a prefix this visitor cannot see, the
+                    // caller holding the XML resolves it with `Document::resolve_unknown`.
+                    map.next_value::<IgnoredAny>()?;
+                    Document::Unknown(Box::new(UnknownMessage { namespace: None, root_element: key, raw_xml: String::new() }))
+                }
+                None => deserialize_message(namespace.as_deref(), &key, &mut map)?,
+            };
+            while map.next_key::<IgnoredAny>()?.is_some() {
+                map.next_value::<IgnoredAny>()?;
+            }
+            return Ok(document);
+        }
+        Err(de::Error::custom("Document does not contain a message element"))
+    }
+}
+
+// Selects the message variant from the namespace and the root element name.
+// A document without namespace falls back to the variant with a matching root element,
+// and fails when several message versions share that root element.
+#[cfg(feature = "derive_serde")]
+#[allow(unused_variables, unreachable_code)]
+fn deserialize_message<'de, A: MapAccess<'de>>(namespace: Option<&str>, root: &str, map: &mut A) -> Result<Document, A::Error> {
+    match (namespace, root) {
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.002.001.01") | None, "admi.002.001.01") => Ok(Document::Admi00200101(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.004.001.02") | None, "SysEvtNtfctn") => Ok(Document::SystemEventNotificationV02(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.005.001.02") | None, "RptQryReq") => Ok(Document::ReportQueryRequestV02(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.006.001.01") | None, "RsndReq") => Ok(Document::ResendRequestV01(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.007.001.01") | None, "RctAck") => Ok(Document::ReceiptAcknowledgementV01(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.009.001.02") | None, "StatcDataReq") => Ok(Document::StaticDataRequestV02(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.010.001.02") | None, "StatcDataRpt") => Ok(Document::StaticDataReportV02(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.011.001.01") | None, "SysEvtAck") => Ok(Document::SystemEventAcknowledgementV01(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.017.001.02") | None, "PrcgReq") => Ok(Document::ProcessingRequestV02(map.next_value()?)),
+			#[cfg(feature = "admi")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:admi.024.001.01") | None, "NtfctnOfCrspdc") => Ok(Document::NotificationOfCorrespondenceV01(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(None, "FIToFIPmtStsRpt") => Err(de::Error::custom("Document without namespace, FIToFIPmtStsRpt exists in several message versions")),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.002.001.12"), "FIToFIPmtStsRpt") => Ok(Document::FIToFIPaymentStatusReportV12(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.002.001.14"), "FIToFIPmtStsRpt") => Ok(Document::FIToFIPaymentStatusReportV14(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.003.001.11") | None, "FIToFICstmrDrctDbt") => Ok(Document::FIToFICustomerDirectDebitV11(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.004.001.13") | None, "PmtRtr") => Ok(Document::PaymentReturnV13(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.007.001.13") | None, "FIToFIPmtRvsl") => Ok(Document::FIToFIPaymentReversalV13(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12") | None, "FIToFICstmrCdtTrf") => Ok(Document::FIToFICustomerCreditTransferV12(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.009.001.11") | None, "FICdtTrf") => Ok(Document::FinancialInstitutionCreditTransferV11(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.010.001.06") | None, "FIDrctDbt") => Ok(Document::FinancialInstitutionDirectDebitV06(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.028.001.06") | None, "FIToFIPmtStsReq") => Ok(Document::FIToFIPaymentStatusRequestV06(map.next_value()?)),
+			#[cfg(feature = "pacs")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pacs.029.001.02") | None, "MulSttlmReq") => Ok(Document::MultilateralSettlementRequestV02(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.001.001.12") | None, "CstmrCdtTrfInitn") => Ok(Document::CustomerCreditTransferInitiationV12(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.002.001.14") | None, "CstmrPmtStsRpt") => Ok(Document::CustomerPaymentStatusReportV14(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.007.001.12") | None, "CstmrPmtRvsl") => Ok(Document::CustomerPaymentReversalV12(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.008.001.11") | None, "CstmrDrctDbtInitn") => Ok(Document::CustomerDirectDebitInitiationV11(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.009.001.08") | None, "MndtInitnReq") => Ok(Document::MandateInitiationRequestV08(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.010.001.08") | None, "MndtAmdmntReq") => Ok(Document::MandateAmendmentRequestV08(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.011.001.08") | None, "MndtCxlReq") => Ok(Document::MandateCancellationRequestV08(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.012.001.08") | None, "MndtAccptncRpt") => Ok(Document::MandateAcceptanceReportV08(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.013.001.11") | None, "CdtrPmtActvtnReq") => Ok(Document::CreditorPaymentActivationRequestV11(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.014.001.11") | None, "CdtrPmtActvtnReqStsRpt") => Ok(Document::CreditorPaymentActivationRequestStatusReportV11(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.017.001.04") | None, "MndtCpyReq") => Ok(Document::MandateCopyRequestV04(map.next_value()?)),
+			#[cfg(feature = "pain")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:pain.018.001.04") | None, "MndtSspnsnReq") => Ok(Document::MandateSuspensionRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.001.001.08") | None, "AcctOpngInstr") => Ok(Document::AccountOpeningInstructionV08(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.002.001.08") | None, "AcctDtlsConf") => Ok(Document::AccountDetailsConfirmationV08(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.003.001.08") | None, "AcctModInstr") => Ok(Document::AccountModificationInstructionV08(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.005.001.06") | None, "ReqForAcctMgmtStsRpt") => Ok(Document::RequestForAccountManagementStatusReportV06(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.006.001.07") | None, "AcctMgmtStsRpt") => Ok(Document::AccountManagementStatusReportV07(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.007.001.05") | None, "AcctOpngReq") => Ok(Document::AccountOpeningRequestV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.008.001.05") | None, "AcctOpngAmdmntReq") => Ok(Document::AccountOpeningAmendmentRequestV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.009.001.04") | None, "AcctOpngAddtlInfReq") => Ok(Document::AccountOpeningAdditionalInformationRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.010.001.04") | None, "AcctReqAck") => Ok(Document::AccountRequestAcknowledgementV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.011.001.04") | None, "AcctReqRjctn") => Ok(Document::AccountRequestRejectionV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.012.001.04") | None, "AcctAddtlInfReq") => Ok(Document::AccountAdditionalInformationRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.013.001.04") | None, "AcctRptReq") => Ok(Document::AccountReportRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.014.001.05") | None, "AcctRpt") => Ok(Document::AccountReportV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.015.001.04") | None, "AcctExcldMndtMntncReq") => Ok(Document::AccountExcludedMandateMaintenanceRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.016.001.04") | None, "AcctExcldMndtMntncAmdmntReq") => Ok(Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.017.001.04") | None, "AcctMndtMntncReq") => Ok(Document::AccountMandateMaintenanceRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.018.001.04") | None, "AcctMndtMntncAmdmntReq") => Ok(Document::AccountMandateMaintenanceAmendmentRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.019.001.04") | None, "AcctClsgReq") => Ok(Document::AccountClosingRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.020.001.04") | None, "AcctClsgAmdmntReq") => Ok(Document::AccountClosingAmendmentRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.021.001.04") | None, "AcctClsgAddtlInfReq") => Ok(Document::AccountClosingAdditionalInformationRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.022.001.04") | None, "IdModAdvc") => Ok(Document::IdentificationModificationAdviceV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.023.001.04") | None, "IdVrfctnReq") => Ok(Document::IdentificationVerificationRequestV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.024.001.04") | None, "IdVrfctnRpt") => Ok(Document::IdentificationVerificationReportV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.027.001.05") | None, "AcctSwtchInfReq") => Ok(Document::AccountSwitchInformationRequestV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.028.001.05") | None, "AcctSwtchInfRspn") => Ok(Document::AccountSwitchInformationResponseV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.029.001.05") | None, "AcctSwtchCclExstgPmt") => Ok(Document::AccountSwitchCancelExistingPaymentV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.030.001.04") | None, "AcctSwtchReqRdrctn") => Ok(Document::AccountSwitchRequestRedirectionV04(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.031.001.05") | None, "AcctSwtchReqBalTrf") => Ok(Document::AccountSwitchRequestBalanceTransferV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.032.001.05") | None, "AcctSwtchBalTrfAck") => Ok(Document::AccountSwitchBalanceTransferAcknowledgementV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.033.001.02") | None, "AcctSwtchNtfyAcctSwtchCmplt") => Ok(Document::AccountSwitchNotifyAccountSwitchCompleteV02(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.034.001.05") | None, "AcctSwtchReqPmt") => Ok(Document::AccountSwitchRequestPaymentV05(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.035.001.02") | None, "AcctSwtchPmtRspn") => Ok(Document::AccountSwitchPaymentResponseV02(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.036.001.01") | None, "AcctSwtchTermntnSwtch") => Ok(Document::AccountSwitchTerminationSwitchV01(map.next_value()?)),
+			#[cfg(feature = "acmt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:acmt.037.001.02") | None, "AcctSwtchTechRjctn") => Ok(Document::AccountSwitchTechnicalRejectionV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.001.001.04") | None, "PricRpt") => Ok(Document::PriceReportV04(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.002.001.04") | None, "PricRptCxl") => Ok(Document::PriceReportCancellationV04(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.004.001.07") | None, "FndRefDataRpt") => Ok(Document::FundReferenceDataReportV07(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.005.001.03") | None, "InvstmtFndRptReq") => Ok(Document::InvestmentFundReportRequestV03(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.006.001.01") | None, "SctyCreReq") => Ok(Document::SecurityCreationRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.007.001.01") | None, "SctyMntncReq") => Ok(Document::SecurityMaintenanceRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.008.001.01") | None, "SctyCreStsAdvc") => Ok(Document::SecurityCreationStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.009.001.01") | None, "SctyActvtyAdvc") => Ok(Document::SecurityActivityAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.010.001.01") | None, "SctyQry") => Ok(Document::SecurityQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.012.001.01") | None, "SctyRpt") => Ok(Document::SecurityReportV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.013.001.01") | None, "SctyDeltnReq") => Ok(Document::SecurityDeletionRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.014.001.02") | None, "PtyCreReq") => Ok(Document::PartyCreationRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.015.001.01") | None, "PtyQry") => Ok(Document::PartyQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.016.001.01") | None, "PtyStsAdvc") => Ok(Document::PartyStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.017.001.02") | None, "PtyRpt") => Ok(Document::PartyReportV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.018.001.01") | None, "SctiesAcctCreReq") => Ok(Document::SecuritiesAccountCreationRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.019.001.01") | None, "SctiesAcctQry") => Ok(Document::SecuritiesAccountQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.020.001.01") | None, "SctiesAcctStsAdvc") => Ok(Document::SecuritiesAccountStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.021.001.01") | None, "SctiesAcctRpt") => Ok(Document::SecuritiesAccountReportV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.022.001.02") | None, "PtyModReq") => Ok(Document::PartyModificationRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.023.001.01") | None, "SctiesAcctModReq") => Ok(Document::SecuritiesAccountModificationRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.029.001.01") | None, "SctyMntncStsAdvc") => Ok(Document::SecurityMaintenanceStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.030.001.01") | None, "SctyDeltnStsAdvc") => Ok(Document::SecurityDeletionStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.031.001.01") | None, "PtyDeltnReq") => Ok(Document::PartyDeletionRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.032.001.01") | None, "SctiesAcctDeltnReq") => Ok(Document::SecuritiesAccountDeletionRequestV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.033.001.01") | None, "SctiesAudtTrlQry") => Ok(Document::SecuritiesAuditTrailQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.034.001.01") | None, "SctiesAudtTrlRpt") => Ok(Document::SecuritiesAuditTrailReportV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.035.001.01") | None, "SctiesAcctActvtyAdvc") => Ok(Document::SecuritiesAccountActivityAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.036.001.01") | None, "SctiesAcctAudtTrlQry") => Ok(Document::SecuritiesAccountAuditTrailQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.037.001.01") | None, "SctiesAcctAudtTrlRpt") => Ok(Document::SecuritiesAccountAuditTrailReportV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.041.001.02") | None, "PtyActvtyAdvc") => Ok(Document::PartyActivityAdviceV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.042.001.01") | None, "PtyAudtTrlQry") => Ok(Document::PartyAuditTrailQueryV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.043.001.02") | None, "PtyAudtTrlRpt") => Ok(Document::PartyAuditTrailReportV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.056.001.01") | None, "StgSttlmInstr") => Ok(Document::StandingSettlementInstructionV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.057.001.01") | None, "StgSttlmInstrDeltn") => Ok(Document::StandingSettlementInstructionDeletionV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.058.001.01") | None, "StgSttlmInstrStsAdvc") => Ok(Document::StandingSettlementInstructionStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.059.001.01") | None, "StgSttlmInstrCxl") => Ok(Document::StandingSettlementInstructionCancellationV01(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.060.001.02") | None, "NetgCutOffRefDataUpdReq") => Ok(Document::NettingCutOffReferenceDataUpdateRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.061.001.02") | None, "NetgCutOffRefDataRpt") => Ok(Document::NettingCutOffReferenceDataReportV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.064.001.02") | None, "CalQry") => Ok(Document::CalendarQueryV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.065.001.02") | None, "CalRpt") => Ok(Document::CalendarReportV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.066.001.02") | None, "ReqToPayCdtrEnrlmntReq") => Ok(Document::RequestToPayCreditorEnrolmentRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.067.001.02") | None, "ReqToPayCdtrEnrlmntAmdmntReq") => Ok(Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.068.001.02") | None, "ReqToPayCdtrEnrlmntCxlReq") => Ok(Document::RequestToPayCreditorEnrolmentCancellationRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.069.001.02") | None, "ReqToPayCdtrEnrlmntStsRpt") => Ok(Document::RequestToPayCreditorEnrolmentStatusReportV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.070.001.02") | None, "ReqToPayDbtrActvtnReq") => Ok(Document::RequestToPayDebtorActivationRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.071.001.02") | None, "ReqToPayDbtrActvtnAmdmntReq") => Ok(Document::RequestToPayDebtorActivationAmendmentRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.072.001.02") | None, "ReqToPayDbtrActvtnCxlReq") => Ok(Document::RequestToPayDebtorActivationCancellationRequestV02(map.next_value()?)),
+			#[cfg(feature = "reda")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:reda.073.001.02") | None, "ReqToPayDbtrActvtnStsRpt") => Ok(Document::RequestToPayDebtorActivationStatusReportV02(map.next_value()?)),
+			#[cfg(feature = "remt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:remt.001.001.06") | None, "RmtAdvc") => Ok(Document::RemittanceAdviceV06(map.next_value()?)),
+			#[cfg(feature = "remt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:remt.002.001.03") | None, "RmtLctnAdvc") => Ok(Document::RemittanceLocationAdviceV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.003.001.08") | None, "GetAcct") => Ok(Document::GetAccountV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.004.001.10") | None, "RtrAcct") => Ok(Document::ReturnAccountV10(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.005.001.11") | None, "GetTx") => Ok(Document::GetTransactionV11(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.006.001.11") | None, "RtrTx") => Ok(Document::ReturnTransactionV11(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.007.001.10") | None, "ModfyTx") => Ok(Document::ModifyTransactionV10(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.008.001.11") | None, "CclTx") => Ok(Document::CancelTransactionV11(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.009.001.08") | None, "GetLmt") => Ok(Document::GetLimitV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.010.001.09") | None, "RtrLmt") => Ok(Document::ReturnLimitV09(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.011.001.08") | None, "ModfyLmt") => Ok(Document::ModifyLimitV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.012.001.08") | None, "DelLmt") => Ok(Document::DeleteLimitV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.013.001.04") | None, "GetMmb") => Ok(Document::GetMemberV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.014.001.05") | None, "RtrMmb") => Ok(Document::ReturnMemberV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.015.001.04") | None, "ModfyMmb") => Ok(Document::ModifyMemberV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.016.001.04") | None, "GetCcyXchgRate") => Ok(Document::GetCurrencyExchangeRateV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.017.001.05") | None, "RtrCcyXchgRate") => Ok(Document::ReturnCurrencyExchangeRateV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.018.001.05") | None, "GetBizDayInf") => Ok(Document::GetBusinessDayInformationV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.019.001.07") | None, "RtrBizDayInf") => Ok(Document::ReturnBusinessDayInformationV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.020.001.04") | None, "GetGnlBizInf") => Ok(Document::GetGeneralBusinessInformationV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.021.001.06") | None, "RtrGnlBizInf") => Ok(Document::ReturnGeneralBusinessInformationV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.023.001.07") | None, "BckpPmt") => Ok(Document::BackupPaymentV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.024.001.08") | None, "ModfyStgOrdr") => Ok(Document::ModifyStandingOrderV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.025.001.08") | None, "Rct") => Ok(Document::ReceiptV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.026.001.10") | None, "UblToApply") => Ok(Document::UnableToApplyV10(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.027.001.10") | None, "ClmNonRct") => Ok(Document::ClaimNonReceiptV10(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.028.001.12") | None, "AddtlPmtInf") => Ok(Document::AdditionalPaymentInformationV12(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.029.001.13") | None, "RsltnOfInvstgtn") => Ok(Document::ResolutionOfInvestigationV13(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.030.001.06") | None, "NtfctnOfCaseAssgnmt") => Ok(Document::NotificationOfCaseAssignmentV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.031.001.07") | None, "RjctInvstgtn") => Ok(Document::RejectInvestigationV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.032.001.05") | None, "CclCaseAssgnmt") => Ok(Document::CancelCaseAssignmentV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.033.001.07") | None, "ReqForDplct") => Ok(Document::RequestForDuplicateV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.034.001.07") | None, "Dplct") => Ok(Document::DuplicateV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.035.001.06") | None, "PrtryFrmtInvstgtn") => Ok(Document::ProprietaryFormatInvestigationV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.036.001.06") | None, "DbtAuthstnRspn") => Ok(Document::DebitAuthorisationResponseV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.037.001.10") | None, "DbtAuthstnReq") => Ok(Document::DebitAuthorisationRequestV10(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.038.001.05") | None, "CaseStsRptReq") => Ok(Document::CaseStatusReportRequestV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.039.001.06") | None, "CaseStsRpt") => Ok(Document::CaseStatusReportV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.040.001.04") | None, "FndEstmtdCshFcstRpt") => Ok(Document::FundEstimatedCashForecastReportV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.041.001.04") | None, "FndConfdCshFcstRpt") => Ok(Document::FundConfirmedCashForecastReportV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.042.001.04") | None, "FndDtldEstmtdCshFcstRpt") => Ok(Document::FundDetailedEstimatedCashForecastReportV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.043.001.04") | None, "FndDtldConfdCshFcstRpt") => Ok(Document::FundDetailedConfirmedCashForecastReportV04(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.044.001.03") | None, "FndConfdCshFcstRptCxl") => Ok(Document::FundConfirmedCashForecastReportCancellationV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.045.001.03") | None, "FndDtldConfdCshFcstRptCxl") => Ok(Document::FundDetailedConfirmedCashForecastReportCancellationV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.046.001.08") | None, "GetRsvatn") => Ok(Document::GetReservationV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.047.001.08") | None, "RtrRsvatn") => Ok(Document::ReturnReservationV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.048.001.07") | None, "ModfyRsvatn") => Ok(Document::ModifyReservationV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.049.001.07") | None, "DelRsvatn") => Ok(Document::DeleteReservationV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.050.001.07") | None, "LqdtyCdtTrf") => Ok(Document::LiquidityCreditTransferV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.051.001.07") | None, "LqdtyDbtTrf") => Ok(Document::LiquidityDebitTransferV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.052.001.12") | None, "BkToCstmrAcctRpt") => Ok(Document::BankToCustomerAccountReportV12(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.053.001.12") | None, "BkToCstmrStmt") => Ok(Document::BankToCustomerStatementV12(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.054.001.12") | None, "BkToCstmrDbtCdtNtfctn") => Ok(Document::BankToCustomerDebitCreditNotificationV12(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.055.001.12") | None, "CstmrPmtCxlReq") => Ok(Document::CustomerPaymentCancellationRequestV12(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.056.001.11") | None, "FIToFIPmtCxlReq") => Ok(Document::FIToFIPaymentCancellationRequestV11(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.057.001.08") | None, "NtfctnToRcv") => Ok(Document::NotificationToReceiveV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.058.001.09") | None, "NtfctnToRcvCxlAdvc") => Ok(Document::NotificationToReceiveCancellationAdviceV09(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.059.001.08") | None, "NtfctnToRcvStsRpt") => Ok(Document::NotificationToReceiveStatusReportV08(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.060.001.07") | None, "AcctRptgReq") => Ok(Document::AccountReportingRequestV07(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.061.001.02") | None, "PayInCall") => Ok(Document::PayInCallV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.062.001.03") | None, "PayInSchdl") => Ok(Document::PayInScheduleV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.063.001.02") | None, "PayInEvtAck") => Ok(Document::PayInEventAcknowledgementV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.066.001.02") | None, "IntraBalMvmntInstr") => Ok(Document::IntraBalanceMovementInstructionV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.067.001.02") | None, "IntraBalMvmntStsAdvc") => Ok(Document::IntraBalanceMovementStatusAdviceV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.068.001.02") | None, "IntraBalMvmntConf") => Ok(Document::IntraBalanceMovementConfirmationV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.069.001.05") | None, "GetStgOrdr") => Ok(Document::GetStandingOrderV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.070.001.06") | None, "RtrStgOrdr") => Ok(Document::ReturnStandingOrderV06(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.071.001.05") | None, "DelStgOrdr") => Ok(Document::DeleteStandingOrderV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.072.001.02") | None, "IntraBalMvmntModReq") => Ok(Document::IntraBalanceMovementModificationRequestV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.073.001.02") | None, "IntraBalMvmntModReqStsAdvc") => Ok(Document::IntraBalanceMovementModificationRequestStatusAdviceV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.074.001.02") | None, "IntraBalMvmntCxlReq") => Ok(Document::IntraBalanceMovementCancellationRequestV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.075.001.02") | None, "IntraBalMvmntCxlReqStsAdvc") => Ok(Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.078.001.02") | None, "IntraBalMvmntQry") => Ok(Document::IntraBalanceMovementQueryV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.079.001.02") | None, "IntraBalMvmntQryRspn") => Ok(Document::IntraBalanceMovementQueryResponseV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.080.001.02") | None, "IntraBalMvmntModQry") => Ok(Document::IntraBalanceMovementModificationQueryV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.081.001.02") | None, "IntraBalMvmntModRpt") => Ok(Document::IntraBalanceMovementModificationReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.082.001.02") | None, "IntraBalMvmntCxlQry") => Ok(Document::IntraBalanceMovementCancellationQueryV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.083.001.02") | None, "IntraBalMvmntCxlRpt") => Ok(Document::IntraBalanceMovementCancellationReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.084.001.02") | None, "IntraBalMvmntPstngRpt") => Ok(Document::IntraBalanceMovementPostingReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.085.001.02") | None, "IntraBalMvmntPdgRpt") => Ok(Document::IntraBalanceMovementPendingReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.086.001.05") | None, "BkSvcsBllgStmt") => Ok(Document::BankServicesBillingStatementV05(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.087.001.09") | None, "ReqToModfyPmt") => Ok(Document::RequestToModifyPaymentV09(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.088.001.02") | None, "NetRpt") => Ok(Document::NetReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.101.001.02") | None, "CretLmt") => Ok(Document::CreateLimitV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.102.001.03") | None, "CretStgOrdr") => Ok(Document::CreateStandingOrderV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.103.001.03") | None, "CretRsvatn") => Ok(Document::CreateReservationV03(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.104.001.01") | None, "CretMmb") => Ok(Document::CreateMemberV01(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.105.001.02") | None, "ChrgsPmtNtfctn") => Ok(Document::ChargesPaymentNotificationV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.106.001.02") | None, "ChrgsPmtReq") => Ok(Document::ChargesPaymentRequestV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.107.001.02") | None, "ChqPresntmntNtfctn") => Ok(Document::ChequePresentmentNotificationV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.108.001.02") | None, "ChqCxlOrStopReq") => Ok(Document::ChequeCancellationOrStopRequestV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.109.001.02") | None, "ChqCxlOrStopRpt") => Ok(Document::ChequeCancellationOrStopReportV02(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.110.001.01") | None, "InvstgtnReq") => Ok(Document::InvestigationRequestV01(map.next_value()?)),
+			#[cfg(feature = "camt")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:camt.111.001.01") | None, "InvstgtnRspn") => Ok(Document::InvestigationResponseV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.001.001.02") | None, "InfReqOpng") => Ok(Document::InformationRequestOpeningV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.002.001.02") | None, "InfReqRspn") => Ok(Document::InformationRequestResponseV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.003.001.01") | None, "InfReqStsChngNtfctn") => Ok(Document::InformationRequestStatusChangeNotificationV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.012.001.02") | None, "MnyMktScrdMktSttstclRpt") => Ok(Document::MoneyMarketSecuredMarketStatisticalReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.013.001.02") | None, "MnyMktUscrdMktSttstclRpt") => Ok(Document::MoneyMarketUnsecuredMarketStatisticalReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.014.001.02") | None, "MnyMktFXSwpsSttstclRpt") => Ok(Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.015.001.02") | None, "MnyMktOvrnghtIndxSwpsSttstclRpt") => Ok(Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.016.001.03") | None, "FinInstrmRptgTxRpt") => Ok(Document::FinancialInstrumentReportingTransactionReportV03(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.017.001.02") | None, "FinInstrmRptgRefDataRpt") => Ok(Document::FinancialInstrumentReportingReferenceDataReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.018.001.04") | None, "CtrctRegnReq") => Ok(Document::ContractRegistrationRequestV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.019.001.04") | None, "CtrctRegnConf") => Ok(Document::ContractRegistrationConfirmationV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.020.001.04") | None, "CtrctRegnClsrReq") => Ok(Document::ContractRegistrationClosureRequestV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.021.001.04") | None, "CtrctRegnAmdmntReq") => Ok(Document::ContractRegistrationAmendmentRequestV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.022.001.04") | None, "CtrctRegnStmt") => Ok(Document::ContractRegistrationStatementV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.023.001.04") | None, "CtrctRegnStmtReq") => Ok(Document::ContractRegistrationStatementRequestV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.024.001.04") | None, "PmtRgltryInfNtfctn") => Ok(Document::PaymentRegulatoryInformationNotificationV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.025.001.04") | None, "CcyCtrlSpprtgDocDlvry") => Ok(Document::CurrencyControlSupportingDocumentDeliveryV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.026.001.04") | None, "CcyCtrlReqOrLttr") => Ok(Document::CurrencyControlRequestOrLetterV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.027.001.04") | None, "CcyCtrlStsAdvc") => Ok(Document::CurrencyControlStatusAdviceV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.028.001.01") | None, "MnyMktSttstclRptStsAdvc") => Ok(Document::MoneyMarketStatisticalReportStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.029.001.05") | None, "DerivsTradRptQry") => Ok(Document::DerivativesTradeReportQueryV05(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.030.001.04") | None, "DerivsTradRpt") => Ok(Document::DerivativesTradeReportV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.031.001.01") | None, "FinInstrmRptgStsAdvc") => Ok(Document::FinancialInstrumentReportingStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.032.001.01") | None, "FinInstrmRptgEqtyTrnsprncyDataRpt") => Ok(Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.033.001.03") | None, "FinInstrmRptgNonEqtyTrnsprncyDataRpt") => Ok(Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.034.001.01") | None, "InvcTaxRpt") => Ok(Document::InvoiceTaxReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.035.001.01") | None, "FinInstrmRptgTradgVolCapDataRpt") => Ok(Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.036.001.03") | None, "FinInstrmRptgRefDataDltaRpt") => Ok(Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.038.001.01") | None, "InvcTaxRptStsAdvc") => Ok(Document::InvoiceTaxReportStatusAdviceV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.039.001.01") | None, "FinInstrmRptgNonWorkgDayRpt") => Ok(Document::FinancialInstrumentReportingNonWorkingDayReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.040.001.01") | None, "FinInstrmRptgEqtyTradgActvtyRpt") => Ok(Document::FinancialInstrumentReportingEquityTradingActivityReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.041.001.01") | None, "FinInstrmRptgNonEqtyTradgActvtyRpt") => Ok(Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.042.001.02") | None, "FinInstrmRptgInvldRefDataRpt") => Ok(Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.043.001.01") | None, "FinInstrmRptgRefDataIndxRpt") => Ok(Document::FinancialInstrumentReportingReferenceDataIndexReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.044.001.02") | None, "FinInstrmRptgEqtyTradgActvtyRslt") => Ok(Document::FinancialInstrumentReportingEquityTradingActivityResultV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.045.001.03") | None, "FinInstrmRptgNonEqtyTradgActvtyRslt") => Ok(Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.047.001.01") | None, "FinInstrmRptgCtryCdRpt") => Ok(Document::FinancialInstrumentReportingCountryCodeReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.048.001.01") | None, "FinInstrmRptgCcyCdRpt") => Ok(Document::FinancialInstrumentReportingCurrencyCodeReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.049.001.02") | None, "FinInstrmRptgMktIdCdRpt") => Ok(Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.050.001.01") | None, "FinInstrmRptgInstrmClssfctnRpt") => Ok(Document::FinancialInstrumentReportingInstrumentClassificationReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.052.001.02") | None, "SctiesFincgRptgTxRpt") => Ok(Document::SecuritiesFinancingReportingTransactionReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.053.001.01") | None, "FinInstrmRptgTradgVolCapRsltRpt") => Ok(Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.054.001.01") | None, "CCPClrMmbRpt") => Ok(Document::CCPClearingMemberReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.055.001.01") | None, "CCPMmbRqrmntsRpt") => Ok(Document::CCPMemberRequirementsReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.056.001.01") | None, "CCPMmbOblgtnsRpt") => Ok(Document::CCPMemberObligationsReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.057.001.02") | None, "CCPPrtflStrssTstgDefRpt") => Ok(Document::CCPPortfolioStressTestingDefinitionReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.058.001.01") | None, "CCPPrtflStrssTstgRsltRpt") => Ok(Document::CCPPortfolioStressTestingResultReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.059.001.01") | None, "CCPIncmStmtAndCptlAdqcyRpt") => Ok(Document::CCPIncomeStatementAndCapitalAdequacyReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.060.001.02") | None, "CCPDalyCshFlowsRpt") => Ok(Document::CCPDailyCashFlowsReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.061.001.01") | None, "CCPInvstmtsRpt") => Ok(Document::CCPInvestmentsReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.062.001.01") | None, "CCPLqdtyStrssTstgDefRpt") => Ok(Document::CCPLiquidityStressTestingDefinitionReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.063.001.01") | None, "CCPLqdtyStrssTstgRsltRpt") => Ok(Document::CCPLiquidityStressTestingResultReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.064.001.01") | None, "CCPAvlblFinRsrcsRpt") => Ok(Document::CCPAvailableFinancialResourcesReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.065.001.01") | None, "CCPBckTstgDefRpt") => Ok(Document::CCPBackTestingDefinitionReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.066.001.01") | None, "CCPBckTstgRsltRpt") => Ok(Document::CCPBackTestingResultReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.067.001.01") | None, "CCPCollRpt") => Ok(Document::CCPCollateralReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.068.001.01") | None, "CCPAcctPosRpt") => Ok(Document::CCPAccountPositionReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.069.001.01") | None, "CCPClrdPdctRpt") => Ok(Document::CCPClearedProductReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.070.001.02") | None, "SctiesFincgRptgTxMrgnDataRpt") => Ok(Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.071.001.02") | None, "SctiesFincgRptgTxReusdCollDataRpt") => Ok(Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.072.001.01") | None, "SttlmIntlrRpt") => Ok(Document::SettlementInternaliserReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.076.001.01") | None, "FinSprvsdPtyIdntyRpt") => Ok(Document::FinancialSupervisedPartyIdentityReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.077.001.01") | None, "FinBchmkRpt") => Ok(Document::FinancialBenchmarkReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.078.001.02") | None, "SctiesFincgRptgPairgReq") => Ok(Document::SecuritiesFinancingReportingPairingRequestV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.079.001.02") | None, "SctiesFincgRptgTxStatRpt") => Ok(Document::SecuritiesFinancingReportingTransactionStateReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.080.001.02") | None, "SctiesFincgRptgRcncltnStsAdvc") => Ok(Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.083.001.02") | None, "SctiesFincgRptgMssngCollReq") => Ok(Document::SecuritiesFinancingReportingMissingCollateralRequestV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.084.001.02") | None, "SctiesFincgRptgTxStsAdvc") => Ok(Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.085.001.02") | None, "SctiesFincgRptgMrgnDataTxStatRpt") => Ok(Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.086.001.02") | None, "SctiesFincgRptgReusdCollDataTxStatRpt") => Ok(Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.090.001.02") | None, "DerivsTradPosSetRpt") => Ok(Document::DerivativesTradePositionSetReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.091.001.03") | None, "DerivsTradRcncltnSttstclRpt") => Ok(Document::DerivativesTradeReconciliationStatisticalReportV03(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.092.001.04") | None, "DerivsTradRjctnSttstclRpt") => Ok(Document::DerivativesTradeRejectionStatisticalReportV04(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.094.001.02") | None, "SctiesFincgRptgTxQry") => Ok(Document::SecuritiesFinancingReportingTransactionQueryV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.100.001.01") | None, "SttlmFlsMnthlyRpt") => Ok(Document::SettlementFailsMonthlyReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.101.001.01") | None, "SttlmFlsAnlRpt") => Ok(Document::SettlementFailsAnnualReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.102.001.01") | None, "FinInstrmRptgCxlRpt") => Ok(Document::FinancialInstrumentReportingCancellationReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.105.001.01") | None, "SctiesFincgRptgPosSetRpt") => Ok(Document::SecuritiesFinancingReportingPositionSetReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.106.001.01") | None, "DerivsTradWrnngsRpt") => Ok(Document::DerivativesTradeWarningsReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.107.001.02") | None, "DerivsTradStatRpt") => Ok(Document::DerivativesTradeStateReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.108.001.02") | None, "DerivsTradMrgnDataRpt") => Ok(Document::DerivativesTradeMarginDataReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.109.001.02") | None, "DerivsTradMrgnDataTxStatRpt") => Ok(Document::DerivativesTradeMarginDataTransactionStateReportV02(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.112.001.01") | None, "CCPIntrprbltyRpt") => Ok(Document::CCPInteroperabilityReportV01(map.next_value()?)),
+			#[cfg(feature = "auth")]
+			(Some("urn:iso:std:iso:20022:tech:xsd:auth.113.001.01") | None, "OrdrBookRpt") => Ok(Document::OrderBookReportV01(map.next_value()?)),
+			(namespace, _) => {
+				map.next_value::<IgnoredAny>()?;
+				Ok(Document::Unknown(Box::new(UnknownMessage {
+					namespace: namespace.map(String::from),
+					root_element: root.to_string(),
+					raw_xml: String::new(),
+				})))
+			}
+    }
+}
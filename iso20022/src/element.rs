@@ -0,0 +1,272 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! A plain tree of the elements of an XML message, for checks that work on the XML itself
+//! rather than on the typed message, e.g. to cover versions that are not compiled in or to check
+//! the XML against its schema before it is deserialized.
+//!
+//! Elements are located like the typed validation does, so that paths compare with
+//! [`ValidationError::path`](iso20022_common::ValidationError::path): every item of a repeated
+//! field carries its position counting from 1, e.g. `CdtTrfTxInf[1]` even when it is the only one.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use quick_xml::errors::IllFormedError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::{NamespaceError, ResolveResult};
+use quick_xml::NsReader;
+use crate::document::Document;
+use crate::error::Error;
+
+/// An XML element with its attributes, text and child elements, names without namespace prefix.
+/// Namespace declarations are not kept as attributes, they resolve the element namespace.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Element {
+	pub name: String,
+	/// The namespace of the element, bound to its prefix or the default one, empty without namespace.
+	pub namespace: String,
+	pub attributes: Vec<(String, String)>,
+	/// The text content, trimmed, empty for an element holding child elements.
+	pub text: String,
+	pub children: Vec<Element>,
+	/// The byte offset of the start tag in the parsed XML.
+	pub position: usize,
+	/// Whether the element is an item of a repeated field, known from [`Element::mark_repeated`].
+	pub repeated: bool,
+}
+
+impl Element {
+	/// Parses the root element of `xml`, a leading XML declaration is skipped.
+	pub fn parse(xml: &str) -> Result<Element, Error> {
+		Element::parse_located(xml).map_err(|(e, _)| xml_error(e))
+	}
+
+	/// Parses like [`Element::parse`], a failure carrying the byte offset in `xml` where it was found.
+	pub fn parse_located(xml: &str) -> Result<Element, (quick_xml::Error, usize)> {
+		let mut reader = NsReader::from_str(xml);
+		reader.config_mut().trim_text(true);
+		let mut stack: Vec<Element> = Vec::new();
+		loop {
+			// The resolved namespace borrows the reader, so it is copied before the position is read.
+			let resolved = reader.read_resolved_event().map(|(namespace, event)| (resolve(namespace), event));
+			let (namespace, event) = resolved.map_err(|e| (e, reader.error_position() as usize))?;
+			let end = reader.buffer_position() as usize;
+			match event {
+				// `e` holds the tag without its `<` and `>`, or `/>` for an empty element.
+				Event::Start(e) => stack.push(Element::start(&e, namespace, end - e.len() - 2).map_err(|e| (e, end))?),
+				Event::Empty(e) => {
+					let element = Element::start(&e, namespace, end - e.len() - 3).map_err(|e| (e, end))?;
+					match stack.last_mut() {
+						Some(parent) => parent.children.push(element),
+						None => return Ok(element),
+					}
+				}
+				Event::Text(e) => {
+					if let Some(open) = stack.last_mut() {
+						open.text.push_str(&e.unescape().map_err(|e| (e, end))?);
+					}
+				}
+				Event::CData(e) => {
+					if let Some(open) = stack.last_mut() {
+						open.text.push_str(&String::from_utf8_lossy(&e));
+					}
+				}
+				Event::End(_) => {
+					// The reader fails on an end tag without its start tag, so one is open.
+					if let Some(element) = stack.pop() {
+						match stack.last_mut() {
+							Some(parent) => parent.children.push(element),
+							None => return Ok(element),
+						}
+					}
+				}
+				Event::Eof => {
+					let name = stack.last().map(|open| open.name.clone()).unwrap_or_default();
+					return Err((quick_xml::Error::IllFormed(IllFormedError::MissingEndTag(name)), end));
+				}
+				_ => {}
+			}
+		}
+	}
+
+	fn start(e: &BytesStart, namespace: Result<String, NamespaceError>, position: usize) -> Result<Element, quick_xml::Error> {
+		let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+		let mut element = Element { name, namespace: namespace?, position, ..Default::default() };
+		for attr in e.attributes() {
+			let attr = attr?;
+			if attr.key.as_namespace_binding().is_some() {
+				continue;
+			}
+			let key = String::from_utf8_lossy(attr.key.local_name().as_ref()).into_owned();
+			let value = attr.unescape_value()?.into_owned();
+			element.attributes.push((key, value));
+		}
+		Ok(element)
+	}
+
+	/// Parses the `<Document>` element of `doc` with its repeated fields marked. While a
+	/// [`RuleSet`](crate::rule::RuleSet) is applied to `doc`, the element is parsed once for all of its rules.
+	#[cfg(feature = "derive_serde")]
+	pub fn of_document(doc: &Document) -> Result<Rc<Element>, Error> {
+		let key = doc as *const Document;
+		let cached = APPLYING.with(|applying| match &*applying.borrow() {
+			Some((applying, Some(element))) if *applying == key => Some(element.clone()),
+			_ => None,
+		});
+		if let Some(element) = cached {
+			return Ok(element);
+		}
+		let mut element = Element::parse(&doc.to_xml_string()?)?;
+		if let Ok(value) = serde_json::to_value(doc) {
+			element.mark_repeated(&value);
+		}
+		let element = Rc::new(element);
+		APPLYING.with(|applying| {
+			if let Some((applying, parsed)) = &mut *applying.borrow_mut() {
+				if *applying == key {
+					*parsed = Some(element.clone());
+				}
+			}
+		});
+		Ok(element)
+	}
+
+	/// Marks the items of the repeated fields of the element and its descendants, `value` being
+	/// the `serde_json` form of the typed message the element was written from, where those
+	/// fields are arrays.
+	pub fn mark_repeated(&mut self, value: &serde_json::Value) {
+		let serde_json::Value::Object(fields) = value else {
+			return;
+		};
+		let mut positions: HashMap<String, usize> = HashMap::new();
+		for child in &mut self.children {
+			match fields.get(&child.name) {
+				Some(serde_json::Value::Array(values)) => {
+					let position = positions.entry(child.name.clone()).or_insert(0);
+					child.repeated = true;
+					if let Some(value) = values.get(*position) {
+						child.mark_repeated(value);
+					}
+					*position += 1;
+				}
+				Some(value) => child.mark_repeated(value),
+				None => {}
+			}
+		}
+	}
+
+	/// The first child element `name`.
+	pub fn child(&self, name: &str) -> Option<&Element> {
+		self.children.iter().find(|child| child.name == name)
+	}
+
+	/// Every child element `name`, in document order.
+	pub fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+		self.children.iter().filter(move |child| child.name == name)
+	}
+
+	/// Whether the element holds a child element `name`.
+	pub fn has(&self, name: &str) -> bool {
+		self.child(name).is_some()
+	}
+
+	/// The text of the child element `name`.
+	pub fn text_of(&self, name: &str) -> Option<&str> {
+		self.child(name).map(|child| child.text.as_str())
+	}
+
+	/// The value of the attribute `name`.
+	pub fn attribute(&self, name: &str) -> Option<&str> {
+		self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+	}
+}
+
+/// The `children` of an element, each with its position among the items of its repeated field
+/// counting from 0 as [`Validator::item`](iso20022_common::Validator::item) takes it, `None` for
+/// the other children. Unless marked by [`Element::mark_repeated`], e.g. for a version that is not
+/// compiled in, a field counts as repeated when it occurs more than once.
+pub fn items(children: &[Element]) -> Vec<(&Element, Option<usize>)> {
+	let mut counts: HashMap<&str, usize> = HashMap::new();
+	for child in children {
+		*counts.entry(child.name.as_str()).or_insert(0) += 1;
+	}
+	let mut positions: HashMap<&str, usize> = HashMap::new();
+	children
+		.iter()
+		.map(|child| {
+			if !child.repeated && counts[child.name.as_str()] == 1 {
+				return (child, None);
+			}
+			let position = positions.entry(child.name.as_str()).or_insert(0);
+			*position += 1;
+			(child, Some(*position - 1))
+		})
+		.collect()
+}
+
+/// The path segment of the child `name` at the position `item` returned by [`items`], e.g. `CdtTrfTxInf[1]`.
+pub fn segment(name: &str, item: Option<usize>) -> String {
+	match item {
+		Some(index) => format!("{}[{}]", name, index + 1),
+		None => name.to_string(),
+	}
+}
+
+// The document a `RuleSet` is applied to, with its element tree once a rule parsed it.
+type Applying = Option<(*const Document, Option<Rc<Element>>)>;
+
+thread_local! {
+	static APPLYING: RefCell<Applying> = const { RefCell::new(None) };
+}
+
+/// Runs `apply` sharing the element tree of `doc` between the calls of [`Element::of_document`].
+/// `doc` is borrowed for the whole run, so it cannot change while its tree is cached.
+pub fn sharing<T>(doc: &Document, apply: impl FnOnce() -> T) -> T {
+	// Restores the document applied before, also when `apply` panics.
+	struct Restore(Applying);
+
+	impl Drop for Restore {
+		fn drop(&mut self) {
+			let previous = self.0.take();
+			APPLYING.with(|applying| *applying.borrow_mut() = previous);
+		}
+	}
+
+	let _restore = Restore(APPLYING.with(|applying| applying.replace(Some((doc as *const Document, None)))));
+	apply()
+}
+
+// The namespace bound to an element, empty without one, or the undeclared prefix of the element.
+fn resolve(namespace: ResolveResult) -> Result<String, NamespaceError> {
+	match namespace {
+		ResolveResult::Bound(namespace) => Ok(String::from_utf8_lossy(namespace.as_ref()).into_owned()),
+		ResolveResult::Unbound => Ok(String::new()),
+		ResolveResult::Unknown(prefix) => Err(NamespaceError::UnknownPrefix(prefix)),
+	}
+}
+
+fn xml_error(e: quick_xml::Error) -> Error {
+	Error::Deserialize { path: String::new(), source: e.into() }
+}
@@ -0,0 +1,88 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+use std::fmt;
+use std::io;
+
+/// Errors raised while reading or writing an ISO 20022 XML message.
+#[derive(Debug)]
+pub enum Error {
+    /// The XML could not be mapped onto a message, `path` locates the failing element.
+    Deserialize { path: String, source: quick_xml::DeError },
+    /// The message could not be written as XML, `path` locates the failing field.
+    Serialize { path: String, source: quick_xml::SeError },
+    /// The document holds an unknown message without its original XML and cannot be written.
+    UnknownDocument,
+    /// Reading from or writing to the underlying stream failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Deserialize { path, source } if path.is_empty() || path == "." => write!(f, "failed to parse XML: {}", source),
+            Error::Deserialize { path, source } => write!(f, "failed to parse XML at {}: {}", path, source),
+            Error::Serialize { path, source } if path.is_empty() || path == "." => write!(f, "failed to write XML: {}", source),
+            Error::Serialize { path, source } => write!(f, "failed to write XML at {}: {}", path, source),
+            Error::UnknownDocument => write!(f, "unknown document type"),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Serialize { source, .. } => Some(source),
+            Error::UnknownDocument => None,
+            Error::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_path_to_error::Error<quick_xml::DeError>> for Error {
+    fn from(e: serde_path_to_error::Error<quick_xml::DeError>) -> Self {
+        Error::Deserialize { path: e.path().to_string(), source: e.into_inner() }
+    }
+}
+
+impl From<serde_path_to_error::Error<quick_xml::SeError>> for Error {
+    fn from(e: serde_path_to_error::Error<quick_xml::SeError>) -> Self {
+        Error::Serialize { path: e.path().to_string(), source: e.into_inner() }
+    }
+}
+
+impl From<quick_xml::SeError> for Error {
+    fn from(e: quick_xml::SeError) -> Self {
+        Error::Serialize { path: String::new(), source: e }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,97 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Identifier of an ISO 20022 message definition, e.g. `pacs.008.001.12`.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Prefix of the XML namespace of every ISO 20022 message.
+pub const NAMESPACE_PREFIX: &str = "urn:iso:std:iso:20022:tech:xsd:";
+
+/// Identifier of a message definition: business area, message number, variant and version,
+/// written as `pacs.008.001.12`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MessageIdentifier {
+	/// The four letter business area, e.g. `pacs`.
+	pub business_area: String,
+	/// The message number within the business area, e.g. `8` for `pacs.008`.
+	pub message: u16,
+	/// The message variant, `1` for the base message.
+	pub variant: u16,
+	/// The message version.
+	pub version: u16,
+}
+
+impl MessageIdentifier {
+	/// Returns the XML namespace of the message, e.g. `urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12`.
+	pub fn namespace(&self) -> String {
+		format!("{}{}", NAMESPACE_PREFIX, self)
+	}
+}
+
+impl fmt::Display for MessageIdentifier {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}.{:03}.{:03}.{:02}", self.business_area, self.message, self.variant, self.version)
+	}
+}
+
+/// Error returned when a string is not a message identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMessageIdentifierError(String);
+
+impl fmt::Display for ParseMessageIdentifierError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "invalid message identifier {}", self.0)
+	}
+}
+
+impl std::error::Error for ParseMessageIdentifierError {}
+
+impl FromStr for MessageIdentifier {
+	type Err = ParseMessageIdentifierError;
+
+	/// Parses `pacs.008.001.12` or the namespace `urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let err = || ParseMessageIdentifierError(s.to_string());
+		let id = s.strip_prefix(NAMESPACE_PREFIX).unwrap_or(s);
+		let parts: Vec<&str> = id.split('.').collect();
+		let [area, message, variant, version] = parts[..] else { return Err(err()) };
+		if area.len() != 4 || !area.bytes().all(|b| b.is_ascii_lowercase()) {
+			return Err(err());
+		}
+		let number = |part: &str, len: usize| -> Result<u16, ParseMessageIdentifierError> {
+			if part.len() != len || !part.bytes().all(|b| b.is_ascii_digit()) {
+				return Err(err());
+			}
+			part.parse().map_err(|_| err())
+		};
+		Ok(MessageIdentifier {
+			business_area: area.to_string(),
+			message: number(message, 3)?,
+			variant: number(variant, 3)?,
+			version: number(version, 2)?,
+		})
+	}
+}
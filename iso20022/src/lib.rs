@@ -25,3 +25,18 @@
 
 pub mod document;
 pub mod common;
+pub mod error;
+pub mod identifier;
+pub mod registry;
+pub mod rule;
+#[cfg(feature = "head")]
+pub mod message;
+#[cfg(feature = "derive_serde")]
+pub mod migrate;
+#[cfg(feature = "derive_serde")]
+pub mod cbpr_plus;
+#[cfg(feature = "derive_serde")]
+pub mod declarative;
+#[cfg_attr(not(feature = "derive_serde"), allow(dead_code))]
+mod element;
+pub mod xsd;
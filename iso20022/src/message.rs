@@ -0,0 +1,366 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+#[cfg(feature = "derive_serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "derive_serde")]
+use serde::de::value::MapAccessDeserializer;
+#[cfg(feature = "derive_serde")]
+use serde::de::{DeserializeSeed, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
+#[cfg(feature = "derive_serde")]
+use serde::ser::SerializeStruct;
+#[cfg(feature = "derive_serde")]
+use std::fmt;
+#[cfg(feature = "derive_serde")]
+use std::io::{BufRead, Read, Write};
+use iso20022_common::{ValidationError, ValidationReport, Validator};
+use open_payments_iso20022_head::header::{BusinessApplicationHeaderV02, BusinessApplicationHeaderV04, PayloadDescription2};
+
+use crate::document::Document;
+#[cfg(feature = "derive_serde")]
+use crate::document::{element_span, element_spans, XML_DECLARATION};
+#[cfg(feature = "derive_serde")]
+use crate::error::Error;
+
+const HEAD_001_001_02: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.02";
+const HEAD_001_001_04: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.04";
+const HEAD_002_001_01: &str = "urn:iso:std:iso:20022:tech:xsd:head.002.001.01";
+
+/// Business Application Header (`<AppHdr>`), the namespace of the element selects the version.
+/// A header without namespace is read as `head.001.001.04`. Only the bundled `head.001.001.02`
+/// and `head.001.001.04` schemas are supported, other versions such as `head.001.001.03` are
+/// rejected with an "unsupported AppHdr namespace" error.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub enum ApplicationHeader {
+	BusinessApplicationHeaderV02(Box<BusinessApplicationHeaderV02>),
+	BusinessApplicationHeaderV04(Box<BusinessApplicationHeaderV04>),
+}
+
+#[cfg(feature = "derive_default")]
+impl Default for ApplicationHeader {
+    fn default() -> Self {
+        ApplicationHeader::BusinessApplicationHeaderV04(Box::default())
+    }
+}
+
+impl ApplicationHeader {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&mut Validator::fail_fast())
+    }
+
+    pub fn validate_all(&self) -> ValidationReport {
+        ValidationReport::collect(|validator| self.validate_with(validator))
+    }
+
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        match self {
+            ApplicationHeader::BusinessApplicationHeaderV02(ref value) => value.validate_with(validator),
+            ApplicationHeader::BusinessApplicationHeaderV04(ref value) => value.validate_with(validator),
+        }
+    }
+
+    /// Returns the XML namespace of the header version.
+    pub fn namespace(&self) -> &'static str {
+        match self {
+            ApplicationHeader::BusinessApplicationHeaderV02(_) => HEAD_001_001_02,
+            ApplicationHeader::BusinessApplicationHeaderV04(_) => HEAD_001_001_04,
+        }
+    }
+
+    /// Returns the business message identifier (`BizMsgIdr`).
+    pub fn biz_msg_idr(&self) -> &str {
+        match self {
+            ApplicationHeader::BusinessApplicationHeaderV02(ref value) => &value.biz_msg_idr,
+            ApplicationHeader::BusinessApplicationHeaderV04(ref value) => &value.biz_msg_idr,
+        }
+    }
+
+    /// Returns the message definition identifier (`MsgDefIdr`), e.g. `pacs.008.001.08`.
+    pub fn msg_def_idr(&self) -> &str {
+        match self {
+            ApplicationHeader::BusinessApplicationHeaderV02(ref value) => &value.msg_def_idr,
+            ApplicationHeader::BusinessApplicationHeaderV04(ref value) => &value.msg_def_idr,
+        }
+    }
+}
+
+/// A business message: the `<AppHdr>` and `<Document>` pair exchanged inside a transport envelope.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessMessage {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "AppHdr") )]
+	pub app_hdr: ApplicationHeader,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Document") )]
+	pub document: Document,
+}
+
+impl BusinessMessage {
+    /// Element name used for the envelope wrapping `<AppHdr>` and `<Document>` when writing XML.
+    pub const ENVELOPE: &'static str = "Envelope";
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&mut Validator::fail_fast())
+    }
+
+    /// Validates the header and the document, returning every violation of both.
+    pub fn validate_all(&self) -> ValidationReport {
+        ValidationReport::collect(|validator| self.validate_with(validator))
+    }
+
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        validator.element("AppHdr", "app_hdr", |validator| self.app_hdr.validate_with(validator))?;
+        validator.element("", "document", |validator| self.document.validate_with(validator))
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl BusinessMessage {
+    /// Parses an envelope holding an `<AppHdr>` and a `<Document>` element.
+    /// The envelope element name is not checked, so any transport wrapper can be read.
+    pub fn from_xml_str(xml: &str) -> Result<Self, Error> {
+        let mut de = quick_xml::de::Deserializer::from_str(xml);
+        let mut message: BusinessMessage = serde_path_to_error::deserialize(&mut de)?;
+        if let Some(document) = element_span(xml, "Document") {
+            message.document.resolve_unknown(document)?;
+        }
+        Ok(message)
+    }
+
+    /// Parses an envelope holding an `<AppHdr>` and a `<Document>` element read from `reader`.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, Error> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+        Self::from_xml_str(&xml)
+    }
+
+    /// Writes the message wrapped in an [`BusinessMessage::ENVELOPE`] element.
+    pub fn to_xml_string(&self) -> Result<String, Error> {
+        self.to_xml_string_with_envelope(Self::ENVELOPE)
+    }
+
+    /// Writes the message wrapped in an `envelope` element.
+    pub fn to_xml_string_with_envelope(&self, envelope: &str) -> Result<String, Error> {
+        let mut xml = String::from(XML_DECLARATION);
+        self.write_envelope(&mut xml, envelope)?;
+        Ok(xml)
+    }
+
+    // Appends the message wrapped in an `envelope` element to `xml`, without an XML declaration.
+    fn write_envelope(&self, xml: &mut String, envelope: &str) -> Result<(), Error> {
+        if let Document::Unknown(ref value) = self.document {
+            // The unknown message is written back as it was read, after the header.
+            if value.raw_xml.is_empty() {
+                return Err(Error::UnknownDocument);
+            }
+            let app_hdr = quick_xml::se::to_string_with_root("AppHdr", &self.app_hdr)?;
+            xml.push_str(&format!("<{}>{}{}</{}>", envelope, app_hdr, value.raw_xml, envelope));
+            return Ok(());
+        }
+        let serializer = quick_xml::se::Serializer::with_root(xml, Some(envelope))?;
+        serde_path_to_error::serialize(self, serializer)?;
+        Ok(())
+    }
+
+    /// Writes the message wrapped in an [`BusinessMessage::ENVELOPE`] element to `writer`.
+    pub fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(self.to_xml_string()?.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// A business file (`head.002.001.01`, root element `<Xchg>`) carrying many business messages,
+/// each `<Pyld>` holds one `<AppHdr>` and `<Document>` pair.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_default", derive(Default))]
+#[cfg_attr(feature = "derive_serde", derive(Deserialize))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessFile {
+	#[cfg_attr( feature = "derive_serde", serde(rename = "PyldDesc") )]
+	pub pyld_desc: PayloadDescription2,
+	#[cfg_attr( feature = "derive_serde", serde(rename = "Pyld", default) )]
+	pub pyld: Vec<BusinessMessage>,
+}
+
+impl BusinessFile {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&mut Validator::fail_fast())
+    }
+
+    pub fn validate_all(&self) -> ValidationReport {
+        ValidationReport::collect(|validator| self.validate_with(validator))
+    }
+
+    pub fn validate_with(&self, validator: &mut Validator) -> Result<(), ValidationError> {
+        validator.element("Xchg", "", |validator| {
+            validator.element("PyldDesc", "pyld_desc", |validator| self.pyld_desc.validate_with(validator))?;
+            for (i, item) in self.pyld.iter().enumerate() {
+                validator.item("Pyld", "pyld", i, |validator| item.validate_with(validator))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl BusinessFile {
+    /// Parses a `<Xchg>` business file. Unknown messages keep their original XML.
+    pub fn from_xml_str(xml: &str) -> Result<Self, Error> {
+        let mut de = quick_xml::de::Deserializer::from_str(xml);
+        let mut file: BusinessFile = serde_path_to_error::deserialize(&mut de)?;
+        // Each payload holds exactly one `<Document>`, in the order of the payloads.
+        for (pyld, document) in file.pyld.iter_mut().zip(element_spans(xml, "Document")) {
+            pyld.document.resolve_unknown(document)?;
+        }
+        Ok(file)
+    }
+
+    /// Parses a `<Xchg>` business file read from `reader`.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, Error> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+        Self::from_xml_str(&xml)
+    }
+
+    /// Writes the business file as a `<Xchg>` XML string. Unknown messages are written back
+    /// as they were read.
+    pub fn to_xml_string(&self) -> Result<String, Error> {
+        let mut xml = format!("{}<Xchg xmlns=\"{}\">", XML_DECLARATION, HEAD_002_001_01);
+        let serializer = quick_xml::se::Serializer::with_root(&mut xml, Some("PyldDesc"))?;
+        serde_path_to_error::serialize(&self.pyld_desc, serializer)?;
+        for pyld in &self.pyld {
+            pyld.write_envelope(&mut xml, "Pyld")?;
+        }
+        xml.push_str("</Xchg>");
+        Ok(xml)
+    }
+
+    /// Writes the business file as a `<Xchg>` XML message to `writer`.
+    pub fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(self.to_xml_string()?.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl Serialize for BusinessFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Xchg", 3)?;
+        state.serialize_field("@xmlns", HEAD_002_001_01)?;
+        state.serialize_field("PyldDesc", &self.pyld_desc)?;
+        state.serialize_field("Pyld", &self.pyld)?;
+        state.end()
+    }
+}
+
+// Writes the header fields after the `xmlns` attribute of the `<AppHdr>` element.
+#[cfg(feature = "derive_serde")]
+#[derive(Serialize)]
+struct Namespaced<'a, T> {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    #[serde(flatten)]
+    value: &'a T,
+}
+
+#[cfg(feature = "derive_serde")]
+impl Serialize for ApplicationHeader {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let xmlns = self.namespace();
+        match self {
+            ApplicationHeader::BusinessApplicationHeaderV02(ref value) => Namespaced { xmlns, value: value.as_ref() }.serialize(serializer),
+            ApplicationHeader::BusinessApplicationHeaderV04(ref value) => Namespaced { xmlns, value: value.as_ref() }.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de> Deserialize<'de> for ApplicationHeader {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ApplicationHeaderVisitor)
+    }
+}
+
+#[cfg(feature = "derive_serde")]
+struct ApplicationHeaderVisitor;
+
+#[cfg(feature = "derive_serde")]
+impl<'de> Visitor<'de> for ApplicationHeaderVisitor {
+    type Value = ApplicationHeader;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an ISO 20022 AppHdr element")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ApplicationHeader, A::Error> {
+        // Attributes come first, the first child element is handed back to the header.
+        let mut namespace: Option<String> = None;
+        let mut key: Option<String> = None;
+        while let Some(next) = map.next_key::<String>()? {
+            if next == "@xmlns" {
+                namespace = Some(map.next_value()?);
+            } else if next.starts_with('@') {
+                map.next_value::<IgnoredAny>()?;
+            } else {
+                key = Some(next);
+                break;
+            }
+        }
+        let fields = MapAccessDeserializer::new(PendingKeyMap { key, map });
+        match namespace.as_deref() {
+            Some(HEAD_001_001_02) => Ok(ApplicationHeader::BusinessApplicationHeaderV02(Box::new(BusinessApplicationHeaderV02::deserialize(fields)?))),
+            Some(HEAD_001_001_04) | None => Ok(ApplicationHeader::BusinessApplicationHeaderV04(Box::new(BusinessApplicationHeaderV04::deserialize(fields)?))),
+            Some(namespace) => Err(de::Error::custom(format!("unsupported AppHdr namespace {}", namespace))),
+        }
+    }
+}
+
+// Map access replaying a key that was already read before the remaining entries.
+#[cfg(feature = "derive_serde")]
+struct PendingKeyMap<A> {
+    key: Option<String>,
+    map: A,
+}
+
+#[cfg(feature = "derive_serde")]
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for PendingKeyMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error> {
+        match self.key.take() {
+            Some(key) => seed.deserialize(key.into_deserializer()).map(Some),
+            None => self.map.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        self.map.next_value_seed(seed)
+    }
+}
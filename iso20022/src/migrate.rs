@@ -0,0 +1,184 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Migration of messages between versions of the same message definition.
+//!
+//! Elements are carried over by name: the source message is written as XML and read back
+//! as the target version. Every element or attribute of the source that does not survive
+//! unchanged in the target is reported as a [`Loss`], so downgrades never drop data silently.
+//! The target is then checked against its own facets, so that a value carried over but too
+//! long or not in a code set of the target version is reported as well.
+
+use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use iso20022_common::{ValidationErrorKind, ValidationReport};
+use crate::element::{self, Element};
+use crate::error::Error;
+
+#[cfg(feature = "head")] use open_payments_iso20022_head::header::{BusinessApplicationHeaderV02, BusinessApplicationHeaderV04};
+#[cfg(feature = "pacs")] use open_payments_iso20022_pacs::pacs_002_001_12::FIToFIPaymentStatusReportV12;
+#[cfg(feature = "pacs")] use open_payments_iso20022_pacs::pacs_002_001_14::FIToFIPaymentStatusReportV14;
+
+const ROOT: &str = "Msg";
+
+/// A value of the source message that was not carried over unchanged.
+/// `path` locates the element below the message root as [`ValidationError::path`] does, e.g.
+/// `/GrpHdr/InstgAgt/FinInstnId/BICFI`: every item of a repeated field carries its position
+/// counting from 1 (`/TxInfAndSts[1]/StsId`) and attributes a leading `@`.
+///
+/// [`ValidationError::path`]: iso20022_common::ValidationError::path
+#[derive(Debug, Clone, PartialEq)]
+pub enum Loss {
+	/// The target version has no such element, the value is dropped.
+	Dropped { path: String, value: String },
+	/// The target version holds a different value, e.g. a value its type writes in another form.
+	Changed { path: String, from: String, to: String },
+	/// The value is carried over but breaks a facet of the target version, e.g. a text longer
+	/// than its `maxLength`.
+	Invalid { path: String, kind: ValidationErrorKind },
+}
+
+impl Loss {
+	pub fn path(&self) -> &str {
+		match self {
+			Loss::Dropped { path, .. } => path,
+			Loss::Changed { path, .. } => path,
+			Loss::Invalid { path, .. } => path,
+		}
+	}
+}
+
+/// A message converted to another version, with the values lost on the way.
+#[derive(Debug)]
+pub struct Migrated<T> {
+	pub message: T,
+	pub losses: Vec<Loss>,
+}
+
+impl<T> Migrated<T> {
+	/// Returns `true` when every value of the source message is present in the target.
+	pub fn is_lossless(&self) -> bool {
+		self.losses.is_empty()
+	}
+
+	/// Returns the message, failing with the losses when the migration was lossy.
+	pub fn into_lossless(self) -> Result<T, Vec<Loss>> {
+		if self.losses.is_empty() { Ok(self.message) } else { Err(self.losses) }
+	}
+}
+
+/// A message version a migration converts to, checked against its facets once converted.
+pub trait Target: Serialize + DeserializeOwned {
+	fn validate_all(&self) -> ValidationReport;
+}
+
+macro_rules! target {
+	($($(#[$cfg:meta])* $ty:ident),*) => {$(
+		$(#[$cfg])*
+		impl Target for $ty {
+			fn validate_all(&self) -> ValidationReport {
+				$ty::validate_all(self)
+			}
+		}
+	)*};
+}
+
+target!(
+	#[cfg(feature = "head")] BusinessApplicationHeaderV02,
+	#[cfg(feature = "head")] BusinessApplicationHeaderV04,
+	#[cfg(feature = "pacs")] FIToFIPaymentStatusReportV12,
+	#[cfg(feature = "pacs")] FIToFIPaymentStatusReportV14
+);
+
+/// Converts `source` into the message version `T` by element name.
+///
+/// Fails when an element mandatory in `T` is missing from `source`.
+pub fn migrate<S: Serialize, T: Target>(source: &S) -> Result<Migrated<T>, Error> {
+	let xml = quick_xml::se::to_string_with_root(ROOT, source)?;
+	let mut de = quick_xml::de::Deserializer::from_str(&xml);
+	let message: T = serde_path_to_error::deserialize(&mut de)?;
+	let written = quick_xml::se::to_string_with_root(ROOT, &message)?;
+
+	let target: HashMap<String, String> = leaves(&written, &message)?.into_iter().collect();
+	let mut losses: Vec<Loss> = leaves(&xml, source)?.into_iter().filter_map(|(path, value)| match target.get(&path) {
+		None => Some(Loss::Dropped { path, value }),
+		Some(to) if *to != value => Some(Loss::Changed { path, from: value, to: to.clone() }),
+		Some(_) => None,
+	}).collect();
+	for error in message.validate_all().errors {
+		losses.push(Loss::Invalid { path: error.path, kind: *error.kind });
+	}
+	Ok(Migrated { message, losses })
+}
+
+// Flattens `xml`, written from `message`, into the paths and values of its attributes and leaf
+// elements in document order. The repeated fields of `message` locate their items.
+fn leaves<M: Serialize>(xml: &str, message: &M) -> Result<Vec<(String, String)>, Error> {
+	let mut root = Element::parse(xml)?;
+	if let Ok(value) = serde_json::to_value(message) {
+		root.mark_repeated(&value);
+	}
+	let mut leaves = Vec::new();
+	flatten(&root, "", &mut leaves);
+	Ok(leaves)
+}
+
+fn flatten(element: &Element, path: &str, leaves: &mut Vec<(String, String)>) {
+	for (child, item) in element::items(&element.children) {
+		let path = format!("{}/{}", path, element::segment(&child.name, item));
+		for (key, value) in &child.attributes {
+			leaves.push((format!("{}/@{}", path, key), value.clone()));
+		}
+		if child.children.is_empty() {
+			leaves.push((path, child.text.clone()));
+		} else {
+			flatten(child, &path, leaves);
+		}
+	}
+}
+
+/// Upgrades a `head.001.001.02` header to `head.001.001.04`.
+#[cfg(feature = "head")]
+pub fn head_001_001_02_to_04(source: &BusinessApplicationHeaderV02) -> Result<Migrated<BusinessApplicationHeaderV04>, Error> {
+	migrate(source)
+}
+
+/// Downgrades a `head.001.001.04` header to `head.001.001.02`.
+#[cfg(feature = "head")]
+pub fn head_001_001_04_to_02(source: &BusinessApplicationHeaderV04) -> Result<Migrated<BusinessApplicationHeaderV02>, Error> {
+	migrate(source)
+}
+
+/// Upgrades a `pacs.002.001.12` status report to `pacs.002.001.14`.
+#[cfg(feature = "pacs")]
+pub fn pacs_002_001_12_to_14(source: &FIToFIPaymentStatusReportV12) -> Result<Migrated<FIToFIPaymentStatusReportV14>, Error> {
+	migrate(source)
+}
+
+/// Downgrades a `pacs.002.001.14` status report to `pacs.002.001.12`.
+#[cfg(feature = "pacs")]
+pub fn pacs_002_001_14_to_12(source: &FIToFIPaymentStatusReportV14) -> Result<Migrated<FIToFIPaymentStatusReportV12>, Error> {
+	migrate(source)
+}
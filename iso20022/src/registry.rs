@@ -0,0 +1,2432 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Registry of the messages compiled into the library, for routing tables and metrics labels.
+
+use crate::identifier::MessageIdentifier;
+
+/// A message definition compiled into the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageInfo {
+	/// The message identifier, e.g. `pacs.008.001.12`.
+	pub identifier: &'static str,
+	/// The XML namespace of the `<Document>` element.
+	pub namespace: &'static str,
+	/// The name of the message element wrapped by `<Document>`.
+	pub root_element: &'static str,
+	/// The full path of the Rust type holding the message.
+	pub type_name: &'static str,
+	/// The cargo feature enabling the message.
+	pub feature: &'static str,
+}
+
+impl MessageInfo {
+	/// Returns the parsed message identifier.
+	pub fn message_identifier(&self) -> MessageIdentifier {
+		self.identifier.parse().expect("registry holds valid message identifiers")
+	}
+}
+
+/// Returns the registry entry of `identifier`, if the message is compiled in.
+pub fn find(identifier: &MessageIdentifier) -> Option<&'static MessageInfo> {
+	let identifier = identifier.to_string();
+	MESSAGES.iter().find(|info| info.identifier == identifier)
+}
+
+/// Returns the registry entry of the message with XML namespace `namespace`, if compiled in.
+pub fn find_by_namespace(namespace: &str) -> Option<&'static MessageInfo> {
+	MESSAGES.iter().find(|info| info.namespace == namespace)
+}
+
+/// Every message compiled into the library, ordered by message identifier.
+pub static MESSAGES: &[MessageInfo] = &[
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.001.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.001.001.08",
+		root_element: "AcctOpngInstr",
+		type_name: "open_payments_iso20022_acmt::acmt_001_001_08::AccountOpeningInstructionV08",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.002.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.002.001.08",
+		root_element: "AcctDtlsConf",
+		type_name: "open_payments_iso20022_acmt::acmt_002_001_08::AccountDetailsConfirmationV08",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.003.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.003.001.08",
+		root_element: "AcctModInstr",
+		type_name: "open_payments_iso20022_acmt::acmt_003_001_08::AccountModificationInstructionV08",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.005.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.005.001.06",
+		root_element: "ReqForAcctMgmtStsRpt",
+		type_name: "open_payments_iso20022_acmt::acmt_005_001_06::RequestForAccountManagementStatusReportV06",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.006.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.006.001.07",
+		root_element: "AcctMgmtStsRpt",
+		type_name: "open_payments_iso20022_acmt::acmt_006_001_07::AccountManagementStatusReportV07",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.007.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.007.001.05",
+		root_element: "AcctOpngReq",
+		type_name: "open_payments_iso20022_acmt::acmt_007_001_05::AccountOpeningRequestV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.008.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.008.001.05",
+		root_element: "AcctOpngAmdmntReq",
+		type_name: "open_payments_iso20022_acmt::acmt_008_001_05::AccountOpeningAmendmentRequestV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.009.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.009.001.04",
+		root_element: "AcctOpngAddtlInfReq",
+		type_name: "open_payments_iso20022_acmt::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.010.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.010.001.04",
+		root_element: "AcctReqAck",
+		type_name: "open_payments_iso20022_acmt::acmt_010_001_04::AccountRequestAcknowledgementV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.011.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.011.001.04",
+		root_element: "AcctReqRjctn",
+		type_name: "open_payments_iso20022_acmt::acmt_011_001_04::AccountRequestRejectionV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.012.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.012.001.04",
+		root_element: "AcctAddtlInfReq",
+		type_name: "open_payments_iso20022_acmt::acmt_012_001_04::AccountAdditionalInformationRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.013.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.013.001.04",
+		root_element: "AcctRptReq",
+		type_name: "open_payments_iso20022_acmt::acmt_013_001_04::AccountReportRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.014.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.014.001.05",
+		root_element: "AcctRpt",
+		type_name: "open_payments_iso20022_acmt::acmt_014_001_05::AccountReportV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.015.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.015.001.04",
+		root_element: "AcctExcldMndtMntncReq",
+		type_name: "open_payments_iso20022_acmt::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.016.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.016.001.04",
+		root_element: "AcctExcldMndtMntncAmdmntReq",
+		type_name: "open_payments_iso20022_acmt::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.017.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.017.001.04",
+		root_element: "AcctMndtMntncReq",
+		type_name: "open_payments_iso20022_acmt::acmt_017_001_04::AccountMandateMaintenanceRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.018.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.018.001.04",
+		root_element: "AcctMndtMntncAmdmntReq",
+		type_name: "open_payments_iso20022_acmt::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.019.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.019.001.04",
+		root_element: "AcctClsgReq",
+		type_name: "open_payments_iso20022_acmt::acmt_019_001_04::AccountClosingRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.020.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.020.001.04",
+		root_element: "AcctClsgAmdmntReq",
+		type_name: "open_payments_iso20022_acmt::acmt_020_001_04::AccountClosingAmendmentRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.021.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.021.001.04",
+		root_element: "AcctClsgAddtlInfReq",
+		type_name: "open_payments_iso20022_acmt::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.022.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.022.001.04",
+		root_element: "IdModAdvc",
+		type_name: "open_payments_iso20022_acmt::acmt_022_001_04::IdentificationModificationAdviceV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.023.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.023.001.04",
+		root_element: "IdVrfctnReq",
+		type_name: "open_payments_iso20022_acmt::acmt_023_001_04::IdentificationVerificationRequestV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.024.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.024.001.04",
+		root_element: "IdVrfctnRpt",
+		type_name: "open_payments_iso20022_acmt::acmt_024_001_04::IdentificationVerificationReportV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.027.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.027.001.05",
+		root_element: "AcctSwtchInfReq",
+		type_name: "open_payments_iso20022_acmt::acmt_027_001_05::AccountSwitchInformationRequestV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.028.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.028.001.05",
+		root_element: "AcctSwtchInfRspn",
+		type_name: "open_payments_iso20022_acmt::acmt_028_001_05::AccountSwitchInformationResponseV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.029.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.029.001.05",
+		root_element: "AcctSwtchCclExstgPmt",
+		type_name: "open_payments_iso20022_acmt::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.030.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.030.001.04",
+		root_element: "AcctSwtchReqRdrctn",
+		type_name: "open_payments_iso20022_acmt::acmt_030_001_04::AccountSwitchRequestRedirectionV04",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.031.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.031.001.05",
+		root_element: "AcctSwtchReqBalTrf",
+		type_name: "open_payments_iso20022_acmt::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.032.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.032.001.05",
+		root_element: "AcctSwtchBalTrfAck",
+		type_name: "open_payments_iso20022_acmt::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.033.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.033.001.02",
+		root_element: "AcctSwtchNtfyAcctSwtchCmplt",
+		type_name: "open_payments_iso20022_acmt::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.034.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.034.001.05",
+		root_element: "AcctSwtchReqPmt",
+		type_name: "open_payments_iso20022_acmt::acmt_034_001_05::AccountSwitchRequestPaymentV05",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.035.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.035.001.02",
+		root_element: "AcctSwtchPmtRspn",
+		type_name: "open_payments_iso20022_acmt::acmt_035_001_02::AccountSwitchPaymentResponseV02",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.036.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.036.001.01",
+		root_element: "AcctSwtchTermntnSwtch",
+		type_name: "open_payments_iso20022_acmt::acmt_036_001_01::AccountSwitchTerminationSwitchV01",
+		feature: "acmt",
+	},
+	#[cfg(feature = "acmt")]
+	MessageInfo {
+		identifier: "acmt.037.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:acmt.037.001.02",
+		root_element: "AcctSwtchTechRjctn",
+		type_name: "open_payments_iso20022_acmt::acmt_037_001_02::AccountSwitchTechnicalRejectionV02",
+		feature: "acmt",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.002.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.002.001.01",
+		root_element: "admi.002.001.01",
+		type_name: "open_payments_iso20022_admi::admi_002_001_01::Admi00200101",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.004.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.004.001.02",
+		root_element: "SysEvtNtfctn",
+		type_name: "open_payments_iso20022_admi::admi_004_001_02::SystemEventNotificationV02",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.005.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.005.001.02",
+		root_element: "RptQryReq",
+		type_name: "open_payments_iso20022_admi::admi_005_001_02::ReportQueryRequestV02",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.006.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.006.001.01",
+		root_element: "RsndReq",
+		type_name: "open_payments_iso20022_admi::admi_006_001_01::ResendRequestV01",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.007.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.007.001.01",
+		root_element: "RctAck",
+		type_name: "open_payments_iso20022_admi::admi_007_001_01::ReceiptAcknowledgementV01",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.009.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.009.001.02",
+		root_element: "StatcDataReq",
+		type_name: "open_payments_iso20022_admi::admi_009_001_02::StaticDataRequestV02",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.010.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.010.001.02",
+		root_element: "StatcDataRpt",
+		type_name: "open_payments_iso20022_admi::admi_010_001_02::StaticDataReportV02",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.011.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.011.001.01",
+		root_element: "SysEvtAck",
+		type_name: "open_payments_iso20022_admi::admi_011_001_01::SystemEventAcknowledgementV01",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.017.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.017.001.02",
+		root_element: "PrcgReq",
+		type_name: "open_payments_iso20022_admi::admi_017_001_02::ProcessingRequestV02",
+		feature: "admi",
+	},
+	#[cfg(feature = "admi")]
+	MessageInfo {
+		identifier: "admi.024.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:admi.024.001.01",
+		root_element: "NtfctnOfCrspdc",
+		type_name: "open_payments_iso20022_admi::admi_024_001_01::NotificationOfCorrespondenceV01",
+		feature: "admi",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.001.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.001.001.02",
+		root_element: "InfReqOpng",
+		type_name: "open_payments_iso20022_auth::auth_001_001_02::InformationRequestOpeningV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.002.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.002.001.02",
+		root_element: "InfReqRspn",
+		type_name: "open_payments_iso20022_auth::auth_002_001_02::InformationRequestResponseV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.003.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.003.001.01",
+		root_element: "InfReqStsChngNtfctn",
+		type_name: "open_payments_iso20022_auth::auth_003_001_01::InformationRequestStatusChangeNotificationV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.012.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.012.001.02",
+		root_element: "MnyMktScrdMktSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.013.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.013.001.02",
+		root_element: "MnyMktUscrdMktSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.014.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.014.001.02",
+		root_element: "MnyMktFXSwpsSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.015.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.015.001.02",
+		root_element: "MnyMktOvrnghtIndxSwpsSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.016.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.016.001.03",
+		root_element: "FinInstrmRptgTxRpt",
+		type_name: "open_payments_iso20022_auth::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.017.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.017.001.02",
+		root_element: "FinInstrmRptgRefDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.018.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.018.001.04",
+		root_element: "CtrctRegnReq",
+		type_name: "open_payments_iso20022_auth::auth_018_001_04::ContractRegistrationRequestV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.019.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.019.001.04",
+		root_element: "CtrctRegnConf",
+		type_name: "open_payments_iso20022_auth::auth_019_001_04::ContractRegistrationConfirmationV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.020.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.020.001.04",
+		root_element: "CtrctRegnClsrReq",
+		type_name: "open_payments_iso20022_auth::auth_020_001_04::ContractRegistrationClosureRequestV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.021.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.021.001.04",
+		root_element: "CtrctRegnAmdmntReq",
+		type_name: "open_payments_iso20022_auth::auth_021_001_04::ContractRegistrationAmendmentRequestV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.022.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.022.001.04",
+		root_element: "CtrctRegnStmt",
+		type_name: "open_payments_iso20022_auth::auth_022_001_04::ContractRegistrationStatementV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.023.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.023.001.04",
+		root_element: "CtrctRegnStmtReq",
+		type_name: "open_payments_iso20022_auth::auth_023_001_04::ContractRegistrationStatementRequestV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.024.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.024.001.04",
+		root_element: "PmtRgltryInfNtfctn",
+		type_name: "open_payments_iso20022_auth::auth_024_001_04::PaymentRegulatoryInformationNotificationV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.025.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.025.001.04",
+		root_element: "CcyCtrlSpprtgDocDlvry",
+		type_name: "open_payments_iso20022_auth::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.026.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.026.001.04",
+		root_element: "CcyCtrlReqOrLttr",
+		type_name: "open_payments_iso20022_auth::auth_026_001_04::CurrencyControlRequestOrLetterV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.027.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.027.001.04",
+		root_element: "CcyCtrlStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_027_001_04::CurrencyControlStatusAdviceV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.028.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.028.001.01",
+		root_element: "MnyMktSttstclRptStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.029.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.029.001.05",
+		root_element: "DerivsTradRptQry",
+		type_name: "open_payments_iso20022_auth::auth_029_001_05::DerivativesTradeReportQueryV05",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.030.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.030.001.04",
+		root_element: "DerivsTradRpt",
+		type_name: "open_payments_iso20022_auth::auth_030_001_04::DerivativesTradeReportV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.031.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.031.001.01",
+		root_element: "FinInstrmRptgStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.032.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.032.001.01",
+		root_element: "FinInstrmRptgEqtyTrnsprncyDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.033.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.033.001.03",
+		root_element: "FinInstrmRptgNonEqtyTrnsprncyDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.034.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.034.001.01",
+		root_element: "InvcTaxRpt",
+		type_name: "open_payments_iso20022_auth::auth_034_001_01::InvoiceTaxReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.035.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.035.001.01",
+		root_element: "FinInstrmRptgTradgVolCapDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.036.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.036.001.03",
+		root_element: "FinInstrmRptgRefDataDltaRpt",
+		type_name: "open_payments_iso20022_auth::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.038.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.038.001.01",
+		root_element: "InvcTaxRptStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_038_001_01::InvoiceTaxReportStatusAdviceV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.039.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.039.001.01",
+		root_element: "FinInstrmRptgNonWorkgDayRpt",
+		type_name: "open_payments_iso20022_auth::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.040.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.040.001.01",
+		root_element: "FinInstrmRptgEqtyTradgActvtyRpt",
+		type_name: "open_payments_iso20022_auth::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.041.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.041.001.01",
+		root_element: "FinInstrmRptgNonEqtyTradgActvtyRpt",
+		type_name: "open_payments_iso20022_auth::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.042.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.042.001.02",
+		root_element: "FinInstrmRptgInvldRefDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.043.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.043.001.01",
+		root_element: "FinInstrmRptgRefDataIndxRpt",
+		type_name: "open_payments_iso20022_auth::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.044.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.044.001.02",
+		root_element: "FinInstrmRptgEqtyTradgActvtyRslt",
+		type_name: "open_payments_iso20022_auth::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.045.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.045.001.03",
+		root_element: "FinInstrmRptgNonEqtyTradgActvtyRslt",
+		type_name: "open_payments_iso20022_auth::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.047.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.047.001.01",
+		root_element: "FinInstrmRptgCtryCdRpt",
+		type_name: "open_payments_iso20022_auth::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.048.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.048.001.01",
+		root_element: "FinInstrmRptgCcyCdRpt",
+		type_name: "open_payments_iso20022_auth::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.049.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.049.001.02",
+		root_element: "FinInstrmRptgMktIdCdRpt",
+		type_name: "open_payments_iso20022_auth::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.050.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.050.001.01",
+		root_element: "FinInstrmRptgInstrmClssfctnRpt",
+		type_name: "open_payments_iso20022_auth::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.052.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.052.001.02",
+		root_element: "SctiesFincgRptgTxRpt",
+		type_name: "open_payments_iso20022_auth::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.053.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.053.001.01",
+		root_element: "FinInstrmRptgTradgVolCapRsltRpt",
+		type_name: "open_payments_iso20022_auth::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.054.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.054.001.01",
+		root_element: "CCPClrMmbRpt",
+		type_name: "open_payments_iso20022_auth::auth_054_001_01::CCPClearingMemberReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.055.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.055.001.01",
+		root_element: "CCPMmbRqrmntsRpt",
+		type_name: "open_payments_iso20022_auth::auth_055_001_01::CCPMemberRequirementsReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.056.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.056.001.01",
+		root_element: "CCPMmbOblgtnsRpt",
+		type_name: "open_payments_iso20022_auth::auth_056_001_01::CCPMemberObligationsReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.057.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.057.001.02",
+		root_element: "CCPPrtflStrssTstgDefRpt",
+		type_name: "open_payments_iso20022_auth::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.058.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.058.001.01",
+		root_element: "CCPPrtflStrssTstgRsltRpt",
+		type_name: "open_payments_iso20022_auth::auth_058_001_01::CCPPortfolioStressTestingResultReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.059.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.059.001.01",
+		root_element: "CCPIncmStmtAndCptlAdqcyRpt",
+		type_name: "open_payments_iso20022_auth::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.060.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.060.001.02",
+		root_element: "CCPDalyCshFlowsRpt",
+		type_name: "open_payments_iso20022_auth::auth_060_001_02::CCPDailyCashFlowsReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.061.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.061.001.01",
+		root_element: "CCPInvstmtsRpt",
+		type_name: "open_payments_iso20022_auth::auth_061_001_01::CCPInvestmentsReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.062.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.062.001.01",
+		root_element: "CCPLqdtyStrssTstgDefRpt",
+		type_name: "open_payments_iso20022_auth::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.063.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.063.001.01",
+		root_element: "CCPLqdtyStrssTstgRsltRpt",
+		type_name: "open_payments_iso20022_auth::auth_063_001_01::CCPLiquidityStressTestingResultReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.064.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.064.001.01",
+		root_element: "CCPAvlblFinRsrcsRpt",
+		type_name: "open_payments_iso20022_auth::auth_064_001_01::CCPAvailableFinancialResourcesReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.065.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.065.001.01",
+		root_element: "CCPBckTstgDefRpt",
+		type_name: "open_payments_iso20022_auth::auth_065_001_01::CCPBackTestingDefinitionReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.066.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.066.001.01",
+		root_element: "CCPBckTstgRsltRpt",
+		type_name: "open_payments_iso20022_auth::auth_066_001_01::CCPBackTestingResultReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.067.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.067.001.01",
+		root_element: "CCPCollRpt",
+		type_name: "open_payments_iso20022_auth::auth_067_001_01::CCPCollateralReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.068.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.068.001.01",
+		root_element: "CCPAcctPosRpt",
+		type_name: "open_payments_iso20022_auth::auth_068_001_01::CCPAccountPositionReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.069.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.069.001.01",
+		root_element: "CCPClrdPdctRpt",
+		type_name: "open_payments_iso20022_auth::auth_069_001_01::CCPClearedProductReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.070.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.070.001.02",
+		root_element: "SctiesFincgRptgTxMrgnDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.071.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.071.001.02",
+		root_element: "SctiesFincgRptgTxReusdCollDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.072.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.072.001.01",
+		root_element: "SttlmIntlrRpt",
+		type_name: "open_payments_iso20022_auth::auth_072_001_01::SettlementInternaliserReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.076.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.076.001.01",
+		root_element: "FinSprvsdPtyIdntyRpt",
+		type_name: "open_payments_iso20022_auth::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.077.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.077.001.01",
+		root_element: "FinBchmkRpt",
+		type_name: "open_payments_iso20022_auth::auth_077_001_01::FinancialBenchmarkReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.078.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.078.001.02",
+		root_element: "SctiesFincgRptgPairgReq",
+		type_name: "open_payments_iso20022_auth::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.079.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.079.001.02",
+		root_element: "SctiesFincgRptgTxStatRpt",
+		type_name: "open_payments_iso20022_auth::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.080.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.080.001.02",
+		root_element: "SctiesFincgRptgRcncltnStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.083.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.083.001.02",
+		root_element: "SctiesFincgRptgMssngCollReq",
+		type_name: "open_payments_iso20022_auth::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.084.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.084.001.02",
+		root_element: "SctiesFincgRptgTxStsAdvc",
+		type_name: "open_payments_iso20022_auth::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.085.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.085.001.02",
+		root_element: "SctiesFincgRptgMrgnDataTxStatRpt",
+		type_name: "open_payments_iso20022_auth::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.086.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.086.001.02",
+		root_element: "SctiesFincgRptgReusdCollDataTxStatRpt",
+		type_name: "open_payments_iso20022_auth::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.090.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.090.001.02",
+		root_element: "DerivsTradPosSetRpt",
+		type_name: "open_payments_iso20022_auth::auth_090_001_02::DerivativesTradePositionSetReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.091.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.091.001.03",
+		root_element: "DerivsTradRcncltnSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.092.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.092.001.04",
+		root_element: "DerivsTradRjctnSttstclRpt",
+		type_name: "open_payments_iso20022_auth::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.094.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.094.001.02",
+		root_element: "SctiesFincgRptgTxQry",
+		type_name: "open_payments_iso20022_auth::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.100.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.100.001.01",
+		root_element: "SttlmFlsMnthlyRpt",
+		type_name: "open_payments_iso20022_auth::auth_100_001_01::SettlementFailsMonthlyReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.101.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.101.001.01",
+		root_element: "SttlmFlsAnlRpt",
+		type_name: "open_payments_iso20022_auth::auth_101_001_01::SettlementFailsAnnualReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.102.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.102.001.01",
+		root_element: "FinInstrmRptgCxlRpt",
+		type_name: "open_payments_iso20022_auth::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.105.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.105.001.01",
+		root_element: "SctiesFincgRptgPosSetRpt",
+		type_name: "open_payments_iso20022_auth::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.106.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.106.001.01",
+		root_element: "DerivsTradWrnngsRpt",
+		type_name: "open_payments_iso20022_auth::auth_106_001_01::DerivativesTradeWarningsReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.107.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.107.001.02",
+		root_element: "DerivsTradStatRpt",
+		type_name: "open_payments_iso20022_auth::auth_107_001_02::DerivativesTradeStateReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.108.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.108.001.02",
+		root_element: "DerivsTradMrgnDataRpt",
+		type_name: "open_payments_iso20022_auth::auth_108_001_02::DerivativesTradeMarginDataReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.109.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.109.001.02",
+		root_element: "DerivsTradMrgnDataTxStatRpt",
+		type_name: "open_payments_iso20022_auth::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.112.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.112.001.01",
+		root_element: "CCPIntrprbltyRpt",
+		type_name: "open_payments_iso20022_auth::auth_112_001_01::CCPInteroperabilityReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "auth")]
+	MessageInfo {
+		identifier: "auth.113.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:auth.113.001.01",
+		root_element: "OrdrBookRpt",
+		type_name: "open_payments_iso20022_auth::auth_113_001_01::OrderBookReportV01",
+		feature: "auth",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.003.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.003.001.08",
+		root_element: "GetAcct",
+		type_name: "open_payments_iso20022_camt::camt_003_001_08::GetAccountV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.004.001.10",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.004.001.10",
+		root_element: "RtrAcct",
+		type_name: "open_payments_iso20022_camt::camt_004_001_10::ReturnAccountV10",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.005.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.005.001.11",
+		root_element: "GetTx",
+		type_name: "open_payments_iso20022_camt::camt_005_001_11::GetTransactionV11",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.006.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.006.001.11",
+		root_element: "RtrTx",
+		type_name: "open_payments_iso20022_camt::camt_006_001_11::ReturnTransactionV11",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.007.001.10",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.007.001.10",
+		root_element: "ModfyTx",
+		type_name: "open_payments_iso20022_camt::camt_007_001_10::ModifyTransactionV10",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.008.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.008.001.11",
+		root_element: "CclTx",
+		type_name: "open_payments_iso20022_camt::camt_008_001_11::CancelTransactionV11",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.009.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.009.001.08",
+		root_element: "GetLmt",
+		type_name: "open_payments_iso20022_camt::camt_009_001_08::GetLimitV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.010.001.09",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.010.001.09",
+		root_element: "RtrLmt",
+		type_name: "open_payments_iso20022_camt::camt_010_001_09::ReturnLimitV09",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.011.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.011.001.08",
+		root_element: "ModfyLmt",
+		type_name: "open_payments_iso20022_camt::camt_011_001_08::ModifyLimitV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.012.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.012.001.08",
+		root_element: "DelLmt",
+		type_name: "open_payments_iso20022_camt::camt_012_001_08::DeleteLimitV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.013.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.013.001.04",
+		root_element: "GetMmb",
+		type_name: "open_payments_iso20022_camt::camt_013_001_04::GetMemberV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.014.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.014.001.05",
+		root_element: "RtrMmb",
+		type_name: "open_payments_iso20022_camt::camt_014_001_05::ReturnMemberV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.015.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.015.001.04",
+		root_element: "ModfyMmb",
+		type_name: "open_payments_iso20022_camt::camt_015_001_04::ModifyMemberV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.016.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.016.001.04",
+		root_element: "GetCcyXchgRate",
+		type_name: "open_payments_iso20022_camt::camt_016_001_04::GetCurrencyExchangeRateV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.017.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.017.001.05",
+		root_element: "RtrCcyXchgRate",
+		type_name: "open_payments_iso20022_camt::camt_017_001_05::ReturnCurrencyExchangeRateV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.018.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.018.001.05",
+		root_element: "GetBizDayInf",
+		type_name: "open_payments_iso20022_camt::camt_018_001_05::GetBusinessDayInformationV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.019.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.019.001.07",
+		root_element: "RtrBizDayInf",
+		type_name: "open_payments_iso20022_camt::camt_019_001_07::ReturnBusinessDayInformationV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.020.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.020.001.04",
+		root_element: "GetGnlBizInf",
+		type_name: "open_payments_iso20022_camt::camt_020_001_04::GetGeneralBusinessInformationV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.021.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.021.001.06",
+		root_element: "RtrGnlBizInf",
+		type_name: "open_payments_iso20022_camt::camt_021_001_06::ReturnGeneralBusinessInformationV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.023.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.023.001.07",
+		root_element: "BckpPmt",
+		type_name: "open_payments_iso20022_camt::camt_023_001_07::BackupPaymentV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.024.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.024.001.08",
+		root_element: "ModfyStgOrdr",
+		type_name: "open_payments_iso20022_camt::camt_024_001_08::ModifyStandingOrderV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.025.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.025.001.08",
+		root_element: "Rct",
+		type_name: "open_payments_iso20022_camt::camt_025_001_08::ReceiptV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.026.001.10",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.026.001.10",
+		root_element: "UblToApply",
+		type_name: "open_payments_iso20022_camt::camt_026_001_10::UnableToApplyV10",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.027.001.10",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.027.001.10",
+		root_element: "ClmNonRct",
+		type_name: "open_payments_iso20022_camt::camt_027_001_10::ClaimNonReceiptV10",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.028.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.028.001.12",
+		root_element: "AddtlPmtInf",
+		type_name: "open_payments_iso20022_camt::camt_028_001_12::AdditionalPaymentInformationV12",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.029.001.13",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.029.001.13",
+		root_element: "RsltnOfInvstgtn",
+		type_name: "open_payments_iso20022_camt::camt_029_001_13::ResolutionOfInvestigationV13",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.030.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.030.001.06",
+		root_element: "NtfctnOfCaseAssgnmt",
+		type_name: "open_payments_iso20022_camt::camt_030_001_06::NotificationOfCaseAssignmentV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.031.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.031.001.07",
+		root_element: "RjctInvstgtn",
+		type_name: "open_payments_iso20022_camt::camt_031_001_07::RejectInvestigationV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.032.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.032.001.05",
+		root_element: "CclCaseAssgnmt",
+		type_name: "open_payments_iso20022_camt::camt_032_001_05::CancelCaseAssignmentV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.033.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.033.001.07",
+		root_element: "ReqForDplct",
+		type_name: "open_payments_iso20022_camt::camt_033_001_07::RequestForDuplicateV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.034.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.034.001.07",
+		root_element: "Dplct",
+		type_name: "open_payments_iso20022_camt::camt_034_001_07::DuplicateV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.035.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.035.001.06",
+		root_element: "PrtryFrmtInvstgtn",
+		type_name: "open_payments_iso20022_camt::camt_035_001_06::ProprietaryFormatInvestigationV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.036.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.036.001.06",
+		root_element: "DbtAuthstnRspn",
+		type_name: "open_payments_iso20022_camt::camt_036_001_06::DebitAuthorisationResponseV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.037.001.10",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.037.001.10",
+		root_element: "DbtAuthstnReq",
+		type_name: "open_payments_iso20022_camt::camt_037_001_10::DebitAuthorisationRequestV10",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.038.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.038.001.05",
+		root_element: "CaseStsRptReq",
+		type_name: "open_payments_iso20022_camt::camt_038_001_05::CaseStatusReportRequestV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.039.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.039.001.06",
+		root_element: "CaseStsRpt",
+		type_name: "open_payments_iso20022_camt::camt_039_001_06::CaseStatusReportV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.040.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.040.001.04",
+		root_element: "FndEstmtdCshFcstRpt",
+		type_name: "open_payments_iso20022_camt::camt_040_001_04::FundEstimatedCashForecastReportV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.041.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.041.001.04",
+		root_element: "FndConfdCshFcstRpt",
+		type_name: "open_payments_iso20022_camt::camt_041_001_04::FundConfirmedCashForecastReportV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.042.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.042.001.04",
+		root_element: "FndDtldEstmtdCshFcstRpt",
+		type_name: "open_payments_iso20022_camt::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.043.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.043.001.04",
+		root_element: "FndDtldConfdCshFcstRpt",
+		type_name: "open_payments_iso20022_camt::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.044.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.044.001.03",
+		root_element: "FndConfdCshFcstRptCxl",
+		type_name: "open_payments_iso20022_camt::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.045.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.045.001.03",
+		root_element: "FndDtldConfdCshFcstRptCxl",
+		type_name: "open_payments_iso20022_camt::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.046.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.046.001.08",
+		root_element: "GetRsvatn",
+		type_name: "open_payments_iso20022_camt::camt_046_001_08::GetReservationV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.047.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.047.001.08",
+		root_element: "RtrRsvatn",
+		type_name: "open_payments_iso20022_camt::camt_047_001_08::ReturnReservationV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.048.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.048.001.07",
+		root_element: "ModfyRsvatn",
+		type_name: "open_payments_iso20022_camt::camt_048_001_07::ModifyReservationV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.049.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.049.001.07",
+		root_element: "DelRsvatn",
+		type_name: "open_payments_iso20022_camt::camt_049_001_07::DeleteReservationV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.050.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.050.001.07",
+		root_element: "LqdtyCdtTrf",
+		type_name: "open_payments_iso20022_camt::camt_050_001_07::LiquidityCreditTransferV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.051.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.051.001.07",
+		root_element: "LqdtyDbtTrf",
+		type_name: "open_payments_iso20022_camt::camt_051_001_07::LiquidityDebitTransferV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.052.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.052.001.12",
+		root_element: "BkToCstmrAcctRpt",
+		type_name: "open_payments_iso20022_camt::camt_052_001_12::BankToCustomerAccountReportV12",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.053.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.053.001.12",
+		root_element: "BkToCstmrStmt",
+		type_name: "open_payments_iso20022_camt::camt_053_001_12::BankToCustomerStatementV12",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.054.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.054.001.12",
+		root_element: "BkToCstmrDbtCdtNtfctn",
+		type_name: "open_payments_iso20022_camt::camt_054_001_12::BankToCustomerDebitCreditNotificationV12",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.055.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.055.001.12",
+		root_element: "CstmrPmtCxlReq",
+		type_name: "open_payments_iso20022_camt::camt_055_001_12::CustomerPaymentCancellationRequestV12",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.056.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.056.001.11",
+		root_element: "FIToFIPmtCxlReq",
+		type_name: "open_payments_iso20022_camt::camt_056_001_11::FIToFIPaymentCancellationRequestV11",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.057.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.057.001.08",
+		root_element: "NtfctnToRcv",
+		type_name: "open_payments_iso20022_camt::camt_057_001_08::NotificationToReceiveV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.058.001.09",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.058.001.09",
+		root_element: "NtfctnToRcvCxlAdvc",
+		type_name: "open_payments_iso20022_camt::camt_058_001_09::NotificationToReceiveCancellationAdviceV09",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.059.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.059.001.08",
+		root_element: "NtfctnToRcvStsRpt",
+		type_name: "open_payments_iso20022_camt::camt_059_001_08::NotificationToReceiveStatusReportV08",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.060.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.060.001.07",
+		root_element: "AcctRptgReq",
+		type_name: "open_payments_iso20022_camt::camt_060_001_07::AccountReportingRequestV07",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.061.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.061.001.02",
+		root_element: "PayInCall",
+		type_name: "open_payments_iso20022_camt::camt_061_001_02::PayInCallV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.062.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.062.001.03",
+		root_element: "PayInSchdl",
+		type_name: "open_payments_iso20022_camt::camt_062_001_03::PayInScheduleV03",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.063.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.063.001.02",
+		root_element: "PayInEvtAck",
+		type_name: "open_payments_iso20022_camt::camt_063_001_02::PayInEventAcknowledgementV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.066.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.066.001.02",
+		root_element: "IntraBalMvmntInstr",
+		type_name: "open_payments_iso20022_camt::camt_066_001_02::IntraBalanceMovementInstructionV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.067.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.067.001.02",
+		root_element: "IntraBalMvmntStsAdvc",
+		type_name: "open_payments_iso20022_camt::camt_067_001_02::IntraBalanceMovementStatusAdviceV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.068.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.068.001.02",
+		root_element: "IntraBalMvmntConf",
+		type_name: "open_payments_iso20022_camt::camt_068_001_02::IntraBalanceMovementConfirmationV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.069.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.069.001.05",
+		root_element: "GetStgOrdr",
+		type_name: "open_payments_iso20022_camt::camt_069_001_05::GetStandingOrderV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.070.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.070.001.06",
+		root_element: "RtrStgOrdr",
+		type_name: "open_payments_iso20022_camt::camt_070_001_06::ReturnStandingOrderV06",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.071.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.071.001.05",
+		root_element: "DelStgOrdr",
+		type_name: "open_payments_iso20022_camt::camt_071_001_05::DeleteStandingOrderV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.072.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.072.001.02",
+		root_element: "IntraBalMvmntModReq",
+		type_name: "open_payments_iso20022_camt::camt_072_001_02::IntraBalanceMovementModificationRequestV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.073.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.073.001.02",
+		root_element: "IntraBalMvmntModReqStsAdvc",
+		type_name: "open_payments_iso20022_camt::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.074.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.074.001.02",
+		root_element: "IntraBalMvmntCxlReq",
+		type_name: "open_payments_iso20022_camt::camt_074_001_02::IntraBalanceMovementCancellationRequestV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.075.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.075.001.02",
+		root_element: "IntraBalMvmntCxlReqStsAdvc",
+		type_name: "open_payments_iso20022_camt::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.078.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.078.001.02",
+		root_element: "IntraBalMvmntQry",
+		type_name: "open_payments_iso20022_camt::camt_078_001_02::IntraBalanceMovementQueryV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.079.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.079.001.02",
+		root_element: "IntraBalMvmntQryRspn",
+		type_name: "open_payments_iso20022_camt::camt_079_001_02::IntraBalanceMovementQueryResponseV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.080.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.080.001.02",
+		root_element: "IntraBalMvmntModQry",
+		type_name: "open_payments_iso20022_camt::camt_080_001_02::IntraBalanceMovementModificationQueryV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.081.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.081.001.02",
+		root_element: "IntraBalMvmntModRpt",
+		type_name: "open_payments_iso20022_camt::camt_081_001_02::IntraBalanceMovementModificationReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.082.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.082.001.02",
+		root_element: "IntraBalMvmntCxlQry",
+		type_name: "open_payments_iso20022_camt::camt_082_001_02::IntraBalanceMovementCancellationQueryV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.083.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.083.001.02",
+		root_element: "IntraBalMvmntCxlRpt",
+		type_name: "open_payments_iso20022_camt::camt_083_001_02::IntraBalanceMovementCancellationReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.084.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.084.001.02",
+		root_element: "IntraBalMvmntPstngRpt",
+		type_name: "open_payments_iso20022_camt::camt_084_001_02::IntraBalanceMovementPostingReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.085.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.085.001.02",
+		root_element: "IntraBalMvmntPdgRpt",
+		type_name: "open_payments_iso20022_camt::camt_085_001_02::IntraBalanceMovementPendingReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.086.001.05",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.086.001.05",
+		root_element: "BkSvcsBllgStmt",
+		type_name: "open_payments_iso20022_camt::camt_086_001_05::BankServicesBillingStatementV05",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.087.001.09",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.087.001.09",
+		root_element: "ReqToModfyPmt",
+		type_name: "open_payments_iso20022_camt::camt_087_001_09::RequestToModifyPaymentV09",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.088.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.088.001.02",
+		root_element: "NetRpt",
+		type_name: "open_payments_iso20022_camt::camt_088_001_02::NetReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.101.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.101.001.02",
+		root_element: "CretLmt",
+		type_name: "open_payments_iso20022_camt::camt_101_001_02::CreateLimitV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.102.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.102.001.03",
+		root_element: "CretStgOrdr",
+		type_name: "open_payments_iso20022_camt::camt_102_001_03::CreateStandingOrderV03",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.103.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.103.001.03",
+		root_element: "CretRsvatn",
+		type_name: "open_payments_iso20022_camt::camt_103_001_03::CreateReservationV03",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.104.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.104.001.01",
+		root_element: "CretMmb",
+		type_name: "open_payments_iso20022_camt::camt_104_001_01::CreateMemberV01",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.105.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.105.001.02",
+		root_element: "ChrgsPmtNtfctn",
+		type_name: "open_payments_iso20022_camt::camt_105_001_02::ChargesPaymentNotificationV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.106.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.106.001.02",
+		root_element: "ChrgsPmtReq",
+		type_name: "open_payments_iso20022_camt::camt_106_001_02::ChargesPaymentRequestV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.107.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.107.001.02",
+		root_element: "ChqPresntmntNtfctn",
+		type_name: "open_payments_iso20022_camt::camt_107_001_02::ChequePresentmentNotificationV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.108.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.108.001.02",
+		root_element: "ChqCxlOrStopReq",
+		type_name: "open_payments_iso20022_camt::camt_108_001_02::ChequeCancellationOrStopRequestV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.109.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.109.001.02",
+		root_element: "ChqCxlOrStopRpt",
+		type_name: "open_payments_iso20022_camt::camt_109_001_02::ChequeCancellationOrStopReportV02",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.110.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.110.001.01",
+		root_element: "InvstgtnReq",
+		type_name: "open_payments_iso20022_camt::camt_110_001_01::InvestigationRequestV01",
+		feature: "camt",
+	},
+	#[cfg(feature = "camt")]
+	MessageInfo {
+		identifier: "camt.111.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:camt.111.001.01",
+		root_element: "InvstgtnRspn",
+		type_name: "open_payments_iso20022_camt::camt_111_001_01::InvestigationResponseV01",
+		feature: "camt",
+	},
+	#[cfg(feature = "head")]
+	MessageInfo {
+		identifier: "head.001.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:head.001.001.02",
+		root_element: "AppHdr",
+		type_name: "open_payments_iso20022_head::header::BusinessApplicationHeaderV02",
+		feature: "head",
+	},
+	#[cfg(feature = "head")]
+	MessageInfo {
+		identifier: "head.001.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:head.001.001.04",
+		root_element: "AppHdr",
+		type_name: "open_payments_iso20022_head::header::BusinessApplicationHeaderV04",
+		feature: "head",
+	},
+	#[cfg(feature = "head")]
+	MessageInfo {
+		identifier: "head.002.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:head.002.001.01",
+		root_element: "Xchg",
+		type_name: "open_payments_iso20022_head::header::BusinessFileHeaderV01",
+		feature: "head",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.002.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.002.001.12",
+		root_element: "FIToFIPmtStsRpt",
+		type_name: "open_payments_iso20022_pacs::pacs_002_001_12::FIToFIPaymentStatusReportV12",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.002.001.14",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.002.001.14",
+		root_element: "FIToFIPmtStsRpt",
+		type_name: "open_payments_iso20022_pacs::pacs_002_001_14::FIToFIPaymentStatusReportV14",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.003.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.003.001.11",
+		root_element: "FIToFICstmrDrctDbt",
+		type_name: "open_payments_iso20022_pacs::pacs_003_001_11::FIToFICustomerDirectDebitV11",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.004.001.13",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.004.001.13",
+		root_element: "PmtRtr",
+		type_name: "open_payments_iso20022_pacs::pacs_004_001_13::PaymentReturnV13",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.007.001.13",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.007.001.13",
+		root_element: "FIToFIPmtRvsl",
+		type_name: "open_payments_iso20022_pacs::pacs_007_001_13::FIToFIPaymentReversalV13",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.008.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12",
+		root_element: "FIToFICstmrCdtTrf",
+		type_name: "open_payments_iso20022_pacs::pacs_008_001_12::FIToFICustomerCreditTransferV12",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.009.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.009.001.11",
+		root_element: "FICdtTrf",
+		type_name: "open_payments_iso20022_pacs::pacs_009_001_11::FinancialInstitutionCreditTransferV11",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.010.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.010.001.06",
+		root_element: "FIDrctDbt",
+		type_name: "open_payments_iso20022_pacs::pacs_010_001_06::FinancialInstitutionDirectDebitV06",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.028.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.028.001.06",
+		root_element: "FIToFIPmtStsReq",
+		type_name: "open_payments_iso20022_pacs::pacs_028_001_06::FIToFIPaymentStatusRequestV06",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pacs")]
+	MessageInfo {
+		identifier: "pacs.029.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pacs.029.001.02",
+		root_element: "MulSttlmReq",
+		type_name: "open_payments_iso20022_pacs::pacs_029_001_02::MultilateralSettlementRequestV02",
+		feature: "pacs",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.001.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.001.001.12",
+		root_element: "CstmrCdtTrfInitn",
+		type_name: "open_payments_iso20022_pain::pain_001_001_12::CustomerCreditTransferInitiationV12",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.002.001.14",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.002.001.14",
+		root_element: "CstmrPmtStsRpt",
+		type_name: "open_payments_iso20022_pain::pain_002_001_14::CustomerPaymentStatusReportV14",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.007.001.12",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.007.001.12",
+		root_element: "CstmrPmtRvsl",
+		type_name: "open_payments_iso20022_pain::pain_007_001_12::CustomerPaymentReversalV12",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.008.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.008.001.11",
+		root_element: "CstmrDrctDbtInitn",
+		type_name: "open_payments_iso20022_pain::pain_008_001_11::CustomerDirectDebitInitiationV11",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.009.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.009.001.08",
+		root_element: "MndtInitnReq",
+		type_name: "open_payments_iso20022_pain::pain_009_001_08::MandateInitiationRequestV08",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.010.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.010.001.08",
+		root_element: "MndtAmdmntReq",
+		type_name: "open_payments_iso20022_pain::pain_010_001_08::MandateAmendmentRequestV08",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.011.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.011.001.08",
+		root_element: "MndtCxlReq",
+		type_name: "open_payments_iso20022_pain::pain_011_001_08::MandateCancellationRequestV08",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.012.001.08",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.012.001.08",
+		root_element: "MndtAccptncRpt",
+		type_name: "open_payments_iso20022_pain::pain_012_001_08::MandateAcceptanceReportV08",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.013.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.013.001.11",
+		root_element: "CdtrPmtActvtnReq",
+		type_name: "open_payments_iso20022_pain::pain_013_001_11::CreditorPaymentActivationRequestV11",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.014.001.11",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.014.001.11",
+		root_element: "CdtrPmtActvtnReqStsRpt",
+		type_name: "open_payments_iso20022_pain::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.017.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.017.001.04",
+		root_element: "MndtCpyReq",
+		type_name: "open_payments_iso20022_pain::pain_017_001_04::MandateCopyRequestV04",
+		feature: "pain",
+	},
+	#[cfg(feature = "pain")]
+	MessageInfo {
+		identifier: "pain.018.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:pain.018.001.04",
+		root_element: "MndtSspnsnReq",
+		type_name: "open_payments_iso20022_pain::pain_018_001_04::MandateSuspensionRequestV04",
+		feature: "pain",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.001.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.001.001.04",
+		root_element: "PricRpt",
+		type_name: "open_payments_iso20022_reda::reda_001_001_04::PriceReportV04",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.002.001.04",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.002.001.04",
+		root_element: "PricRptCxl",
+		type_name: "open_payments_iso20022_reda::reda_002_001_04::PriceReportCancellationV04",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.004.001.07",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.004.001.07",
+		root_element: "FndRefDataRpt",
+		type_name: "open_payments_iso20022_reda::reda_004_001_07::FundReferenceDataReportV07",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.005.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.005.001.03",
+		root_element: "InvstmtFndRptReq",
+		type_name: "open_payments_iso20022_reda::reda_005_001_03::InvestmentFundReportRequestV03",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.006.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.006.001.01",
+		root_element: "SctyCreReq",
+		type_name: "open_payments_iso20022_reda::reda_006_001_01::SecurityCreationRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.007.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.007.001.01",
+		root_element: "SctyMntncReq",
+		type_name: "open_payments_iso20022_reda::reda_007_001_01::SecurityMaintenanceRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.008.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.008.001.01",
+		root_element: "SctyCreStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_008_001_01::SecurityCreationStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.009.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.009.001.01",
+		root_element: "SctyActvtyAdvc",
+		type_name: "open_payments_iso20022_reda::reda_009_001_01::SecurityActivityAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.010.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.010.001.01",
+		root_element: "SctyQry",
+		type_name: "open_payments_iso20022_reda::reda_010_001_01::SecurityQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.012.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.012.001.01",
+		root_element: "SctyRpt",
+		type_name: "open_payments_iso20022_reda::reda_012_001_01::SecurityReportV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.013.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.013.001.01",
+		root_element: "SctyDeltnReq",
+		type_name: "open_payments_iso20022_reda::reda_013_001_01::SecurityDeletionRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.014.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.014.001.02",
+		root_element: "PtyCreReq",
+		type_name: "open_payments_iso20022_reda::reda_014_001_02::PartyCreationRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.015.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.015.001.01",
+		root_element: "PtyQry",
+		type_name: "open_payments_iso20022_reda::reda_015_001_01::PartyQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.016.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.016.001.01",
+		root_element: "PtyStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_016_001_01::PartyStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.017.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.017.001.02",
+		root_element: "PtyRpt",
+		type_name: "open_payments_iso20022_reda::reda_017_001_02::PartyReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.018.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.018.001.01",
+		root_element: "SctiesAcctCreReq",
+		type_name: "open_payments_iso20022_reda::reda_018_001_01::SecuritiesAccountCreationRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.019.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.019.001.01",
+		root_element: "SctiesAcctQry",
+		type_name: "open_payments_iso20022_reda::reda_019_001_01::SecuritiesAccountQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.020.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.020.001.01",
+		root_element: "SctiesAcctStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_020_001_01::SecuritiesAccountStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.021.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.021.001.01",
+		root_element: "SctiesAcctRpt",
+		type_name: "open_payments_iso20022_reda::reda_021_001_01::SecuritiesAccountReportV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.022.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.022.001.02",
+		root_element: "PtyModReq",
+		type_name: "open_payments_iso20022_reda::reda_022_001_02::PartyModificationRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.023.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.023.001.01",
+		root_element: "SctiesAcctModReq",
+		type_name: "open_payments_iso20022_reda::reda_023_001_01::SecuritiesAccountModificationRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.029.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.029.001.01",
+		root_element: "SctyMntncStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_029_001_01::SecurityMaintenanceStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.030.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.030.001.01",
+		root_element: "SctyDeltnStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_030_001_01::SecurityDeletionStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.031.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.031.001.01",
+		root_element: "PtyDeltnReq",
+		type_name: "open_payments_iso20022_reda::reda_031_001_01::PartyDeletionRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.032.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.032.001.01",
+		root_element: "SctiesAcctDeltnReq",
+		type_name: "open_payments_iso20022_reda::reda_032_001_01::SecuritiesAccountDeletionRequestV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.033.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.033.001.01",
+		root_element: "SctiesAudtTrlQry",
+		type_name: "open_payments_iso20022_reda::reda_033_001_01::SecuritiesAuditTrailQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.034.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.034.001.01",
+		root_element: "SctiesAudtTrlRpt",
+		type_name: "open_payments_iso20022_reda::reda_034_001_01::SecuritiesAuditTrailReportV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.035.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.035.001.01",
+		root_element: "SctiesAcctActvtyAdvc",
+		type_name: "open_payments_iso20022_reda::reda_035_001_01::SecuritiesAccountActivityAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.036.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.036.001.01",
+		root_element: "SctiesAcctAudtTrlQry",
+		type_name: "open_payments_iso20022_reda::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.037.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.037.001.01",
+		root_element: "SctiesAcctAudtTrlRpt",
+		type_name: "open_payments_iso20022_reda::reda_037_001_01::SecuritiesAccountAuditTrailReportV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.041.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.041.001.02",
+		root_element: "PtyActvtyAdvc",
+		type_name: "open_payments_iso20022_reda::reda_041_001_02::PartyActivityAdviceV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.042.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.042.001.01",
+		root_element: "PtyAudtTrlQry",
+		type_name: "open_payments_iso20022_reda::reda_042_001_01::PartyAuditTrailQueryV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.043.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.043.001.02",
+		root_element: "PtyAudtTrlRpt",
+		type_name: "open_payments_iso20022_reda::reda_043_001_02::PartyAuditTrailReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.056.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.056.001.01",
+		root_element: "StgSttlmInstr",
+		type_name: "open_payments_iso20022_reda::reda_056_001_01::StandingSettlementInstructionV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.057.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.057.001.01",
+		root_element: "StgSttlmInstrDeltn",
+		type_name: "open_payments_iso20022_reda::reda_057_001_01::StandingSettlementInstructionDeletionV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.058.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.058.001.01",
+		root_element: "StgSttlmInstrStsAdvc",
+		type_name: "open_payments_iso20022_reda::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.059.001.01",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.059.001.01",
+		root_element: "StgSttlmInstrCxl",
+		type_name: "open_payments_iso20022_reda::reda_059_001_01::StandingSettlementInstructionCancellationV01",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.060.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.060.001.02",
+		root_element: "NetgCutOffRefDataUpdReq",
+		type_name: "open_payments_iso20022_reda::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.061.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.061.001.02",
+		root_element: "NetgCutOffRefDataRpt",
+		type_name: "open_payments_iso20022_reda::reda_061_001_02::NettingCutOffReferenceDataReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.064.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.064.001.02",
+		root_element: "CalQry",
+		type_name: "open_payments_iso20022_reda::reda_064_001_02::CalendarQueryV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.065.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.065.001.02",
+		root_element: "CalRpt",
+		type_name: "open_payments_iso20022_reda::reda_065_001_02::CalendarReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.066.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.066.001.02",
+		root_element: "ReqToPayCdtrEnrlmntReq",
+		type_name: "open_payments_iso20022_reda::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.067.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.067.001.02",
+		root_element: "ReqToPayCdtrEnrlmntAmdmntReq",
+		type_name: "open_payments_iso20022_reda::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.068.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.068.001.02",
+		root_element: "ReqToPayCdtrEnrlmntCxlReq",
+		type_name: "open_payments_iso20022_reda::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.069.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.069.001.02",
+		root_element: "ReqToPayCdtrEnrlmntStsRpt",
+		type_name: "open_payments_iso20022_reda::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.070.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.070.001.02",
+		root_element: "ReqToPayDbtrActvtnReq",
+		type_name: "open_payments_iso20022_reda::reda_070_001_02::RequestToPayDebtorActivationRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.071.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.071.001.02",
+		root_element: "ReqToPayDbtrActvtnAmdmntReq",
+		type_name: "open_payments_iso20022_reda::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.072.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.072.001.02",
+		root_element: "ReqToPayDbtrActvtnCxlReq",
+		type_name: "open_payments_iso20022_reda::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "reda")]
+	MessageInfo {
+		identifier: "reda.073.001.02",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:reda.073.001.02",
+		root_element: "ReqToPayDbtrActvtnStsRpt",
+		type_name: "open_payments_iso20022_reda::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02",
+		feature: "reda",
+	},
+	#[cfg(feature = "remt")]
+	MessageInfo {
+		identifier: "remt.001.001.06",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:remt.001.001.06",
+		root_element: "RmtAdvc",
+		type_name: "open_payments_iso20022_remt::remt_001_001_06::RemittanceAdviceV06",
+		feature: "remt",
+	},
+	#[cfg(feature = "remt")]
+	MessageInfo {
+		identifier: "remt.002.001.03",
+		namespace: "urn:iso:std:iso:20022:tech:xsd:remt.002.001.03",
+		root_element: "RmtLctnAdvc",
+		type_name: "open_payments_iso20022_remt::remt_002_001_03::RemittanceLocationAdviceV03",
+		feature: "remt",
+	},
+];
@@ -0,0 +1,267 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Rules applied to a [`Document`] as a [`RuleSet`], for checks the schemas and the library do not know,
+//! e.g. internal limits or the bilateral agreements with a correspondent.
+//!
+//! A [`Rule`] reports violations to a [`Validator`] like the built-in checks do, and every violation
+//! becomes a [`Finding`] carrying the id and [`Severity`] of its rule along with the element path.
+//! The schema facets ([`Schema`]), the cross-field rules of the message definitions ([`MessageRules`])
+//! and the CBPR+ profile ([`CbprPlus`]) are rules too, so they run alongside the user-defined ones:
+//!
+//! ```ignore
+//! let rules = RuleSet::new()
+//!     .with(Schema)
+//!     .with(MessageRules)
+//!     .with(from_fn("limit", Severity::Warning, |doc, validator| check_limit(doc, validator)));
+//! let findings = rules.apply(&doc);
+//! ```
+
+use std::fmt;
+use iso20022_common::{ValidationError, Validator};
+use crate::document::Document;
+use crate::element;
+
+/// How a [`Finding`] weighs, only errors make the document fail its [`RuleSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "derive_serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "derive_serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+	Warning,
+	Error,
+}
+
+impl fmt::Display for Severity {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Severity::Warning => write!(f, "warning"),
+			Severity::Error => write!(f, "error"),
+		}
+	}
+}
+
+/// A check of a [`Document`], identified in its findings by its [`id`](Rule::id).
+pub trait Rule: Send + Sync {
+	/// Identifies the rule in its findings, e.g. `schema` or `internal-amount-limit`.
+	fn id(&self) -> &str;
+
+	/// The severity of the violations of the rule, unless the [`RuleSet`] overrides it.
+	fn severity(&self) -> Severity {
+		Severity::Error
+	}
+
+	/// Checks `doc` and reports every violation to `validator`, located from the `Document` element
+	/// down as [`Document::validate_with`] does.
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError>;
+}
+
+/// A violation of a rule of a [`RuleSet`]. `error.path` locates the offending element.
+#[derive(Debug)]
+pub struct Finding {
+	pub rule: String,
+	pub severity: Severity,
+	pub error: ValidationError,
+}
+
+impl fmt::Display for Finding {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} [{}] {}", self.severity, self.rule, self.error)
+	}
+}
+
+/// Every finding of [`RuleSet::apply`], in the order of the rules.
+#[derive(Debug, Default)]
+pub struct Findings {
+	pub findings: Vec<Finding>,
+}
+
+impl Findings {
+	/// Whether no rule reported an error, warnings aside.
+	pub fn is_valid(&self) -> bool {
+		self.errors().next().is_none()
+	}
+
+	/// The findings of severity [`Severity::Error`].
+	pub fn errors(&self) -> impl Iterator<Item = &Finding> {
+		self.findings.iter().filter(|finding| finding.severity == Severity::Error)
+	}
+
+	/// The findings of severity [`Severity::Warning`].
+	pub fn warnings(&self) -> impl Iterator<Item = &Finding> {
+		self.findings.iter().filter(|finding| finding.severity == Severity::Warning)
+	}
+}
+
+impl fmt::Display for Findings {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for finding in &self.findings {
+			writeln!(f, "{}", finding)?;
+		}
+		Ok(())
+	}
+}
+
+/// The rules applied together to a document, each with its severity.
+#[derive(Default)]
+pub struct RuleSet {
+	rules: Vec<(Box<dyn Rule>, Severity)>,
+}
+
+impl RuleSet {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The schema facets and the cross-field rules of the message definitions, as checked by
+	/// [`Document::validate_with`] with a validator built with [`Validator::with_rules`].
+	pub fn standard() -> Self {
+		Self::new().with(Schema).with(MessageRules)
+	}
+
+	/// Adds `rule` with its own severity.
+	pub fn with<R: Rule + 'static>(self, rule: R) -> Self {
+		let severity = rule.severity();
+		self.with_severity(rule, severity)
+	}
+
+	/// Adds `rule`, its violations reported as `severity`, e.g. to only warn about a built-in rule.
+	pub fn with_severity<R: Rule + 'static>(mut self, rule: R, severity: Severity) -> Self {
+		self.rules.push((Box::new(rule), severity));
+		self
+	}
+
+	/// Adds every rule of `rules` with its own severity, e.g. the rules of a [`declarative`](crate::declarative) file.
+	pub fn with_all<R: Rule + 'static, I: IntoIterator<Item = R>>(mut self, rules: I) -> Self {
+		for rule in rules {
+			self.add(rule);
+		}
+		self
+	}
+
+	/// Adds `rule` to the set in place.
+	pub fn add<R: Rule + 'static>(&mut self, rule: R) {
+		let severity = rule.severity();
+		self.rules.push((Box::new(rule), severity));
+	}
+
+	/// The ids of the rules, in the order they are applied.
+	pub fn ids(&self) -> impl Iterator<Item = &str> {
+		self.rules.iter().map(|(rule, _)| rule.id())
+	}
+
+	/// Applies every rule to `doc` and returns all of their findings. The rules checking the XML of
+	/// the message, such as the [`declarative`](crate::declarative) ones, share a single parse of it.
+	pub fn apply(&self, doc: &Document) -> Findings {
+		let mut findings = Findings::default();
+		element::sharing(doc, || {
+			for (rule, severity) in &self.rules {
+				let mut validator = Validator::accumulate();
+				// An accumulating validator never stops the rule, so the result is always `Ok`.
+				let _ = rule.check(doc, &mut validator);
+				findings.findings.extend(validator.into_report().errors.into_iter().map(|error| Finding {
+					rule: rule.id().to_string(),
+					severity: *severity,
+					error,
+				}));
+			}
+		});
+		findings
+	}
+}
+
+/// A rule checking a document with a closure, see [`from_fn`].
+pub struct FnRule<F> {
+	id: String,
+	severity: Severity,
+	check: F,
+}
+
+/// Creates the rule `id` of `severity` checking a document with `check`.
+pub fn from_fn<F>(id: &str, severity: Severity, check: F) -> FnRule<F>
+where
+	F: Fn(&Document, &mut Validator) -> Result<(), ValidationError> + Send + Sync,
+{
+	FnRule { id: id.to_string(), severity, check }
+}
+
+impl<F> Rule for FnRule<F>
+where
+	F: Fn(&Document, &mut Validator) -> Result<(), ValidationError> + Send + Sync,
+{
+	fn id(&self) -> &str {
+		&self.id
+	}
+
+	fn severity(&self) -> Severity {
+		self.severity
+	}
+
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+		(self.check)(doc, validator)
+	}
+}
+
+/// The XSD facets of the message, as checked by [`Document::validate_all`].
+pub struct Schema;
+
+impl Rule for Schema {
+	fn id(&self) -> &str {
+		"schema"
+	}
+
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+		doc.validate_with(validator)
+	}
+}
+
+/// The cross-field rules of the message definition, as checked by [`Document::validate_rules`].
+pub struct MessageRules;
+
+impl Rule for MessageRules {
+	fn id(&self) -> &str {
+		"message-rules"
+	}
+
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+		doc.validate_rules(validator)
+	}
+}
+
+/// The CBPR+ usage guidelines, as checked by [`cbpr_plus::validate`](crate::cbpr_plus::validate).
+#[cfg(feature = "derive_serde")]
+pub struct CbprPlus;
+
+#[cfg(feature = "derive_serde")]
+impl Rule for CbprPlus {
+	fn id(&self) -> &str {
+		"cbpr-plus"
+	}
+
+	fn check(&self, doc: &Document, validator: &mut Validator) -> Result<(), ValidationError> {
+		match crate::cbpr_plus::validate(doc) {
+			Ok(report) => report.errors.into_iter().try_for_each(|error| validator.report(error)),
+			Err(e) => validator.report(ValidationError::new(0, format!("message could not be checked: {}", e))),
+		}
+	}
+}
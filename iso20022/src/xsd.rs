@@ -0,0 +1,667 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+//! Validation of raw XML messages against their XSD schema, before they are deserialized.
+//!
+//! A [`SchemaSet`] holds the schemas loaded from the `xsd/` directory of the repository, or any other,
+//! and checks a message against the schema of its namespace. Structural violations the typed
+//! messages only surface as deserialization errors, such as elements out of order, missing or not
+//! allowed, are reported with the line and column of the offending element along with the facet
+//! violations of its value:
+//!
+//! ```ignore
+//! let schemas = SchemaSet::from_dir("xsd/iso20022")?;
+//! let report = schemas.validate(&xml)?;
+//! for violation in &report.violations {
+//!     println!("{}", violation); // 12:6: /Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs: missing element NbOfTxs
+//! }
+//! ```
+//!
+//! The schemas are read as the ISO 20022 schemas are written: a single file per message of global
+//! elements, complex types of a `sequence` or `choice` content model or of simple content with
+//! attributes, and simple types restricting a built-in type by facets. The content of `xs:any`
+//! elements, e.g. supplementary data, is not checked.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+use iso20022_common::{Date, DateTime, Decimal, Time, ValidationError};
+use regex::Regex;
+#[cfg(feature = "derive_serde")]
+use crate::document::Document;
+use crate::element::Element;
+
+/// Errors raised while loading schemas or reading a message to validate.
+#[derive(Debug)]
+pub enum XsdError {
+	Io(io::Error),
+	/// The schema `file`, empty for one added from a string, is not well-formed or uses an unsupported construct.
+	Schema { file: String, message: String },
+	/// The message is not well-formed XML at `line` and `column`.
+	Xml { line: usize, column: usize, source: quick_xml::Error },
+	/// No schema is loaded for the namespace of the message.
+	UnknownNamespace(String),
+	/// The message violates its schema.
+	Invalid(XsdReport),
+	/// The message is valid but could not be deserialized.
+	Parse(crate::error::Error),
+}
+
+impl fmt::Display for XsdError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			XsdError::Io(e) => write!(f, "I/O error: {}", e),
+			XsdError::Schema { file, message } if file.is_empty() => write!(f, "invalid schema: {}", message),
+			XsdError::Schema { file, message } => write!(f, "invalid schema {}: {}", file, message),
+			XsdError::Xml { line, column, source } => write!(f, "{}:{}: malformed XML: {}", line, column, source),
+			XsdError::UnknownNamespace(namespace) => write!(f, "no schema for namespace {:?}", namespace),
+			XsdError::Invalid(report) => write!(f, "message violates its schema:\n{}", report),
+			XsdError::Parse(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl std::error::Error for XsdError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			XsdError::Io(e) => Some(e),
+			XsdError::Xml { source, .. } => Some(source),
+			XsdError::Invalid(report) => Some(report),
+			XsdError::Parse(e) => Some(e),
+			XsdError::Schema { .. } | XsdError::UnknownNamespace(_) => None,
+		}
+	}
+}
+
+impl From<io::Error> for XsdError {
+	fn from(e: io::Error) -> Self {
+		XsdError::Io(e)
+	}
+}
+
+/// A schema violation, located by the line and column of the start tag of the offending element,
+/// both starting at 1, and by the element path of `error`.
+#[derive(Debug)]
+pub struct XsdViolation {
+	pub line: usize,
+	pub column: usize,
+	pub error: ValidationError,
+}
+
+impl fmt::Display for XsdViolation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}:{}: {}", self.line, self.column, self.error)
+	}
+}
+
+/// Every schema violation of a message, in document order.
+#[derive(Debug, Default)]
+pub struct XsdReport {
+	pub violations: Vec<XsdViolation>,
+}
+
+impl XsdReport {
+	pub fn is_valid(&self) -> bool {
+		self.violations.is_empty()
+	}
+
+	/// Drops the offending values of the violations, see [`ValidationError::redact`].
+	pub fn redact(&mut self) {
+		for violation in &mut self.violations {
+			violation.error.redact();
+		}
+	}
+}
+
+impl fmt::Display for XsdReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for violation in &self.violations {
+			writeln!(f, "{}", violation)?;
+		}
+		Ok(())
+	}
+}
+
+impl std::error::Error for XsdReport {}
+
+/// The schemas messages are checked against, by namespace.
+#[derive(Debug, Default)]
+pub struct SchemaSet {
+	schemas: HashMap<String, Schema>,
+}
+
+impl SchemaSet {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Loads every `.xsd` file of `dir` and its subdirectories, e.g. `xsd/iso20022`.
+	pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, XsdError> {
+		let mut schemas = SchemaSet::new();
+		schemas.add_dir(dir.as_ref())?;
+		Ok(schemas)
+	}
+
+	fn add_dir(&mut self, dir: &Path) -> Result<(), XsdError> {
+		let mut paths = fs::read_dir(dir)?.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>()?;
+		paths.sort();
+		for path in paths {
+			if path.is_dir() {
+				self.add_dir(&path)?;
+			} else if path.extension().and_then(|e| e.to_str()) == Some("xsd") {
+				self.add(&fs::read_to_string(&path)?).map_err(|e| match e {
+					XsdError::Schema { message, .. } => XsdError::Schema { file: path.display().to_string(), message },
+					e => e,
+				})?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Loads the schema `xsd` and returns its target namespace. It replaces any schema of the same namespace.
+	pub fn add(&mut self, xsd: &str) -> Result<String, XsdError> {
+		let schema = Schema::parse(xsd)?;
+		let namespace = schema.namespace.clone();
+		self.schemas.insert(namespace.clone(), schema);
+		Ok(namespace)
+	}
+
+	/// The target namespaces of the loaded schemas.
+	pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+		self.schemas.keys().map(String::as_str)
+	}
+
+	/// Checks the XML message `xml` against the schema of the namespace of its root element and returns every violation.
+	pub fn validate(&self, xml: &str) -> Result<XsdReport, XsdError> {
+		let lines = Lines::new(xml);
+		let root = Element::parse_located(xml).map_err(|(source, position)| {
+			let (line, column) = lines.locate(position);
+			XsdError::Xml { line, column, source }
+		})?;
+		let schema = self.schemas.get(&root.namespace).ok_or_else(|| XsdError::UnknownNamespace(root.namespace.clone()))?;
+		let mut validation = Validation { schema, lines, violations: Vec::new() };
+		let path = format!("/{}", root.name);
+		match schema.elements.get(&root.name) {
+			Some(type_name) => validation.element(&root, type_name, &path),
+			None => validation.report(&root, &path, ValidationError::unexpected_element(&root.name)),
+		}
+		Ok(XsdReport { violations: validation.violations })
+	}
+
+	/// Checks `xml` against its schema and only deserializes it when it is valid.
+	#[cfg(feature = "derive_serde")]
+	pub fn parse_document(&self, xml: &str) -> Result<Document, XsdError> {
+		let report = self.validate(xml)?;
+		if !report.is_valid() {
+			return Err(XsdError::Invalid(report));
+		}
+		Document::from_xml_str(xml).map_err(XsdError::Parse)
+	}
+}
+
+#[derive(Debug)]
+struct Schema {
+	namespace: String,
+	/// The type of every global element, e.g. `Document`.
+	elements: HashMap<String, String>,
+	complex_types: HashMap<String, ComplexType>,
+	simple_types: HashMap<String, SimpleType>,
+}
+
+#[derive(Debug)]
+enum ComplexType {
+	Content(Particle),
+	Simple { base: String, attributes: Vec<Attribute> },
+}
+
+#[derive(Debug)]
+struct Attribute {
+	name: String,
+	type_name: String,
+	required: bool,
+}
+
+#[derive(Debug)]
+enum Particle {
+	Element { name: String, type_name: String, min: usize, max: usize },
+	Sequence(Vec<Particle>),
+	Choice(Vec<Particle>),
+	Any { min: usize, max: usize },
+}
+
+#[derive(Debug, Default)]
+struct SimpleType {
+	base: String,
+	enumeration: Vec<String>,
+	/// The pattern facets, compiled on first use.
+	patterns: Vec<(String, OnceLock<Option<Regex>>)>,
+	length: Option<usize>,
+	min_length: Option<usize>,
+	max_length: Option<usize>,
+	total_digits: Option<u32>,
+	fraction_digits: Option<u32>,
+	min_inclusive: Option<Decimal>,
+	max_inclusive: Option<Decimal>,
+	min_exclusive: Option<Decimal>,
+	max_exclusive: Option<Decimal>,
+}
+
+fn schema_error(message: String) -> XsdError {
+	XsdError::Schema { file: String::new(), message }
+}
+
+fn required<'a>(element: &'a Element, attribute: &str) -> Result<&'a str, XsdError> {
+	element.attribute(attribute).ok_or_else(|| schema_error(format!("{} without {}", element.name, attribute)))
+}
+
+fn parsed<T: std::str::FromStr>(element: &Element, attribute: &str) -> Result<T, XsdError> {
+	let value = required(element, attribute)?;
+	value.parse().map_err(|_| schema_error(format!("invalid {} {:?} of {}", attribute, value, element.name)))
+}
+
+fn occurs(element: &Element) -> Result<(usize, usize), XsdError> {
+	let min = match element.attribute("minOccurs") {
+		Some(_) => parsed(element, "minOccurs")?,
+		None => 1,
+	};
+	let max = match element.attribute("maxOccurs") {
+		Some("unbounded") => usize::MAX,
+		Some(_) => parsed(element, "maxOccurs")?,
+		None => 1,
+	};
+	Ok((min, max))
+}
+
+impl Schema {
+	fn parse(xsd: &str) -> Result<Schema, XsdError> {
+		let root = Element::parse(xsd).map_err(|e| schema_error(e.to_string()))?;
+		if root.name != "schema" {
+			return Err(schema_error(format!("root element {} is not a schema", root.name)));
+		}
+		let mut schema = Schema {
+			namespace: required(&root, "targetNamespace")?.to_string(),
+			elements: HashMap::new(),
+			complex_types: HashMap::new(),
+			simple_types: HashMap::new(),
+		};
+		for child in &root.children {
+			let name = required(child, "name")?.to_string();
+			match child.name.as_str() {
+				"element" => {
+					schema.elements.insert(name, required(child, "type")?.to_string());
+				}
+				"complexType" => {
+					schema.complex_types.insert(name, Schema::complex_type(child)?);
+				}
+				"simpleType" => {
+					schema.simple_types.insert(name, Schema::simple_type(child)?);
+				}
+				other => return Err(schema_error(format!("unsupported xs:{}", other))),
+			}
+		}
+		Ok(schema)
+	}
+
+	fn complex_type(element: &Element) -> Result<ComplexType, XsdError> {
+		match element.children.as_slice() {
+			[content] if content.name == "simpleContent" => {
+				let extension = content.child("extension").ok_or_else(|| schema_error("simpleContent without extension".to_string()))?;
+				let attributes = extension
+					.children("attribute")
+					.map(|attribute| {
+						Ok(Attribute {
+							name: required(attribute, "name")?.to_string(),
+							type_name: required(attribute, "type")?.to_string(),
+							required: attribute.attribute("use") == Some("required"),
+						})
+					})
+					.collect::<Result<_, XsdError>>()?;
+				Ok(ComplexType::Simple { base: required(extension, "base")?.to_string(), attributes })
+			}
+			[content] => Ok(ComplexType::Content(Schema::particle(content)?)),
+			_ => Err(schema_error(format!("unsupported content of complex type {}", element.attribute("name").unwrap_or_default()))),
+		}
+	}
+
+	fn particle(element: &Element) -> Result<Particle, XsdError> {
+		match element.name.as_str() {
+			"element" => {
+				let (min, max) = occurs(element)?;
+				Ok(Particle::Element { name: required(element, "name")?.to_string(), type_name: required(element, "type")?.to_string(), min, max })
+			}
+			"sequence" => Ok(Particle::Sequence(element.children.iter().map(Schema::particle).collect::<Result<_, _>>()?)),
+			"choice" => Ok(Particle::Choice(element.children.iter().map(Schema::particle).collect::<Result<_, _>>()?)),
+			"any" => {
+				let (min, max) = occurs(element)?;
+				Ok(Particle::Any { min, max })
+			}
+			other => Err(schema_error(format!("unsupported xs:{}", other))),
+		}
+	}
+
+	fn simple_type(element: &Element) -> Result<SimpleType, XsdError> {
+		let restriction = element.child("restriction").ok_or_else(|| schema_error("simpleType without restriction".to_string()))?;
+		let mut simple_type = SimpleType { base: required(restriction, "base")?.to_string(), ..Default::default() };
+		for facet in &restriction.children {
+			match facet.name.as_str() {
+				"enumeration" => simple_type.enumeration.push(required(facet, "value")?.to_string()),
+				"pattern" => simple_type.patterns.push((required(facet, "value")?.to_string(), OnceLock::new())),
+				"length" => simple_type.length = Some(parsed(facet, "value")?),
+				"minLength" => simple_type.min_length = Some(parsed(facet, "value")?),
+				"maxLength" => simple_type.max_length = Some(parsed(facet, "value")?),
+				"totalDigits" => simple_type.total_digits = Some(parsed(facet, "value")?),
+				"fractionDigits" => simple_type.fraction_digits = Some(parsed(facet, "value")?),
+				"minInclusive" => simple_type.min_inclusive = Some(parsed(facet, "value")?),
+				"maxInclusive" => simple_type.max_inclusive = Some(parsed(facet, "value")?),
+				"minExclusive" => simple_type.min_exclusive = Some(parsed(facet, "value")?),
+				"maxExclusive" => simple_type.max_exclusive = Some(parsed(facet, "value")?),
+				other => return Err(schema_error(format!("unsupported facet xs:{}", other))),
+			}
+		}
+		Ok(simple_type)
+	}
+}
+
+impl Particle {
+	// Whether the particle matches no element at all.
+	fn is_optional(&self) -> bool {
+		match self {
+			Particle::Element { min, .. } | Particle::Any { min, .. } => *min == 0,
+			Particle::Sequence(particles) => particles.iter().all(Particle::is_optional),
+			Particle::Choice(particles) => particles.iter().any(Particle::is_optional),
+		}
+	}
+
+	// Whether the particle can start with the element `name`.
+	fn starts_with(&self, name: &str) -> bool {
+		match self {
+			Particle::Element { name: element, .. } => element == name,
+			Particle::Any { .. } => true,
+			Particle::Sequence(particles) => {
+				for particle in particles {
+					if particle.starts_with(name) {
+						return true;
+					}
+					if !particle.is_optional() {
+						return false;
+					}
+				}
+				false
+			}
+			Particle::Choice(particles) => particles.iter().any(|particle| particle.starts_with(name)),
+		}
+	}
+
+	// The elements the particle can start with, to name what is missing.
+	fn first_names(&self, names: &mut Vec<String>) {
+		match self {
+			Particle::Element { name, .. } => names.push(name.clone()),
+			Particle::Any { .. } => names.push("any element".to_string()),
+			Particle::Sequence(particles) => {
+				for particle in particles {
+					particle.first_names(names);
+					if !particle.is_optional() {
+						break;
+					}
+				}
+			}
+			Particle::Choice(particles) => particles.iter().for_each(|particle| particle.first_names(names)),
+		}
+	}
+}
+
+// The start offset of every line of a message, to locate its elements.
+struct Lines<'a> {
+	xml: &'a str,
+	starts: Vec<usize>,
+}
+
+impl<'a> Lines<'a> {
+	fn new(xml: &'a str) -> Self {
+		let starts = std::iter::once(0).chain(xml.match_indices('\n').map(|(i, _)| i + 1)).collect();
+		Lines { xml, starts }
+	}
+
+	// The line and column, in characters, of the byte offset `position`.
+	fn locate(&self, position: usize) -> (usize, usize) {
+		let position = position.min(self.xml.len());
+		let line = self.starts.partition_point(|start| *start <= position);
+		let start = self.starts[line - 1];
+		let column = self.xml.get(start..position).map_or(position - start, |prefix| prefix.chars().count());
+		(line, column + 1)
+	}
+}
+
+// A single run of a schema over a message.
+struct Validation<'a> {
+	schema: &'a Schema,
+	lines: Lines<'a>,
+	violations: Vec<XsdViolation>,
+}
+
+impl Validation<'_> {
+	fn report(&mut self, element: &Element, path: &str, mut error: ValidationError) {
+		let (line, column) = self.lines.locate(element.position);
+		error.path = path.to_string();
+		self.violations.push(XsdViolation { line, column, error });
+	}
+
+	// Checks `element` at `path` against the type `type_name`.
+	fn element(&mut self, element: &Element, type_name: &str, path: &str) {
+		let schema = self.schema;
+		match schema.complex_types.get(type_name) {
+			Some(ComplexType::Content(particle)) => {
+				let paths = child_paths(element, path);
+				let mut next = 0;
+				self.particle(particle, element, path, &paths, &mut next);
+				for (child, path) in element.children.iter().zip(&paths).skip(next) {
+					self.report(child, path, ValidationError::unexpected_element(&child.name));
+				}
+			}
+			Some(ComplexType::Simple { base, attributes }) => {
+				self.children_not_allowed(element, path);
+				self.value(element, path, &element.name, base, &element.text);
+				for attribute in attributes {
+					let attribute_path = format!("{}/@{}", path, attribute.name);
+					match element.attribute(&attribute.name) {
+						Some(value) => self.value(element, &attribute_path, &attribute.name, &attribute.type_name, value),
+						None if attribute.required => self.report(element, path, ValidationError::missing_attribute(&attribute.name)),
+						None => {}
+					}
+				}
+			}
+			None => {
+				self.children_not_allowed(element, path);
+				self.value(element, path, &element.name, type_name, &element.text);
+			}
+		}
+	}
+
+	fn children_not_allowed(&mut self, element: &Element, path: &str) {
+		for (child, path) in element.children.iter().zip(child_paths(element, path)) {
+			self.report(child, &path, ValidationError::unexpected_element(&child.name));
+		}
+	}
+
+	// Matches the children of `parent` from `next` on against `particle`, as many as it takes.
+	// The content models of the schemas are deterministic, so the first alternative that fits is the one.
+	fn particle(&mut self, particle: &Particle, parent: &Element, path: &str, paths: &[String], next: &mut usize) {
+		let children = &parent.children;
+		match particle {
+			Particle::Element { name, type_name, min, max } => {
+				let mut count = 0;
+				while *next < children.len() && children[*next].name == *name && count < *max {
+					self.element(&children[*next], type_name, &paths[*next]);
+					*next += 1;
+					count += 1;
+				}
+				if count < *min {
+					self.missing(parent, path, *next, name);
+				}
+			}
+			Particle::Any { min, max } => {
+				let count = (children.len() - *next).min(*max);
+				*next += count;
+				if count < *min {
+					self.missing(parent, path, *next, "any element");
+				}
+			}
+			Particle::Sequence(particles) => {
+				for particle in particles {
+					self.particle(particle, parent, path, paths, next);
+				}
+			}
+			Particle::Choice(particles) => {
+				let chosen = children.get(*next).and_then(|child| particles.iter().find(|particle| particle.starts_with(&child.name)));
+				match chosen {
+					Some(particle) => self.particle(particle, parent, path, paths, next),
+					None if !particle.is_optional() => {
+						let mut names = Vec::new();
+						particle.first_names(&mut names);
+						self.missing(parent, path, *next, &names.join(" or "));
+					}
+					None => {}
+				}
+			}
+		}
+	}
+
+	// Reports the missing `name` at `parent`, located at the child it was expected before or at `parent` past its last child.
+	fn missing(&mut self, parent: &Element, path: &str, next: usize, name: &str) {
+		let located = parent.children.get(next).unwrap_or(parent);
+		self.report(located, path, ValidationError::missing_element(name));
+	}
+
+	// Checks `value` of the element or attribute `name` at `path` against the simple or built-in type `type_name`.
+	fn value(&mut self, element: &Element, path: &str, name: &str, type_name: &str, value: &str) {
+		let mut type_name = type_name;
+		while let Some(simple_type) = self.schema.simple_types.get(type_name) {
+			for error in simple_type.check(type_name, name, value) {
+				self.report(element, path, error);
+			}
+			type_name = &simple_type.base;
+		}
+		if let Some(error) = check_builtin(type_name, value) {
+			self.report(element, path, error);
+		}
+	}
+}
+
+// The element path of every child of `element` at `path`, repeated children carrying their position.
+fn child_paths(element: &Element, path: &str) -> Vec<String> {
+	let mut counts: HashMap<&str, usize> = HashMap::new();
+	for child in &element.children {
+		*counts.entry(child.name.as_str()).or_insert(0) += 1;
+	}
+	let mut positions: HashMap<&str, usize> = HashMap::new();
+	element
+		.children
+		.iter()
+		.map(|child| {
+			let position = positions.entry(child.name.as_str()).or_insert(0);
+			*position += 1;
+			match counts[child.name.as_str()] {
+				1 => format!("{}/{}", path, child.name),
+				_ => format!("{}/{}[{}]", path, child.name, position),
+			}
+		})
+		.collect()
+}
+
+impl SimpleType {
+	// The facet violations of `value` of the element or attribute `name`.
+	fn check(&self, type_name: &str, name: &str, value: &str) -> Vec<ValidationError> {
+		let mut errors = Vec::new();
+		if !self.enumeration.is_empty() && !self.enumeration.iter().any(|allowed| allowed == value) {
+			errors.push(ValidationError::invalid_value(type_name, value, "not in its enumeration"));
+		}
+		for (pattern, regex) in &self.patterns {
+			let regex = regex.get_or_init(|| Regex::new(&format!("^(?:{})$", pattern)).ok());
+			if regex.as_ref().is_some_and(|regex| !regex.is_match(value)) {
+				errors.push(ValidationError::invalid_value(type_name, value, &format!("does not match the pattern {}", pattern)));
+			}
+		}
+		let length = value.chars().count();
+		if let Some(min) = self.min_length.or(self.length).filter(|min| length < *min) {
+			errors.push(ValidationError::min_length(name, min, length));
+		}
+		if let Some(max) = self.max_length.or(self.length).filter(|max| length > *max) {
+			errors.push(ValidationError::max_length(name, max, length));
+		}
+		if let Ok(decimal) = value.parse::<Decimal>() {
+			if let Some(max) = self.total_digits.filter(|max| decimal.total_digits() > *max) {
+				errors.push(ValidationError::total_digits(name, max, &decimal));
+			}
+			if let Some(max) = self.fraction_digits.filter(|max| decimal.fraction_digits() > *max) {
+				errors.push(ValidationError::fraction_digits(name, max, &decimal));
+			}
+			if let Some(min) = self.min_inclusive.filter(|min| decimal < *min) {
+				errors.push(ValidationError::min_inclusive(name, min, &decimal));
+			}
+			if let Some(max) = self.max_inclusive.filter(|max| decimal > *max) {
+				errors.push(ValidationError::max_inclusive(name, max, &decimal));
+			}
+			if let Some(min) = self.min_exclusive.filter(|min| decimal <= *min) {
+				errors.push(ValidationError::min_exclusive(name, min, &decimal));
+			}
+			if let Some(max) = self.max_exclusive.filter(|max| decimal >= *max) {
+				errors.push(ValidationError::max_exclusive(name, max, &decimal));
+			}
+		}
+		errors
+	}
+}
+
+// Checks the lexical form of `value` for the built-in type `type_name`, other types pass.
+fn check_builtin(type_name: &str, value: &str) -> Option<ValidationError> {
+	static G_YEAR: OnceLock<Regex> = OnceLock::new();
+	static G_YEAR_MONTH: OnceLock<Regex> = OnceLock::new();
+	static G_MONTH: OnceLock<Regex> = OnceLock::new();
+	const TIME_ZONE: &str = "(Z|[+-]((0[0-9]|1[0-3]):[0-5][0-9]|14:00))?";
+	let valid = match type_name {
+		"xs:date" => return check_date_time(value.parse::<Date>().map(|date| date.is_valid()), type_name, value),
+		"xs:dateTime" => return check_date_time(value.parse::<DateTime>().map(|date_time| date_time.is_valid()), type_name, value),
+		"xs:time" => return check_date_time(value.parse::<Time>().map(|time| time.is_valid()), type_name, value),
+		"xs:decimal" => value.parse::<Decimal>().is_ok(),
+		"xs:boolean" => matches!(value, "true" | "false" | "1" | "0"),
+		"xs:gYear" => G_YEAR.get_or_init(|| Regex::new(&format!("^-?[0-9]{{4,}}{}$", TIME_ZONE)).unwrap()).is_match(value),
+		"xs:gYearMonth" => G_YEAR_MONTH.get_or_init(|| Regex::new(&format!("^-?[0-9]{{4,}}-(0[1-9]|1[0-2]){}$", TIME_ZONE)).unwrap()).is_match(value),
+		"xs:gMonth" => G_MONTH.get_or_init(|| Regex::new(&format!("^--(0[1-9]|1[0-2]){}$", TIME_ZONE)).unwrap()).is_match(value),
+		"xs:base64Binary" => value.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=') || c.is_ascii_whitespace()),
+		_ => true,
+	};
+	(!valid).then(|| ValidationError::invalid_value(type_name, value, "malformed"))
+}
+
+// A date or time that parses but does not exist, e.g. `2024-02-30`, is reported as such.
+fn check_date_time<E>(parsed: Result<bool, E>, type_name: &str, value: &str) -> Option<ValidationError> {
+	match parsed {
+		Ok(true) => None,
+		Ok(false) => Some(ValidationError::invalid_date_time(value)),
+		Err(_) => Some(ValidationError::invalid_value(type_name, value, "malformed")),
+	}
+}
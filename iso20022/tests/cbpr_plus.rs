@@ -0,0 +1,188 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+#![cfg(feature = "derive_serde")]
+
+use std::fs;
+use std::path::Path;
+use iso20022_common::ValidationErrorKind;
+use open_payments_iso20022::cbpr_plus;
+
+const SAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../samples");
+
+// Every CBPR+ sample and the rules it violates. Besides the rule they are named after, the samples
+// of a `Case` with a `Cretr` named without its postal address violate `CBPR_PartyNamePostalAddressRule`.
+const EXPECTED: &[(&str, &[&str])] = &[
+	("CBPR_AgentNamePostalAddressRule1_1.xml", &[]),
+	("CBPR_AgentNamePostalAddressRule_01.xml", &[]),
+	("CBPR_AgentNamePostalAddressRule_02.xml", &[]),
+	("CBPR_AgentNamePostalAddressRule_1_1.xml", &[]),
+	("CBPR_Agent_Name_Postal_Address_FormalRule.xml", &[]),
+	("CBPR_CancellationIdentification_Rule_01.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_CancellationIdentification_Rule_02.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_CancellationIdentification_Rule_03.xml", &["CBPR_CancellationIdentification_Rule", "CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_CaseIdentification_Rule_01.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_CaseIdentification_Rule_02.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_CaseIdentification_Rule_03.xml", &["CBPR_CaseIdentification_Rule", "CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_Case_Identification_FormalRule.xml", &[]),
+	("CBPR_DEBT_FormalRule_1.xml", &[]),
+	("CBPR_DEBT_FormalRule_2.xml", &[]),
+	("CBPR_Debtor_BIC_PresenceRule_1.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_01.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_02.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_1_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_2_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_3_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent1_4_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_01.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_02.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_1_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_2_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_3_.xml", &[]),
+	("CBPR_InstructionforCreditorAgent2_4_.xml", &[]),
+	("CBPR_NameAnyBICRule_1.xml", &[]),
+	("CBPR_NameAnyBICRule_2.xml", &[]),
+	("CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule_1.xml", &[]),
+	("CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule_2.xml", &[]),
+	("CBPR_Originator_Option1Rule_01.xml", &[]),
+	("CBPR_Originator_Option1Rule_02.xml", &[]),
+	("CBPR_Originator_Option1Rule_03.xml", &[]),
+	("CBPR_Originator_Option2Rule_01.xml", &[]),
+	("CBPR_Originator_Option2Rule_02.xml", &[]),
+	("CBPR_Originator_Option2Rule_03.xml", &[]),
+	("CBPR_PartyNameAnyBICRule1.xml", &[]),
+	("CBPR_PartyNameAnyBICRule2.xml", &[]),
+	("CBPR_PartyNameAnyBICRule3.xml", &[]),
+	("CBPR_PartyNameAnyBICRule_01.xml", &[]),
+	("CBPR_PartyNameAnyBICRule_02.xml", &["CBPR_PartyNameAnyBICRule"]),
+	("CBPR_PartyNameAnyBICRule_03.xml", &[]),
+	("CBPR_PartyNameAnyBICRule_04.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_01.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_02.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_03.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_PartyNamePostalAddressRule_04.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_1.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_1_1.xml", &[]),
+	("CBPR_PartyNamePostalAddressRule_1_2.xml", &["CBPR_PartyNameAnyBICRule"]),
+	("CBPR_PartyNamePostalAddressRule_2.xml", &[]),
+	("CBPR_ReasonCodeNARR_AdditionalInformationRule_01.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_ReasonCodeNARR_AdditionalInformationRule_02.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_ReasonCodeNARR_AdditionalInformationRule_03.xml", &["CBPR_PartyNamePostalAddressRule"]),
+	("CBPR_Reason_Code_NARR_Additional_Information_FormalRule_1.xml", &[]),
+	("CBPR_Reason_Code_NARR_Additional_Information_FormalRule_2.xml", &[]),
+	("CBPR_RemittanceRules_1.xml", &[]),
+	("CBPR_Structured_vs_Unstructured_FormalRule_1.xml", &[]),
+	("CBPR_Structured_vs_Unstructured_FormalRule_2.xml", &[]),
+	("CBPR_TransactionStatusRejectReasonRule_01.xml", &[]),
+	("CBPR_TransactionStatusRejectReasonRule_02.xml", &[]),
+	("CBPR_TransactionStatusRejectReasonRule_03.xml", &[]),
+	("DEBTRule1_1.xml", &[]),
+	("DEBTRule1_2.xml", &["CBPR_DEBTRule1"]),
+	("InterbankSettlementCurrencyRule_1.xml", &[]),
+	("InterbankSettlementCurrencyRule_2.xml", &[]),
+];
+
+// The rules `xml` violates, each once and sorted.
+fn violated(xml: &str) -> Vec<String> {
+	let report = cbpr_plus::validate_xml(xml).unwrap();
+	let mut rules: Vec<String> = report
+		.errors
+		.iter()
+		.filter_map(|error| match &*error.kind {
+			ValidationErrorKind::Rule { rule } => Some(rule.clone()),
+			_ => None,
+		})
+		.collect();
+	rules.sort();
+	rules.dedup();
+	rules
+}
+
+fn sample(name: &str) -> String {
+	fs::read_to_string(Path::new(SAMPLES).join(name)).unwrap()
+}
+
+#[test]
+fn samples_violate_the_expected_rules() {
+	for (name, expected) in EXPECTED {
+		let mut expected: Vec<&str> = expected.to_vec();
+		expected.sort();
+		assert_eq!(violated(&sample(name)), expected, "{}", name);
+	}
+}
+
+#[test]
+fn every_sample_is_expected() {
+	for entry in fs::read_dir(SAMPLES).unwrap() {
+		let name = entry.unwrap().file_name().into_string().unwrap();
+		let rule_sample = ["CBPR_", "DEBTRule1_", "InterbankSettlementCurrencyRule_"].iter().any(|prefix| name.starts_with(prefix));
+		if rule_sample && name.ends_with(".xml") {
+			assert!(EXPECTED.iter().any(|(expected, _)| *expected == name), "{} is not in EXPECTED", name);
+		}
+	}
+}
+
+// The rules without a failing sample are broken by editing a passing one.
+#[test]
+fn edited_samples_violate_their_rule() {
+	let cases = [
+		("CBPR_DEBT_FormalRule_1.xml", "</ChrgsInf>", "</ChrgsInf><ChrgsInf><Amt Ccy=\"USD\">1</Amt><Agt><FinInstnId><BICFI>TESTCNVT</BICFI></FinInstnId></Agt></ChrgsInf>", "CBPR_DEBT_FormalRule"),
+		("CBPR_Debtor_BIC_PresenceRule_1.xml", "<AnyBIC>TESTCNVT</AnyBIC>", "", "CBPR_Debtor_BIC_PresenceRule"),
+		("CBPR_Originator_Option1Rule_01.xml", "<Nm>Nm</Nm>", "", "CBPR_Originator_Option1Rule"),
+		("CBPR_Originator_Option2Rule_01.xml", "<Nm>Nm</Nm>", "", "CBPR_Originator_Option2Rule"),
+		(
+			"CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule_1.xml",
+			"<OrgnlReqdColltnDt>",
+			"<OrgnlReqdExctnDt><Dt>2023-06-13</Dt></OrgnlReqdExctnDt><OrgnlReqdColltnDt>",
+			"CBPR_OriginalRequestedExecutionDate_OriginalRequestedCollectionDate_FormalRule",
+		),
+		("InterbankSettlementCurrencyRule_1.xml", "<IntrBkSttlmAmt Ccy=\"INR\">", "<IntrBkSttlmAmt Ccy=\"XAU\">", "CBPR_InterbankSettlementCurrencyRule"),
+	];
+	for (name, from, to, rule) in cases {
+		let xml = sample(name);
+		assert!(xml.contains(from), "{}", name);
+		assert!(violated(&xml.replacen(from, to, 1)).contains(&rule.to_string()), "{}: {}", name, rule);
+	}
+}
+
+// A document the profile does not apply to is reported instead of passing unchecked.
+#[test]
+fn documents_outside_the_profile_are_reported() {
+	let xml = sample("CBPR_DEBT_FormalRule_1.xml").replace(" xmlns=\"urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08\"", "");
+	let report = cbpr_plus::validate_xml(&xml).unwrap();
+	assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
+	assert_eq!(report.errors[0].code, 9999);
+	assert_eq!(report.errors[0].path, "/Document");
+	match &*report.errors[0].kind {
+		ValidationErrorKind::UnknownDocument { root_element } => assert_eq!(root_element, "FIToFICstmrCdtTrf"),
+		kind => panic!("{:?}", kind),
+	}
+
+	let xml = r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08"><BkToCstmrStmt/></Document>"#;
+	let report = cbpr_plus::validate_xml(xml).unwrap();
+	assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
+	assert_eq!(report.errors[0].code, 0);
+	assert_eq!(report.errors[0].message, "CBPR+ does not apply to camt.053.001.08");
+}
@@ -0,0 +1,256 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+#![cfg(feature = "derive_serde")]
+
+use iso20022_common::{ValidationErrorKind, ValidationReport};
+use open_payments_iso20022::declarative::{self, DefinitionError};
+use open_payments_iso20022::document::Document;
+use open_payments_iso20022::rule::{Rule, RuleSet, Severity};
+
+const SAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../samples");
+
+const TRANSACTION: &str = "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf";
+
+// A pacs.008.001.08 message with a single transaction, charges borne by the debtor and an amount in INR.
+fn sample() -> Document {
+	let xml = std::fs::read_to_string(format!("{}/CBPR_DEBT_FormalRule_1.xml", SAMPLES)).unwrap();
+	Document::from_xml_str(&xml).unwrap()
+}
+
+// The path and message of every violation of the rules of `json` in the sample.
+fn violations(json: &str) -> Vec<(String, String)> {
+	let rules = declarative::from_json(json).unwrap();
+	let doc = sample();
+	let report = ValidationReport::collect(|validator| declarative::check(&rules, &doc, validator));
+	report.errors.into_iter().map(|error| (error.path, error.message)).collect()
+}
+
+// The violations of the single rule `id` of `context`, `when` and `check`, given as JSON.
+fn rule_violations(context: &str, when: Option<&str>, check: &str) -> Vec<(String, String)> {
+	let when = when.map(|when| format!(", \"when\": {}", when)).unwrap_or_default();
+	violations(&format!(r#"{{ "rules": [{{ "id": "test", "context": "{}"{}, "check": {} }}] }}"#, context, when, check))
+}
+
+fn violation(path: &str, message: &str) -> (String, String) {
+	(path.to_string(), message.to_string())
+}
+
+fn definition_error(json: &str) -> DefinitionError {
+	match declarative::from_json(json) {
+		Err(e) => e,
+		Ok(rules) => panic!("{} compiled to {:?}", json, rules),
+	}
+}
+
+#[test]
+fn documented_rules_compile() {
+	let json = r#"{ "rules": [
+		{ "id": "eur-shared-charges", "context": "//CdtTrfTxInf",
+		  "when": { "path": "IntrBkSttlmAmt/@Ccy", "in": ["EUR"] },
+		  "check": { "path": "ChrgBr", "in": ["SHAR"] } },
+		{ "id": "purpose-for-india", "context": "//CdtTrfTxInf",
+		  "when": { "path": "Cdtr/PstlAdr/Ctry", "in": ["IN"] },
+		  "check": { "path": "Purp/Cd", "required": true } },
+		{ "id": "address-lines", "severity": "warning", "context": "//PstlAdr",
+		  "check": { "path": "AdrLine", "max_occurs": 3 }, "message": "at most 3 AdrLine" }
+	] }"#;
+	let rules = declarative::from_json(json).unwrap();
+	assert_eq!(rules.iter().map(|rule| rule.id()).collect::<Vec<_>>(), ["eur-shared-charges", "purpose-for-india", "address-lines"]);
+	assert_eq!(rules.iter().map(|rule| rule.severity()).collect::<Vec<_>>(), [Severity::Error, Severity::Error, Severity::Warning]);
+	assert!(violations(json).is_empty());
+}
+
+#[test]
+fn malformed_files_are_rejected() {
+	for json in [
+		"not json",
+		r#"{ "rules": [{ "id": "test" }] }"#,
+		r#"{ "rules": [{ "id": "test", "check": { "path": "ChrgBr", "requird": true } }] }"#,
+		r#"{ "rules": [{ "id": "test", "severity": "fatal", "check": { "path": "ChrgBr" } }] }"#,
+		r#"{ "rules": [{ "id": "test", "check": { "path": "ChrgBr", "max_occurs": -1 } }] }"#,
+		r#"{ "rule": [] }"#,
+	] {
+		assert!(matches!(definition_error(json), DefinitionError::Json(_)), "{}", json);
+	}
+}
+
+#[test]
+fn malformed_paths_are_rejected() {
+	for path in ["", "/", "//", "ChrgBr/", "A//", "A///B", "@", "@Ccy/Amt", "//@Ccy", "Amt@Ccy", "/@Ccy"] {
+		let json = format!(r#"{{ "rules": [{{ "id": "test", "check": {{ "path": "{}" }} }}] }}"#, path);
+		match definition_error(&json) {
+			DefinitionError::Path { rule, path: malformed } => assert_eq!((rule.as_str(), malformed.as_str()), ("test", path)),
+			e => panic!("{}: {}", path, e),
+		}
+	}
+	// A context selects elements, not attributes.
+	let json = r#"{ "rules": [{ "id": "test", "context": "//IntrBkSttlmAmt/@Ccy", "check": { "path": "ChrgBr" } }] }"#;
+	assert!(matches!(definition_error(json), DefinitionError::Path { path, .. } if path == "//IntrBkSttlmAmt/@Ccy"));
+	let json = r#"{ "rules": [{ "id": "test", "when": { "path": "" }, "check": { "path": "ChrgBr" } }] }"#;
+	assert!(matches!(definition_error(json), DefinitionError::Path { .. }));
+}
+
+#[test]
+fn malformed_patterns_are_rejected() {
+	let json = r#"{ "rules": [{ "id": "test", "check": { "path": "ChrgBr", "pattern": "[A-Z" } }] }"#;
+	match definition_error(json) {
+		DefinitionError::Pattern { rule, .. } => assert_eq!(rule, "test"),
+		e => panic!("{}", e),
+	}
+}
+
+#[test]
+fn required_elements_are_reported_at_their_context() {
+	assert_eq!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "Purp", "required": true }"#), [violation(TRANSACTION, "Purp is required")]);
+	assert!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "ChrgBr", "required": true }"#).is_empty());
+	assert_eq!(
+		rule_violations("//CdtTrfTxInf", None, r#"{ "path": "ChrgsInf", "required": false }"#),
+		[violation(&format!("{}/ChrgsInf", TRANSACTION), "ChrgsInf is not allowed")]
+	);
+	assert!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "Purp", "required": false }"#).is_empty());
+}
+
+#[test]
+fn occurrences_are_counted_in_each_context() {
+	let payment_type = format!("{}/PmtTpInf", TRANSACTION);
+	assert_eq!(rule_violations("//PmtTpInf", None, r#"{ "path": "SvcLvl", "max_occurs": 1 }"#), [violation(&payment_type, "SvcLvl occurs 2 times, more than 1")]);
+	assert_eq!(rule_violations("//PmtTpInf", None, r#"{ "path": "SvcLvl", "min_occurs": 3 }"#), [violation(&payment_type, "SvcLvl occurs 2 times, fewer than 3")]);
+	assert!(rule_violations("//PmtTpInf", None, r#"{ "path": "SvcLvl", "min_occurs": 2, "max_occurs": 2 }"#).is_empty());
+	// Every postal address is a context of its own, each with a single AdrLine.
+	assert!(rule_violations("//PstlAdr", None, r#"{ "path": "AdrLine", "max_occurs": 1 }"#).is_empty());
+	assert_eq!(rule_violations("//PstlAdr", None, r#"{ "path": "AdrLine", "min_occurs": 2 }"#).len(), 3);
+}
+
+#[test]
+fn values_are_checked_against_the_allowed_ones() {
+	assert_eq!(
+		rule_violations("//CdtTrfTxInf", None, r#"{ "path": "ChrgBr", "in": ["SHAR", "CRED"] }"#),
+		[violation(&format!("{}/ChrgBr", TRANSACTION), "\"DEBT\" is not one of SHAR, CRED")]
+	);
+	assert!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "ChrgBr", "in": ["DEBT"] }"#).is_empty());
+	// Nothing to check when the path selects nothing.
+	assert!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "Purp/Cd", "in": ["SALA"] }"#).is_empty());
+}
+
+#[test]
+fn lengths_are_counted_in_characters() {
+	let msg_id = "/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId";
+	assert_eq!(rule_violations("/Document", None, r#"{ "path": "/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId", "max_length": 30 }"#), [violation(msg_id, "/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId is longer than 30 characters")]);
+	assert_eq!(rule_violations("//GrpHdr", None, r#"{ "path": "MsgId", "min_length": 32 }"#), [violation(msg_id, "MsgId is shorter than 32 characters")]);
+	assert!(rule_violations("//GrpHdr", None, r#"{ "path": "MsgId", "min_length": 31, "max_length": 31 }"#).is_empty());
+}
+
+#[test]
+fn patterns_match_whole_values() {
+	assert!(rule_violations("//PmtId", None, r#"{ "path": "UETR", "pattern": "[0-9a-f]{8}(-[0-9a-f]{4}){3}-[0-9a-f]{12}" }"#).is_empty());
+	assert_eq!(
+		rule_violations("//Cdtr", None, r#"{ "path": "PstlAdr/AdrLine", "pattern": "[A-Z]+" }"#),
+		[violation(&format!("{}/Cdtr/PstlAdr/AdrLine", TRANSACTION), "\"Value\" does not match ^(?:[A-Z]+)$")]
+	);
+	// `Val` alone is found in `Value`, but does not match it as a whole.
+	assert_eq!(rule_violations("//Cdtr", None, r#"{ "path": "PstlAdr/AdrLine", "pattern": "Val" }"#).len(), 1);
+}
+
+#[test]
+fn attributes_are_checked_on_their_element() {
+	assert_eq!(
+		rule_violations("//CdtTrfTxInf", None, r#"{ "path": "IntrBkSttlmAmt/@Ccy", "in": ["EUR", "USD"] }"#),
+		[violation(&format!("{}/IntrBkSttlmAmt/@Ccy", TRANSACTION), "\"INR\" is not one of EUR, USD")]
+	);
+	assert_eq!(rule_violations("//CdtTrfTxInf", None, r#"{ "path": "IntrBkSttlmAmt/@Dt", "required": true }"#), [violation(TRANSACTION, "IntrBkSttlmAmt/@Dt is required")]);
+	// From the context itself.
+	assert!(rule_violations("//IntrBkSttlmAmt", None, r#"{ "path": "@Ccy", "in": ["INR"] }"#).is_empty());
+}
+
+#[test]
+fn descendant_steps_match_at_any_depth() {
+	// The agents of the charges, instructing and instructed agents have no name, the debtor and creditor agents have one.
+	let fin_instn_ids = rule_violations("//FinInstnId", None, r#"{ "path": "Nm", "required": true }"#);
+	assert_eq!(
+		fin_instn_ids.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(),
+		[
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/ChrgsInf/Agt/FinInstnId",
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/InstgAgt/FinInstnId",
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/InstdAgt/FinInstnId",
+		]
+	);
+	assert_eq!(rule_violations("/Document", None, r#"{ "path": "//ClrSysId/Cd", "in": ["USABA"] }"#).len(), 4);
+	assert_eq!(rule_violations("//Dbtr", None, r#"{ "path": "Id//SchmeNm/Cd", "in": ["TXID"] }"#).len(), 2);
+}
+
+#[test]
+fn wildcards_match_any_element_and_items_carry_their_position() {
+	assert_eq!(
+		rule_violations("/Document/*", None, r#"{ "path": "*/NbOfTxs", "in": ["2"] }"#),
+		[violation("/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs", "\"1\" is not one of 2")]
+	);
+	assert_eq!(
+		rule_violations("//Dbtr/Id/OrgId", None, r#"{ "path": "*/Issr", "max_length": 5 }"#),
+		[violation(&format!("{}/Dbtr/Id/OrgId/Othr[2]/Issr", TRANSACTION), "*/Issr is longer than 5 characters")]
+	);
+	assert_eq!(
+		rule_violations("//PmtTpInf/*", None, r#"{ "path": "Cd", "in": ["SDVA"] }"#).iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(),
+		[
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/PmtTpInf/SvcLvl[1]/Cd",
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/PmtTpInf/SvcLvl[2]/Cd",
+			"/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/PmtTpInf/CtgyPurp/Cd",
+		]
+	);
+}
+
+#[test]
+fn when_restricts_the_contexts_checked() {
+	let purpose = r#"{ "path": "Purp", "required": true }"#;
+	assert_eq!(rule_violations("//CdtTrfTxInf", Some(r#"{ "path": "IntrBkSttlmAmt/@Ccy", "in": ["INR"] }"#), purpose).len(), 1);
+	assert!(rule_violations("//CdtTrfTxInf", Some(r#"{ "path": "IntrBkSttlmAmt/@Ccy", "in": ["EUR"] }"#), purpose).is_empty());
+	// A `when` constraint needs its path to be present, unless it requires its absence.
+	assert!(rule_violations("//CdtTrfTxInf", Some(r#"{ "path": "Purp/Cd", "max_length": 4 }"#), purpose).is_empty());
+	assert_eq!(rule_violations("//CdtTrfTxInf", Some(r#"{ "path": "InstrForCdtrAgt", "required": false }"#), purpose).len(), 1);
+	assert!(rule_violations("//CdtTrfTxInf", Some(r#"{ "path": "ChrgsInf", "required": false }"#), purpose).is_empty());
+}
+
+#[test]
+fn rules_without_context_apply_to_the_document() {
+	let json = r#"{ "rules": [{ "id": "test", "check": { "path": "FIToFICstmrCdtTrf/GrpHdr/NbOfTxs", "in": ["2"] } }] }"#;
+	assert_eq!(violations(json), [violation("/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs", "\"1\" is not one of 2")]);
+}
+
+#[test]
+fn findings_carry_the_id_severity_and_message_of_their_rule() {
+	let json = r#"{ "rules": [
+		{ "id": "shared-charges", "context": "//CdtTrfTxInf", "check": { "path": "ChrgBr", "in": ["SHAR"] } },
+		{ "id": "purpose", "severity": "warning", "message": "a purpose is expected", "context": "//CdtTrfTxInf", "check": { "path": "Purp", "required": true } }
+	] }"#;
+	let findings = RuleSet::new().with_all(declarative::from_json(json).unwrap()).apply(&sample());
+	assert_eq!(findings.findings.len(), 2, "{}", findings);
+	let (charges, purpose) = (&findings.findings[0], &findings.findings[1]);
+	assert_eq!((charges.rule.as_str(), charges.severity), ("shared-charges", Severity::Error));
+	assert_eq!(charges.error.message, "\"DEBT\" is not one of SHAR");
+	assert_eq!(charges.error.code, 1015);
+	assert!(matches!(&*charges.error.kind, ValidationErrorKind::Rule { rule } if rule == "shared-charges"));
+	assert_eq!((purpose.rule.as_str(), purpose.severity), ("purpose", Severity::Warning));
+	assert_eq!((purpose.error.path.as_str(), purpose.error.message.as_str()), (TRANSACTION, "a purpose is expected"));
+	assert!(!findings.is_valid());
+}
@@ -0,0 +1,133 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+#![cfg(all(feature = "pacs", feature = "derive_serde"))]
+
+use open_payments_iso20022::document::Document;
+use open_payments_iso20022::error::Error;
+
+const SAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../samples");
+
+const PACS_002_001_12: &str = "urn:iso:std:iso:20022:tech:xsd:pacs.002.001.12";
+const PACS_002_001_14: &str = "urn:iso:std:iso:20022:tech:xsd:pacs.002.001.14";
+
+// A pacs.002 status report in the namespace `xmlns`, or without one when `xmlns` is empty.
+fn status_report(xmlns: &str) -> String {
+	let xmlns = if xmlns.is_empty() { String::new() } else { format!(" xmlns=\"{}\"", xmlns) };
+	format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document{}>
+	<FIToFIPmtStsRpt>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+		</GrpHdr>
+	</FIToFIPmtStsRpt>
+</Document>
+"#, xmlns)
+}
+
+#[test]
+fn namespace_selects_the_message_version() {
+	match Document::from_xml_str(&status_report(PACS_002_001_12)).unwrap() {
+		Document::FIToFIPaymentStatusReportV12(ref report) => assert_eq!(report.grp_hdr.msg_id, "MSG-1"),
+		_ => panic!("expected pacs.002.001.12"),
+	}
+	match Document::from_xml_str(&status_report(PACS_002_001_14)).unwrap() {
+		Document::FIToFIPaymentStatusReportV14(ref report) => assert_eq!(report.grp_hdr.msg_id, "MSG-1"),
+		_ => panic!("expected pacs.002.001.14"),
+	}
+}
+
+#[test]
+fn namespace_bound_to_a_prefix_selects_the_message_version() {
+	let xml = status_report("").replace("<Document>", &format!("<ns:Document xmlns:ns=\"{}\">", PACS_002_001_14)).replace("</Document>", "</ns:Document>");
+	let document = Document::from_xml_str(&xml).unwrap();
+	assert!(matches!(document, Document::FIToFIPaymentStatusReportV14(_)));
+	assert_eq!(document.namespace(), Some(PACS_002_001_14));
+}
+
+#[test]
+fn root_element_shared_by_several_versions_needs_a_namespace() {
+	match Document::from_xml_str(&status_report("")) {
+		Err(Error::Deserialize { source, .. }) => assert!(source.to_string().contains("FIToFIPmtStsRpt exists in several message versions"), "{}", source),
+		Err(e) => panic!("unexpected error {}", e),
+		Ok(_) => panic!("a FIToFIPmtStsRpt without namespace was read"),
+	}
+}
+
+#[test]
+fn unknown_message_keeps_its_namespace_root_element_and_xml() {
+	// pacs.008.001.08 is not compiled in, its samples are kept as unknown messages.
+	let xml = std::fs::read_to_string(format!("{}/CBPR_DEBT_FormalRule_1.xml", SAMPLES)).unwrap();
+	let document = Document::from_xml_str(&xml).unwrap();
+	let Document::Unknown(ref unknown) = document else {
+		panic!("pacs.008.001.08 was read as a typed message");
+	};
+	assert_eq!(unknown.namespace.as_deref(), Some("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08"));
+	assert_eq!(unknown.root_element, "FIToFICstmrCdtTrf");
+	assert!(unknown.raw_xml.starts_with("<Document"), "{}", &unknown.raw_xml[..40]);
+	assert!(unknown.raw_xml.ends_with("</Document>"));
+	assert!(xml.contains(&unknown.raw_xml));
+	assert_eq!(document.namespace(), unknown.namespace.as_deref());
+	assert_eq!(document.root_element(), Some("FIToFICstmrCdtTrf"));
+
+	// Written back unchanged after the XML declaration.
+	let written = document.to_xml_string().unwrap();
+	assert!(written.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Document"));
+	assert!(written.ends_with(&unknown.raw_xml));
+}
+
+#[test]
+fn unknown_message_serializes_as_its_fields() {
+	let xml = std::fs::read_to_string(format!("{}/CBPR_DEBT_FormalRule_1.xml", SAMPLES)).unwrap();
+	let document = Document::from_xml_str(&xml).unwrap();
+	let value = serde_json::to_value(&document).unwrap();
+	assert_eq!(value["namespace"], "urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08");
+	assert_eq!(value["root_element"], "FIToFICstmrCdtTrf");
+	assert!(value["raw_xml"].as_str().unwrap().starts_with("<Document"));
+}
+
+#[test]
+fn to_xml_string_writes_the_declaration_and_namespace() {
+	for xmlns in [PACS_002_001_12, PACS_002_001_14] {
+		let document = Document::from_xml_str(&status_report(xmlns)).unwrap();
+		let xml = document.to_xml_string().unwrap();
+		assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"), "{}", xml);
+		assert!(xml.contains(&format!("<Document xmlns=\"{}\"><FIToFIPmtStsRpt>", xmlns)), "{}", xml);
+		assert!(xml.contains("<MsgId>MSG-1</MsgId>"), "{}", xml);
+
+		// The written message reads back as the same version.
+		let read = Document::from_xml_str(&xml).unwrap();
+		assert_eq!(read.namespace(), Some(xmlns));
+	}
+}
+
+#[cfg(feature = "derive_default")]
+#[test]
+fn default_document_cannot_be_written() {
+	let document = Document::default();
+	assert!(matches!(document, Document::Unknown(_)));
+	assert!(matches!(document.to_xml_string(), Err(Error::UnknownDocument)));
+}
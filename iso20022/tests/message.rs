@@ -0,0 +1,171 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+
+#![cfg(all(feature = "head", feature = "pacs", feature = "derive_serde"))]
+
+use open_payments_iso20022::document::Document;
+use open_payments_iso20022::message::{ApplicationHeader, BusinessFile, BusinessMessage};
+
+const SAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../samples");
+
+const HEAD_001_001_02: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.02";
+const HEAD_001_001_04: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.04";
+
+// An `<AppHdr>` in the namespace `xmlns`, or without one when `xmlns` is empty.
+fn app_hdr(xmlns: &str, biz_msg_idr: &str, msg_def_idr: &str) -> String {
+	let xmlns = if xmlns.is_empty() { String::new() } else { format!(" xmlns=\"{}\"", xmlns) };
+	format!(r#"<AppHdr{}>
+		<Fr><FIId><FinInstnId><BICFI>AAAAGB2L</BICFI></FinInstnId></FIId></Fr>
+		<To><FIId><FinInstnId><BICFI>BBBBDEFF</BICFI></FinInstnId></FIId></To>
+		<BizMsgIdr>{}</BizMsgIdr>
+		<MsgDefIdr>{}</MsgDefIdr>
+		<CreDt>2024-05-01T10:00:00Z</CreDt>
+	</AppHdr>"#, xmlns, biz_msg_idr, msg_def_idr)
+}
+
+// A pacs.002.001.14 `<Document>` with the message identification `msg_id`.
+fn status_report(msg_id: &str) -> String {
+	format!(r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.002.001.14">
+		<FIToFIPmtStsRpt>
+			<GrpHdr>
+				<MsgId>{}</MsgId>
+				<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+			</GrpHdr>
+		</FIToFIPmtStsRpt>
+	</Document>"#, msg_id)
+}
+
+// The `<Document>` element of a pacs.008.001.08 sample, a version that is not compiled in.
+fn unknown_document() -> String {
+	let xml = std::fs::read_to_string(format!("{}/CBPR_DEBT_FormalRule_1.xml", SAMPLES)).unwrap();
+	let start = xml.find("<Document").unwrap();
+	let end = xml.rfind("</Document>").unwrap() + "</Document>".len();
+	xml[start..end].to_string()
+}
+
+fn envelope(app_hdr: &str, document: &str) -> String {
+	format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Envelope>\n\t{}\n\t{}\n</Envelope>\n", app_hdr, document)
+}
+
+fn msg_id(document: &Document) -> &str {
+	match document {
+		Document::FIToFIPaymentStatusReportV14(ref report) => &report.grp_hdr.msg_id,
+		_ => panic!("expected pacs.002.001.14"),
+	}
+}
+
+#[test]
+fn business_message_round_trips() {
+	let xml = envelope(&app_hdr(HEAD_001_001_04, "BIZ-1", "pacs.002.001.14"), &status_report("MSG-1"));
+	let message = BusinessMessage::from_xml_str(&xml).unwrap();
+	assert!(matches!(message.app_hdr, ApplicationHeader::BusinessApplicationHeaderV04(_)));
+	assert_eq!(message.app_hdr.biz_msg_idr(), "BIZ-1");
+	assert_eq!(message.app_hdr.msg_def_idr(), "pacs.002.001.14");
+	assert_eq!(msg_id(&message.document), "MSG-1");
+
+	let written = message.to_xml_string().unwrap();
+	assert!(written.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Envelope>"), "{}", written);
+	assert!(written.contains(&format!("<AppHdr xmlns=\"{}\">", HEAD_001_001_04)), "{}", written);
+	let read = BusinessMessage::from_xml_str(&written).unwrap();
+	assert_eq!(read.app_hdr.namespace(), HEAD_001_001_04);
+	assert_eq!(read.app_hdr.biz_msg_idr(), "BIZ-1");
+	assert_eq!(read.document.namespace(), message.document.namespace());
+	assert_eq!(msg_id(&read.document), "MSG-1");
+}
+
+#[test]
+fn header_version_follows_its_namespace() {
+	let xml = envelope(&app_hdr(HEAD_001_001_02, "BIZ-1", "pacs.002.001.14"), &status_report("MSG-1"));
+	let message = BusinessMessage::from_xml_str(&xml).unwrap();
+	assert!(matches!(message.app_hdr, ApplicationHeader::BusinessApplicationHeaderV02(_)));
+	assert_eq!(message.app_hdr.namespace(), HEAD_001_001_02);
+}
+
+#[test]
+fn header_without_namespace_is_read_as_head_001_001_04() {
+	let xml = envelope(&app_hdr("", "BIZ-1", "pacs.002.001.14"), &status_report("MSG-1"));
+	let message = BusinessMessage::from_xml_str(&xml).unwrap();
+	assert!(matches!(message.app_hdr, ApplicationHeader::BusinessApplicationHeaderV04(_)));
+	assert_eq!(message.app_hdr.biz_msg_idr(), "BIZ-1");
+}
+
+#[test]
+fn header_in_another_version_is_rejected() {
+	let xml = envelope(&app_hdr("urn:iso:std:iso:20022:tech:xsd:head.001.001.03", "BIZ-1", "pacs.002.001.14"), &status_report("MSG-1"));
+	let error = BusinessMessage::from_xml_str(&xml).err().expect("a head.001.001.03 header was read");
+	assert!(error.to_string().contains("unsupported AppHdr namespace urn:iso:std:iso:20022:tech:xsd:head.001.001.03"), "{}", error);
+}
+
+#[test]
+fn unknown_document_is_written_back_after_the_header() {
+	let document = unknown_document();
+	let xml = envelope(&app_hdr(HEAD_001_001_04, "BIZ-1", "pacs.008.001.08"), &document);
+	let message = BusinessMessage::from_xml_str(&xml).unwrap();
+	match message.document {
+		Document::Unknown(ref unknown) => assert_eq!(unknown.raw_xml, document),
+		_ => panic!("pacs.008.001.08 was read as a typed message"),
+	}
+	assert!(message.to_xml_string().unwrap().ends_with(&format!("</AppHdr>{}</Envelope>", document)));
+}
+
+#[test]
+fn business_file_carries_several_payloads() {
+	let unknown = unknown_document();
+	let xml = format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<Xchg xmlns="urn:iso:std:iso:20022:tech:xsd:head.002.001.01">
+	<PyldDesc>
+		<PyldData>
+			<PyldIdr>PYLD-1</PyldIdr>
+			<CreDtAndTm>2024-05-01T10:00:00Z</CreDtAndTm>
+		</PyldData>
+		<PyldTp>ISO20022</PyldTp>
+	</PyldDesc>
+	<Pyld>{}{}</Pyld>
+	<Pyld>{}{}</Pyld>
+	<Pyld>{}{}</Pyld>
+</Xchg>
+"#,
+		app_hdr(HEAD_001_001_04, "BIZ-1", "pacs.002.001.14"), status_report("MSG-1"),
+		app_hdr(HEAD_001_001_04, "BIZ-2", "pacs.008.001.08"), unknown,
+		app_hdr(HEAD_001_001_02, "BIZ-3", "pacs.002.001.14"), status_report("MSG-3"),
+	);
+	let file = BusinessFile::from_xml_str(&xml).unwrap();
+	assert_eq!(file.pyld_desc.pyld_data.pyld_idr, "PYLD-1");
+	let ids: Vec<&str> = file.pyld.iter().map(|pyld| pyld.app_hdr.biz_msg_idr()).collect();
+	assert_eq!(ids, ["BIZ-1", "BIZ-2", "BIZ-3"]);
+	assert_eq!(msg_id(&file.pyld[0].document), "MSG-1");
+	match file.pyld[1].document {
+		Document::Unknown(ref value) => assert_eq!(value.raw_xml, unknown),
+		_ => panic!("pacs.008.001.08 was read as a typed message"),
+	}
+	assert!(matches!(file.pyld[2].app_hdr, ApplicationHeader::BusinessApplicationHeaderV02(_)));
+	assert_eq!(msg_id(&file.pyld[2].document), "MSG-3");
+
+	let read = BusinessFile::from_xml_str(&file.to_xml_string().unwrap()).unwrap();
+	assert_eq!(read.pyld.len(), 3);
+	assert_eq!(read.pyld_desc.pyld_data.pyld_idr, "PYLD-1");
+	assert_eq!(msg_id(&read.pyld[2].document), "MSG-3");
+	assert!(matches!(read.pyld[1].document, Document::Unknown(_)));
+}
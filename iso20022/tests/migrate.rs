@@ -0,0 +1,302 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+#![cfg(feature = "derive_serde")]
+
+use serde::{Deserialize, Serialize};
+use iso20022_common::{Decimal, ValidationError, ValidationErrorKind, ValidationReport};
+use open_payments_iso20022::error::Error;
+use open_payments_iso20022::migrate::{self, Loss, Migrated, Target};
+
+// Two versions of a status report: the second drops `AddtlInf`, reads amounts as decimals and
+// allows a `StsId` of at most 5 characters.
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GroupHeader {
+	#[serde(rename = "MsgId")]
+	msg_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Amount {
+	#[serde(rename = "@Ccy")]
+	ccy: String,
+	#[serde(rename = "$value")]
+	value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Transaction {
+	#[serde(rename = "StsId")]
+	sts_id: String,
+	#[serde(rename = "Amt")]
+	amt: Amount,
+	#[serde(rename = "AddtlInf", skip_serializing_if = "Option::is_none")]
+	addtl_inf: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StatusReportV1 {
+	#[serde(rename = "GrpHdr")]
+	grp_hdr: GroupHeader,
+	#[serde(rename = "TxInfAndSts")]
+	tx_inf_and_sts: Vec<Transaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AmountV2 {
+	#[serde(rename = "@Ccy")]
+	ccy: String,
+	#[serde(rename = "$value")]
+	value: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TransactionV2 {
+	#[serde(rename = "StsId")]
+	sts_id: String,
+	#[serde(rename = "Amt")]
+	amt: AmountV2,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StatusReportV2 {
+	#[serde(rename = "GrpHdr")]
+	grp_hdr: GroupHeader,
+	#[serde(rename = "TxInfAndSts")]
+	tx_inf_and_sts: Vec<TransactionV2>,
+}
+
+// A version requiring an `OrgnlMsgId` the others do not have.
+#[derive(Debug, Serialize, Deserialize)]
+struct StatusReportV3 {
+	#[serde(rename = "GrpHdr")]
+	grp_hdr: GroupHeader,
+	#[serde(rename = "OrgnlMsgId")]
+	orgnl_msg_id: String,
+}
+
+impl Target for StatusReportV1 {
+	fn validate_all(&self) -> ValidationReport {
+		ValidationReport::default()
+	}
+}
+
+impl Target for StatusReportV2 {
+	fn validate_all(&self) -> ValidationReport {
+		ValidationReport::collect(|validator| {
+			for (i, tx) in self.tx_inf_and_sts.iter().enumerate() {
+				validator.item("TxInfAndSts", "tx_inf_and_sts", i, |validator| {
+					let length = tx.sts_id.chars().count();
+					if length > 5 {
+						validator.report_at("StsId", "sts_id", ValidationError::max_length("sts_id", 5, length))?;
+					}
+					Ok(())
+				})?;
+			}
+			Ok(())
+		})
+	}
+}
+
+impl Target for StatusReportV3 {
+	fn validate_all(&self) -> ValidationReport {
+		ValidationReport::default()
+	}
+}
+
+fn transaction(sts_id: &str, amount: &str, addtl_inf: Option<&str>) -> Transaction {
+	Transaction {
+		sts_id: sts_id.to_string(),
+		amt: Amount { ccy: "EUR".to_string(), value: amount.to_string() },
+		addtl_inf: addtl_inf.map(str::to_string),
+	}
+}
+
+fn report(transactions: Vec<Transaction>) -> StatusReportV1 {
+	StatusReportV1 { grp_hdr: GroupHeader { msg_id: "MSG-1".to_string() }, tx_inf_and_sts: transactions }
+}
+
+#[test]
+fn values_carried_over_unchanged_are_lossless() {
+	let source = report(vec![transaction("STS-1", "1.50", None), transaction("STS-2", "20", None)]);
+	let migrated: Migrated<StatusReportV2> = migrate::migrate(&source).unwrap();
+	assert!(migrated.is_lossless(), "{:?}", migrated.losses);
+	let message = migrated.into_lossless().unwrap();
+	assert_eq!(message.grp_hdr.msg_id, "MSG-1");
+	assert_eq!(message.tx_inf_and_sts.iter().map(|tx| tx.amt.value.to_string()).collect::<Vec<_>>(), ["1.50", "20"]);
+}
+
+#[test]
+fn elements_the_target_lacks_are_dropped() {
+	let source = report(vec![transaction("STS-1", "1.50", None), transaction("STS-2", "20", Some("late"))]);
+	let migrated: Migrated<StatusReportV2> = migrate::migrate(&source).unwrap();
+	assert_eq!(migrated.losses, [Loss::Dropped { path: "/TxInfAndSts[2]/AddtlInf".to_string(), value: "late".to_string() }]);
+	assert_eq!(migrated.losses[0].path(), "/TxInfAndSts[2]/AddtlInf");
+	assert!(!migrated.is_lossless());
+	assert_eq!(migrated.into_lossless().unwrap_err().len(), 1);
+}
+
+#[test]
+fn values_the_target_writes_differently_are_changed() {
+	// A single transaction is an item of a repeated field all the same.
+	let source = report(vec![transaction("STS-1", "+1.50", None)]);
+	let migrated: Migrated<StatusReportV2> = migrate::migrate(&source).unwrap();
+	assert_eq!(
+		migrated.losses,
+		[Loss::Changed { path: "/TxInfAndSts[1]/Amt".to_string(), from: "+1.50".to_string(), to: "1.50".to_string() }]
+	);
+}
+
+#[test]
+fn values_breaking_a_facet_of_the_target_are_invalid() {
+	let source = report(vec![transaction("STS-1", "1", None), transaction("STS-LONG", "2", None)]);
+	let migrated: Migrated<StatusReportV2> = migrate::migrate(&source).unwrap();
+	assert_eq!(
+		migrated.losses,
+		[Loss::Invalid { path: "/TxInfAndSts[2]/StsId".to_string(), kind: ValidationErrorKind::MaxLength { max: 5, actual: 8 } }]
+	);
+	// The value is carried over nonetheless.
+	assert_eq!(migrated.message.tx_inf_and_sts[1].sts_id, "STS-LONG");
+}
+
+#[test]
+fn losses_are_reported_together() {
+	let source = report(vec![transaction("STS-LONG", "+1", Some("late"))]);
+	let migrated: Migrated<StatusReportV2> = migrate::migrate(&source).unwrap();
+	let paths: Vec<&str> = migrated.losses.iter().map(Loss::path).collect();
+	assert_eq!(paths, ["/TxInfAndSts[1]/Amt", "/TxInfAndSts[1]/AddtlInf", "/TxInfAndSts[1]/StsId"]);
+}
+
+#[test]
+fn downgrades_keep_what_the_older_version_holds() {
+	let source = StatusReportV2 {
+		grp_hdr: GroupHeader { msg_id: "MSG-1".to_string() },
+		tx_inf_and_sts: vec![TransactionV2 { sts_id: "STS-1".to_string(), amt: AmountV2 { ccy: "USD".to_string(), value: "100.10".parse().unwrap() } }],
+	};
+	let migrated: Migrated<StatusReportV1> = migrate::migrate(&source).unwrap();
+	assert!(migrated.is_lossless(), "{:?}", migrated.losses);
+	assert_eq!(migrated.message.tx_inf_and_sts[0].amt.value, "100.10");
+	assert_eq!(migrated.message.tx_inf_and_sts[0].amt.ccy, "USD");
+}
+
+#[test]
+fn elements_mandatory_in_the_target_must_be_present() {
+	let source = report(vec![transaction("STS-1", "1", None)]);
+	assert!(matches!(migrate::migrate::<_, StatusReportV3>(&source), Err(Error::Deserialize { .. })));
+}
+
+#[cfg(feature = "pacs")]
+mod pacs_002 {
+	use open_payments_iso20022::document::Document;
+	use open_payments_iso20022::migrate::{self, Loss};
+	use open_payments_iso20022_pacs::pacs_002_001_12::FIToFIPaymentStatusReportV12;
+	use open_payments_iso20022_pacs::pacs_002_001_14::FIToFIPaymentStatusReportV14;
+
+	// A status report rejecting a transaction, in the namespace of `version`, whose reason comes from `originator`.
+	fn status_report(version: &str, originator: &str) -> Document {
+		let xml = format!(r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.002.001.{}">
+	<FIToFIPmtStsRpt>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+		</GrpHdr>
+		<TxInfAndSts>
+			<OrgnlEndToEndId>E2E-1</OrgnlEndToEndId>
+			<TxSts>RJCT</TxSts>
+			<StsRsnInf>
+				<Orgtr>{}</Orgtr>
+				<Rsn><Cd>AC04</Cd></Rsn>
+			</StsRsnInf>
+		</TxInfAndSts>
+	</FIToFIPmtStsRpt>
+</Document>"#, version, originator);
+		Document::from_xml_str(&xml).unwrap()
+	}
+
+	fn v12(document: Document) -> FIToFIPaymentStatusReportV12 {
+		match document {
+			Document::FIToFIPaymentStatusReportV12(report) => *report,
+			_ => panic!("expected pacs.002.001.12"),
+		}
+	}
+
+	fn v14(document: Document) -> FIToFIPaymentStatusReportV14 {
+		match document {
+			Document::FIToFIPaymentStatusReportV14(report) => *report,
+			_ => panic!("expected pacs.002.001.14"),
+		}
+	}
+
+	#[test]
+	fn upgrade_is_lossless() {
+		let source = v12(status_report("12", "<Nm>Bank</Nm><PstlAdr><StrtNm>Main Street</StrtNm><TwnNm>Berlin</TwnNm><Ctry>DE</Ctry></PstlAdr>"));
+		let migrated = migrate::pacs_002_001_12_to_14(&source).unwrap();
+		assert!(migrated.is_lossless(), "{:?}", migrated.losses);
+		assert_eq!(migrated.message.grp_hdr.msg_id, "MSG-1");
+	}
+
+	#[test]
+	fn downgrade_drops_what_the_older_version_lacks() {
+		let source = v14(status_report("14", "<Nm>Bank</Nm><PstlAdr><StrtNm>Main Street</StrtNm><UnitNb>4B</UnitNb><TwnNm>Berlin</TwnNm><Ctry>DE</Ctry></PstlAdr>"));
+		let migrated = migrate::pacs_002_001_14_to_12(&source).unwrap();
+		assert_eq!(
+			migrated.losses,
+			[Loss::Dropped { path: "/TxInfAndSts[1]/StsRsnInf[1]/Orgtr/PstlAdr/UnitNb".to_string(), value: "4B".to_string() }]
+		);
+	}
+}
+
+#[cfg(feature = "head")]
+mod head_001 {
+	use open_payments_iso20022::migrate::{self, Loss};
+	use open_payments_iso20022_head::header::{BusinessApplicationHeaderV02, BusinessApplicationHeaderV04};
+
+	// A header from an agent whose postal address holds `address`.
+	fn app_hdr(address: &str) -> String {
+		format!(r#"<AppHdr>
+	<Fr><FIId><FinInstnId><BICFI>AAAAGB2L</BICFI><PstlAdr>{}</PstlAdr></FinInstnId></FIId></Fr>
+	<To><FIId><FinInstnId><BICFI>BBBBDEFF</BICFI></FinInstnId></FIId></To>
+	<BizMsgIdr>BIZ-1</BizMsgIdr>
+	<MsgDefIdr>pacs.002.001.12</MsgDefIdr>
+	<CreDt>2024-05-01T10:00:00Z</CreDt>
+</AppHdr>"#, address)
+	}
+
+	#[test]
+	fn upgrade_is_lossless() {
+		let source: BusinessApplicationHeaderV02 = quick_xml::de::from_str(&app_hdr("<TwnNm>London</TwnNm><Ctry>GB</Ctry>")).unwrap();
+		let migrated = migrate::head_001_001_02_to_04(&source).unwrap();
+		assert!(migrated.is_lossless(), "{:?}", migrated.losses);
+		assert_eq!(migrated.message.biz_msg_idr, "BIZ-1");
+	}
+
+	#[test]
+	fn downgrade_drops_what_the_older_version_lacks() {
+		let source: BusinessApplicationHeaderV04 = quick_xml::de::from_str(&app_hdr("<UnitNb>4B</UnitNb><TwnNm>London</TwnNm><Ctry>GB</Ctry>")).unwrap();
+		let migrated = migrate::head_001_001_04_to_02(&source).unwrap();
+		assert_eq!(migrated.losses, [Loss::Dropped { path: "/Fr/FIId/FinInstnId/PstlAdr/UnitNb".to_string(), value: "4B".to_string() }]);
+		assert_eq!(migrated.message.biz_msg_idr, "BIZ-1");
+	}
+}
@@ -0,0 +1,240 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+#![cfg(all(feature = "pacs", feature = "derive_serde"))]
+
+use iso20022_common::{Decimal, ValidationError, ValidationErrorKind, Validator};
+use open_payments_iso20022::document::Document;
+use open_payments_iso20022::rule::{from_fn, CbprPlus, Findings, MessageRules, RuleSet, Schema, Severity};
+
+// A pacs.008.001.12 message passing the schema, the message rules and CBPR+.
+const CREDIT_TRANSFER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12">
+	<FIToFICstmrCdtTrf>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+			<NbOfTxs>1</NbOfTxs>
+			<IntrBkSttlmDt>2024-05-02</IntrBkSttlmDt>
+			<SttlmInf>
+				<SttlmMtd>INDA</SttlmMtd>
+			</SttlmInf>
+		</GrpHdr>
+		<CdtTrfTxInf>
+			<PmtId>
+				<EndToEndId>E2E-1</EndToEndId>
+			</PmtId>
+			<IntrBkSttlmAmt Ccy="EUR">100.00</IntrBkSttlmAmt>
+			<ChrgBr>SHAR</ChrgBr>
+			<Dbtr>
+				<Nm>Debtor</Nm>
+			</Dbtr>
+			<DbtrAgt>
+				<FinInstnId>
+					<BICFI>AAAAGB2L</BICFI>
+				</FinInstnId>
+			</DbtrAgt>
+			<CdtrAgt>
+				<FinInstnId>
+					<BICFI>BBBBDEFF</BICFI>
+				</FinInstnId>
+			</CdtrAgt>
+			<Cdtr>
+				<Nm>Creditor</Nm>
+			</Cdtr>
+		</CdtTrfTxInf>
+	</FIToFICstmrCdtTrf>
+</Document>
+"#;
+
+const NB_OF_TXS: &str = "/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs";
+
+fn credit_transfer(from: &str, to: &str) -> Document {
+	assert!(CREDIT_TRANSFER.contains(from), "{}", from);
+	Document::from_xml_str(&CREDIT_TRANSFER.replacen(from, to, 1)).unwrap()
+}
+
+// A message whose `MsgId` breaks its schema, whose `NbOfTxs` breaks its message rules and whose
+// creditor address breaks CBPR+. Typed text would reject the `MsgId` when reading the message.
+#[cfg(not(feature = "typed_text"))]
+fn violating_every_rule() -> Document {
+	let xml = CREDIT_TRANSFER
+		.replacen("<MsgId>MSG-1</MsgId>", &format!("<MsgId>{}</MsgId>", "M".repeat(36)), 1)
+		.replacen("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>2</NbOfTxs>", 1)
+		.replacen("<Nm>Creditor</Nm>", "<Nm>Creditor</Nm><PstlAdr><StrtNm>Main Street</StrtNm><TwnNm>Berlin</TwnNm></PstlAdr>", 1);
+	Document::from_xml_str(&xml).unwrap()
+}
+
+fn rules(findings: &Findings) -> Vec<(&str, Severity, &str)> {
+	findings.findings.iter().map(|finding| (finding.rule.as_str(), finding.severity, finding.error.path.as_str())).collect()
+}
+
+fn rule_name(error: &ValidationError) -> &str {
+	match &*error.kind {
+		ValidationErrorKind::Rule { rule } => rule,
+		kind => panic!("{:?}", kind),
+	}
+}
+
+#[test]
+fn valid_message_has_no_findings() {
+	let doc = Document::from_xml_str(CREDIT_TRANSFER).unwrap();
+	let findings = RuleSet::standard().with(CbprPlus).apply(&doc);
+	assert!(findings.findings.is_empty(), "{}", findings);
+	assert!(findings.is_valid());
+}
+
+#[test]
+fn ids_follow_the_order_of_the_rules() {
+	let mut rules = RuleSet::new()
+		.with(MessageRules)
+		.with_severity(Schema, Severity::Warning)
+		.with_all([from_fn("first", Severity::Error, |_, _| Ok(())), from_fn("second", Severity::Error, |_, _| Ok(()))]);
+	rules.add(CbprPlus);
+	assert_eq!(rules.ids().collect::<Vec<_>>(), ["message-rules", "schema", "first", "second", "cbpr-plus"]);
+	assert_eq!(RuleSet::standard().ids().collect::<Vec<_>>(), ["schema", "message-rules"]);
+	assert_eq!(RuleSet::new().ids().count(), 0);
+}
+
+#[test]
+fn closure_findings_carry_the_id_severity_and_path_of_their_rule() {
+	let doc = Document::from_xml_str(CREDIT_TRANSFER).unwrap();
+	let limit = from_fn("limit", Severity::Warning, |doc, validator| {
+		let Document::FIToFICustomerCreditTransferV12(ref message) = *doc else {
+			return Ok(());
+		};
+		for (i, tx) in message.cdt_trf_tx_inf.iter().enumerate() {
+			if tx.intr_bk_sttlm_amt.value > "50".parse::<Decimal>().unwrap() {
+				validator.item("CdtTrfTxInf", "cdt_trf_tx_inf", i, |validator| {
+					validator.report_at("IntrBkSttlmAmt", "intr_bk_sttlm_amt", ValidationError::rule("Limit", "above 50".to_string()))
+				})?;
+			}
+		}
+		Ok(())
+	});
+	let findings = RuleSet::new().with(limit).apply(&doc);
+	assert_eq!(rules(&findings), [("limit", Severity::Warning, "/CdtTrfTxInf[1]/IntrBkSttlmAmt")]);
+	assert_eq!(findings.findings[0].error.field_path, "cdt_trf_tx_inf[0].intr_bk_sttlm_amt");
+	assert_eq!(rule_name(&findings.findings[0].error), "Limit");
+	assert!(findings.is_valid());
+	assert_eq!(findings.warnings().count(), 1);
+	assert_eq!(findings.to_string(), "warning [limit] ".to_string() + &findings.findings[0].error.to_string() + "\n");
+}
+
+#[test]
+fn findings_follow_the_order_of_the_rules() {
+	let doc = Document::from_xml_str(CREDIT_TRANSFER).unwrap();
+	let report = |id: &'static str| from_fn(id, Severity::Error, move |_, validator| validator.report(ValidationError::rule(id, id.to_string())));
+	let findings = RuleSet::new().with(report("b")).with(report("a")).with(report("c")).apply(&doc);
+	assert_eq!(findings.findings.iter().map(|finding| finding.rule.as_str()).collect::<Vec<_>>(), ["b", "a", "c"]);
+	assert!(!findings.is_valid());
+}
+
+#[test]
+fn every_rule_runs_after_a_failing_one() {
+	let doc = Document::from_xml_str(CREDIT_TRANSFER).unwrap();
+	let failing = from_fn("failing", Severity::Error, |_, validator| {
+		validator.report(ValidationError::rule("First", "first".to_string()))?;
+		validator.report(ValidationError::rule("Second", "second".to_string()))
+	});
+	let passing = from_fn("passing", Severity::Error, |_, _| Ok(()));
+	let findings = RuleSet::new().with(failing).with(passing).with(MessageRules).apply(&credit_transfer("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>3</NbOfTxs>"));
+	assert_eq!(findings.findings.iter().map(|finding| rule_name(&finding.error)).collect::<Vec<_>>(), ["First", "Second", "NumberOfTransactionsRule"]);
+	assert_eq!(RuleSet::new().with(MessageRules).apply(&doc).findings.len(), 0);
+}
+
+#[test]
+fn duplicate_ids_are_applied_as_separate_rules() {
+	let doc = credit_transfer("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>2</NbOfTxs>");
+	let findings = RuleSet::new().with(MessageRules).with_severity(MessageRules, Severity::Warning).apply(&doc);
+	assert_eq!(rules(&findings), [("message-rules", Severity::Error, NB_OF_TXS), ("message-rules", Severity::Warning, NB_OF_TXS)]);
+	assert_eq!(findings.errors().count(), 1);
+	assert_eq!(findings.warnings().count(), 1);
+}
+
+#[test]
+fn severity_overrides_the_one_of_the_rule() {
+	let doc = credit_transfer("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>2</NbOfTxs>");
+	let findings = RuleSet::new().with_severity(MessageRules, Severity::Warning).apply(&doc);
+	assert_eq!(rules(&findings), [("message-rules", Severity::Warning, NB_OF_TXS)]);
+	assert!(findings.is_valid());
+
+	let warning = from_fn("warning", Severity::Warning, |_, validator| validator.report(ValidationError::new(0, "raised".to_string())));
+	let findings = RuleSet::new().with_severity(warning, Severity::Error).apply(&doc);
+	assert_eq!(findings.findings[0].severity, Severity::Error);
+	assert!(!findings.is_valid());
+}
+
+#[test]
+fn message_rules_report_the_rule_and_its_element() {
+	let findings = RuleSet::new().with(MessageRules).apply(&credit_transfer("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>2</NbOfTxs>"));
+	assert_eq!(rules(&findings), [("message-rules", Severity::Error, NB_OF_TXS)]);
+	assert_eq!(rule_name(&findings.findings[0].error), "NumberOfTransactionsRule");
+	assert_eq!(findings.findings[0].error.code, 1015);
+
+	let doc = credit_transfer("<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>1</NbOfTxs><CtrlSum>99.00</CtrlSum>");
+	let findings = RuleSet::new().with(MessageRules).apply(&doc);
+	assert_eq!(rules(&findings), [("message-rules", Severity::Error, "/Document/FIToFICstmrCdtTrf/GrpHdr/CtrlSum")]);
+	assert_eq!(rule_name(&findings.findings[0].error), "ControlSumRule");
+}
+
+#[test]
+#[cfg(not(feature = "typed_text"))]
+fn built_in_rules_report_each_their_own_findings() {
+	let doc = violating_every_rule();
+	let findings = RuleSet::standard().with(CbprPlus).apply(&doc);
+	assert_eq!(
+		rules(&findings),
+		[
+			("schema", Severity::Error, "/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId"),
+			("message-rules", Severity::Error, NB_OF_TXS),
+			("cbpr-plus", Severity::Error, "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/Cdtr/PstlAdr"),
+		]
+	);
+	assert_eq!(rule_name(&findings.findings[2].error), "CBPR_TownNameAndCountryRule");
+
+	// The schema rule leaves the message rules to their own rule, so that none is reported twice.
+	let findings = RuleSet::new().with(Schema).apply(&doc);
+	assert_eq!(rules(&findings), [("schema", Severity::Error, "/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId")]);
+}
+
+#[test]
+#[cfg(not(feature = "typed_text"))]
+fn standard_rules_find_what_validation_with_rules_finds() {
+	let doc = violating_every_rule();
+	let findings = RuleSet::standard().apply(&doc);
+	let mut validator = Validator::accumulate().with_rules();
+	doc.validate_with(&mut validator).unwrap();
+	let errors = validator.into_report().errors;
+	assert_eq!(findings.findings.iter().map(|finding| &finding.error.path).collect::<Vec<_>>(), errors.iter().map(|error| &error.path).collect::<Vec<_>>());
+}
+
+#[test]
+fn cbpr_plus_reports_messages_it_does_not_apply_to() {
+	// camt.053.001.08 is not compiled in, so the message is kept unknown and checked from its XML.
+	let doc = Document::from_xml_str(&CREDIT_TRANSFER.replace("pacs.008.001.12", "camt.053.001.08")).unwrap();
+	let findings = RuleSet::new().with(CbprPlus).apply(&doc);
+	assert_eq!(rules(&findings), [("cbpr-plus", Severity::Error, "/Document")]);
+	assert_eq!(findings.findings[0].error.message, "CBPR+ does not apply to camt.053.001.08");
+}
@@ -0,0 +1,335 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+#![cfg(all(feature = "derive_serde", any(feature = "pacs", feature = "pain", feature = "camt")))]
+
+use iso20022_common::{ValidationErrorKind, ValidationReport};
+use open_payments_iso20022::document::Document;
+
+// The rule and path of every violation of the cross-field rules of `xml`.
+fn violations(xml: &str) -> Vec<(String, String)> {
+	let doc = Document::from_xml_str(xml).unwrap();
+	let report = ValidationReport::collect(|validator| doc.validate_rules(validator));
+	report
+		.errors
+		.into_iter()
+		.map(|error| match *error.kind {
+			ValidationErrorKind::Rule { rule } => (rule, error.path),
+			kind => panic!("{:?} at {}", kind, error.path),
+		})
+		.collect()
+}
+
+// The violations of `xml` once its first `from` is replaced by `to`.
+fn edited(xml: &str, from: &str, to: &str) -> Vec<(String, String)> {
+	assert!(xml.contains(from), "{}", from);
+	violations(&xml.replacen(from, to, 1))
+}
+
+fn violation(rule: &str, path: &str) -> (String, String) {
+	(rule.to_string(), path.to_string())
+}
+
+#[cfg(feature = "pacs")]
+mod pacs {
+	use super::*;
+
+	const CREDIT_TRANSFER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12">
+	<FIToFICstmrCdtTrf>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+			<NbOfTxs>1</NbOfTxs>
+			<IntrBkSttlmDt>2024-05-02</IntrBkSttlmDt>
+			<SttlmInf>
+				<SttlmMtd>INDA</SttlmMtd>
+			</SttlmInf>
+		</GrpHdr>
+		<CdtTrfTxInf>
+			<PmtId>
+				<EndToEndId>E2E-1</EndToEndId>
+			</PmtId>
+			<IntrBkSttlmAmt Ccy="EUR">100.00</IntrBkSttlmAmt>
+			<ChrgBr>SHAR</ChrgBr>
+			<Dbtr>
+				<Nm>Debtor</Nm>
+			</Dbtr>
+			<DbtrAgt>
+				<FinInstnId>
+					<BICFI>AAAAGB2L</BICFI>
+				</FinInstnId>
+			</DbtrAgt>
+			<CdtrAgt>
+				<FinInstnId>
+					<BICFI>BBBBDEFF</BICFI>
+				</FinInstnId>
+			</CdtrAgt>
+			<Cdtr>
+				<Nm>Creditor</Nm>
+			</Cdtr>
+		</CdtTrfTxInf>
+	</FIToFICstmrCdtTrf>
+</Document>
+"#;
+
+	const GROUP_HEADER: &str = "/Document/FIToFICstmrCdtTrf/GrpHdr";
+	const TRANSACTION: &str = "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]";
+
+	#[test]
+	fn valid_message_has_no_violations() {
+		assert!(violations(CREDIT_TRANSFER).is_empty());
+	}
+
+	#[test]
+	fn number_of_transactions_counts_the_transactions() {
+		assert_eq!(
+			edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>2</NbOfTxs>"),
+			[violation("NumberOfTransactionsRule", &format!("{}/NbOfTxs", GROUP_HEADER))]
+		);
+	}
+
+	#[test]
+	fn control_sum_sums_the_amounts() {
+		assert!(edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>1</NbOfTxs><CtrlSum>100</CtrlSum>").is_empty());
+		assert_eq!(
+			edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", "<NbOfTxs>1</NbOfTxs><CtrlSum>99.99</CtrlSum>"),
+			[violation("ControlSumRule", &format!("{}/CtrlSum", GROUP_HEADER))]
+		);
+	}
+
+	#[test]
+	fn total_amount_sums_the_amounts_in_their_currency() {
+		let total = |amount: &str| format!("<NbOfTxs>1</NbOfTxs><TtlIntrBkSttlmAmt Ccy=\"{}\">{}</TtlIntrBkSttlmAmt>", &amount[..3], &amount[4..]);
+		assert!(edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", &total("EUR 100.00")).is_empty());
+		assert_eq!(
+			edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", &total("EUR 90.00")),
+			[violation("TotalInterbankSettlementAmountAndSumRule", &format!("{}/TtlIntrBkSttlmAmt", GROUP_HEADER))]
+		);
+		assert_eq!(
+			edited(CREDIT_TRANSFER, "<NbOfTxs>1</NbOfTxs>", &total("USD 100.00")),
+			[violation("TotalInterbankSettlementAmountRule", &format!("{}/TtlIntrBkSttlmAmt", GROUP_HEADER))]
+		);
+	}
+
+	#[test]
+	fn settlement_date_is_given_once() {
+		let amount = r#"<IntrBkSttlmAmt Ccy="EUR">100.00</IntrBkSttlmAmt>"#;
+		let with_date = format!("{}<IntrBkSttlmDt>2024-05-02</IntrBkSttlmDt>", amount);
+		assert_eq!(
+			edited(CREDIT_TRANSFER, amount, &with_date),
+			[violation("GroupHeaderInterbankSettlementDateRule", &format!("{}/IntrBkSttlmDt", TRANSACTION))]
+		);
+		assert_eq!(
+			edited(CREDIT_TRANSFER, "<IntrBkSttlmDt>2024-05-02</IntrBkSttlmDt>", ""),
+			[violation("TransactionInterbankSettlementDateRule", &format!("{}/IntrBkSttlmDt", TRANSACTION))]
+		);
+	}
+}
+
+#[cfg(feature = "pain")]
+mod pain {
+	use super::*;
+
+	// The group header and the payment information block give the same count and sum in different forms.
+	const INITIATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pain.001.001.12">
+	<CstmrCdtTrfInitn>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+			<NbOfTxs>2</NbOfTxs>
+			<CtrlSum>150.00</CtrlSum>
+			<InitgPty>
+				<Nm>Initiator</Nm>
+			</InitgPty>
+		</GrpHdr>
+		<PmtInf>
+			<PmtInfId>PMT-1</PmtInfId>
+			<PmtMtd>TRF</PmtMtd>
+			<NbOfTxs>02</NbOfTxs>
+			<CtrlSum>150</CtrlSum>
+			<ReqdExctnDt>
+				<Dt>2024-05-02</Dt>
+			</ReqdExctnDt>
+			<Dbtr>
+				<Nm>Debtor</Nm>
+			</Dbtr>
+			<DbtrAcct>
+				<Id>
+					<IBAN>DE89370400440532013000</IBAN>
+				</Id>
+			</DbtrAcct>
+			<DbtrAgt>
+				<FinInstnId>
+					<BICFI>AAAAGB2L</BICFI>
+				</FinInstnId>
+			</DbtrAgt>
+			<CdtTrfTxInf>
+				<PmtId>
+					<EndToEndId>E2E-1</EndToEndId>
+				</PmtId>
+				<Amt>
+					<InstdAmt Ccy="EUR">100.00</InstdAmt>
+				</Amt>
+			</CdtTrfTxInf>
+			<CdtTrfTxInf>
+				<PmtId>
+					<EndToEndId>E2E-2</EndToEndId>
+				</PmtId>
+				<Amt>
+					<InstdAmt Ccy="EUR">50.00</InstdAmt>
+				</Amt>
+			</CdtTrfTxInf>
+		</PmtInf>
+	</CstmrCdtTrfInitn>
+</Document>
+"#;
+
+	#[test]
+	fn valid_message_has_no_violations() {
+		assert!(violations(INITIATION).is_empty());
+	}
+
+	#[test]
+	fn group_header_counts_and_sums_every_transaction() {
+		assert_eq!(
+			edited(INITIATION, "<NbOfTxs>2</NbOfTxs>", "<NbOfTxs>3</NbOfTxs>"),
+			[violation("NumberOfTransactionsRule", "/Document/CstmrCdtTrfInitn/GrpHdr/NbOfTxs")]
+		);
+		assert_eq!(
+			edited(INITIATION, "<CtrlSum>150.00</CtrlSum>", "<CtrlSum>100.00</CtrlSum>"),
+			[violation("ControlSumRule", "/Document/CstmrCdtTrfInitn/GrpHdr/CtrlSum")]
+		);
+	}
+
+	#[test]
+	fn payment_information_counts_and_sums_its_transactions() {
+		assert_eq!(
+			edited(INITIATION, "<NbOfTxs>02</NbOfTxs>", "<NbOfTxs>1</NbOfTxs>"),
+			[violation("NumberOfTransactionsRule", "/Document/CstmrCdtTrfInitn/PmtInf[1]/NbOfTxs")]
+		);
+		assert_eq!(
+			edited(INITIATION, "<CtrlSum>150</CtrlSum>", "<CtrlSum>150.01</CtrlSum>"),
+			[violation("ControlSumRule", "/Document/CstmrCdtTrfInitn/PmtInf[1]/CtrlSum")]
+		);
+	}
+}
+
+#[cfg(feature = "camt")]
+mod camt {
+	use super::*;
+
+	const STATEMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.12">
+	<BkToCstmrStmt>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+		</GrpHdr>
+		<Stmt>
+			<Id>STMT-1</Id>
+			<Acct>
+				<Id>
+					<IBAN>DE89370400440532013000</IBAN>
+				</Id>
+			</Acct>
+			<Bal>
+				<Tp>
+					<CdOrPrtry>
+						<Cd>CLBD</Cd>
+					</CdOrPrtry>
+				</Tp>
+				<Amt Ccy="EUR">1050.00</Amt>
+				<CdtDbtInd>CRDT</CdtDbtInd>
+				<Dt>
+					<Dt>2024-05-01</Dt>
+				</Dt>
+			</Bal>
+			<TxsSummry>
+				<TtlNtries>
+					<NbOfNtries>2</NbOfNtries>
+					<Sum>150.00</Sum>
+				</TtlNtries>
+				<TtlCdtNtries>
+					<NbOfNtries>1</NbOfNtries>
+				</TtlCdtNtries>
+				<TtlDbtNtries>
+					<NbOfNtries>1</NbOfNtries>
+				</TtlDbtNtries>
+			</TxsSummry>
+			<Ntry>
+				<Amt Ccy="EUR">100.00</Amt>
+				<CdtDbtInd>CRDT</CdtDbtInd>
+				<Sts>
+					<Cd>BOOK</Cd>
+				</Sts>
+				<BkTxCd/>
+			</Ntry>
+			<Ntry>
+				<Amt Ccy="EUR">50.00</Amt>
+				<CdtDbtInd>DBIT</CdtDbtInd>
+				<Sts>
+					<Cd>BOOK</Cd>
+				</Sts>
+				<BkTxCd/>
+			</Ntry>
+		</Stmt>
+	</BkToCstmrStmt>
+</Document>
+"#;
+
+	const TOTAL_ENTRIES: &str = "/Document/BkToCstmrStmt/Stmt[1]/TxsSummry/TtlNtries";
+
+	#[test]
+	fn valid_message_has_no_violations() {
+		assert!(violations(STATEMENT).is_empty());
+	}
+
+	#[test]
+	fn total_entries_count_and_sum_the_entries() {
+		assert_eq!(
+			edited(STATEMENT, "<NbOfNtries>2</NbOfNtries>", "<NbOfNtries>3</NbOfNtries>"),
+			[violation("TotalEntriesRule", &format!("{}/NbOfNtries", TOTAL_ENTRIES))]
+		);
+		assert_eq!(
+			edited(STATEMENT, "<Sum>150.00</Sum>", "<Sum>140.00</Sum>"),
+			[violation("TotalEntriesSumRule", &format!("{}/Sum", TOTAL_ENTRIES))]
+		);
+	}
+
+	#[test]
+	fn credit_and_debit_entries_add_up_to_the_entries() {
+		assert_eq!(
+			edited(STATEMENT, "<TtlCdtNtries>\n\t\t\t\t\t<NbOfNtries>1</NbOfNtries>", "<TtlCdtNtries>\n\t\t\t\t\t<NbOfNtries>2</NbOfNtries>"),
+			[violation("TotalCreditAndDebitEntriesRule", "/Document/BkToCstmrStmt/Stmt[1]/TxsSummry")]
+		);
+	}
+
+	#[test]
+	fn paginated_statements_are_not_checked() {
+		let paginated = STATEMENT.replacen("<Id>STMT-1</Id>", "<Id>STMT-1</Id><StmtPgntn><PgNb>1</PgNb><LastPgInd>false</LastPgInd></StmtPgntn>", 1);
+		assert!(edited(&paginated, "<NbOfNtries>2</NbOfNtries>", "<NbOfNtries>3</NbOfNtries>").is_empty());
+	}
+}
@@ -0,0 +1,149 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/iso20022-rs
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/iso20022-rs
+
+use std::fs;
+use std::path::Path;
+use open_payments_iso20022::identifier::MessageIdentifier;
+use open_payments_iso20022::xsd::{SchemaSet, XsdError};
+
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// A pacs.008.001.12 message whose only violation is the `NbOfTxs` of line 7, column 4.
+const NB_OF_TXS_VIOLATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12">
+	<FIToFICstmrCdtTrf>
+		<GrpHdr>
+			<MsgId>MSG-1</MsgId>
+			<CreDtTm>2024-05-01T10:00:00Z</CreDtTm>
+			<NbOfTxs>one</NbOfTxs>
+			<SttlmInf>
+				<SttlmMtd>INDA</SttlmMtd>
+			</SttlmInf>
+		</GrpHdr>
+		<CdtTrfTxInf>
+			<PmtId>
+				<EndToEndId>E2E-1</EndToEndId>
+			</PmtId>
+			<IntrBkSttlmAmt Ccy="EUR">100.00</IntrBkSttlmAmt>
+			<ChrgBr>SHAR</ChrgBr>
+			<Dbtr>
+				<Nm>Debtor</Nm>
+			</Dbtr>
+			<DbtrAgt>
+				<FinInstnId>
+					<BICFI>AAAAGB2L</BICFI>
+				</FinInstnId>
+			</DbtrAgt>
+			<CdtrAgt>
+				<FinInstnId>
+					<BICFI>BBBBDEFF</BICFI>
+				</FinInstnId>
+			</CdtrAgt>
+			<Cdtr>
+				<Nm>Creditor</Nm>
+			</Cdtr>
+		</CdtTrfTxInf>
+	</FIToFICstmrCdtTrf>
+</Document>
+"#;
+
+fn schemas() -> SchemaSet {
+	SchemaSet::from_dir(Path::new(ROOT).join("xsd/iso20022")).expect("bundled schemas load")
+}
+
+// The namespace of the bundled schema of the message of `namespace`, which may be another version.
+fn bundled_namespace(schemas: &SchemaSet, namespace: &str) -> Option<String> {
+	let id: MessageIdentifier = namespace.parse().ok()?;
+	schemas
+		.namespaces()
+		.filter_map(|namespace| namespace.parse::<MessageIdentifier>().ok())
+		.filter(|bundled| bundled.business_area == id.business_area && bundled.message == id.message && bundled.variant == id.variant)
+		.max_by_key(|bundled| bundled.version)
+		.map(|bundled| bundled.namespace())
+}
+
+// The samples are of older versions (pacs.008.001.08, camt.056.001.08, ...) than the bundled
+// schemas, so each one is checked against the bundled version of the same message. The only part
+// they differ in is the structured remittance information, reworked since pacs.008.001.08: the
+// `RltdDt` date, the `Yr` of a tax period and the amounts of a referred document.
+#[test]
+fn samples_validate_against_the_bundled_schemas() {
+	let schemas = schemas();
+	let mut valid = 0;
+	let mut paths: Vec<_> = fs::read_dir(Path::new(ROOT).join("samples")).unwrap().map(|entry| entry.unwrap().path()).collect();
+	paths.sort();
+	for path in paths.iter().filter(|path| path.extension().is_some_and(|e| e == "xml")) {
+		let xml = fs::read_to_string(path).unwrap();
+		let Some(namespace) = xml.split("xmlns=\"").nth(1).and_then(|rest| rest.split('"').next()) else {
+			assert!(matches!(schemas.validate(&xml), Err(XsdError::UnknownNamespace(_))), "{}", path.display());
+			continue;
+		};
+		let bundled = bundled_namespace(&schemas, namespace).unwrap_or_else(|| panic!("{}: no bundled schema for {}", path.display(), namespace));
+		let report = schemas.validate(&xml.replace(namespace, &bundled)).unwrap();
+		for violation in &report.violations {
+			assert!(violation.error.path.contains("/RmtInf/Strd/"), "{}: {}", path.display(), violation);
+		}
+		if report.is_valid() {
+			valid += 1;
+		}
+	}
+	assert!(valid > 100, "only {} samples valid", valid);
+}
+
+#[test]
+fn violation_carries_line_and_column() {
+	let report = schemas().validate(NB_OF_TXS_VIOLATION).unwrap();
+	assert_eq!(report.violations.len(), 1, "{}", report);
+	let violation = &report.violations[0];
+	assert_eq!((violation.line, violation.column), (7, 4));
+	assert_eq!(violation.error.path, "/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs");
+	assert_eq!(violation.error.code, 1019);
+}
+
+#[test]
+fn valid_message_has_no_violation() {
+	let xml = NB_OF_TXS_VIOLATION.replace("<NbOfTxs>one</NbOfTxs>", "<NbOfTxs>1</NbOfTxs>");
+	let report = schemas().validate(&xml).unwrap();
+	assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn prefixed_namespace_selects_the_schema() {
+	// Every element name takes the `ns` prefix, end tags are set aside while start tags are renamed.
+	let xml = NB_OF_TXS_VIOLATION
+		.replace(" xmlns=", " xmlns:ns=")
+		.replace("</", "\0")
+		.replace('<', "<ns:")
+		.replace("<ns:?", "<?")
+		.replace('\0', "</ns:");
+	let report = schemas().validate(&xml).unwrap();
+	assert_eq!(report.violations.len(), 1, "{}", report);
+	assert_eq!(report.violations[0].error.path, "/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs");
+}
+
+#[test]
+fn unbound_namespace_is_unknown() {
+	let xml = NB_OF_TXS_VIOLATION.replace(" xmlns=\"urn:iso:std:iso:20022:tech:xsd:pacs.008.001.12\"", "");
+	assert!(matches!(schemas().validate(&xml), Err(XsdError::UnknownNamespace(namespace)) if namespace.is_empty()));
+}